@@ -26,6 +26,7 @@
 #
 
 import os
+import re
 import sys
 import zipfile
 
@@ -41,6 +42,7 @@ COMPOSITION_EXCLUSIONS = "CompositionExclusions%s.txt"
 EASTASIAN_WIDTH = "EastAsianWidth%s.txt"
 UNIHAN = "Unihan%s.zip"
 DERIVED_CORE_PROPERTIES = "DerivedCoreProperties%s.txt"
+PROP_LIST = "PropList%s.txt"
 DERIVEDNORMALIZATION_PROPS = "DerivedNormalizationProps%s.txt"
 LINE_BREAK = "LineBreak%s.txt"
 NAME_ALIASES = "NameAliases%s.txt"
@@ -60,6 +62,9 @@ PUA_16 = range(0x100000, 0x10FFFE)
 NAME_ALIASES_START = 0xF0000
 NAMED_SEQUENCES_START = 0xF0200
 
+NAME_ALIAS_TYPE_NAMES = [ "correction", "control", "alternate", "figment",
+    "abbreviation" ]
+
 old_versions = []
 
 CATEGORY_NAMES = [ "Cc", "Cf", "Cn", "Co", "Cs", "Ll", "Lm", "Lo", "Lt",
@@ -113,6 +118,12 @@ LINEBREAK_CLASSES = [ "OP", "CL", "CP", "QU", "GL", "NS", "EX", "SY",
 
 BIDI_PAIRED_BRACKET_TYPES = [ "o", "c", "n"]
 
+# decomposition prefixes, the empty prefix is used for canonical decompositions
+DECOMPOSITION_TYPE_NAMES = [ "", "<noBreak>", "<compat>", "<super>",
+    "<fraction>", "<sub>", "<font>", "<circle>", "<wide>", "<vertical>",
+    "<square>", "<isolated>", "<final>", "<initial>", "<medial>", "<small>",
+    "<narrow>" ]
+
 # note: should match definitions in Objects/unicodectype.c
 ALPHA_MASK = 0x01
 DECIMAL_MASK = 0x02
@@ -129,6 +140,16 @@ NUMERIC_MASK = 0x800
 CASE_IGNORABLE_MASK = 0x1000
 CASED_MASK = 0x2000
 EXTENDED_CASE_MASK = 0x4000
+SOFT_DOTTED_MASK = 0x8000
+
+# context conditions of conditional case mappings, "Not_" conditions are
+# negated with CASING_CONDITION_NOT
+CASING_CONDITION_NAMES = [ "", "Final_Sigma", "After_Soft_Dotted",
+    "More_Above", "Before_Dot", "After_I" ]
+CASING_CONDITION_NOT = 0x80
+
+# language tailorings of conditional case mappings
+CASE_TAILORING_LANGUAGES = { "": 0, "tr": 1, "az": 1, "lt": 2 }
 
 # these ranges need to match unicodedata.c:is_unified_ideograph
 cjk_ranges = [
@@ -140,12 +161,17 @@ cjk_ranges = [
     ('2B820', '2CEA1')
 ]
 
+# ranges of ideographs with names derived from their codepoints
+ideograph_ranges = [(first, last, 'CJK UNIFIED IDEOGRAPH-') for first, last in cjk_ranges] + [
+    ('17000', '187EC', 'TANGUT IDEOGRAPH-')
+]
+
 def maketables(trace=0):
 
     print("--- Reading", UNICODE_DATA % "", "...")
 
     version = ""
-    unicode = UnicodeData(UNIDATA_VERSION)
+    unicode = UnicodeData(UNIDATA_VERSION, named_seq=True)
 
     print(len(list(filter(None, unicode.table))), "characters")
 
@@ -155,9 +181,9 @@ def maketables(trace=0):
         print(len(list(filter(None, old_unicode.table))), "characters")
         merge_old_version(version, unicode, old_unicode)
 
-    #makeunicodename(unicode, trace)
+    makeunicodename(unicode, trace)
     makeunicodedata(unicode, trace)
-    #makeunicodetype(unicode, trace)
+    makeunicodetype(unicode, trace)
 
 # --------------------------------------------------------------------
 # unicode character properties
@@ -166,7 +192,7 @@ def makeunicodedata(unicode, trace):
 
     dummy = (CATEGORY_NAMES.index("Cn"), 0, BIDIRECTIONAL_NAMES.index("ON"), 0,
         EASTASIANWIDTH_NAMES.index("N"), SCRIPT_NAMES.index("Unknown"),
-        LINEBREAK_CLASSES.index("XX"))
+        LINEBREAK_CLASSES.index("XX"), 0)
     table = [dummy]
     cache = {0: dummy}
     index = [0] * len(unicode.chars)
@@ -188,9 +214,10 @@ def makeunicodedata(unicode, trace):
             eastasianwidth = EASTASIANWIDTH_NAMES.index(record[15])
             scriptname = SCRIPT_NAMES.index(record[18])
             linebreakclass = LINEBREAK_CLASSES.index(record[19])
+            quickcheck = record[17]
             item = (
                 category, combining, bidirectional, mirrored, eastasianwidth,
-                scriptname, linebreakclass
+                scriptname, linebreakclass, quickcheck
                 )
             # add entry to index and item tables
             i = cache.get(item)
@@ -202,7 +229,6 @@ def makeunicodedata(unicode, trace):
     # 2) decomposition data
 
     decomp_data = [0]
-    decomp_prefix = [""]
     decomp_index = [0] * len(unicode.chars)
     decomp_size = 0
 
@@ -222,13 +248,7 @@ def makeunicodedata(unicode, trace):
                     prefix = decomp.pop(0)
                 else:
                     prefix = ""
-                try:
-                    i = decomp_prefix.index(prefix)
-                except ValueError:
-                    i = len(decomp_prefix)
-                    decomp_prefix.append(prefix)
-                prefix = i
-                assert prefix < 256
+                prefix = DECOMPOSITION_TYPE_NAMES.index(prefix)
                 # content
                 # XXX: check if all prefixes should be considered for compatibility decomposition
                 decomp = [prefix + (len(decomp)<<8)] + [int(s, 16) for s in decomp]
@@ -255,6 +275,45 @@ def makeunicodedata(unicode, trace):
                 i = 0
             decomp_index[char] = i
 
+    # 3) full canonical decompositions
+
+    def full_decomposition(char):
+        decomp = unicode.table[char][5].split()
+        if not decomp or decomp[0][0] == "<":
+            return [char]
+        return sum([full_decomposition(int(s, 16)) for s in decomp], [])
+
+    full_decomp = []
+    for char in unicode.chars:
+        record = unicode.table[char]
+        if record and record[5] and record[5][0] != "<":
+            decomp = full_decomposition(char)
+            # only store decompositions that need more than one step
+            if len(decomp) > len(record[5].split()):
+                assert len(decomp) <= 4
+                full_decomp.append((char, decomp + [0] * (4 - len(decomp))))
+
+    # 4) NFKC_Casefold mappings
+
+    nfkc_cf_data = [0]
+    nfkc_cf_cache = {}
+    nfkc_cf_index = [0] * len(unicode.chars)
+
+    for char in unicode.chars:
+        mapping = unicode.nfkc_casefold.get(char)
+        if mapping is not None:
+            i = nfkc_cf_cache.get(tuple(mapping))
+            if i is None:
+                mapping_utf16 = []
+                for code in mapping:
+                    if code < 0x10000: mapping_utf16.append(code)
+                    else: mapping_utf16.extend(encode_utf16_surrogates(code))
+                # the low byte marks an existing (possibly empty) mapping
+                i = nfkc_cf_cache[tuple(mapping)] = len(nfkc_cf_data)
+                nfkc_cf_data.append((len(mapping_utf16) << 8) + 1)
+                nfkc_cf_data.extend(mapping_utf16)
+            nfkc_cf_index[char] = i
+
     f = l = 0
     comp_first_ranges = []
     comp_last_ranges = []
@@ -292,9 +351,10 @@ def makeunicodedata(unicode, trace):
         comp_data[f*total_last+l] = char
 
     print(len(table), "unique properties")
-    print(len(decomp_prefix), "unique decomposition prefixes")
     print(len(decomp_data), "unique decomposition entries:", end=' ')
     print(decomp_size, "bytes")
+    print(len(full_decomp), "multi-step canonical decompositions")
+    print(len(unicode.nfkc_casefold), "NFKC_Casefold mappings,", len(nfkc_cf_data), "entries")
     print(total_first, "first characters in NFC")
     print(total_last, "last characters in NFC")
     print(len(comp_pairs), "NFC pairs")
@@ -312,7 +372,7 @@ def makeunicodedata(unicode, trace):
     print("/* a list of unique database records */", file=fp)
     print("pub static UCD_RECORDS: [UCDRecord; %d] = [" % len(table), file=fp)
     for item in table:
-        print("    UCDRecord{category: %d, combining: %d, bidi_class: %d, mirrored: %d, east_asian_width: %d, script: %d, linebreak_class: %d}," % item, file=fp)
+        print("    UCDRecord{category: %d, combining: %d, bidi_class: %d, mirrored: %d, east_asian_width: %d, script: %d, linebreak_class: %d, quickcheck: %d}," % item, file=fp)
     print("];", file=fp)
     print(file=fp)
 
@@ -402,13 +462,6 @@ def makeunicodedata(unicode, trace):
     print("}", file=fp)
     print(file=fp)
 
-    """
-    print("static const char *decomp_prefix[] = {", file=fp)
-    for name in decomp_prefix:
-        print("    \"%s\"," % name, file=fp)
-    print("    NULL", file=fp)
-    print("};", file=fp)
-    """
 
     index0, index1, index2, shift1, shift2 = get_best_split(index)
     print("/* index tables for the database records */", file=fp)
@@ -429,6 +482,24 @@ def makeunicodedata(unicode, trace):
     Array("DECOMP_INDEX1", index1).dump(fp, trace)
     Array("DECOMP_INDEX2", index2).dump(fp, trace)
 
+    print("/* full canonical decompositions that need more than one step */", file=fp)
+    print("pub static FULL_DECOMP: [FullDecomp; %d] = [" % len(full_decomp), file=fp)
+    for char, decomp in full_decomp:
+        print("    FullDecomp{code: %d, decomposed: [%d, %d, %d, %d]}," % ((char,) + tuple(decomp)), file=fp)
+    print("];", file=fp)
+    print(file=fp)
+
+    index0, index1, index2, shift1, shift2 = get_best_split(nfkc_cf_index)
+    print("/* NFKC_Casefold mapping data */", file=fp)
+    Array("NFKC_CF_DATA", nfkc_cf_data).dump(fp, trace)
+
+    print("/* index tables for the NFKC_Casefold mapping data */", file=fp)
+    print("#[allow(dead_code)] pub const NFKC_CF_SHIFT1: u32 = %d;" % shift1, file=fp)
+    print("#[allow(dead_code)] pub const NFKC_CF_SHIFT2: u32 = %d;" % shift2, file=fp)
+    Array("NFKC_CF_INDEX0", index0).dump(fp, trace)
+    Array("NFKC_CF_INDEX1", index1).dump(fp, trace)
+    Array("NFKC_CF_INDEX2", index2).dump(fp, trace)
+
     index0, index1, index2, shift1, shift2 = get_best_split(comp_data)
     print("/* NFC pairs */", file=fp)
     print("#[allow(dead_code)] pub const COMP_SHIFT1: u32 = %d;" % shift1, file=fp)
@@ -483,19 +554,27 @@ def makeunicodedata(unicode, trace):
 
 def makeunicodetype(unicode, trace):
 
-    FILE = "unicodetype_db.h"
+    # the type tables are appended to the file written by makeunicodedata
+    FILE = "src/db.rs"
 
     print("--- Preparing", FILE, "...")
 
     # extract unicode types
-    dummy = (0, 0, 0, 0, 0, 0)
+    dummy = (0, 0, 0, 0, 0, 0, 0)
     table = [dummy]
     cache = {0: dummy}
     index = [0] * len(unicode.chars)
+    types = {}
     numeric = {}
-    spaces = []
-    linebreaks = []
-    extra_casing = []
+    extended_case = [0]
+
+    def extended_case_entry(mappings):
+        # a header with the length of each mapping, followed by the mappings
+        i = len(extended_case)
+        extended_case.append(sum(len(m) << (8 * n) for n, m in enumerate(mappings)))
+        for m in mappings:
+            extended_case.extend(m)
+        return i
 
     for char in unicode.chars:
         record = unicode.table[char]
@@ -505,17 +584,14 @@ def makeunicodetype(unicode, trace):
             bidirectional = record[4]
             properties = record[16]
             flags = 0
-            delta = True
             if category in ["Lm", "Lt", "Lu", "Ll", "Lo"]:
                 flags |= ALPHA_MASK
             if "Lowercase" in properties:
                 flags |= LOWER_MASK
             if 'Line_Break' in properties or bidirectional == "B":
                 flags |= LINEBREAK_MASK
-                linebreaks.append(char)
             if category == "Zs" or bidirectional in ("WS", "B", "S"):
                 flags |= SPACE_MASK
-                spaces.append(char)
             if category == "Lt":
                 flags |= TITLE_MASK
             if "Uppercase" in properties:
@@ -530,8 +606,7 @@ def makeunicodetype(unicode, trace):
                 flags |= CASED_MASK
             if "Case_Ignorable" in properties:
                 flags |= CASE_IGNORABLE_MASK
-            sc = unicode.special_casing.get(char)
-            cf = unicode.case_folding.get(char, [char])
+            # simple case mappings and simple case folding, stored as deltas
             if record[12]:
                 upper = int(record[12], 16)
             else:
@@ -544,151 +619,124 @@ def makeunicodetype(unicode, trace):
                 title = int(record[14], 16)
             else:
                 title = upper
+            fold = unicode.case_folding_simple.get(char, char)
+            # unconditional full case mappings and full case folding go to
+            # the extended case array, unless they are simple mappings
+            sc = unicode.special_casing.get(char)
+            cf = unicode.case_folding.get(char, [char])
             if sc is None and cf != [lower]:
                 sc = ([lower], [title], [upper])
-            if sc is None:
-                if upper == lower == title:
-                    upper = lower = title = 0
-                else:
-                    upper = upper - char
-                    lower = lower - char
-                    title = title - char
-                    assert (abs(upper) <= 2147483647 and
-                            abs(lower) <= 2147483647 and
-                            abs(title) <= 2147483647)
-            else:
-                # This happens either when some character maps to more than one
-                # character in uppercase, lowercase, or titlecase or the
-                # casefolded version of the character is different from the
-                # lowercase. The extra characters are stored in a different
-                # array.
+            extended = 0
+            if sc is not None:
                 flags |= EXTENDED_CASE_MASK
-                lower = len(extra_casing) | (len(sc[0]) << 24)
-                extra_casing.extend(sc[0])
-                if cf != sc[0]:
-                    lower |= len(cf) << 20
-                    extra_casing.extend(cf)
-                upper = len(extra_casing) | (len(sc[2]) << 24)
-                extra_casing.extend(sc[2])
-                # Title is probably equal to upper.
-                if sc[1] == sc[2]:
-                    title = upper
-                else:
-                    title = len(extra_casing) | (len(sc[1]) << 24)
-                    extra_casing.extend(sc[1])
-            # decimal digit, integer digit
-            decimal = 0
+                extended = extended_case_entry(sc + (cf,))
+            upper = upper - char
+            lower = lower - char
+            title = title - char
+            fold = fold - char
+            if "Soft_Dotted" in properties:
+                flags |= SOFT_DOTTED_MASK
+            # decimal digit, integer digit, numeric value
             if record[6]:
                 flags |= DECIMAL_MASK
-                decimal = int(record[6])
-            digit = 0
             if record[7]:
                 flags |= DIGIT_MASK
-                digit = int(record[7])
             if record[8]:
                 flags |= NUMERIC_MASK
-                numeric.setdefault(record[8], []).append(char)
-            item = (
-                upper, lower, title, decimal, digit, flags
-                )
-            # add entry to index and item tables
-            i = cache.get(item)
-            if i is None:
-                cache[item] = i = len(table)
-                table.append(item)
-            index[char] = i
+                numerator, _, denominator = record[8].partition('/')
+                numeric[record[8]] = (int(numerator), int(denominator or 1))
+            types[char] = (upper, lower, title, fold, extended, record[8], flags)
+
+    # numeric values are stored as fractions, ordered by value
+    numeric_values = sorted(numeric.values(), key=lambda v: v[0] / v[1])
+    numeric_index = {v: i for i, v in enumerate(numeric_values)}
+
+    for char, (upper, lower, title, fold, extended, value, flags) in types.items():
+        item = (upper, lower, title, fold, extended, numeric_index[numeric[value]] if value else 0, flags)
+        # add entry to index and item tables
+        i = cache.get(item)
+        if i is None:
+            cache[item] = i = len(table)
+            table.append(item)
+        index[char] = i
 
     print(len(table), "unique character type entries")
-    print(sum(map(len, numeric.values())), "numeric code points")
-    print(len(spaces), "whitespace code points")
-    print(len(linebreaks), "linebreak code points")
-    print(len(extra_casing), "extended case array")
+    print(sum(1 for item in types.values() if item[5]), "numeric code points")
+    print(len(numeric_values), "unique numeric values")
+
+    # conditional case mappings, ordered by code point
+    conditional = []
+    for char, language, condition, mappings in unicode.special_casing_conditional:
+        language = CASE_TAILORING_LANGUAGES[language]
+        if condition.startswith("Not_"):
+            condition = CASING_CONDITION_NAMES.index(condition[4:]) | CASING_CONDITION_NOT
+        else:
+            condition = CASING_CONDITION_NAMES.index(condition)
+        # languages sharing a tailoring (tr and az) have identical entries
+        if (char, language, condition, mappings) not in conditional:
+            conditional.append((char, language, condition, mappings))
+    conditional.sort(key=lambda item: item[0])
+    conditional = [item[:3] + (extended_case_entry(item[3]),) for item in conditional]
+
+    print(len(extended_case), "extended case array")
+    print(len(conditional), "conditional case mappings")
 
     print("--- Writing", FILE, "...")
 
-    fp = open(FILE, "w")
-    print("/* this file was generated by %s %s */" % (SCRIPT, VERSION), file=fp)
+    fp = open(FILE, "a")
+    print("/* character type flags */", file=fp)
+    for name in ("ALPHA", "DECIMAL", "DIGIT", "LOWER", "LINEBREAK", "SPACE",
+                 "TITLE", "UPPER", "XID_START", "XID_CONTINUE", "PRINTABLE",
+                 "NUMERIC", "CASE_IGNORABLE", "CASED", "EXTENDED_CASE",
+                 "SOFT_DOTTED"):
+        print("#[allow(dead_code)] pub const %s_MASK: u16 = 0x%04x;" % (name, globals()[name + "_MASK"]), file=fp)
     print(file=fp)
+
     print("/* a list of unique character type descriptors */", file=fp)
-    print("const _PyUnicode_TypeRecord _PyUnicode_TypeRecords[] = {", file=fp)
+    print("pub static TYPE_RECORDS: [TypeRecord; %d] = [" % len(table), file=fp)
     for item in table:
-        print("    {%d, %d, %d, %d, %d, %d}," % item, file=fp)
-    print("};", file=fp)
+        print("    TypeRecord{upper: %d, lower: %d, title: %d, fold: %d, extended: %d, numeric: %d, flags: %d}," % item, file=fp)
+    print("];", file=fp)
     print(file=fp)
 
     print("/* extended case mappings */", file=fp)
-    print(file=fp)
-    print("const Py_UCS4 _PyUnicode_ExtendedCase[] = {", file=fp)
-    for c in extra_casing:
-        print("    %d," % c, file=fp)
-    print("};", file=fp)
+    Array("EXTENDED_CASE", extended_case).dump(fp, trace)
+
+    print("/* Turkic case folding */", file=fp)
+    print("pub static TURKIC_FOLDING: [CaseFolding; %d] = [" % len(unicode.case_folding_turkic), file=fp)
+    for item in sorted(unicode.case_folding_turkic.items()):
+        print("    CaseFolding{code: %d, folded: %d}," % item, file=fp)
+    print("];", file=fp)
     print(file=fp)
 
-    # split decomposition index table
-    index1, index2, shift = splitbins(index, trace)
-
-    print("/* type indexes */", file=fp)
-    print("#define SHIFT", shift, file=fp)
-    Array("index1", index1).dump(fp, trace)
-    Array("index2", index2).dump(fp, trace)
-
-    # Generate code for _PyUnicode_ToNumeric()
-    numeric_items = sorted(numeric.items())
-    print('/* Returns the numeric value as double for Unicode characters', file=fp)
-    print(' * having this property, -1.0 otherwise.', file=fp)
-    print(' */', file=fp)
-    print('double _PyUnicode_ToNumeric(Py_UCS4 ch)', file=fp)
-    print('{', file=fp)
-    print('    switch (ch) {', file=fp)
-    for value, codepoints in numeric_items:
-        # Turn text into float literals
-        parts = value.split('/')
-        parts = [repr(float(part)) for part in parts]
-        value = '/'.join(parts)
-
-        codepoints.sort()
-        for codepoint in codepoints:
-            print('    case 0x%04X:' % (codepoint,), file=fp)
-        print('        return (double) %s;' % (value,), file=fp)
-    print('    }', file=fp)
-    print('    return -1.0;', file=fp)
-    print('}', file=fp)
+    print("/* conditions of conditional case mappings */", file=fp)
+    for i, name in enumerate(CASING_CONDITION_NAMES[1:]):
+        print("#[allow(dead_code)] pub const CONDITION_%s: u8 = %d;" % (name.upper(), i + 1), file=fp)
+    print("#[allow(dead_code)] pub const CONDITION_NOT: u8 = 0x%02x;" % CASING_CONDITION_NOT, file=fp)
     print(file=fp)
 
-    # Generate code for _PyUnicode_IsWhitespace()
-    print("/* Returns 1 for Unicode characters having the bidirectional", file=fp)
-    print(" * type 'WS', 'B' or 'S' or the category 'Zs', 0 otherwise.", file=fp)
-    print(" */", file=fp)
-    print('int _PyUnicode_IsWhitespace(register const Py_UCS4 ch)', file=fp)
-    print('{', file=fp)
-    print('    switch (ch) {', file=fp)
-
-    for codepoint in sorted(spaces):
-        print('    case 0x%04X:' % (codepoint,), file=fp)
-    print('        return 1;', file=fp)
-
-    print('    }', file=fp)
-    print('    return 0;', file=fp)
-    print('}', file=fp)
+    print("/* conditional case mappings */", file=fp)
+    print("pub static CONDITIONAL_CASING: [ConditionalCasing; %d] = [" % len(conditional), file=fp)
+    for item in conditional:
+        print("    ConditionalCasing{code: %d, language: %d, condition: %d, mapping: %d}," % item, file=fp)
+    print("];", file=fp)
     print(file=fp)
 
-    # Generate code for _PyUnicode_IsLinebreak()
-    print("/* Returns 1 for Unicode characters having the line break", file=fp)
-    print(" * property 'BK', 'CR', 'LF' or 'NL' or having bidirectional", file=fp)
-    print(" * type 'B', 0 otherwise.", file=fp)
-    print(" */", file=fp)
-    print('int _PyUnicode_IsLinebreak(register const Py_UCS4 ch)', file=fp)
-    print('{', file=fp)
-    print('    switch (ch) {', file=fp)
-    for codepoint in sorted(linebreaks):
-        print('    case 0x%04X:' % (codepoint,), file=fp)
-    print('        return 1;', file=fp)
-
-    print('    }', file=fp)
-    print('    return 0;', file=fp)
-    print('}', file=fp)
+    print("/* numeric values as fractions */", file=fp)
+    print("pub static NUMERIC_VALUES: [NumericValue; %d] = [" % len(numeric_values), file=fp)
+    for value in numeric_values:
+        print("    NumericValue{numerator: %d, denominator: %d}," % value, file=fp)
+    print("];", file=fp)
     print(file=fp)
 
+    index0, index1, index2, shift1, shift2 = get_best_split(index)
+    print("/* index tables for the character type descriptors */", file=fp)
+    print("#[allow(dead_code)] pub const TYPE_SHIFT1: u32 = %d;" % shift1, file=fp)
+    print("#[allow(dead_code)] pub const TYPE_SHIFT2: u32 = %d;" % shift2, file=fp)
+    Array("TYPE_INDEX0", index0).dump(fp, trace)
+    Array("TYPE_INDEX1", index1).dump(fp, trace)
+    Array("TYPE_INDEX2", index2).dump(fp, trace)
+
     fp.close()
 
 # --------------------------------------------------------------------
@@ -696,7 +744,7 @@ def makeunicodetype(unicode, trace):
 
 def makeunicodename(unicode, trace):
 
-    FILE = "unicodename_db.h"
+    FILE = "src/name_db.rs"
 
     print("--- Preparing", FILE, "...")
 
@@ -705,7 +753,7 @@ def makeunicodename(unicode, trace):
 
     for char in unicode.chars:
         record = unicode.table[char]
-        if record:
+        if record and record[2] != "Cn":
             name = record[1].strip()
             if name and name[0] != "<":
                 names[char] = name + chr(0)
@@ -811,78 +859,97 @@ def makeunicodename(unicode, trace):
     #
     # unicode name hash table
 
-    # extract names
+    # extract names, with loose matching keys
     data = []
     for char in unicode.chars:
-        record = unicode.table[char]
-        if record:
-            name = record[1].strip()
-            if name and name[0] != "<":
-                data.append((name, char))
+        if names[char]:
+            data.append((loose_name(names[char][:-1]), char))
+    assert len(set(key for key, char in data)) == len(data), "loose names are not unique"
 
     # the magic number 47 was chosen to minimize the number of
     # collisions on the current data set.  if you like, change it
     # and see what happens...
 
-    codehash = Hash("code", data, 47)
+    namehash = Hash("NAME", data, 47)
+
+    #
+    # word index for name search
+
+    # collect the characters using each word, aliases count for the
+    # character they belong to, named sequences are left out
+    postings = {}
+    for char in unicode.chars:
+        name = names[char]
+        if name and not NAMED_SEQUENCES_START <= char < NAMED_SEQUENCES_START + len(unicode.named_sequences):
+            if NAME_ALIASES_START <= char < NAME_ALIASES_START + len(unicode.aliases):
+                char = unicode.aliases[char - NAME_ALIASES_START][1]
+            for w in name.split():
+                postings.setdefault(w.rstrip(chr(0)), set()).add(char)
+
+    # words are sorted by their text, so words with a common prefix
+    # are adjacent, and refer to the lexicon
+    search_words = []
+    search_offset = [0]
+    search_postings = []
+    for w in sorted(postings):
+        search_words.append(words[w] if w in words else words[w + chr(0)])
+        search_postings.extend(sorted(postings[w]))
+        search_offset.append(len(search_postings))
+
+    print(len(search_words), "words in search index,", len(search_postings), "postings")
 
     print("--- Writing", FILE, "...")
 
     fp = open(FILE, "w")
     print("/* this file was generated by %s %s */" % (SCRIPT, VERSION), file=fp)
-    print(file=fp)
-    print("#define NAME_MAXLEN", 256, file=fp)
+    print("use db_types::*;", file=fp)
     print(file=fp)
     print("/* lexicon */", file=fp)
-    Array("lexicon", lexicon).dump(fp, trace)
-    Array("lexicon_offset", lexicon_offset).dump(fp, trace)
-
-    # split decomposition index table
-    offset1, offset2, shift = splitbins(phrasebook_offset, trace)
+    Array("LEXICON", lexicon).dump(fp, trace)
+    Array("LEXICON_OFFSET", lexicon_offset).dump(fp, trace)
 
+    index0, index1, index2, shift1, shift2 = get_best_split(phrasebook_offset)
     print("/* code->name phrasebook */", file=fp)
-    print("#define phrasebook_shift", shift, file=fp)
-    print("#define phrasebook_short", short, file=fp)
+    print("pub const PHRASEBOOK_SHORT: u8 = %d;" % short, file=fp)
+    Array("PHRASEBOOK", phrasebook).dump(fp, trace)
 
-    Array("phrasebook", phrasebook).dump(fp, trace)
-    Array("phrasebook_offset1", offset1).dump(fp, trace)
-    Array("phrasebook_offset2", offset2).dump(fp, trace)
+    print("/* index tables for the phrasebook */", file=fp)
+    print("#[allow(dead_code)] pub const PHRASEBOOK_SHIFT1: u32 = %d;" % shift1, file=fp)
+    print("#[allow(dead_code)] pub const PHRASEBOOK_SHIFT2: u32 = %d;" % shift2, file=fp)
+    Array("PHRASEBOOK_INDEX0", index0).dump(fp, trace)
+    Array("PHRASEBOOK_INDEX1", index1).dump(fp, trace)
+    Array("PHRASEBOOK_INDEX2", index2).dump(fp, trace)
 
     print("/* name->code dictionary */", file=fp)
-    codehash.dump(fp, trace)
-
+    namehash.dump(fp, trace)
+
+    print("/* word->code index for name search */", file=fp)
+    Array("SEARCH_WORDS", search_words).dump(fp, trace)
+    Array("SEARCH_OFFSET", search_offset).dump(fp, trace)
+    Array("SEARCH_POSTINGS", search_postings).dump(fp, trace)
+
+    print("/* name aliases, named in the phrasebook at their PUA codepoints */", file=fp)
+    print("pub const NAME_ALIASES_START: u32 = %#x;" % NAME_ALIASES_START, file=fp)
+    print("pub static NAME_ALIASES: [NameAlias; %d] = [" % len(unicode.aliases), file=fp)
+    for name, codepoint, kind in unicode.aliases:
+        print("    NameAlias{code: %d, alias_type: %d}," % (codepoint, NAME_ALIAS_TYPE_NAMES.index(kind)), file=fp)
+    print("];", file=fp)
     print(file=fp)
-    print('static const unsigned int aliases_start = %#x;' %
-          NAME_ALIASES_START, file=fp)
-    print('static const unsigned int aliases_end = %#x;' %
-          (NAME_ALIASES_START + len(unicode.aliases)), file=fp)
-
-    print('static const unsigned int name_aliases[] = {', file=fp)
-    for name, codepoint in unicode.aliases:
-        print('    0x%04X,' % codepoint, file=fp)
-    print('};', file=fp)
-
-    # In Unicode 6.0.0, the sequences contain at most 4 BMP chars,
-    # so we are using Py_UCS2 seq[4].  This needs to be updated if longer
-    # sequences or sequences with non-BMP chars are added.
-    # unicodedata_lookup should be adapted too.
-    print(dedent("""
-        typedef struct NamedSequence {
-            int seqlen;
-            Py_UCS2 seq[4];
-        } named_sequence;
-        """), file=fp)
-
-    print('static const unsigned int named_sequences_start = %#x;' %
-          NAMED_SEQUENCES_START, file=fp)
-    print('static const unsigned int named_sequences_end = %#x;' %
-          (NAMED_SEQUENCES_START + len(unicode.named_sequences)), file=fp)
-
-    print('static const named_sequence named_sequences[] = {', file=fp)
+
+    print("/* named sequences, named in the phrasebook at their PUA codepoints */", file=fp)
+    print("pub const NAMED_SEQUENCES_START: u32 = %#x;" % NAMED_SEQUENCES_START, file=fp)
+    print("pub static NAMED_SEQUENCES: [NamedSequence; %d] = [" % len(unicode.named_sequences), file=fp)
     for name, sequence in unicode.named_sequences:
-        seq_str = ', '.join('0x%04X' % cp for cp in sequence)
-        print('    {%d, {%s}},' % (len(sequence), seq_str), file=fp)
-    print('};', file=fp)
+        padded = list(sequence) + [0] * (4 - len(sequence))
+        print("    NamedSequence{len: %d, seq: [%s]}," % (len(sequence), ", ".join(map(str, padded))), file=fp)
+    print("];", file=fp)
+    print(file=fp)
+
+    print("/* ideographs with names derived from their codepoints */", file=fp)
+    print("pub static IDEOGRAPH_RANGES: [NameRange; %d] = [" % len(ideograph_ranges), file=fp)
+    for first, last, prefix in ideograph_ranges:
+        print('    NameRange{start: %d, end: %d, prefix: "%s"},' % (int(first, 16), int(last, 16), prefix), file=fp)
+    print("];", file=fp)
 
     fp.close()
 
@@ -1066,7 +1133,7 @@ class UnicodeData:
         # aliases and named sequences are not in 3.2.0
         if version != '3.2.0' and named_seq:
             self.aliases = []
-            # store aliases in the Private Use Area 15, in range U+F0000..U+F00FF,
+            # store aliases in the Private Use Area 15, in range U+F0000..U+F01FF,
             # in order to take advantage of the compression and lookup
             # algorithms used for the other characters
             pua_index = NAME_ALIASES_START
@@ -1075,9 +1142,9 @@ class UnicodeData:
                     s = s.strip()
                     if not s or s.startswith('#'):
                         continue
-                    char, name, abbrev = s.split(';')
+                    char, name, kind = s.split(';')
                     char = int(char, 16)
-                    self.aliases.append((name, char))
+                    self.aliases.append((name, char, kind))
                     # also store the name in the PUA 1
                     self.table[pua_index][1] = name
                     pua_index += 1
@@ -1098,9 +1165,7 @@ class UnicodeData:
                     name, chars = s.split(';')
                     chars = tuple(int(char, 16) for char in chars.split())
                     # check that the structure defined in makeunicodename is OK
-                    assert 2 <= len(chars) <= 4, "change the Py_UCS2 array size"
-                    assert all(c <= 0xFFFF for c in chars), ("use Py_UCS4 in "
-                        "the NamedSequence struct and in unicodedata_lookup")
+                    assert 2 <= len(chars) <= 4, "change the NamedSequence array size"
                     self.named_sequences.append((name, chars))
                     # also store these in the PUA 1
                     self.table[pua_index][1] = name
@@ -1143,25 +1208,26 @@ class UnicodeData:
             if table[i] is not None:
                 table[i].append(set())
 
-        with open_data(DERIVED_CORE_PROPERTIES, version) as file:
-            for s in file:
-                s = s.split('#', 1)[0].strip()
-                if not s:
-                    continue
+        for filename in (DERIVED_CORE_PROPERTIES, PROP_LIST):
+            with open_data(filename, version) as file:
+                for s in file:
+                    s = s.split('#', 1)[0].strip()
+                    if not s:
+                        continue
 
-                r, p = s.split(";")
-                r = r.strip()
-                p = p.strip()
-                if ".." in r:
-                    first, last = [int(c, 16) for c in r.split('..')]
-                    chars = list(range(first, last+1))
-                else:
-                    chars = [int(r, 16)]
-                for char in chars:
-                    if table[char]:
-                        # Some properties (e.g. Default_Ignorable_Code_Point)
-                        # apply to unassigned code points; ignore them
-                        table[char][-1].add(p)
+                    r, p = s.split(";")
+                    r = r.strip()
+                    p = p.strip()
+                    if ".." in r:
+                        first, last = [int(c, 16) for c in r.split('..')]
+                        chars = list(range(first, last+1))
+                    else:
+                        chars = [int(r, 16)]
+                    for char in chars:
+                        if table[char]:
+                            # Some properties (e.g. Default_Ignorable_Code_Point)
+                            # apply to unassigned code points; ignore them
+                            table[char][-1].add(p)
 
         with open_data(LINE_BREAK, version) as file:
             for s in file:
@@ -1183,13 +1249,24 @@ class UnicodeData:
         # The parsing will incorrectly determine these as
         # "yes", however, unicodedata.c will not perform quickchecks
         # for older versions, and no delta records will be created.
+        # NFKC_Casefold mappings are collected as well
+        # Format: NFKC_CF; mapping (possibly empty)
         quickchecks = [0] * 0x110000
         qc_order = 'NFD_QC NFKD_QC NFC_QC NFKC_QC'.split()
+        nfkc_casefold = self.nfkc_casefold = {}
         with open_data(DERIVEDNORMALIZATION_PROPS, version) as file:
             for s in file:
                 if '#' in s:
                     s = s[:s.index('#')]
                 s = [i.strip() for i in s.split(';')]
+                if len(s) == 3 and s[1] == 'NFKC_CF':
+                    if '..' not in s[0]:
+                        first = last = int(s[0], 16)
+                    else:
+                        first, last = [int(c, 16) for c in s[0].split('..')]
+                    for char in range(first, last+1):
+                        nfkc_casefold[char] = [int(c, 16) for c in s[2].split()]
+                    continue
                 if len(s) < 2 or s[1] not in qc_order:
                     continue
                 quickcheck = 'MN'.index(s[2]) + 1 # Maybe or No
@@ -1259,23 +1336,32 @@ class UnicodeData:
             if table[i] is not None:
                 table[i][8] = value
         sc = self.special_casing = {}
+        scc = self.special_casing_conditional = []
         with open_data(SPECIAL_CASING, version) as file:
             for s in file:
                 s = s[:-1].split('#', 1)[0]
                 if not s:
                     continue
                 data = s.split("; ")
-                if data[4]:
-                    # We ignore all conditionals (since they depend on
-                    # languages) except for one, which is hardcoded. See
-                    # handle_capital_sigma in unicodeobject.c.
-                    continue
                 c = int(data[0], 16)
                 lower = [int(char, 16) for char in data[1].split()]
                 title = [int(char, 16) for char in data[2].split()]
                 upper = [int(char, 16) for char in data[3].split()]
+                if data[4]:
+                    # Conditional mappings are kept in a separate list,
+                    # with an optional language and context condition
+                    language = condition = ""
+                    for item in data[4].split():
+                        if item in CASE_TAILORING_LANGUAGES:
+                            language = item
+                        else:
+                            condition = item
+                    scc.append((c, language, condition, (lower, title, upper)))
+                    continue
                 sc[c] = (lower, title, upper)
         cf = self.case_folding = {}
+        scf = self.case_folding_simple = {}
+        tcf = self.case_folding_turkic = {}
         if version != '3.2.0':
             with open_data(CASE_FOLDING, version) as file:
                 for s in file:
@@ -1283,9 +1369,13 @@ class UnicodeData:
                     if not s:
                         continue
                     data = s.split("; ")
+                    c = int(data[0], 16)
                     if data[1] in "CF":
-                        c = int(data[0], 16)
                         cf[c] = [int(char, 16) for char in data[2].split()]
+                    if data[1] in "CS":
+                        scf[c] = int(data[2], 16)
+                    if data[1] == "T":
+                        tcf[c] = int(data[2], 16)
 
         with open_data(BIDI_MIRRORING, version) as file:
             for s in file:
@@ -1306,6 +1396,16 @@ class UnicodeData:
         # restrict character range to ISO Latin 1
         self.chars = list(range(256))
 
+# loose matching of character names as defined in UAX44-LM2: ignore
+# case, whitespace, underscores and medial hyphens, except for the
+# hyphen in U+1180 HANGUL JUNGSEONG O-E
+
+def loose_name(name):
+    strict = re.sub(r"[\s_]", "", name).upper()
+    if strict == "HANGULJUNGSEONGO-E":
+        return strict
+    return re.sub(r"[\s_]", "", re.sub(r"(?<=[A-Za-z0-9])-(?=[A-Za-z0-9])", "", name)).upper()
+
 # hash table tools
 
 # this is a straight-forward reimplementation of Python's built-in
@@ -1379,18 +1479,18 @@ class Hash:
             if table[i] is None:
                 table[i] = 0
 
-        self.data = Array(name + "_hash", table)
+        self.data = Array(name + "_HASH", table)
         self.magic = magic
         self.name = name
         self.size = size
         self.poly = poly
 
     def dump(self, file, trace):
-        # write data to file, as a C array
+        # write data to file, as a Rust array
         self.data.dump(file, trace)
-        file.write("#define %s_magic %d\n" % (self.name, self.magic))
-        file.write("#define %s_size %d\n" % (self.name, self.size))
-        file.write("#define %s_poly %d\n" % (self.name, self.poly))
+        file.write("pub const %s_HASH_MAGIC: u32 = %d;\n" % (self.name, self.magic))
+        file.write("pub const %s_HASH_SIZE: u32 = %d;\n" % (self.name, self.size))
+        file.write("pub const %s_HASH_POLY: u32 = %d;\n\n" % (self.name, self.poly))
 
 # stuff to deal with arrays of unsigned integers
 
@@ -0,0 +1,515 @@
+/* Copyright (c) 2017 Grigori Goronzy <greg@chown.ath.cx>
+ * 
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ * 
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ * 
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Case mapping as defined in chapter 3.13 of the Unicode core specification.
+
+use super::*;
+use std::char;
+
+/**
+ * Get simple uppercase mapping of a codepoint as defined in UAX#44.
+ * Codepoints without an uppercase mapping map to themselves.
+ *
+ */
+pub fn to_upper(code: u32) -> Result<u32, &'static str> {
+    match get_type_record(code) {
+        Ok(v) => Ok((code as i32 + v.upper) as u32),
+        Err(e) => Err(e)
+    }
+}
+
+/**
+ * Get simple lowercase mapping of a codepoint as defined in UAX#44.
+ * Codepoints without a lowercase mapping map to themselves.
+ *
+ */
+pub fn to_lower(code: u32) -> Result<u32, &'static str> {
+    match get_type_record(code) {
+        Ok(v) => Ok((code as i32 + v.lower) as u32),
+        Err(e) => Err(e)
+    }
+}
+
+/**
+ * Get simple titlecase mapping of a codepoint as defined in UAX#44.
+ * Codepoints without a titlecase mapping map to themselves.
+ *
+ */
+pub fn to_title(code: u32) -> Result<u32, &'static str> {
+    match get_type_record(code) {
+        Ok(v) => Ok((code as i32 + v.title) as u32),
+        Err(e) => Err(e)
+    }
+}
+
+/* Full case mappings and case folding, in the order they are stored in
+ * an extended case entry */
+#[derive(Copy, Clone, PartialEq)]
+enum CaseMapping {
+    Lower = 0,
+    Title = 1,
+    Upper = 2,
+    Fold = 3
+}
+
+fn type_flags(code: u32) -> u16 {
+    match get_type_record(code) {
+        Ok(v) => v.flags,
+        Err(_) => 0
+    }
+}
+
+fn is_cased_code(code: u32) -> bool {
+    type_flags(code) & db::CASED_MASK != 0
+}
+
+fn is_case_ignorable_code(code: u32) -> bool {
+    type_flags(code) & db::CASE_IGNORABLE_MASK != 0
+}
+
+/* Get one mapping of an extended case entry. The entry starts with the
+ * lengths of its mappings, one byte each, followed by the mappings. */
+fn get_extended_case(index: usize, mapping: CaseMapping) -> &'static [u32] {
+    let header = db::EXTENDED_CASE[index];
+    let mut start = index + 1;
+    for i in 0..mapping as u32 {
+        start += ((header >> (8 * i)) & 0xff) as usize;
+    }
+    let len = ((header >> (8 * mapping as u32)) & 0xff) as usize;
+    &db::EXTENDED_CASE[start..start + len]
+}
+
+/* Scan the context of a codepoint for a codepoint satisfying a
+ * condition, giving up at the first codepoint that blocks the scan */
+fn scan_context<'a, I, F, G>(context: I, found: F, blocked: G) -> bool
+    where I: Iterator<Item=&'a u32>, F: Fn(u32) -> bool, G: Fn(u32) -> bool {
+    for &code in context {
+        if found(code) {
+            return true
+        }
+        if blocked(code) {
+            return false
+        }
+    }
+    false
+}
+
+fn is_ccc_0_or_230(code: u32) -> bool {
+    matches!(get_combining_class(code), Ok(0) | Ok(230))
+}
+
+/* Evaluate a casing context condition as defined in table 3-17 of the
+ * Unicode core specification */
+fn condition_applies(condition: u8, text: &[u32], i: usize) -> bool {
+    let before = || text[..i].iter().rev();
+    let after = || text[i + 1..].iter();
+    let result = match condition & !db::CONDITION_NOT {
+        db::CONDITION_FINAL_SIGMA =>
+            scan_context(before(), is_cased_code, |c| !is_case_ignorable_code(c)) &&
+            !scan_context(after(), is_cased_code, |c| !is_case_ignorable_code(c)),
+        db::CONDITION_AFTER_SOFT_DOTTED =>
+            scan_context(before(), |c| type_flags(c) & db::SOFT_DOTTED_MASK != 0, is_ccc_0_or_230),
+        db::CONDITION_MORE_ABOVE =>
+            scan_context(after(), |c| get_combining_class(c) == Ok(230), |c| get_combining_class(c) == Ok(0)),
+        db::CONDITION_BEFORE_DOT =>
+            scan_context(after(), |c| c == 0x0307, is_ccc_0_or_230),
+        db::CONDITION_AFTER_I =>
+            scan_context(before(), |c| c == 0x0049, is_ccc_0_or_230),
+        _ => true
+    };
+    result != (condition & db::CONDITION_NOT != 0)
+}
+
+fn push_codes(out: &mut String, codes: &[u32]) {
+    out.extend(codes.iter().filter_map(|&c| char::from_u32(c)));
+}
+
+/* Apply a full case mapping to the codepoint at position i of a text,
+ * taking conditional mappings for its context into account */
+fn map_case(text: &[u32], i: usize, mapping: CaseMapping, tailoring: CaseTailoring, out: &mut String) {
+    let code = text[i];
+    for entry in db::CONDITIONAL_CASING.iter().filter(|e| e.code == code) {
+        if (entry.language == 0 || entry.language == tailoring as u8) &&
+            (entry.condition == 0 || condition_applies(entry.condition, text, i)) {
+            push_codes(out, get_extended_case(entry.mapping as usize, mapping));
+            return
+        }
+    }
+    map_unconditional(code, mapping, out);
+}
+
+/* Apply the unconditional full case mapping or case folding of a
+ * codepoint */
+fn map_unconditional(code: u32, mapping: CaseMapping, out: &mut String) {
+    match get_type_record(code) {
+        Ok(v) if v.flags & db::EXTENDED_CASE_MASK != 0 =>
+            push_codes(out, get_extended_case(v.extended as usize, mapping)),
+        Ok(v) => push_codes(out, &[(code as i32 + get_case_delta(&v, mapping)) as u32]),
+        Err(_) => push_codes(out, &[code])
+    }
+}
+
+/* Get the delta of a simple case mapping, which is also the full
+ * mapping for codepoints without an extended case entry */
+fn get_case_delta(record: &TypeRecord, mapping: CaseMapping) -> i32 {
+    match mapping {
+        CaseMapping::Lower | CaseMapping::Fold => record.lower,
+        CaseMapping::Title => record.title,
+        CaseMapping::Upper => record.upper
+    }
+}
+
+fn convert_case<F>(s: &str, tailoring: CaseTailoring, mut select: F) -> String
+    where F: FnMut(&[u32], usize) -> CaseMapping {
+    let text: Vec<u32> = s.chars().map(|c| c as u32).collect();
+    let mut out = String::with_capacity(s.len());
+    for i in 0..text.len() {
+        let mapping = select(&text, i);
+        map_case(&text, i, mapping, tailoring, &mut out);
+    }
+    out
+}
+
+/* Classes for word segmentation when titlecasing. The default word
+ * boundaries of UAX#29 are approximated with the General_Category:
+ * cased characters, letters, numbers and connectors form words, marks
+ * and format characters extend the preceding character, and
+ * case-ignorable punctuation like the apostrophe does not break a word
+ * between two word characters. */
+#[derive(Copy, Clone, PartialEq)]
+enum WordClass {
+    Word,
+    Extend,
+    Mid,
+    Other
+}
+
+fn get_word_class(code: u32) -> WordClass {
+    if is_cased_code(code) {
+        return WordClass::Word
+    }
+    match get_general_category(code) {
+        Ok(GeneralCategory::LU) | Ok(GeneralCategory::LL) | Ok(GeneralCategory::LT) |
+        Ok(GeneralCategory::LM) | Ok(GeneralCategory::LO) | Ok(GeneralCategory::NL) |
+        Ok(GeneralCategory::ND) | Ok(GeneralCategory::PC) => WordClass::Word,
+        Ok(GeneralCategory::MN) | Ok(GeneralCategory::MC) | Ok(GeneralCategory::ME) |
+        Ok(GeneralCategory::CF) => WordClass::Extend,
+        Ok(GeneralCategory::PD) | Ok(GeneralCategory::PS) | Ok(GeneralCategory::PE) |
+        Ok(GeneralCategory::PI) | Ok(GeneralCategory::PF) | Ok(GeneralCategory::PO)
+            if is_case_ignorable_code(code) => WordClass::Mid,
+        _ => WordClass::Other
+    }
+}
+
+fn get_turkic_folding(code: u32) -> Option<u32> {
+    db::TURKIC_FOLDING.iter().find(|f| f.code == code).map(|f| f.folded)
+}
+
+/**
+ * Convert a string to lowercase with the full case mappings of
+ * SpecialCasing.txt, including context-dependent mappings like
+ * Final_Sigma, and the language tailorings for Turkish and Azeri (tr, az)
+ * or Lithuanian (lt).
+ *
+ */
+pub fn to_lowercase_tailored(s: &str, tailoring: CaseTailoring) -> String {
+    convert_case(s, tailoring, |_, _| CaseMapping::Lower)
+}
+
+/**
+ * Convert a string to uppercase with the full case mappings of
+ * SpecialCasing.txt and a language tailoring. See
+ * to_lowercase_tailored().
+ *
+ */
+pub fn to_uppercase_tailored(s: &str, tailoring: CaseTailoring) -> String {
+    convert_case(s, tailoring, |_, _| CaseMapping::Upper)
+}
+
+/**
+ * Convert a string to titlecase with the full case mappings of
+ * SpecialCasing.txt and a language tailoring. The first cased character
+ * of each word is mapped to titlecase, all other characters are mapped
+ * to lowercase. With the Dutch tailoring (nl), a word starting with
+ * "ij" is titlecased as "IJ".
+ *
+ */
+pub fn to_titlecase_tailored(s: &str, tailoring: CaseTailoring) -> String {
+    let mut in_word = false;
+    let mut last = WordClass::Other;
+    let mut seen_cased = false;
+    let mut dutch_ij = false;
+    convert_case(s, tailoring, |text, i| {
+        let code = text[i];
+        match get_word_class(code) {
+            WordClass::Word => {
+                if !in_word {
+                    in_word = true;
+                    seen_cased = false;
+                }
+                last = WordClass::Word;
+            },
+            WordClass::Extend => {},
+            WordClass::Mid => {
+                let next = text[i + 1..].iter().map(|&c| get_word_class(c))
+                    .find(|&c| c != WordClass::Extend);
+                in_word = in_word && last == WordClass::Word && next == Some(WordClass::Word);
+                last = WordClass::Mid;
+            },
+            WordClass::Other => {
+                in_word = false;
+                last = WordClass::Other;
+            }
+        }
+
+        if dutch_ij && (code == 0x004a || code == 0x006a) {
+            dutch_ij = false;
+            return CaseMapping::Upper
+        }
+        dutch_ij = false;
+        if in_word && !seen_cased && is_cased_code(code) {
+            seen_cased = true;
+            dutch_ij = tailoring == CaseTailoring::Dutch && (code == 0x0049 || code == 0x0069);
+            CaseMapping::Title
+        } else {
+            CaseMapping::Lower
+        }
+    })
+}
+
+/**
+ * Convert a string to lowercase with the full case mappings of
+ * SpecialCasing.txt, without language tailoring.
+ *
+ */
+pub fn to_lowercase(s: &str) -> String {
+    to_lowercase_tailored(s, CaseTailoring::None)
+}
+
+/**
+ * Convert a string to uppercase with the full case mappings of
+ * SpecialCasing.txt, without language tailoring.
+ *
+ */
+pub fn to_uppercase(s: &str) -> String {
+    to_uppercase_tailored(s, CaseTailoring::None)
+}
+
+/**
+ * Convert a string to titlecase with the full case mappings of
+ * SpecialCasing.txt, without language tailoring.
+ *
+ */
+pub fn to_titlecase(s: &str) -> String {
+    to_titlecase_tailored(s, CaseTailoring::None)
+}
+
+/**
+ * Get simple case folding of a codepoint as defined in UAX#44, using
+ * the mappings with status C and S of CaseFolding.txt. Codepoints
+ * without a case folding map to themselves.
+ *
+ */
+pub fn fold_case_simple(code: u32) -> Result<u32, &'static str> {
+    fold_case_simple_tailored(code, CaseTailoring::None)
+}
+
+/**
+ * Get simple case folding of a codepoint with a language tailoring. The
+ * Turkic tailoring uses the mappings with status T of CaseFolding.txt
+ * for dotted and dotless I, other tailorings do not change case folding.
+ *
+ */
+pub fn fold_case_simple_tailored(code: u32, tailoring: CaseTailoring) -> Result<u32, &'static str> {
+    if tailoring == CaseTailoring::Turkic {
+        if let Some(folded) = get_turkic_folding(code) {
+            return Ok(folded)
+        }
+    }
+    match get_type_record(code) {
+        Ok(v) => Ok((code as i32 + v.fold) as u32),
+        Err(e) => Err(e)
+    }
+}
+
+/**
+ * Apply full case folding to a string as defined in UAX#44, using the
+ * mappings with status C and F of CaseFolding.txt.
+ *
+ */
+pub fn fold_case_full(s: &str) -> String {
+    fold_case_full_tailored(s, CaseTailoring::None)
+}
+
+/**
+ * Apply full case folding to a string with a language tailoring. See
+ * fold_case_simple_tailored().
+ *
+ */
+pub fn fold_case_full_tailored(s: &str, tailoring: CaseTailoring) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        let code = c as u32;
+        match get_turkic_folding(code) {
+            Some(folded) if tailoring == CaseTailoring::Turkic => push_codes(&mut out, &[folded]),
+            _ => map_unconditional(code, CaseMapping::Fold, &mut out)
+        }
+    }
+    out
+}
+
+fn canonical_caseless_key(s: &str) -> String {
+    to_nfd(&fold_case_full(&to_nfd(s)))
+}
+
+fn compatibility_caseless_key(s: &str) -> String {
+    to_nfkd(&fold_case_full(&to_nfkd(&fold_case_full(&to_nfd(s)))))
+}
+
+/**
+ * Check if two strings are caseless matches as defined in definition
+ * D144 of the Unicode core specification. Both strings are compared
+ * after full case folding, without normalization.
+ *
+ */
+pub fn caseless_eq(a: &str, b: &str) -> bool {
+    fold_case_full(a) == fold_case_full(b)
+}
+
+/**
+ * Check if two strings are canonical caseless matches as defined in
+ * definition D145 of the Unicode core specification, that is
+ * NFD(toCasefold(NFD(a))) == NFD(toCasefold(NFD(b))).
+ *
+ */
+pub fn canonical_caseless_eq(a: &str, b: &str) -> bool {
+    canonical_caseless_key(a) == canonical_caseless_key(b)
+}
+
+/**
+ * Check if two strings are compatibility caseless matches as defined in
+ * definition D146 of the Unicode core specification, that is
+ * NFKD(toCasefold(NFKD(toCasefold(NFD(a))))) ==
+ * NFKD(toCasefold(NFKD(toCasefold(NFD(b))))).
+ *
+ */
+pub fn compatibility_caseless_eq(a: &str, b: &str) -> bool {
+    compatibility_caseless_key(a) == compatibility_caseless_key(b)
+}
+
+/**
+ * Check if a codepoint is cased as defined in definition D135 of the
+ * Unicode core specification.
+ *
+ */
+pub fn is_cased(code: u32) -> Result<bool, &'static str> {
+    match get_type_record(code) {
+        Ok(v) => Ok(v.flags & db::CASED_MASK != 0),
+        Err(e) => Err(e)
+    }
+}
+
+/**
+ * Check if a codepoint is case-ignorable as defined in definition D136
+ * of the Unicode core specification.
+ *
+ */
+pub fn is_case_ignorable(code: u32) -> Result<bool, &'static str> {
+    match get_type_record(code) {
+        Ok(v) => Ok(v.flags & db::CASE_IGNORABLE_MASK != 0),
+        Err(e) => Err(e)
+    }
+}
+
+/* Check if the full case mapping or case folding of the canonical
+ * decomposition of a codepoint differs from the decomposition, as in
+ * the derivation of the Changes_When_* properties in
+ * DerivedCoreProperties.txt */
+fn changes_when(code: u32, mapping: CaseMapping) -> Result<bool, &'static str> {
+    get_type_record(code)?;
+    let (len, decomposed) = decompose_full(code).unwrap_or((1, [code, 0, 0, 0]));
+    let mut seen_cased = false;
+    for &c in &decomposed[..len] {
+        // toTitlecase only maps the first cased character to titlecase
+        let mapping = match mapping {
+            CaseMapping::Title if seen_cased || !is_cased_code(c) => CaseMapping::Lower,
+            mapping => mapping
+        };
+        seen_cased = seen_cased || is_cased_code(c);
+        let changes = match get_type_record(c) {
+            Ok(v) if v.flags & db::EXTENDED_CASE_MASK != 0 =>
+                get_extended_case(v.extended as usize, mapping) != [c],
+            Ok(v) => get_case_delta(&v, mapping) != 0,
+            Err(_) => false
+        };
+        if changes {
+            return Ok(true)
+        }
+    }
+    Ok(false)
+}
+
+/**
+ * Check if a codepoint changes when lowercased as defined in UAX#44
+ * (Changes_When_Lowercased).
+ *
+ */
+pub fn changes_when_lowercased(code: u32) -> Result<bool, &'static str> {
+    changes_when(code, CaseMapping::Lower)
+}
+
+/**
+ * Check if a codepoint changes when uppercased as defined in UAX#44
+ * (Changes_When_Uppercased).
+ *
+ */
+pub fn changes_when_uppercased(code: u32) -> Result<bool, &'static str> {
+    changes_when(code, CaseMapping::Upper)
+}
+
+/**
+ * Check if a codepoint changes when titlecased as defined in UAX#44
+ * (Changes_When_Titlecased).
+ *
+ */
+pub fn changes_when_titlecased(code: u32) -> Result<bool, &'static str> {
+    changes_when(code, CaseMapping::Title)
+}
+
+/**
+ * Check if a codepoint changes when case folded as defined in UAX#44
+ * (Changes_When_Casefolded).
+ *
+ */
+pub fn changes_when_casefolded(code: u32) -> Result<bool, &'static str> {
+    changes_when(code, CaseMapping::Fold)
+}
+
+/**
+ * Check if a codepoint changes when case mapped as defined in UAX#44
+ * (Changes_When_Casemapped), that is if it changes when lowercased,
+ * uppercased or titlecased.
+ *
+ */
+pub fn changes_when_casemapped(code: u32) -> Result<bool, &'static str> {
+    Ok(changes_when_lowercased(code)? || changes_when_uppercased(code)? ||
+       changes_when_titlecased(code)?)
+}
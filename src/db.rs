@@ -4,998 +4,1134 @@ use db_types::*;
 pub const UNIDATA_VERSION: &str = "9.0.0\0";
 
 /* a list of unique database records */
-pub static UCD_RECORDS: [UCDRecord; 991] = [
-    UCDRecord{category: 2, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 102, linebreak_class: 39},
-    UCDRecord{category: 0, combining: 0, bidi_class: 14, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 21},
-    UCDRecord{category: 0, combining: 0, bidi_class: 16, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 17},
-    UCDRecord{category: 0, combining: 0, bidi_class: 15, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 34},
-    UCDRecord{category: 0, combining: 0, bidi_class: 16, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 30},
-    UCDRecord{category: 0, combining: 0, bidi_class: 17, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 30},
-    UCDRecord{category: 0, combining: 0, bidi_class: 15, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 33},
-    UCDRecord{category: 0, combining: 0, bidi_class: 15, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 21},
-    UCDRecord{category: 0, combining: 0, bidi_class: 16, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 21},
-    UCDRecord{category: 29, combining: 0, bidi_class: 17, mirrored: 0, east_asian_width: 3, script: 0, linebreak_class: 38},
-    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 3, script: 0, linebreak_class: 6},
-    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 3, script: 0, linebreak_class: 3},
-    UCDRecord{category: 21, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 3, script: 0, linebreak_class: 12},
-    UCDRecord{category: 23, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 3, script: 0, linebreak_class: 9},
-    UCDRecord{category: 21, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 3, script: 0, linebreak_class: 10},
-    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 3, script: 0, linebreak_class: 12},
-    UCDRecord{category: 22, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 3, script: 0, linebreak_class: 0},
-    UCDRecord{category: 18, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 3, script: 0, linebreak_class: 2},
-    UCDRecord{category: 25, combining: 0, bidi_class: 9, mirrored: 0, east_asian_width: 3, script: 0, linebreak_class: 9},
-    UCDRecord{category: 21, combining: 0, bidi_class: 12, mirrored: 0, east_asian_width: 3, script: 0, linebreak_class: 8},
-    UCDRecord{category: 17, combining: 0, bidi_class: 9, mirrored: 0, east_asian_width: 3, script: 0, linebreak_class: 16},
-    UCDRecord{category: 21, combining: 0, bidi_class: 12, mirrored: 0, east_asian_width: 3, script: 0, linebreak_class: 7},
-    UCDRecord{category: 13, combining: 0, bidi_class: 8, mirrored: 0, east_asian_width: 3, script: 0, linebreak_class: 11},
-    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 3, script: 0, linebreak_class: 8},
-    UCDRecord{category: 25, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 3, script: 0, linebreak_class: 12},
-    UCDRecord{category: 25, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 3, script: 0, linebreak_class: 12},
-    UCDRecord{category: 9, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 3, script: 1, linebreak_class: 12},
-    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 3, script: 0, linebreak_class: 9},
-    UCDRecord{category: 24, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 3, script: 0, linebreak_class: 12},
-    UCDRecord{category: 16, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 3, script: 0, linebreak_class: 12},
-    UCDRecord{category: 5, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 3, script: 1, linebreak_class: 12},
-    UCDRecord{category: 25, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 3, script: 0, linebreak_class: 17},
-    UCDRecord{category: 18, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 3, script: 0, linebreak_class: 1},
-    UCDRecord{category: 0, combining: 0, bidi_class: 15, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 35},
-    UCDRecord{category: 29, combining: 0, bidi_class: 12, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 4},
-    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 0},
-    UCDRecord{category: 23, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 3, script: 0, linebreak_class: 10},
-    UCDRecord{category: 23, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 9},
-    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 3, script: 0, linebreak_class: 12},
-    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 29},
-    UCDRecord{category: 24, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 29},
-    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 4, script: 1, linebreak_class: 29},
-    UCDRecord{category: 20, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 5, script: 0, linebreak_class: 3},
-    UCDRecord{category: 1, combining: 0, bidi_class: 14, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 17},
-    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 12},
-    UCDRecord{category: 26, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 10},
-    UCDRecord{category: 25, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 9},
-    UCDRecord{category: 15, combining: 0, bidi_class: 8, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 29},
-    UCDRecord{category: 24, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 18},
-    UCDRecord{category: 5, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12},
-    UCDRecord{category: 19, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 5, script: 0, linebreak_class: 3},
-    UCDRecord{category: 15, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 29},
-    UCDRecord{category: 9, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 1, linebreak_class: 12},
-    UCDRecord{category: 9, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 4, script: 1, linebreak_class: 12},
-    UCDRecord{category: 25, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 29},
-    UCDRecord{category: 5, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 4, script: 1, linebreak_class: 12},
-    UCDRecord{category: 5, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 1, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 1, linebreak_class: 12},
-    UCDRecord{category: 8, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 1, linebreak_class: 12},
-    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 1, linebreak_class: 12},
-    UCDRecord{category: 6, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12},
-    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12},
-    UCDRecord{category: 24, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12},
-    UCDRecord{category: 24, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 12},
-    UCDRecord{category: 6, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 29},
-    UCDRecord{category: 6, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 18},
-    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 29},
-    UCDRecord{category: 24, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 34, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 4, script: 40, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 232, bidi_class: 13, mirrored: 0, east_asian_width: 4, script: 40, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 220, bidi_class: 13, mirrored: 0, east_asian_width: 4, script: 40, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 216, bidi_class: 13, mirrored: 0, east_asian_width: 4, script: 40, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 202, bidi_class: 13, mirrored: 0, east_asian_width: 4, script: 40, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 1, bidi_class: 13, mirrored: 0, east_asian_width: 4, script: 40, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 240, bidi_class: 13, mirrored: 0, east_asian_width: 4, script: 40, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 4, script: 40, linebreak_class: 4},
-    UCDRecord{category: 12, combining: 233, bidi_class: 13, mirrored: 0, east_asian_width: 4, script: 40, linebreak_class: 4},
-    UCDRecord{category: 12, combining: 234, bidi_class: 13, mirrored: 0, east_asian_width: 4, script: 40, linebreak_class: 4},
-    UCDRecord{category: 9, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 2, linebreak_class: 12},
-    UCDRecord{category: 5, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 2, linebreak_class: 12},
-    UCDRecord{category: 24, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 2, linebreak_class: 12},
-    UCDRecord{category: 2, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 102, linebreak_class: 39},
-    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 2, linebreak_class: 12},
-    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 8},
-    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12},
-    UCDRecord{category: 9, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 4, script: 2, linebreak_class: 12},
-    UCDRecord{category: 5, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 4, script: 2, linebreak_class: 12},
-    UCDRecord{category: 9, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 54, linebreak_class: 12},
-    UCDRecord{category: 5, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 54, linebreak_class: 12},
-    UCDRecord{category: 25, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 2, linebreak_class: 12},
-    UCDRecord{category: 9, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 3, linebreak_class: 12},
-    UCDRecord{category: 9, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 4, script: 3, linebreak_class: 12},
-    UCDRecord{category: 5, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 4, script: 3, linebreak_class: 12},
-    UCDRecord{category: 5, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 3, linebreak_class: 12},
-    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 3, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 3, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 40, linebreak_class: 21},
-    UCDRecord{category: 11, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 3, linebreak_class: 21},
-    UCDRecord{category: 9, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 4, linebreak_class: 12},
-    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 4, linebreak_class: 12},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 4, linebreak_class: 12},
-    UCDRecord{category: 5, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 4, linebreak_class: 12},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 8},
-    UCDRecord{category: 17, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 4, linebreak_class: 17},
-    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 4, linebreak_class: 12},
-    UCDRecord{category: 23, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 5, script: 4, linebreak_class: 9},
-    UCDRecord{category: 12, combining: 220, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 222, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 228, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 10, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 11, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 12, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 13, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 14, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 15, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 16, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 17, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 18, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 19, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 20, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 21, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 22, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 21},
-    UCDRecord{category: 17, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 17},
-    UCDRecord{category: 12, combining: 23, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 21},
-    UCDRecord{category: 21, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 24, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 25, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 21},
-    UCDRecord{category: 21, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 6},
-    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 13},
-    UCDRecord{category: 1, combining: 0, bidi_class: 11, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 12},
-    UCDRecord{category: 1, combining: 0, bidi_class: 11, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12},
-    UCDRecord{category: 25, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 12},
-    UCDRecord{category: 25, combining: 0, bidi_class: 4, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 12},
-    UCDRecord{category: 21, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 10},
-    UCDRecord{category: 23, combining: 0, bidi_class: 4, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 10},
-    UCDRecord{category: 21, combining: 0, bidi_class: 12, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 8},
-    UCDRecord{category: 21, combining: 0, bidi_class: 4, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 8},
-    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 30, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 31, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 32, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 21},
-    UCDRecord{category: 21, combining: 0, bidi_class: 4, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 6},
-    UCDRecord{category: 1, combining: 0, bidi_class: 4, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 21},
-    UCDRecord{category: 21, combining: 0, bidi_class: 4, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 6},
-    UCDRecord{category: 7, combining: 0, bidi_class: 4, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 12},
-    UCDRecord{category: 6, combining: 0, bidi_class: 4, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 27, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 40, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 28, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 40, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 29, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 40, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 30, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 40, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 31, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 40, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 32, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 40, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 33, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 40, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 34, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 40, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 220, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 40, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 220, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 21},
-    UCDRecord{category: 13, combining: 0, bidi_class: 11, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 11},
-    UCDRecord{category: 21, combining: 0, bidi_class: 11, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 11},
-    UCDRecord{category: 21, combining: 0, bidi_class: 4, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 35, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 40, linebreak_class: 21},
-    UCDRecord{category: 6, combining: 0, bidi_class: 4, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 12},
-    UCDRecord{category: 13, combining: 0, bidi_class: 8, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 11},
-    UCDRecord{category: 26, combining: 0, bidi_class: 4, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 12},
-    UCDRecord{category: 21, combining: 0, bidi_class: 4, mirrored: 0, east_asian_width: 5, script: 7, linebreak_class: 12},
-    UCDRecord{category: 1, combining: 0, bidi_class: 4, mirrored: 0, east_asian_width: 5, script: 7, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 4, mirrored: 0, east_asian_width: 5, script: 7, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 36, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 7, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 7, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 220, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 7, linebreak_class: 21},
-    UCDRecord{category: 7, combining: 0, bidi_class: 4, mirrored: 0, east_asian_width: 5, script: 8, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 8, linebreak_class: 21},
-    UCDRecord{category: 13, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 65, linebreak_class: 11},
-    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 65, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 65, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 220, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 65, linebreak_class: 21},
-    UCDRecord{category: 6, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 65, linebreak_class: 12},
-    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 65, linebreak_class: 12},
-    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 65, linebreak_class: 12},
-    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 65, linebreak_class: 8},
-    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 65, linebreak_class: 6},
-    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 81, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 81, linebreak_class: 21},
-    UCDRecord{category: 6, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 81, linebreak_class: 12},
-    UCDRecord{category: 21, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 81, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 94, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 220, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 94, linebreak_class: 21},
-    UCDRecord{category: 21, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 94, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 27, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 28, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 29, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 9, linebreak_class: 21},
-    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 9, linebreak_class: 21},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 9, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 7, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 9, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 9, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 9, linebreak_class: 21},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 17},
-    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 9, linebreak_class: 11},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 9, linebreak_class: 12},
-    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 9, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 10, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 10, linebreak_class: 21},
-    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 10, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 7, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 10, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 10, linebreak_class: 21},
-    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 10, linebreak_class: 11},
-    UCDRecord{category: 23, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 5, script: 10, linebreak_class: 10},
-    UCDRecord{category: 15, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 10, linebreak_class: 12},
-    UCDRecord{category: 15, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 10, linebreak_class: 10},
-    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 10, linebreak_class: 12},
-    UCDRecord{category: 23, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 5, script: 10, linebreak_class: 9},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 11, linebreak_class: 21},
-    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 11, linebreak_class: 21},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 11, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 7, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 11, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 11, linebreak_class: 21},
-    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 11, linebreak_class: 11},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 12, linebreak_class: 21},
-    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 12, linebreak_class: 21},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 12, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 7, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 12, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 12, linebreak_class: 21},
-    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 12, linebreak_class: 11},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 12, linebreak_class: 12},
-    UCDRecord{category: 23, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 5, script: 12, linebreak_class: 9},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 13, linebreak_class: 21},
-    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 13, linebreak_class: 21},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 13, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 7, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 13, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 13, linebreak_class: 21},
-    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 13, linebreak_class: 11},
-    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 13, linebreak_class: 12},
-    UCDRecord{category: 15, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 13, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 14, linebreak_class: 21},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 14, linebreak_class: 12},
-    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 14, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 14, linebreak_class: 21},
-    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 14, linebreak_class: 11},
-    UCDRecord{category: 15, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 14, linebreak_class: 12},
-    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 14, linebreak_class: 12},
-    UCDRecord{category: 23, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 5, script: 14, linebreak_class: 9},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 15, linebreak_class: 21},
-    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 15, linebreak_class: 21},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 15, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 15, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 84, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 15, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 91, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 15, linebreak_class: 21},
-    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 15, linebreak_class: 11},
-    UCDRecord{category: 15, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 15, linebreak_class: 12},
-    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 15, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 16, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 16, linebreak_class: 21},
-    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 16, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 7, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 16, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 16, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 16, linebreak_class: 21},
-    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 16, linebreak_class: 11},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 17, linebreak_class: 21},
-    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 17, linebreak_class: 21},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 17, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 17, linebreak_class: 21},
-    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 17, linebreak_class: 12},
-    UCDRecord{category: 15, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 17, linebreak_class: 12},
-    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 17, linebreak_class: 11},
-    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 17, linebreak_class: 10},
-    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 18, linebreak_class: 21},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 18, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 18, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 18, linebreak_class: 21},
-    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 18, linebreak_class: 11},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 18, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 19, linebreak_class: 36},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 19, linebreak_class: 36},
-    UCDRecord{category: 12, combining: 103, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 19, linebreak_class: 36},
-    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 19, linebreak_class: 36},
-    UCDRecord{category: 23, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 9},
-    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 19, linebreak_class: 36},
-    UCDRecord{category: 12, combining: 107, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 19, linebreak_class: 36},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 19, linebreak_class: 12},
-    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 19, linebreak_class: 11},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 19, linebreak_class: 17},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 20, linebreak_class: 36},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 20, linebreak_class: 36},
-    UCDRecord{category: 12, combining: 118, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 20, linebreak_class: 36},
-    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 20, linebreak_class: 36},
-    UCDRecord{category: 12, combining: 122, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 20, linebreak_class: 36},
-    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 20, linebreak_class: 11},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 21, linebreak_class: 12},
-    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 21, linebreak_class: 18},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 21, linebreak_class: 18},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 21, linebreak_class: 12},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 21, linebreak_class: 4},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 21, linebreak_class: 17},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 21, linebreak_class: 6},
-    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 21, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 220, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 21, linebreak_class: 21},
-    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 21, linebreak_class: 11},
-    UCDRecord{category: 15, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 21, linebreak_class: 12},
-    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 21, linebreak_class: 17},
-    UCDRecord{category: 12, combining: 216, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 21, linebreak_class: 21},
-    UCDRecord{category: 22, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 5, script: 21, linebreak_class: 0},
-    UCDRecord{category: 18, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 5, script: 21, linebreak_class: 1},
-    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 21, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 129, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 21, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 130, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 21, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 21, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 132, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 21, linebreak_class: 21},
-    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 21, linebreak_class: 17},
-    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 21, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 21, linebreak_class: 21},
-    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 22, linebreak_class: 36},
-    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 22, linebreak_class: 36},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 22, linebreak_class: 36},
-    UCDRecord{category: 12, combining: 7, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 22, linebreak_class: 36},
-    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 22, linebreak_class: 36},
-    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 22, linebreak_class: 11},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 22, linebreak_class: 17},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 22, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 220, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 22, linebreak_class: 36},
-    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 22, linebreak_class: 36},
-    UCDRecord{category: 9, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 23, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 23, linebreak_class: 12},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12},
-    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 23, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 24, linebreak_class: 25},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 24, linebreak_class: 26},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 24, linebreak_class: 27},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 25, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 25, linebreak_class: 21},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 25, linebreak_class: 12},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 25, linebreak_class: 17},
-    UCDRecord{category: 15, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 25, linebreak_class: 12},
-    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 25, linebreak_class: 12},
-    UCDRecord{category: 9, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 26, linebreak_class: 12},
-    UCDRecord{category: 5, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 26, linebreak_class: 12},
-    UCDRecord{category: 17, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 27, linebreak_class: 17},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 27, linebreak_class: 12},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 27, linebreak_class: 12},
-    UCDRecord{category: 29, combining: 0, bidi_class: 17, mirrored: 0, east_asian_width: 5, script: 28, linebreak_class: 17},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 28, linebreak_class: 12},
-    UCDRecord{category: 22, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 5, script: 28, linebreak_class: 0},
-    UCDRecord{category: 18, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 5, script: 28, linebreak_class: 1},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 29, linebreak_class: 12},
-    UCDRecord{category: 14, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 29, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 41, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 41, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 41, linebreak_class: 21},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 42, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 42, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 42, linebreak_class: 21},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 43, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 43, linebreak_class: 21},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 44, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 44, linebreak_class: 21},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 30, linebreak_class: 36},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 30, linebreak_class: 36},
-    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 30, linebreak_class: 36},
-    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 30, linebreak_class: 36},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 30, linebreak_class: 17},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 30, linebreak_class: 5},
-    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 30, linebreak_class: 36},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 30, linebreak_class: 12},
-    UCDRecord{category: 23, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 5, script: 30, linebreak_class: 9},
-    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 30, linebreak_class: 36},
-    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 30, linebreak_class: 11},
-    UCDRecord{category: 15, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 30, linebreak_class: 12},
-    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 31, linebreak_class: 12},
-    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 6},
-    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 31, linebreak_class: 17},
-    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 17},
-    UCDRecord{category: 17, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 31, linebreak_class: 18},
-    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 31, linebreak_class: 6},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 31, linebreak_class: 21},
-    UCDRecord{category: 1, combining: 0, bidi_class: 14, mirrored: 0, east_asian_width: 5, script: 31, linebreak_class: 4},
-    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 31, linebreak_class: 11},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 31, linebreak_class: 12},
-    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 31, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 228, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 31, linebreak_class: 21},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 45, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 45, linebreak_class: 21},
-    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 45, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 222, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 45, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 45, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 220, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 45, linebreak_class: 21},
-    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 45, linebreak_class: 12},
-    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 45, linebreak_class: 6},
-    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 45, linebreak_class: 11},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 46, linebreak_class: 36},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 55, linebreak_class: 36},
-    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 55, linebreak_class: 11},
-    UCDRecord{category: 15, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 55, linebreak_class: 36},
-    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 55, linebreak_class: 36},
-    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 30, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 53, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 53, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 220, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 53, linebreak_class: 21},
-    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 53, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 53, linebreak_class: 21},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 53, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 77, linebreak_class: 36},
-    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 77, linebreak_class: 36},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 77, linebreak_class: 36},
-    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 77, linebreak_class: 36},
-    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 77, linebreak_class: 36},
-    UCDRecord{category: 12, combining: 220, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 77, linebreak_class: 21},
-    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 77, linebreak_class: 11},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 77, linebreak_class: 36},
-    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 77, linebreak_class: 36},
-    UCDRecord{category: 11, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 40, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 61, linebreak_class: 21},
-    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 61, linebreak_class: 21},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 61, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 7, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 61, linebreak_class: 21},
-    UCDRecord{category: 10, combining: 9, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 61, linebreak_class: 21},
-    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 61, linebreak_class: 11},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 61, linebreak_class: 17},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 61, linebreak_class: 12},
-    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 61, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 61, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 220, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 61, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 66, linebreak_class: 21},
-    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 66, linebreak_class: 21},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 66, linebreak_class: 12},
-    UCDRecord{category: 10, combining: 9, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 66, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 66, linebreak_class: 21},
-    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 66, linebreak_class: 11},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 92, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 7, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 92, linebreak_class: 21},
-    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 92, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 92, linebreak_class: 21},
-    UCDRecord{category: 10, combining: 9, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 92, linebreak_class: 21},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 92, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 67, linebreak_class: 12},
-    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 67, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 67, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 7, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 67, linebreak_class: 21},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 67, linebreak_class: 17},
-    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 67, linebreak_class: 11},
-    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 68, linebreak_class: 11},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 68, linebreak_class: 12},
-    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 68, linebreak_class: 12},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 68, linebreak_class: 17},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 66, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 1, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 40, linebreak_class: 21},
-    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 21},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12},
-    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 3, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 234, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 40, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 214, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 40, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 202, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 40, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 233, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 40, linebreak_class: 21},
-    UCDRecord{category: 8, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 2, linebreak_class: 12},
-    UCDRecord{category: 24, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 2, linebreak_class: 18},
-    UCDRecord{category: 29, combining: 0, bidi_class: 17, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 17},
-    UCDRecord{category: 29, combining: 0, bidi_class: 17, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 4},
-    UCDRecord{category: 1, combining: 0, bidi_class: 14, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 20},
-    UCDRecord{category: 1, combining: 0, bidi_class: 14, mirrored: 0, east_asian_width: 5, script: 40, linebreak_class: 21},
-    UCDRecord{category: 1, combining: 0, bidi_class: 14, mirrored: 0, east_asian_width: 5, script: 40, linebreak_class: 40},
-    UCDRecord{category: 1, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 21},
-    UCDRecord{category: 1, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 21},
-    UCDRecord{category: 17, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 17},
-    UCDRecord{category: 17, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 4},
-    UCDRecord{category: 17, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 17},
-    UCDRecord{category: 17, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 19},
-    UCDRecord{category: 17, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 29},
-    UCDRecord{category: 20, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 3},
-    UCDRecord{category: 19, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 3},
-    UCDRecord{category: 22, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 0},
-    UCDRecord{category: 20, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 3},
-    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 12},
-    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 15},
-    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 17},
-    UCDRecord{category: 27, combining: 0, bidi_class: 17, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 30},
-    UCDRecord{category: 28, combining: 0, bidi_class: 15, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 30},
-    UCDRecord{category: 1, combining: 0, bidi_class: 1, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 21},
-    UCDRecord{category: 1, combining: 0, bidi_class: 5, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 21},
-    UCDRecord{category: 1, combining: 0, bidi_class: 7, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 21},
-    UCDRecord{category: 1, combining: 0, bidi_class: 2, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 21},
-    UCDRecord{category: 1, combining: 0, bidi_class: 6, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 21},
-    UCDRecord{category: 21, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 10},
-    UCDRecord{category: 21, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 10},
-    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 10},
-    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 10},
-    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 5},
-    UCDRecord{category: 16, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12},
-    UCDRecord{category: 25, combining: 0, bidi_class: 12, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 8},
-    UCDRecord{category: 22, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 5, script: 0, linebreak_class: 0},
-    UCDRecord{category: 18, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 5, script: 0, linebreak_class: 1},
-    UCDRecord{category: 25, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12},
-    UCDRecord{category: 1, combining: 0, bidi_class: 14, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 22},
-    UCDRecord{category: 1, combining: 0, bidi_class: 14, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12},
-    UCDRecord{category: 1, combining: 0, bidi_class: 19, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 21},
-    UCDRecord{category: 1, combining: 0, bidi_class: 20, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 21},
-    UCDRecord{category: 1, combining: 0, bidi_class: 21, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 21},
-    UCDRecord{category: 1, combining: 0, bidi_class: 22, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 21},
-    UCDRecord{category: 1, combining: 0, bidi_class: 14, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 21},
-    UCDRecord{category: 15, combining: 0, bidi_class: 8, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12},
-    UCDRecord{category: 25, combining: 0, bidi_class: 9, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12},
-    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 4, script: 1, linebreak_class: 29},
-    UCDRecord{category: 23, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 10},
-    UCDRecord{category: 23, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 1, script: 0, linebreak_class: 9},
-    UCDRecord{category: 2, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 102, linebreak_class: 9},
-    UCDRecord{category: 9, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12},
-    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 10},
-    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 29},
-    UCDRecord{category: 5, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 29},
-    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 9},
-    UCDRecord{category: 9, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 4, script: 1, linebreak_class: 29},
-    UCDRecord{category: 26, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12},
-    UCDRecord{category: 25, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 5, script: 0, linebreak_class: 12},
-    UCDRecord{category: 15, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12},
-    UCDRecord{category: 15, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 12},
-    UCDRecord{category: 15, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 29},
-    UCDRecord{category: 14, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 4, script: 1, linebreak_class: 29},
-    UCDRecord{category: 14, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 1, linebreak_class: 12},
-    UCDRecord{category: 25, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 4, script: 0, linebreak_class: 29},
-    UCDRecord{category: 25, combining: 0, bidi_class: 9, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 9},
-    UCDRecord{category: 25, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 9},
-    UCDRecord{category: 25, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 15},
-    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 14},
-    UCDRecord{category: 22, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 2, script: 0, linebreak_class: 0},
-    UCDRecord{category: 18, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 2, script: 0, linebreak_class: 1},
-    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 12},
-    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 14},
-    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 29},
-    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 29},
-    UCDRecord{category: 25, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 12},
-    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 14},
-    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 41},
-    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 41},
-    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 41},
-    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 29},
-    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 3},
-    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 6},
-    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 52, linebreak_class: 12},
-    UCDRecord{category: 9, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 56, linebreak_class: 12},
-    UCDRecord{category: 5, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 56, linebreak_class: 12},
-    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 54, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 54, linebreak_class: 21},
-    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 54, linebreak_class: 6},
-    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 54, linebreak_class: 17},
-    UCDRecord{category: 15, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 54, linebreak_class: 12},
-    UCDRecord{category: 5, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 23, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 57, linebreak_class: 12},
-    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 57, linebreak_class: 12},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 57, linebreak_class: 17},
-    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 57, linebreak_class: 21},
-    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 3},
-    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 0},
-    UCDRecord{category: 17, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12},
-    UCDRecord{category: 17, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 19},
-    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 35, linebreak_class: 14},
-    UCDRecord{category: 29, combining: 0, bidi_class: 17, mirrored: 0, east_asian_width: 0, script: 0, linebreak_class: 17},
-    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 1},
-    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 14},
-    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 35, linebreak_class: 5},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 14},
-    UCDRecord{category: 14, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 35, linebreak_class: 14},
-    UCDRecord{category: 17, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 5},
-    UCDRecord{category: 22, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 0},
-    UCDRecord{category: 18, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 1},
-    UCDRecord{category: 12, combining: 218, bidi_class: 13, mirrored: 0, east_asian_width: 2, script: 40, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 228, bidi_class: 13, mirrored: 0, east_asian_width: 2, script: 40, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 232, bidi_class: 13, mirrored: 0, east_asian_width: 2, script: 40, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 222, bidi_class: 13, mirrored: 0, east_asian_width: 2, script: 40, linebreak_class: 21},
-    UCDRecord{category: 10, combining: 224, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 24, linebreak_class: 21},
-    UCDRecord{category: 17, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 14},
-    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 14},
-    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 21},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 5},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 32, linebreak_class: 32},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 32, linebreak_class: 14},
-    UCDRecord{category: 12, combining: 8, bidi_class: 13, mirrored: 0, east_asian_width: 2, script: 40, linebreak_class: 21},
-    UCDRecord{category: 24, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 5},
-    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 32, linebreak_class: 5},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 33, linebreak_class: 32},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 33, linebreak_class: 14},
-    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 5},
-    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 32},
-    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 33, linebreak_class: 5},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 34, linebreak_class: 14},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 24, linebreak_class: 14},
-    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 14},
-    UCDRecord{category: 15, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 14},
-    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 24, linebreak_class: 14},
-    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 24, linebreak_class: 14},
-    UCDRecord{category: 15, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 29},
-    UCDRecord{category: 15, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 14},
-    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 33, linebreak_class: 14},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 35, linebreak_class: 14},
-    UCDRecord{category: 2, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 102, linebreak_class: 14},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 36, linebreak_class: 14},
-    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 36, linebreak_class: 5},
-    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 36, linebreak_class: 14},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 82, linebreak_class: 12},
-    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 82, linebreak_class: 12},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 82, linebreak_class: 17},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 69, linebreak_class: 12},
-    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 69, linebreak_class: 12},
-    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 69, linebreak_class: 17},
-    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 69, linebreak_class: 6},
-    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 69, linebreak_class: 11},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 3, linebreak_class: 12},
-    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 3, linebreak_class: 12},
-    UCDRecord{category: 6, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 3, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 83, linebreak_class: 12},
-    UCDRecord{category: 14, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 83, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 83, linebreak_class: 21},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 83, linebreak_class: 12},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 83, linebreak_class: 17},
-    UCDRecord{category: 24, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 58, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 58, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 58, linebreak_class: 21},
-    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 58, linebreak_class: 21},
-    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 58, linebreak_class: 12},
-    UCDRecord{category: 15, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 64, linebreak_class: 12},
-    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 64, linebreak_class: 18},
-    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 64, linebreak_class: 6},
-    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 70, linebreak_class: 21},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 70, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 70, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 70, linebreak_class: 21},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 70, linebreak_class: 17},
-    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 70, linebreak_class: 11},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 9, linebreak_class: 18},
-    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 71, linebreak_class: 11},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 71, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 71, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 220, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 71, linebreak_class: 21},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 71, linebreak_class: 17},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 72, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 72, linebreak_class: 21},
-    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 72, linebreak_class: 21},
-    UCDRecord{category: 10, combining: 9, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 72, linebreak_class: 21},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 72, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 84, linebreak_class: 21},
-    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 84, linebreak_class: 21},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 84, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 7, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 84, linebreak_class: 21},
-    UCDRecord{category: 10, combining: 9, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 84, linebreak_class: 21},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 84, linebreak_class: 12},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 84, linebreak_class: 17},
-    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 84, linebreak_class: 11},
-    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 22, linebreak_class: 36},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 76, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 76, linebreak_class: 21},
-    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 76, linebreak_class: 21},
-    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 76, linebreak_class: 11},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 76, linebreak_class: 12},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 76, linebreak_class: 17},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 78, linebreak_class: 36},
-    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 78, linebreak_class: 36},
-    UCDRecord{category: 12, combining: 220, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 78, linebreak_class: 36},
-    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 78, linebreak_class: 36},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 78, linebreak_class: 36},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 85, linebreak_class: 12},
-    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 85, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 85, linebreak_class: 21},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 85, linebreak_class: 17},
-    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 85, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 85, linebreak_class: 21},
-    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 85, linebreak_class: 11},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 24, linebreak_class: 23},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 24, linebreak_class: 24},
-    UCDRecord{category: 4, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 102, linebreak_class: 37},
-    UCDRecord{category: 3, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 4, script: 102, linebreak_class: 39},
-    UCDRecord{category: 12, combining: 26, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 21},
-    UCDRecord{category: 25, combining: 0, bidi_class: 9, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 12},
-    UCDRecord{category: 24, combining: 0, bidi_class: 4, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 12},
-    UCDRecord{category: 18, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 1},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 4, script: 40, linebreak_class: 21},
-    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 8},
-    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 6},
-    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 15},
-    UCDRecord{category: 16, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 14},
-    UCDRecord{category: 21, combining: 0, bidi_class: 12, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 1},
-    UCDRecord{category: 21, combining: 0, bidi_class: 12, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 5},
-    UCDRecord{category: 21, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 14},
-    UCDRecord{category: 25, combining: 0, bidi_class: 9, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 14},
-    UCDRecord{category: 17, combining: 0, bidi_class: 9, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 14},
-    UCDRecord{category: 25, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 2, script: 0, linebreak_class: 14},
-    UCDRecord{category: 25, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 14},
-    UCDRecord{category: 23, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 9},
-    UCDRecord{category: 21, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 10},
-    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 0, script: 0, linebreak_class: 6},
-    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 0, script: 0, linebreak_class: 14},
-    UCDRecord{category: 21, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 0, script: 0, linebreak_class: 14},
-    UCDRecord{category: 23, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 0, script: 0, linebreak_class: 9},
-    UCDRecord{category: 21, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 0, script: 0, linebreak_class: 10},
-    UCDRecord{category: 22, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 0, script: 0, linebreak_class: 0},
-    UCDRecord{category: 18, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 0, script: 0, linebreak_class: 1},
-    UCDRecord{category: 25, combining: 0, bidi_class: 9, mirrored: 0, east_asian_width: 0, script: 0, linebreak_class: 14},
-    UCDRecord{category: 21, combining: 0, bidi_class: 12, mirrored: 0, east_asian_width: 0, script: 0, linebreak_class: 1},
-    UCDRecord{category: 17, combining: 0, bidi_class: 9, mirrored: 0, east_asian_width: 0, script: 0, linebreak_class: 14},
-    UCDRecord{category: 21, combining: 0, bidi_class: 12, mirrored: 0, east_asian_width: 0, script: 0, linebreak_class: 14},
-    UCDRecord{category: 13, combining: 0, bidi_class: 8, mirrored: 0, east_asian_width: 0, script: 0, linebreak_class: 14},
-    UCDRecord{category: 21, combining: 0, bidi_class: 12, mirrored: 0, east_asian_width: 0, script: 0, linebreak_class: 5},
-    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 0, script: 0, linebreak_class: 5},
-    UCDRecord{category: 25, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 0, script: 0, linebreak_class: 14},
-    UCDRecord{category: 25, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 0, script: 0, linebreak_class: 14},
-    UCDRecord{category: 9, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 0, script: 1, linebreak_class: 14},
-    UCDRecord{category: 24, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 0, script: 0, linebreak_class: 14},
-    UCDRecord{category: 16, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 0, script: 0, linebreak_class: 14},
-    UCDRecord{category: 5, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 0, script: 1, linebreak_class: 14},
-    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 1, script: 0, linebreak_class: 1},
-    UCDRecord{category: 22, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 1, script: 0, linebreak_class: 0},
-    UCDRecord{category: 18, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 1, script: 0, linebreak_class: 1},
-    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 1, script: 0, linebreak_class: 5},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 1, script: 33, linebreak_class: 14},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 1, script: 33, linebreak_class: 32},
-    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 1, script: 0, linebreak_class: 32},
-    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 1, script: 0, linebreak_class: 5},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 1, script: 24, linebreak_class: 14},
-    UCDRecord{category: 23, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 0, script: 0, linebreak_class: 10},
-    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 0, script: 0, linebreak_class: 14},
-    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 1, script: 0, linebreak_class: 12},
-    UCDRecord{category: 25, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 1, script: 0, linebreak_class: 12},
-    UCDRecord{category: 1, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 21},
-    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 31},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 47, linebreak_class: 12},
-    UCDRecord{category: 14, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 2, linebreak_class: 12},
-    UCDRecord{category: 15, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 2, linebreak_class: 12},
-    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 2, linebreak_class: 12},
-    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 2, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 73, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 74, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 37, linebreak_class: 12},
-    UCDRecord{category: 15, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 37, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 38, linebreak_class: 12},
-    UCDRecord{category: 14, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 38, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 118, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 118, linebreak_class: 21},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 48, linebreak_class: 12},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 48, linebreak_class: 17},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 59, linebreak_class: 12},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 59, linebreak_class: 17},
-    UCDRecord{category: 14, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 59, linebreak_class: 12},
-    UCDRecord{category: 9, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 39, linebreak_class: 12},
-    UCDRecord{category: 5, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 39, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 49, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 50, linebreak_class: 12},
-    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 50, linebreak_class: 11},
-    UCDRecord{category: 9, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 136, linebreak_class: 12},
-    UCDRecord{category: 5, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 136, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 106, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 104, linebreak_class: 12},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 104, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 110, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 51, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 86, linebreak_class: 12},
-    UCDRecord{category: 21, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 86, linebreak_class: 17},
-    UCDRecord{category: 15, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 86, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 120, linebreak_class: 12},
-    UCDRecord{category: 26, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 120, linebreak_class: 12},
-    UCDRecord{category: 15, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 120, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 116, linebreak_class: 12},
-    UCDRecord{category: 15, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 116, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 128, linebreak_class: 12},
-    UCDRecord{category: 15, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 128, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 63, linebreak_class: 12},
-    UCDRecord{category: 15, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 63, linebreak_class: 12},
-    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 63, linebreak_class: 17},
-    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 75, linebreak_class: 12},
-    UCDRecord{category: 21, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 75, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 97, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 96, linebreak_class: 12},
-    UCDRecord{category: 15, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 96, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 60, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 60, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 220, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 60, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 60, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 1, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 60, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 60, linebreak_class: 21},
-    UCDRecord{category: 15, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 60, linebreak_class: 12},
-    UCDRecord{category: 21, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 60, linebreak_class: 17},
-    UCDRecord{category: 21, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 60, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 87, linebreak_class: 12},
-    UCDRecord{category: 15, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 87, linebreak_class: 12},
-    UCDRecord{category: 21, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 87, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 117, linebreak_class: 12},
-    UCDRecord{category: 15, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 117, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 112, linebreak_class: 12},
-    UCDRecord{category: 26, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 112, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 112, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 220, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 112, linebreak_class: 21},
-    UCDRecord{category: 15, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 112, linebreak_class: 12},
-    UCDRecord{category: 21, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 112, linebreak_class: 17},
-    UCDRecord{category: 21, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 112, linebreak_class: 15},
-    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 79, linebreak_class: 12},
-    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 79, linebreak_class: 17},
-    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 88, linebreak_class: 12},
-    UCDRecord{category: 15, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 88, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 89, linebreak_class: 12},
-    UCDRecord{category: 15, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 89, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 122, linebreak_class: 12},
-    UCDRecord{category: 21, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 122, linebreak_class: 12},
-    UCDRecord{category: 15, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 122, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 90, linebreak_class: 12},
-    UCDRecord{category: 9, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 130, linebreak_class: 12},
-    UCDRecord{category: 5, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 130, linebreak_class: 12},
-    UCDRecord{category: 15, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 130, linebreak_class: 12},
-    UCDRecord{category: 15, combining: 0, bidi_class: 11, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 12},
-    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 93, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 93, linebreak_class: 21},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 93, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 93, linebreak_class: 21},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 93, linebreak_class: 17},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 93, linebreak_class: 12},
-    UCDRecord{category: 15, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 93, linebreak_class: 12},
-    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 93, linebreak_class: 11},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 91, linebreak_class: 21},
-    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 91, linebreak_class: 21},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 91, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 91, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 7, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 91, linebreak_class: 21},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 91, linebreak_class: 12},
-    UCDRecord{category: 1, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 91, linebreak_class: 12},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 91, linebreak_class: 17},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 100, linebreak_class: 12},
-    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 100, linebreak_class: 11},
-    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 95, linebreak_class: 21},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 95, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 95, linebreak_class: 21},
-    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 95, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 95, linebreak_class: 21},
-    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 95, linebreak_class: 11},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 95, linebreak_class: 17},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 111, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 7, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 111, linebreak_class: 21},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 111, linebreak_class: 12},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 111, linebreak_class: 18},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 99, linebreak_class: 21},
-    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 99, linebreak_class: 21},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 99, linebreak_class: 12},
-    UCDRecord{category: 10, combining: 9, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 99, linebreak_class: 21},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 99, linebreak_class: 17},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 99, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 7, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 99, linebreak_class: 21},
-    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 99, linebreak_class: 11},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 99, linebreak_class: 18},
-    UCDRecord{category: 15, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 18, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 108, linebreak_class: 12},
-    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 108, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 108, linebreak_class: 21},
-    UCDRecord{category: 10, combining: 9, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 108, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 7, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 108, linebreak_class: 21},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 108, linebreak_class: 17},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 108, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 129, linebreak_class: 12},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 129, linebreak_class: 17},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 109, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 109, linebreak_class: 21},
-    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 109, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 7, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 109, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 109, linebreak_class: 21},
-    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 109, linebreak_class: 11},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 107, linebreak_class: 21},
-    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 107, linebreak_class: 21},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 107, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 7, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 107, linebreak_class: 21},
-    UCDRecord{category: 10, combining: 9, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 107, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 107, linebreak_class: 21},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 135, linebreak_class: 12},
-    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 135, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 135, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 135, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 7, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 135, linebreak_class: 21},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 135, linebreak_class: 17},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 135, linebreak_class: 12},
-    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 135, linebreak_class: 11},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 124, linebreak_class: 12},
-    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 124, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 124, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 124, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 7, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 124, linebreak_class: 21},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 124, linebreak_class: 12},
-    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 124, linebreak_class: 11},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 123, linebreak_class: 12},
-    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 123, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 123, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 123, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 7, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 123, linebreak_class: 21},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 123, linebreak_class: 18},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 123, linebreak_class: 17},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 123, linebreak_class: 6},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 123, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 114, linebreak_class: 12},
-    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 114, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 114, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 114, linebreak_class: 21},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 114, linebreak_class: 17},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 114, linebreak_class: 12},
-    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 114, linebreak_class: 11},
-    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 31, linebreak_class: 18},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 101, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 101, linebreak_class: 21},
-    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 101, linebreak_class: 21},
-    UCDRecord{category: 10, combining: 9, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 101, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 7, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 101, linebreak_class: 21},
-    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 101, linebreak_class: 11},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 126, linebreak_class: 36},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 126, linebreak_class: 36},
-    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 126, linebreak_class: 36},
-    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 126, linebreak_class: 36},
-    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 126, linebreak_class: 11},
-    UCDRecord{category: 15, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 126, linebreak_class: 36},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 126, linebreak_class: 17},
-    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 126, linebreak_class: 36},
-    UCDRecord{category: 9, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 125, linebreak_class: 12},
-    UCDRecord{category: 5, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 125, linebreak_class: 12},
-    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 125, linebreak_class: 11},
-    UCDRecord{category: 15, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 125, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 125, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 121, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 133, linebreak_class: 12},
-    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 133, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 133, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 9, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 133, linebreak_class: 21},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 133, linebreak_class: 17},
-    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 133, linebreak_class: 11},
-    UCDRecord{category: 15, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 133, linebreak_class: 12},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 134, linebreak_class: 18},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 134, linebreak_class: 6},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 134, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 134, linebreak_class: 21},
-    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 134, linebreak_class: 21},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 62, linebreak_class: 12},
-    UCDRecord{category: 14, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 62, linebreak_class: 12},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 62, linebreak_class: 17},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 80, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 80, linebreak_class: 0},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 80, linebreak_class: 1},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 127, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 127, linebreak_class: 0},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 127, linebreak_class: 1},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 115, linebreak_class: 12},
-    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 115, linebreak_class: 11},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 115, linebreak_class: 17},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 103, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 1, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 103, linebreak_class: 21},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 103, linebreak_class: 17},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 119, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 119, linebreak_class: 21},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 119, linebreak_class: 17},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 119, linebreak_class: 12},
-    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 119, linebreak_class: 12},
-    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 119, linebreak_class: 12},
-    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 119, linebreak_class: 11},
-    UCDRecord{category: 15, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 119, linebreak_class: 12},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 98, linebreak_class: 12},
-    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 98, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 98, linebreak_class: 21},
-    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 98, linebreak_class: 12},
-    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 137, linebreak_class: 5},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 137, linebreak_class: 14},
-    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 105, linebreak_class: 12},
-    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 105, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 105, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 1, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 105, linebreak_class: 21},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 105, linebreak_class: 17},
-    UCDRecord{category: 10, combining: 216, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 21},
-    UCDRecord{category: 10, combining: 226, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 2, linebreak_class: 21},
-    UCDRecord{category: 25, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12},
-    UCDRecord{category: 13, combining: 0, bidi_class: 8, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 11},
-    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 131, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 131, linebreak_class: 21},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 131, linebreak_class: 17},
-    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 131, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 56, linebreak_class: 21},
-    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 113, linebreak_class: 12},
-    UCDRecord{category: 15, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 113, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 220, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 113, linebreak_class: 21},
-    UCDRecord{category: 9, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 132, linebreak_class: 12},
-    UCDRecord{category: 5, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 132, linebreak_class: 12},
-    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 132, linebreak_class: 21},
-    UCDRecord{category: 12, combining: 7, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 132, linebreak_class: 21},
-    UCDRecord{category: 13, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 132, linebreak_class: 11},
-    UCDRecord{category: 21, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 132, linebreak_class: 0},
-    UCDRecord{category: 2, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 102, linebreak_class: 14},
-    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 29},
-    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 28},
-    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 32, linebreak_class: 14},
-    UCDRecord{category: 24, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 42},
-    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 5},
+pub static UCD_RECORDS: [UCDRecord; 1127] = [
+    UCDRecord{category: 2, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 102, linebreak_class: 39, quickcheck: 0},
+    UCDRecord{category: 0, combining: 0, bidi_class: 14, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 0, combining: 0, bidi_class: 16, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 0, combining: 0, bidi_class: 15, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 34, quickcheck: 0},
+    UCDRecord{category: 0, combining: 0, bidi_class: 16, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 30, quickcheck: 0},
+    UCDRecord{category: 0, combining: 0, bidi_class: 17, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 30, quickcheck: 0},
+    UCDRecord{category: 0, combining: 0, bidi_class: 15, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 33, quickcheck: 0},
+    UCDRecord{category: 0, combining: 0, bidi_class: 15, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 0, combining: 0, bidi_class: 16, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 29, combining: 0, bidi_class: 17, mirrored: 0, east_asian_width: 3, script: 0, linebreak_class: 38, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 3, script: 0, linebreak_class: 6, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 3, script: 0, linebreak_class: 3, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 3, script: 0, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 23, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 3, script: 0, linebreak_class: 9, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 3, script: 0, linebreak_class: 10, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 3, script: 0, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 22, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 3, script: 0, linebreak_class: 0, quickcheck: 0},
+    UCDRecord{category: 18, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 3, script: 0, linebreak_class: 2, quickcheck: 0},
+    UCDRecord{category: 25, combining: 0, bidi_class: 9, mirrored: 0, east_asian_width: 3, script: 0, linebreak_class: 9, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 12, mirrored: 0, east_asian_width: 3, script: 0, linebreak_class: 8, quickcheck: 0},
+    UCDRecord{category: 17, combining: 0, bidi_class: 9, mirrored: 0, east_asian_width: 3, script: 0, linebreak_class: 16, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 12, mirrored: 0, east_asian_width: 3, script: 0, linebreak_class: 7, quickcheck: 0},
+    UCDRecord{category: 13, combining: 0, bidi_class: 8, mirrored: 0, east_asian_width: 3, script: 0, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 3, script: 0, linebreak_class: 8, quickcheck: 0},
+    UCDRecord{category: 25, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 3, script: 0, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 25, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 3, script: 0, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 9, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 3, script: 1, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 3, script: 0, linebreak_class: 9, quickcheck: 0},
+    UCDRecord{category: 24, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 3, script: 0, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 16, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 3, script: 0, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 5, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 3, script: 1, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 25, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 3, script: 0, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 18, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 3, script: 0, linebreak_class: 1, quickcheck: 0},
+    UCDRecord{category: 0, combining: 0, bidi_class: 15, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 35, quickcheck: 0},
+    UCDRecord{category: 29, combining: 0, bidi_class: 12, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 4, quickcheck: 136},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 0, quickcheck: 0},
+    UCDRecord{category: 23, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 3, script: 0, linebreak_class: 10, quickcheck: 0},
+    UCDRecord{category: 23, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 9, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 3, script: 0, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 29, quickcheck: 0},
+    UCDRecord{category: 24, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 29, quickcheck: 136},
+    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 4, script: 1, linebreak_class: 29, quickcheck: 136},
+    UCDRecord{category: 20, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 5, script: 0, linebreak_class: 3, quickcheck: 0},
+    UCDRecord{category: 1, combining: 0, bidi_class: 14, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 24, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 3, script: 0, linebreak_class: 12, quickcheck: 136},
+    UCDRecord{category: 26, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 10, quickcheck: 0},
+    UCDRecord{category: 25, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 9, quickcheck: 0},
+    UCDRecord{category: 15, combining: 0, bidi_class: 8, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 29, quickcheck: 136},
+    UCDRecord{category: 24, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 18, quickcheck: 136},
+    UCDRecord{category: 5, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12, quickcheck: 136},
+    UCDRecord{category: 19, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 5, script: 0, linebreak_class: 3, quickcheck: 0},
+    UCDRecord{category: 15, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 29, quickcheck: 136},
+    UCDRecord{category: 9, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 1, linebreak_class: 12, quickcheck: 10},
+    UCDRecord{category: 9, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 4, script: 1, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 25, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 29, quickcheck: 0},
+    UCDRecord{category: 5, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 4, script: 1, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 5, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 4, script: 1, linebreak_class: 12, quickcheck: 10},
+    UCDRecord{category: 5, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 1, linebreak_class: 12, quickcheck: 10},
+    UCDRecord{category: 9, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 1, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 9, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 4, script: 1, linebreak_class: 12, quickcheck: 136},
+    UCDRecord{category: 5, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 4, script: 1, linebreak_class: 12, quickcheck: 136},
+    UCDRecord{category: 5, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 1, linebreak_class: 12, quickcheck: 136},
+    UCDRecord{category: 5, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 1, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 1, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 9, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 1, linebreak_class: 12, quickcheck: 136},
+    UCDRecord{category: 8, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 1, linebreak_class: 12, quickcheck: 136},
+    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 1, linebreak_class: 12, quickcheck: 136},
+    UCDRecord{category: 6, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 24, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 24, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 6, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 29, quickcheck: 0},
+    UCDRecord{category: 6, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 18, quickcheck: 0},
+    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 29, quickcheck: 0},
+    UCDRecord{category: 24, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12, quickcheck: 136},
+    UCDRecord{category: 24, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 18, quickcheck: 0},
+    UCDRecord{category: 24, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 34, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 4, script: 40, linebreak_class: 21, quickcheck: 80},
+    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 4, script: 40, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 232, bidi_class: 13, mirrored: 0, east_asian_width: 4, script: 40, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 220, bidi_class: 13, mirrored: 0, east_asian_width: 4, script: 40, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 216, bidi_class: 13, mirrored: 0, east_asian_width: 4, script: 40, linebreak_class: 21, quickcheck: 80},
+    UCDRecord{category: 12, combining: 202, bidi_class: 13, mirrored: 0, east_asian_width: 4, script: 40, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 220, bidi_class: 13, mirrored: 0, east_asian_width: 4, script: 40, linebreak_class: 21, quickcheck: 80},
+    UCDRecord{category: 12, combining: 202, bidi_class: 13, mirrored: 0, east_asian_width: 4, script: 40, linebreak_class: 21, quickcheck: 80},
+    UCDRecord{category: 12, combining: 1, bidi_class: 13, mirrored: 0, east_asian_width: 4, script: 40, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 1, bidi_class: 13, mirrored: 0, east_asian_width: 4, script: 40, linebreak_class: 21, quickcheck: 80},
+    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 4, script: 40, linebreak_class: 21, quickcheck: 170},
+    UCDRecord{category: 12, combining: 240, bidi_class: 13, mirrored: 0, east_asian_width: 4, script: 40, linebreak_class: 21, quickcheck: 80},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 4, script: 40, linebreak_class: 4, quickcheck: 0},
+    UCDRecord{category: 12, combining: 233, bidi_class: 13, mirrored: 0, east_asian_width: 4, script: 40, linebreak_class: 4, quickcheck: 0},
+    UCDRecord{category: 12, combining: 234, bidi_class: 13, mirrored: 0, east_asian_width: 4, script: 40, linebreak_class: 4, quickcheck: 0},
+    UCDRecord{category: 9, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 2, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 5, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 2, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 6, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12, quickcheck: 170},
+    UCDRecord{category: 24, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 2, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 2, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 102, linebreak_class: 39, quickcheck: 0},
+    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 2, linebreak_class: 12, quickcheck: 136},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 8, quickcheck: 170},
+    UCDRecord{category: 24, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 2, linebreak_class: 12, quickcheck: 136},
+    UCDRecord{category: 24, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12, quickcheck: 138},
+    UCDRecord{category: 9, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 2, linebreak_class: 12, quickcheck: 10},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12, quickcheck: 170},
+    UCDRecord{category: 5, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 2, linebreak_class: 12, quickcheck: 10},
+    UCDRecord{category: 9, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 4, script: 2, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 5, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 4, script: 2, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 5, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 2, linebreak_class: 12, quickcheck: 136},
+    UCDRecord{category: 9, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 2, linebreak_class: 12, quickcheck: 136},
+    UCDRecord{category: 9, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 2, linebreak_class: 12, quickcheck: 138},
+    UCDRecord{category: 9, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 54, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 5, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 54, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 25, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 2, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 9, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 3, linebreak_class: 12, quickcheck: 10},
+    UCDRecord{category: 9, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 4, script: 3, linebreak_class: 12, quickcheck: 10},
+    UCDRecord{category: 9, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 3, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 9, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 4, script: 3, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 5, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 4, script: 3, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 5, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 4, script: 3, linebreak_class: 12, quickcheck: 10},
+    UCDRecord{category: 5, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 3, linebreak_class: 12, quickcheck: 10},
+    UCDRecord{category: 5, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 3, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 3, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 3, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 40, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 11, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 3, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 9, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 4, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 4, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 4, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 5, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 4, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 5, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 4, linebreak_class: 12, quickcheck: 136},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 8, quickcheck: 0},
+    UCDRecord{category: 17, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 4, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 4, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 23, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 5, script: 4, linebreak_class: 9, quickcheck: 0},
+    UCDRecord{category: 12, combining: 220, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 222, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 228, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 10, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 11, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 12, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 13, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 14, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 15, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 16, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 17, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 18, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 19, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 20, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 21, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 22, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 17, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 12, combining: 23, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 24, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 25, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 6, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 13, quickcheck: 0},
+    UCDRecord{category: 1, combining: 0, bidi_class: 11, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 1, combining: 0, bidi_class: 11, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 25, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 25, combining: 0, bidi_class: 4, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 10, quickcheck: 0},
+    UCDRecord{category: 23, combining: 0, bidi_class: 4, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 10, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 12, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 8, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 4, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 8, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 30, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 31, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 32, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 4, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 6, quickcheck: 0},
+    UCDRecord{category: 1, combining: 0, bidi_class: 4, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 4, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 6, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 4, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 4, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 12, quickcheck: 10},
+    UCDRecord{category: 6, combining: 0, bidi_class: 4, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 27, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 40, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 28, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 40, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 29, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 40, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 30, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 40, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 31, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 40, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 32, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 40, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 33, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 40, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 34, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 40, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 40, linebreak_class: 21, quickcheck: 80},
+    UCDRecord{category: 12, combining: 220, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 40, linebreak_class: 21, quickcheck: 80},
+    UCDRecord{category: 12, combining: 220, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 13, combining: 0, bidi_class: 11, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 11, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 4, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 35, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 40, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 4, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 12, quickcheck: 136},
+    UCDRecord{category: 6, combining: 0, bidi_class: 4, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 13, combining: 0, bidi_class: 8, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 4, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 4, mirrored: 0, east_asian_width: 5, script: 7, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 1, combining: 0, bidi_class: 4, mirrored: 0, east_asian_width: 5, script: 7, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 4, mirrored: 0, east_asian_width: 5, script: 7, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 36, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 7, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 7, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 220, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 7, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 4, mirrored: 0, east_asian_width: 5, script: 8, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 8, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 13, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 65, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 65, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 65, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 220, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 65, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 6, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 65, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 65, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 65, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 65, linebreak_class: 8, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 65, linebreak_class: 6, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 81, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 81, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 6, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 81, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 81, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 94, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 220, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 94, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 94, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 27, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 28, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 29, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 9, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 9, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 9, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 9, linebreak_class: 12, quickcheck: 10},
+    UCDRecord{category: 12, combining: 7, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 9, linebreak_class: 21, quickcheck: 80},
+    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 9, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 220, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 40, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 9, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 9, linebreak_class: 12, quickcheck: 170},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 9, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 9, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 9, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 10, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 10, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 10, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 7, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 10, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 10, linebreak_class: 21, quickcheck: 80},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 10, linebreak_class: 21, quickcheck: 10},
+    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 10, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 10, linebreak_class: 12, quickcheck: 170},
+    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 10, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 23, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 5, script: 10, linebreak_class: 10, quickcheck: 0},
+    UCDRecord{category: 15, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 10, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 15, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 10, linebreak_class: 10, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 10, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 23, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 5, script: 10, linebreak_class: 9, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 11, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 11, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 11, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 11, linebreak_class: 12, quickcheck: 170},
+    UCDRecord{category: 12, combining: 7, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 11, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 11, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 11, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 12, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 12, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 12, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 7, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 12, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 12, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 12, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 12, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 23, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 5, script: 12, linebreak_class: 9, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 13, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 13, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 13, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 7, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 13, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 13, linebreak_class: 21, quickcheck: 80},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 13, linebreak_class: 21, quickcheck: 10},
+    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 13, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 13, linebreak_class: 21, quickcheck: 80},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 13, linebreak_class: 12, quickcheck: 170},
+    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 13, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 13, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 15, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 13, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 14, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 14, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 14, linebreak_class: 12, quickcheck: 10},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 14, linebreak_class: 21, quickcheck: 80},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 14, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 14, linebreak_class: 21, quickcheck: 10},
+    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 14, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 14, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 15, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 14, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 14, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 23, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 5, script: 14, linebreak_class: 9, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 15, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 15, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 15, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 15, linebreak_class: 21, quickcheck: 10},
+    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 15, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 84, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 15, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 91, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 15, linebreak_class: 21, quickcheck: 80},
+    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 15, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 15, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 15, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 15, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 16, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 16, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 16, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 7, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 16, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 16, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 16, linebreak_class: 21, quickcheck: 10},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 16, linebreak_class: 21, quickcheck: 80},
+    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 16, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 16, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 17, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 17, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 17, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 17, linebreak_class: 21, quickcheck: 80},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 17, linebreak_class: 21, quickcheck: 10},
+    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 17, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 17, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 15, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 17, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 17, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 17, linebreak_class: 10, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 18, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 18, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 18, linebreak_class: 21, quickcheck: 80},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 18, linebreak_class: 21, quickcheck: 80},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 18, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 18, linebreak_class: 21, quickcheck: 10},
+    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 18, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 18, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 19, linebreak_class: 36, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 19, linebreak_class: 36, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 19, linebreak_class: 36, quickcheck: 136},
+    UCDRecord{category: 12, combining: 103, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 19, linebreak_class: 36, quickcheck: 0},
+    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 19, linebreak_class: 36, quickcheck: 0},
+    UCDRecord{category: 23, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 9, quickcheck: 0},
+    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 19, linebreak_class: 36, quickcheck: 0},
+    UCDRecord{category: 12, combining: 107, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 19, linebreak_class: 36, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 19, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 19, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 19, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 20, linebreak_class: 36, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 20, linebreak_class: 36, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 20, linebreak_class: 36, quickcheck: 136},
+    UCDRecord{category: 12, combining: 118, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 20, linebreak_class: 36, quickcheck: 0},
+    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 20, linebreak_class: 36, quickcheck: 0},
+    UCDRecord{category: 12, combining: 122, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 20, linebreak_class: 36, quickcheck: 0},
+    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 20, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 21, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 21, linebreak_class: 18, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 21, linebreak_class: 18, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 21, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 21, linebreak_class: 4, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 21, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 21, linebreak_class: 4, quickcheck: 136},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 21, linebreak_class: 6, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 21, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 220, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 21, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 21, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 15, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 21, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 21, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 12, combining: 216, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 21, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 22, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 5, script: 21, linebreak_class: 0, quickcheck: 0},
+    UCDRecord{category: 18, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 5, script: 21, linebreak_class: 1, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 21, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 21, linebreak_class: 12, quickcheck: 170},
+    UCDRecord{category: 12, combining: 129, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 21, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 130, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 21, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 21, linebreak_class: 21, quickcheck: 170},
+    UCDRecord{category: 12, combining: 132, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 21, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 21, linebreak_class: 21, quickcheck: 136},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 21, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 21, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 21, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 21, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 22, linebreak_class: 36, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 22, linebreak_class: 36, quickcheck: 10},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 22, linebreak_class: 36, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 22, linebreak_class: 36, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 22, linebreak_class: 36, quickcheck: 80},
+    UCDRecord{category: 12, combining: 7, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 22, linebreak_class: 36, quickcheck: 0},
+    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 22, linebreak_class: 36, quickcheck: 0},
+    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 22, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 22, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 22, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 220, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 22, linebreak_class: 36, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 22, linebreak_class: 36, quickcheck: 0},
+    UCDRecord{category: 9, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 23, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 23, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 23, linebreak_class: 12, quickcheck: 136},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 24, linebreak_class: 25, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 24, linebreak_class: 26, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 24, linebreak_class: 26, quickcheck: 80},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 24, linebreak_class: 27, quickcheck: 80},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 24, linebreak_class: 27, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 25, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 25, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 25, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 25, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 15, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 25, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 25, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 9, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 26, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 5, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 26, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 17, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 27, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 27, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 27, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 29, combining: 0, bidi_class: 17, mirrored: 0, east_asian_width: 5, script: 28, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 28, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 22, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 5, script: 28, linebreak_class: 0, quickcheck: 0},
+    UCDRecord{category: 18, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 5, script: 28, linebreak_class: 1, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 29, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 14, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 29, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 41, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 41, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 41, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 42, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 42, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 42, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 43, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 43, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 44, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 44, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 30, linebreak_class: 36, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 30, linebreak_class: 36, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 30, linebreak_class: 36, quickcheck: 0},
+    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 30, linebreak_class: 36, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 30, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 30, linebreak_class: 5, quickcheck: 0},
+    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 30, linebreak_class: 36, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 30, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 23, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 5, script: 30, linebreak_class: 9, quickcheck: 0},
+    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 30, linebreak_class: 36, quickcheck: 0},
+    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 30, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 15, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 30, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 31, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 6, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 31, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 17, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 31, linebreak_class: 18, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 31, linebreak_class: 6, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 31, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 1, combining: 0, bidi_class: 14, mirrored: 0, east_asian_width: 5, script: 31, linebreak_class: 4, quickcheck: 0},
+    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 31, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 31, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 31, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 228, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 31, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 45, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 45, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 45, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 222, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 45, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 45, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 220, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 45, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 45, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 45, linebreak_class: 6, quickcheck: 0},
+    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 45, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 46, linebreak_class: 36, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 55, linebreak_class: 36, quickcheck: 0},
+    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 55, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 15, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 55, linebreak_class: 36, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 55, linebreak_class: 36, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 30, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 53, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 53, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 220, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 53, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 53, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 53, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 53, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 77, linebreak_class: 36, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 77, linebreak_class: 36, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 77, linebreak_class: 36, quickcheck: 0},
+    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 77, linebreak_class: 36, quickcheck: 0},
+    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 77, linebreak_class: 36, quickcheck: 0},
+    UCDRecord{category: 12, combining: 220, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 77, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 77, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 77, linebreak_class: 36, quickcheck: 0},
+    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 77, linebreak_class: 36, quickcheck: 0},
+    UCDRecord{category: 11, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 40, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 61, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 61, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 61, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 61, linebreak_class: 12, quickcheck: 10},
+    UCDRecord{category: 12, combining: 7, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 61, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 61, linebreak_class: 21, quickcheck: 80},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 61, linebreak_class: 21, quickcheck: 10},
+    UCDRecord{category: 10, combining: 9, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 61, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 61, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 61, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 61, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 61, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 61, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 220, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 61, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 66, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 66, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 66, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 10, combining: 9, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 66, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 66, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 66, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 92, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 7, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 92, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 92, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 92, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 10, combining: 9, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 92, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 92, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 67, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 67, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 67, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 7, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 67, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 67, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 67, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 68, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 68, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 68, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 68, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 66, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 1, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 40, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 1, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 3, linebreak_class: 12, quickcheck: 136},
+    UCDRecord{category: 12, combining: 234, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 40, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 214, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 40, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 202, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 40, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 233, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 40, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 5, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 1, linebreak_class: 12, quickcheck: 138},
+    UCDRecord{category: 5, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 2, linebreak_class: 12, quickcheck: 170},
+    UCDRecord{category: 8, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 2, linebreak_class: 12, quickcheck: 10},
+    UCDRecord{category: 9, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 2, linebreak_class: 12, quickcheck: 170},
+    UCDRecord{category: 24, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 2, linebreak_class: 12, quickcheck: 138},
+    UCDRecord{category: 24, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 2, linebreak_class: 12, quickcheck: 170},
+    UCDRecord{category: 24, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 2, linebreak_class: 18, quickcheck: 170},
+    UCDRecord{category: 29, combining: 0, bidi_class: 17, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 17, quickcheck: 170},
+    UCDRecord{category: 29, combining: 0, bidi_class: 17, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 17, quickcheck: 136},
+    UCDRecord{category: 29, combining: 0, bidi_class: 17, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 4, quickcheck: 136},
+    UCDRecord{category: 1, combining: 0, bidi_class: 14, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 20, quickcheck: 0},
+    UCDRecord{category: 1, combining: 0, bidi_class: 14, mirrored: 0, east_asian_width: 5, script: 40, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 1, combining: 0, bidi_class: 14, mirrored: 0, east_asian_width: 5, script: 40, linebreak_class: 40, quickcheck: 0},
+    UCDRecord{category: 1, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 1, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 17, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 17, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 4, quickcheck: 136},
+    UCDRecord{category: 17, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 17, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 19, quickcheck: 0},
+    UCDRecord{category: 17, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 29, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12, quickcheck: 136},
+    UCDRecord{category: 20, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 3, quickcheck: 0},
+    UCDRecord{category: 19, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 3, quickcheck: 0},
+    UCDRecord{category: 22, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 0, quickcheck: 0},
+    UCDRecord{category: 20, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 3, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 15, quickcheck: 136},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 27, combining: 0, bidi_class: 17, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 30, quickcheck: 0},
+    UCDRecord{category: 28, combining: 0, bidi_class: 15, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 30, quickcheck: 0},
+    UCDRecord{category: 1, combining: 0, bidi_class: 1, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 1, combining: 0, bidi_class: 5, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 1, combining: 0, bidi_class: 7, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 1, combining: 0, bidi_class: 2, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 1, combining: 0, bidi_class: 6, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 10, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 10, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 10, quickcheck: 136},
+    UCDRecord{category: 21, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 10, quickcheck: 136},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 10, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 10, quickcheck: 136},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 5, quickcheck: 136},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 5, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 12, quickcheck: 136},
+    UCDRecord{category: 16, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 25, combining: 0, bidi_class: 12, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 8, quickcheck: 0},
+    UCDRecord{category: 22, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 5, script: 0, linebreak_class: 0, quickcheck: 0},
+    UCDRecord{category: 18, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 5, script: 0, linebreak_class: 1, quickcheck: 0},
+    UCDRecord{category: 25, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 1, combining: 0, bidi_class: 14, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 22, quickcheck: 0},
+    UCDRecord{category: 1, combining: 0, bidi_class: 14, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 1, combining: 0, bidi_class: 19, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 1, combining: 0, bidi_class: 20, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 1, combining: 0, bidi_class: 21, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 1, combining: 0, bidi_class: 22, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 1, combining: 0, bidi_class: 14, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 15, combining: 0, bidi_class: 8, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12, quickcheck: 136},
+    UCDRecord{category: 25, combining: 0, bidi_class: 9, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12, quickcheck: 136},
+    UCDRecord{category: 25, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12, quickcheck: 136},
+    UCDRecord{category: 22, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 5, script: 0, linebreak_class: 0, quickcheck: 136},
+    UCDRecord{category: 18, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 5, script: 0, linebreak_class: 1, quickcheck: 136},
+    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 4, script: 1, linebreak_class: 29, quickcheck: 136},
+    UCDRecord{category: 23, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 10, quickcheck: 0},
+    UCDRecord{category: 23, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 9, quickcheck: 136},
+    UCDRecord{category: 23, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 1, script: 0, linebreak_class: 9, quickcheck: 0},
+    UCDRecord{category: 2, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 102, linebreak_class: 9, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12, quickcheck: 136},
+    UCDRecord{category: 9, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12, quickcheck: 136},
+    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 10, quickcheck: 136},
+    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 29, quickcheck: 136},
+    UCDRecord{category: 5, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 29, quickcheck: 136},
+    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 9, quickcheck: 136},
+    UCDRecord{category: 9, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 4, script: 2, linebreak_class: 12, quickcheck: 170},
+    UCDRecord{category: 9, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 1, linebreak_class: 12, quickcheck: 170},
+    UCDRecord{category: 9, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 4, script: 1, linebreak_class: 29, quickcheck: 170},
+    UCDRecord{category: 26, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12, quickcheck: 136},
+    UCDRecord{category: 25, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 5, script: 0, linebreak_class: 12, quickcheck: 136},
+    UCDRecord{category: 15, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12, quickcheck: 136},
+    UCDRecord{category: 15, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 12, quickcheck: 136},
+    UCDRecord{category: 15, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 29, quickcheck: 136},
+    UCDRecord{category: 14, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 4, script: 1, linebreak_class: 29, quickcheck: 136},
+    UCDRecord{category: 14, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 1, linebreak_class: 12, quickcheck: 136},
+    UCDRecord{category: 14, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 1, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 29, quickcheck: 0},
+    UCDRecord{category: 25, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12, quickcheck: 10},
+    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12, quickcheck: 10},
+    UCDRecord{category: 25, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 5, script: 0, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 25, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 4, script: 0, linebreak_class: 29, quickcheck: 0},
+    UCDRecord{category: 25, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 5, script: 0, linebreak_class: 12, quickcheck: 10},
+    UCDRecord{category: 25, combining: 0, bidi_class: 9, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 9, quickcheck: 0},
+    UCDRecord{category: 25, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 9, quickcheck: 0},
+    UCDRecord{category: 25, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 4, script: 0, linebreak_class: 29, quickcheck: 136},
+    UCDRecord{category: 25, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 4, script: 0, linebreak_class: 29, quickcheck: 10},
+    UCDRecord{category: 25, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 15, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 14, quickcheck: 0},
+    UCDRecord{category: 22, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 2, script: 0, linebreak_class: 0, quickcheck: 170},
+    UCDRecord{category: 18, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 2, script: 0, linebreak_class: 1, quickcheck: 170},
+    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 14, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 29, quickcheck: 136},
+    UCDRecord{category: 15, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 29, quickcheck: 0},
+    UCDRecord{category: 15, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 29, quickcheck: 0},
+    UCDRecord{category: 25, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 14, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 41, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 41, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 41, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 29, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 3, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 6, quickcheck: 0},
+    UCDRecord{category: 15, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 29, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 52, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 25, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 5, script: 0, linebreak_class: 12, quickcheck: 170},
+    UCDRecord{category: 9, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 56, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 5, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 56, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 54, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 54, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 54, linebreak_class: 6, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 54, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 15, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 54, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 5, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 23, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 57, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 57, linebreak_class: 12, quickcheck: 136},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 57, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 57, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 3, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 0, quickcheck: 0},
+    UCDRecord{category: 17, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 17, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 19, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 35, linebreak_class: 14, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 35, linebreak_class: 14, quickcheck: 136},
+    UCDRecord{category: 29, combining: 0, bidi_class: 17, mirrored: 0, east_asian_width: 0, script: 0, linebreak_class: 17, quickcheck: 136},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 1, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 14, quickcheck: 0},
+    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 35, linebreak_class: 5, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 14, quickcheck: 0},
+    UCDRecord{category: 14, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 35, linebreak_class: 14, quickcheck: 0},
+    UCDRecord{category: 22, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 2, script: 0, linebreak_class: 0, quickcheck: 0},
+    UCDRecord{category: 18, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 2, script: 0, linebreak_class: 1, quickcheck: 0},
+    UCDRecord{category: 17, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 5, quickcheck: 0},
+    UCDRecord{category: 22, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 0, quickcheck: 0},
+    UCDRecord{category: 18, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 1, quickcheck: 0},
+    UCDRecord{category: 12, combining: 218, bidi_class: 13, mirrored: 0, east_asian_width: 2, script: 40, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 228, bidi_class: 13, mirrored: 0, east_asian_width: 2, script: 40, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 232, bidi_class: 13, mirrored: 0, east_asian_width: 2, script: 40, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 222, bidi_class: 13, mirrored: 0, east_asian_width: 2, script: 40, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 10, combining: 224, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 24, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 17, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 14, quickcheck: 0},
+    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 14, quickcheck: 0},
+    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 14, quickcheck: 136},
+    UCDRecord{category: 14, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 35, linebreak_class: 14, quickcheck: 136},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 5, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 32, linebreak_class: 32, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 32, linebreak_class: 14, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 32, linebreak_class: 14, quickcheck: 10},
+    UCDRecord{category: 12, combining: 8, bidi_class: 13, mirrored: 0, east_asian_width: 2, script: 40, linebreak_class: 21, quickcheck: 80},
+    UCDRecord{category: 24, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 5, quickcheck: 136},
+    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 32, linebreak_class: 5, quickcheck: 0},
+    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 32, linebreak_class: 5, quickcheck: 10},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 32, linebreak_class: 14, quickcheck: 136},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 33, linebreak_class: 32, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 33, linebreak_class: 14, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 33, linebreak_class: 14, quickcheck: 10},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 5, quickcheck: 0},
+    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 32, quickcheck: 0},
+    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 33, linebreak_class: 5, quickcheck: 0},
+    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 33, linebreak_class: 5, quickcheck: 10},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 33, linebreak_class: 14, quickcheck: 136},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 34, linebreak_class: 14, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 24, linebreak_class: 14, quickcheck: 136},
+    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 14, quickcheck: 0},
+    UCDRecord{category: 15, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 14, quickcheck: 136},
+    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 14, quickcheck: 136},
+    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 24, linebreak_class: 14, quickcheck: 136},
+    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 24, linebreak_class: 14, quickcheck: 136},
+    UCDRecord{category: 15, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 29, quickcheck: 0},
+    UCDRecord{category: 15, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 14, quickcheck: 136},
+    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 33, linebreak_class: 14, quickcheck: 136},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 35, linebreak_class: 14, quickcheck: 0},
+    UCDRecord{category: 2, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 102, linebreak_class: 14, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 36, linebreak_class: 14, quickcheck: 0},
+    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 36, linebreak_class: 5, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 36, linebreak_class: 14, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 82, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 82, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 82, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 69, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 69, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 69, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 69, linebreak_class: 6, quickcheck: 0},
+    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 69, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 3, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 3, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 6, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 3, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 83, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 14, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 83, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 83, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 83, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 83, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 24, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 58, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 58, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 58, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 58, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 58, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 15, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 64, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 64, linebreak_class: 18, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 64, linebreak_class: 6, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 70, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 70, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 70, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 70, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 70, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 70, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 9, linebreak_class: 18, quickcheck: 0},
+    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 71, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 71, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 71, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 220, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 71, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 71, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 72, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 72, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 72, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 10, combining: 9, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 72, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 72, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 84, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 84, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 84, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 7, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 84, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 10, combining: 9, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 84, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 84, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 84, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 84, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 22, linebreak_class: 36, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 76, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 76, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 76, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 76, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 76, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 76, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 78, linebreak_class: 36, quickcheck: 0},
+    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 78, linebreak_class: 36, quickcheck: 0},
+    UCDRecord{category: 12, combining: 220, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 78, linebreak_class: 36, quickcheck: 0},
+    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 78, linebreak_class: 36, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 78, linebreak_class: 36, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 85, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 85, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 85, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 85, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 85, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 85, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 85, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 24, linebreak_class: 23, quickcheck: 10},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 24, linebreak_class: 24, quickcheck: 10},
+    UCDRecord{category: 4, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 102, linebreak_class: 37, quickcheck: 0},
+    UCDRecord{category: 3, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 4, script: 102, linebreak_class: 39, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 35, linebreak_class: 14, quickcheck: 170},
+    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 13, quickcheck: 170},
+    UCDRecord{category: 12, combining: 26, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 13, quickcheck: 136},
+    UCDRecord{category: 25, combining: 0, bidi_class: 9, mirrored: 0, east_asian_width: 5, script: 5, linebreak_class: 12, quickcheck: 136},
+    UCDRecord{category: 24, combining: 0, bidi_class: 4, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 18, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 1, quickcheck: 0},
+    UCDRecord{category: 23, combining: 0, bidi_class: 4, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 10, quickcheck: 136},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 4, script: 40, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 8, quickcheck: 136},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 1, quickcheck: 136},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 6, quickcheck: 136},
+    UCDRecord{category: 22, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 0, quickcheck: 136},
+    UCDRecord{category: 18, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 1, quickcheck: 136},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 15, quickcheck: 136},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 14, quickcheck: 136},
+    UCDRecord{category: 17, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 14, quickcheck: 136},
+    UCDRecord{category: 16, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 14, quickcheck: 136},
+    UCDRecord{category: 21, combining: 0, bidi_class: 12, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 1, quickcheck: 136},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 5, quickcheck: 136},
+    UCDRecord{category: 21, combining: 0, bidi_class: 12, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 5, quickcheck: 136},
+    UCDRecord{category: 22, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 2, script: 0, linebreak_class: 0, quickcheck: 136},
+    UCDRecord{category: 18, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 2, script: 0, linebreak_class: 1, quickcheck: 136},
+    UCDRecord{category: 21, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 14, quickcheck: 136},
+    UCDRecord{category: 25, combining: 0, bidi_class: 9, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 14, quickcheck: 136},
+    UCDRecord{category: 17, combining: 0, bidi_class: 9, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 14, quickcheck: 136},
+    UCDRecord{category: 25, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 2, script: 0, linebreak_class: 14, quickcheck: 136},
+    UCDRecord{category: 25, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 14, quickcheck: 136},
+    UCDRecord{category: 23, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 9, quickcheck: 136},
+    UCDRecord{category: 21, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 10, quickcheck: 136},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 0, script: 0, linebreak_class: 6, quickcheck: 136},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 0, script: 0, linebreak_class: 14, quickcheck: 136},
+    UCDRecord{category: 21, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 0, script: 0, linebreak_class: 14, quickcheck: 136},
+    UCDRecord{category: 23, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 0, script: 0, linebreak_class: 9, quickcheck: 136},
+    UCDRecord{category: 21, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 0, script: 0, linebreak_class: 10, quickcheck: 136},
+    UCDRecord{category: 22, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 0, script: 0, linebreak_class: 0, quickcheck: 136},
+    UCDRecord{category: 18, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 0, script: 0, linebreak_class: 1, quickcheck: 136},
+    UCDRecord{category: 25, combining: 0, bidi_class: 9, mirrored: 0, east_asian_width: 0, script: 0, linebreak_class: 14, quickcheck: 136},
+    UCDRecord{category: 21, combining: 0, bidi_class: 12, mirrored: 0, east_asian_width: 0, script: 0, linebreak_class: 1, quickcheck: 136},
+    UCDRecord{category: 17, combining: 0, bidi_class: 9, mirrored: 0, east_asian_width: 0, script: 0, linebreak_class: 14, quickcheck: 136},
+    UCDRecord{category: 21, combining: 0, bidi_class: 12, mirrored: 0, east_asian_width: 0, script: 0, linebreak_class: 14, quickcheck: 136},
+    UCDRecord{category: 13, combining: 0, bidi_class: 8, mirrored: 0, east_asian_width: 0, script: 0, linebreak_class: 14, quickcheck: 136},
+    UCDRecord{category: 21, combining: 0, bidi_class: 12, mirrored: 0, east_asian_width: 0, script: 0, linebreak_class: 5, quickcheck: 136},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 0, script: 0, linebreak_class: 5, quickcheck: 136},
+    UCDRecord{category: 25, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 0, script: 0, linebreak_class: 14, quickcheck: 136},
+    UCDRecord{category: 25, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 0, script: 0, linebreak_class: 14, quickcheck: 136},
+    UCDRecord{category: 9, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 0, script: 1, linebreak_class: 14, quickcheck: 136},
+    UCDRecord{category: 24, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 0, script: 0, linebreak_class: 14, quickcheck: 136},
+    UCDRecord{category: 16, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 0, script: 0, linebreak_class: 14, quickcheck: 136},
+    UCDRecord{category: 5, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 0, script: 1, linebreak_class: 14, quickcheck: 136},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 1, script: 0, linebreak_class: 1, quickcheck: 136},
+    UCDRecord{category: 22, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 1, script: 0, linebreak_class: 0, quickcheck: 136},
+    UCDRecord{category: 18, combining: 0, bidi_class: 18, mirrored: 1, east_asian_width: 1, script: 0, linebreak_class: 1, quickcheck: 136},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 1, script: 0, linebreak_class: 5, quickcheck: 136},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 1, script: 33, linebreak_class: 14, quickcheck: 136},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 1, script: 33, linebreak_class: 32, quickcheck: 136},
+    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 1, script: 0, linebreak_class: 32, quickcheck: 136},
+    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 1, script: 0, linebreak_class: 5, quickcheck: 136},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 1, script: 24, linebreak_class: 14, quickcheck: 136},
+    UCDRecord{category: 23, combining: 0, bidi_class: 10, mirrored: 0, east_asian_width: 0, script: 0, linebreak_class: 10, quickcheck: 136},
+    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 0, script: 0, linebreak_class: 14, quickcheck: 136},
+    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 1, script: 0, linebreak_class: 12, quickcheck: 136},
+    UCDRecord{category: 25, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 1, script: 0, linebreak_class: 12, quickcheck: 136},
+    UCDRecord{category: 1, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 31, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 47, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 14, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 2, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 15, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 2, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 2, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 2, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 73, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 74, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 15, combining: 0, bidi_class: 8, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 37, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 15, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 37, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 38, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 14, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 38, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 118, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 118, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 48, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 48, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 59, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 59, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 14, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 59, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 9, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 39, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 5, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 39, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 49, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 50, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 50, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 9, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 136, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 5, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 136, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 106, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 104, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 104, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 110, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 51, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 86, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 86, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 15, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 86, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 120, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 120, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 15, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 120, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 116, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 15, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 116, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 128, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 15, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 128, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 63, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 15, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 63, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 63, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 75, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 75, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 97, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 96, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 15, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 96, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 60, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 60, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 220, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 60, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 60, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 1, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 60, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 60, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 15, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 60, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 60, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 60, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 87, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 15, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 87, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 87, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 117, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 15, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 117, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 112, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 112, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 112, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 220, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 112, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 15, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 112, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 112, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 112, linebreak_class: 15, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 79, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 79, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 88, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 15, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 88, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 89, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 15, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 89, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 122, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 122, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 15, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 122, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 90, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 9, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 130, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 5, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 130, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 15, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 130, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 15, combining: 0, bidi_class: 11, mirrored: 0, east_asian_width: 5, script: 6, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 93, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 93, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 93, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 93, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 93, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 93, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 15, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 93, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 93, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 91, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 91, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 91, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 91, linebreak_class: 12, quickcheck: 10},
+    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 91, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 7, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 91, linebreak_class: 21, quickcheck: 80},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 91, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 1, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 91, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 91, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 100, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 100, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 95, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 95, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 95, linebreak_class: 21, quickcheck: 80},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 95, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 95, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 95, linebreak_class: 21, quickcheck: 10},
+    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 95, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 95, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 95, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 111, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 7, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 111, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 111, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 111, linebreak_class: 18, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 99, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 99, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 99, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 10, combining: 9, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 99, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 99, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 99, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 7, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 99, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 99, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 99, linebreak_class: 18, quickcheck: 0},
+    UCDRecord{category: 15, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 18, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 108, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 108, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 108, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 10, combining: 9, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 108, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 7, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 108, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 108, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 108, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 129, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 129, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 109, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 109, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 109, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 7, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 109, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 109, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 109, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 107, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 107, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 107, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 7, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 107, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 107, linebreak_class: 21, quickcheck: 80},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 107, linebreak_class: 21, quickcheck: 10},
+    UCDRecord{category: 10, combining: 9, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 107, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 107, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 135, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 135, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 135, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 135, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 7, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 135, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 135, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 135, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 135, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 124, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 124, linebreak_class: 21, quickcheck: 80},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 124, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 124, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 124, linebreak_class: 21, quickcheck: 80},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 124, linebreak_class: 21, quickcheck: 10},
+    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 124, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 7, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 124, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 124, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 124, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 123, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 123, linebreak_class: 21, quickcheck: 80},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 123, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 123, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 123, linebreak_class: 21, quickcheck: 10},
+    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 123, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 7, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 123, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 123, linebreak_class: 18, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 123, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 123, linebreak_class: 6, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 123, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 114, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 114, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 114, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 114, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 114, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 114, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 114, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 31, linebreak_class: 18, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 101, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 101, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 101, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 10, combining: 9, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 101, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 7, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 101, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 101, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 126, linebreak_class: 36, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 126, linebreak_class: 36, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 126, linebreak_class: 36, quickcheck: 0},
+    UCDRecord{category: 12, combining: 9, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 126, linebreak_class: 36, quickcheck: 0},
+    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 126, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 15, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 126, linebreak_class: 36, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 126, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 126, linebreak_class: 36, quickcheck: 0},
+    UCDRecord{category: 9, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 125, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 5, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 125, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 125, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 15, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 125, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 125, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 121, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 133, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 133, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 133, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 9, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 133, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 133, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 133, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 15, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 133, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 134, linebreak_class: 18, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 134, linebreak_class: 6, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 134, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 134, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 134, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 62, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 14, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 62, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 62, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 80, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 80, linebreak_class: 0, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 80, linebreak_class: 1, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 127, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 127, linebreak_class: 0, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 127, linebreak_class: 1, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 115, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 115, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 115, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 103, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 1, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 103, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 103, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 119, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 119, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 119, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 119, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 119, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 119, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 13, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 119, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 15, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 119, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 98, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 10, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 98, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 98, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 98, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 6, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 137, linebreak_class: 5, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 137, linebreak_class: 14, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 105, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 105, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 105, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 1, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 105, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 105, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12, quickcheck: 170},
+    UCDRecord{category: 10, combining: 216, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 10, combining: 226, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 2, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 25, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12, quickcheck: 136},
+    UCDRecord{category: 13, combining: 0, bidi_class: 8, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 11, quickcheck: 136},
+    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 131, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 0, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 131, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 131, linebreak_class: 17, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 131, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 56, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 7, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 113, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 15, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 113, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 220, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 113, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 9, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 132, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 5, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 132, linebreak_class: 12, quickcheck: 0},
+    UCDRecord{category: 12, combining: 230, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 132, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 12, combining: 7, bidi_class: 13, mirrored: 0, east_asian_width: 5, script: 132, linebreak_class: 21, quickcheck: 0},
+    UCDRecord{category: 13, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 132, linebreak_class: 11, quickcheck: 0},
+    UCDRecord{category: 21, combining: 0, bidi_class: 3, mirrored: 0, east_asian_width: 5, script: 132, linebreak_class: 0, quickcheck: 0},
+    UCDRecord{category: 2, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 102, linebreak_class: 14, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 12, quickcheck: 136},
+    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 4, script: 0, linebreak_class: 29, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 29, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 28, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 0, mirrored: 0, east_asian_width: 2, script: 32, linebreak_class: 14, quickcheck: 136},
+    UCDRecord{category: 24, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 2, script: 0, linebreak_class: 42, quickcheck: 0},
+    UCDRecord{category: 26, combining: 0, bidi_class: 18, mirrored: 0, east_asian_width: 5, script: 0, linebreak_class: 5, quickcheck: 0},
 ];
 
 pub static MIRROR_PAIRS: [MirrorPair; 364] = [
@@ -1978,23 +2114,23 @@ pub static INDEX0: [u8; 4352] = [
     69, 70, 71, 65, 66, 67, 68, 69, 70, 71, 65, 66, 67, 68, 69, 70, 71, 65, 
     66, 67, 68, 69, 70, 71, 65, 66, 67, 68, 69, 70, 71, 65, 72, 73, 73, 73, 
     73, 73, 73, 73, 73, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 
-    74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 52, 75, 76, 77, 78, 79, 
-    80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 92, 93, 94, 95, 94, 96, 
-    97, 98, 99, 100, 101, 102, 103, 104, 94, 105, 94, 106, 94, 94, 94, 107, 
-    107, 107, 108, 109, 110, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 111, 
-    111, 112, 113, 114, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 115, 116, 117, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 118, 118, 119, 120, 94, 94, 94, 121, 122, 122, 122, 122, 122, 
-    122, 122, 122, 122, 122, 122, 122, 122, 122, 122, 122, 122, 122, 122, 
-    122, 122, 122, 122, 123, 122, 122, 124, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 125, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 126, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 127, 128, 129, 130, 131, 132, 133, 134, 135, 
-    135, 136, 94, 94, 94, 94, 94, 137, 94, 94, 94, 94, 94, 94, 94, 138, 139, 
-    94, 94, 94, 94, 140, 94, 141, 142, 143, 144, 145, 146, 147, 148, 149, 
-    150, 151, 151, 151, 151, 151, 152, 52, 52, 52, 52, 52, 52, 52, 52, 52, 
+    74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 75, 76, 77, 78, 79, 80, 
+    81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 92, 93, 94, 95, 96, 95, 97, 
+    98, 99, 100, 101, 102, 103, 104, 105, 95, 106, 95, 107, 95, 95, 95, 108, 
+    108, 108, 109, 110, 111, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 112, 
+    112, 113, 114, 115, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 116, 117, 118, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 119, 119, 120, 121, 95, 95, 95, 122, 123, 123, 123, 123, 123, 
+    123, 123, 123, 123, 123, 123, 123, 123, 123, 123, 123, 123, 123, 123, 
+    123, 123, 123, 123, 124, 123, 123, 125, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 126, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 127, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 128, 129, 130, 131, 132, 133, 134, 135, 136, 
+    136, 137, 95, 95, 95, 95, 95, 138, 95, 95, 95, 95, 95, 95, 95, 139, 140, 
+    95, 95, 95, 95, 141, 95, 142, 143, 144, 145, 146, 147, 148, 149, 150, 
+    151, 152, 152, 152, 152, 152, 153, 52, 52, 52, 52, 52, 52, 52, 52, 52, 
     52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 
     52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 
     52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 
@@ -2003,188 +2139,188 @@ pub static INDEX0: [u8; 4352] = [
     52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 
     52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 
     52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 
-    52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 153, 52, 52, 52, 52, 
-    52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 154, 155, 52, 52, 52, 52, 
-    52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 156, 
-    157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 
-    157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 
-    157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 52, 52, 
-    158, 157, 157, 157, 157, 159, 157, 157, 157, 157, 157, 157, 157, 157, 
-    157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 
-    157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 
-    157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 
-    157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 
-    157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 
-    157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 
-    157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 
-    157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 
-    157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 
-    157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 
-    157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 
-    157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 
-    157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 
-    157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 
-    157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 
-    157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 
-    157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 
-    157, 157, 157, 157, 157, 157, 157, 157, 157, 159, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 160, 161, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 
-    94, 94, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 
+    52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 154, 52, 52, 52, 52, 
+    52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 155, 156, 52, 52, 52, 52, 
+    52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 157, 
+    158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 
+    158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 
+    158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 75, 75, 
+    159, 158, 158, 158, 158, 160, 158, 158, 158, 158, 158, 158, 158, 158, 
+    158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 
+    158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 
+    158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 
+    158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 
+    158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 
+    158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 
+    158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 
+    158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 
+    158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 
+    158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 
+    158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 
+    158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 
+    158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 
+    158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 
+    158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 
+    158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 
+    158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 
+    158, 158, 158, 158, 158, 158, 158, 158, 158, 160, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 161, 162, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 95, 
+    95, 95, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 
     74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 
     74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 
     74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 
@@ -2198,7 +2334,7 @@ pub static INDEX0: [u8; 4352] = [
     74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 
     74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 
     74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 
-    74, 74, 74, 74, 74, 162, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 
+    74, 74, 74, 74, 74, 163, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 
     74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 
     74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 
     74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 
@@ -2212,1156 +2348,1222 @@ pub static INDEX0: [u8; 4352] = [
     74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 
     74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 
     74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 74, 
-    74, 74, 74, 74, 74, 74, 74, 74, 74, 162, 
+    74, 74, 74, 74, 74, 74, 74, 74, 74, 163, 
 ];
 
-pub static INDEX1: [u16; 5216] = [
+pub static INDEX1: [u16; 5248] = [
     0, 1, 0, 2, 3, 4, 5, 6, 7, 8, 8, 9, 10, 11, 11, 12, 13, 0, 0, 0, 14, 15, 
     16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 29, 31, 32, 
     33, 34, 35, 27, 30, 29, 27, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 
-    47, 48, 27, 27, 49, 27, 27, 27, 27, 27, 27, 27, 50, 51, 52, 27, 53, 54, 
-    53, 54, 54, 54, 54, 54, 55, 54, 54, 54, 56, 57, 58, 59, 60, 61, 62, 63, 
-    64, 64, 65, 65, 66, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 65, 77, 78, 
-    79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 92, 93, 94, 95, 96, 
-    97, 97, 97, 97, 98, 98, 98, 98, 99, 100, 101, 101, 101, 101, 102, 103, 
-    101, 101, 101, 101, 101, 101, 104, 105, 101, 101, 101, 101, 101, 101, 
-    101, 101, 101, 101, 101, 101, 106, 107, 107, 107, 108, 109, 110, 111, 
-    111, 111, 111, 112, 113, 114, 115, 116, 117, 118, 119, 120, 121, 121, 
-    121, 122, 123, 120, 124, 125, 126, 127, 128, 128, 128, 128, 129, 130, 
-    131, 132, 133, 134, 135, 128, 128, 128, 128, 128, 128, 128, 128, 128, 
-    128, 128, 136, 137, 138, 139, 140, 141, 142, 143, 144, 145, 145, 145, 
-    146, 147, 148, 149, 128, 128, 128, 128, 128, 128, 150, 150, 150, 150, 
-    151, 152, 153, 120, 154, 155, 156, 156, 156, 157, 158, 159, 160, 160, 
-    161, 162, 163, 164, 165, 166, 167, 167, 167, 168, 120, 120, 120, 120, 
-    120, 120, 120, 120, 128, 128, 169, 170, 120, 120, 171, 126, 172, 173, 
-    174, 175, 176, 177, 177, 177, 177, 177, 177, 178, 179, 180, 181, 177, 
-    182, 183, 184, 177, 185, 186, 187, 188, 188, 189, 190, 191, 192, 193, 
-    194, 195, 196, 197, 198, 199, 200, 201, 202, 203, 203, 204, 205, 206, 
-    207, 208, 209, 210, 211, 212, 213, 120, 214, 215, 216, 217, 217, 218, 
-    219, 220, 221, 222, 223, 120, 224, 225, 226, 227, 228, 229, 230, 231, 
-    231, 232, 233, 234, 235, 236, 237, 238, 239, 240, 241, 120, 242, 243, 
-    244, 245, 246, 243, 247, 248, 249, 250, 251, 120, 252, 253, 254, 255, 
-    256, 257, 258, 259, 259, 258, 259, 260, 261, 262, 263, 264, 265, 266, 
-    120, 267, 268, 269, 270, 271, 271, 270, 272, 273, 274, 275, 276, 277, 
-    278, 279, 280, 120, 281, 282, 283, 284, 284, 284, 284, 285, 286, 287, 
-    288, 289, 290, 291, 292, 293, 294, 295, 296, 297, 295, 295, 298, 299, 
-    296, 300, 301, 302, 303, 304, 305, 120, 306, 307, 307, 307, 307, 307, 
-    308, 309, 310, 311, 312, 313, 120, 120, 120, 120, 314, 315, 316, 317, 
-    318, 319, 320, 321, 322, 323, 324, 325, 120, 120, 120, 120, 326, 327, 
-    328, 329, 330, 331, 332, 333, 334, 335, 334, 334, 334, 336, 337, 338, 
-    339, 340, 341, 342, 341, 341, 341, 343, 344, 345, 346, 347, 120, 120, 
-    120, 120, 348, 348, 348, 348, 348, 349, 350, 351, 352, 353, 354, 355, 
-    356, 357, 358, 348, 359, 360, 352, 361, 362, 362, 362, 362, 363, 364, 
-    365, 365, 365, 365, 365, 366, 367, 367, 367, 367, 367, 367, 367, 367, 
-    367, 367, 367, 367, 368, 368, 368, 368, 368, 368, 368, 368, 368, 369, 
-    369, 369, 369, 369, 369, 369, 369, 369, 369, 369, 370, 370, 370, 370, 
-    370, 370, 370, 370, 370, 371, 372, 371, 370, 370, 370, 370, 370, 371, 
-    370, 370, 370, 370, 371, 372, 371, 370, 372, 370, 370, 370, 370, 370, 
-    370, 370, 371, 370, 370, 370, 370, 370, 370, 370, 370, 373, 374, 375, 
-    376, 377, 370, 370, 378, 379, 380, 380, 380, 380, 380, 380, 380, 380, 
-    380, 380, 381, 382, 383, 384, 384, 384, 384, 384, 384, 384, 384, 384, 
-    384, 384, 384, 384, 384, 384, 384, 384, 384, 384, 384, 384, 384, 384, 
-    384, 384, 384, 384, 384, 384, 384, 384, 384, 384, 384, 384, 384, 384, 
-    384, 384, 384, 384, 384, 384, 384, 384, 384, 384, 384, 384, 384, 384, 
-    384, 384, 384, 384, 384, 384, 384, 384, 384, 384, 384, 384, 384, 384, 
-    384, 384, 384, 384, 384, 384, 384, 384, 384, 384, 384, 385, 384, 384, 
-    386, 387, 387, 388, 389, 389, 389, 389, 389, 389, 389, 389, 389, 390, 
-    391, 392, 393, 394, 395, 120, 396, 396, 397, 120, 398, 398, 399, 120, 
-    400, 401, 402, 120, 403, 403, 403, 403, 403, 403, 404, 405, 406, 407, 
-    408, 409, 410, 411, 412, 413, 414, 415, 416, 417, 418, 418, 418, 418, 
-    419, 418, 418, 418, 418, 418, 418, 120, 420, 418, 418, 418, 418, 421, 
-    384, 384, 384, 384, 384, 384, 384, 384, 422, 120, 423, 423, 423, 424, 
-    425, 426, 427, 428, 429, 430, 431, 431, 431, 432, 433, 120, 434, 434, 
-    434, 434, 434, 435, 434, 434, 434, 436, 437, 438, 439, 439, 439, 439, 
-    440, 440, 441, 442, 443, 443, 443, 443, 443, 443, 444, 445, 446, 447, 
-    448, 449, 450, 451, 450, 451, 452, 453, 454, 455, 120, 120, 120, 120, 
-    120, 120, 120, 120, 456, 457, 457, 457, 457, 457, 458, 459, 460, 461, 
-    462, 463, 464, 465, 466, 467, 468, 469, 469, 469, 470, 471, 472, 473, 
-    474, 474, 474, 474, 475, 476, 477, 478, 479, 479, 479, 479, 480, 481, 
-    482, 483, 484, 485, 486, 487, 488, 488, 488, 489, 100, 490, 120, 120, 
-    120, 120, 120, 120, 491, 120, 492, 493, 494, 495, 496, 497, 54, 54, 54, 
-    54, 498, 499, 56, 56, 56, 56, 56, 500, 501, 502, 54, 503, 54, 54, 54, 
-    504, 56, 56, 56, 505, 506, 507, 508, 509, 509, 509, 510, 511, 27, 27, 27, 
-    27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 512, 513, 27, 
-    27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 514, 515, 516, 517, 514, 515, 
-    514, 515, 516, 517, 514, 518, 514, 515, 514, 516, 514, 519, 514, 519, 
-    514, 519, 520, 521, 522, 523, 524, 525, 514, 526, 527, 528, 529, 530, 
-    531, 532, 533, 534, 535, 536, 537, 538, 539, 540, 541, 542, 543, 544, 
-    545, 546, 56, 547, 548, 549, 550, 551, 552, 552, 553, 554, 555, 556, 557, 
-    120, 558, 559, 560, 561, 562, 563, 564, 565, 566, 567, 568, 569, 570, 
-    571, 570, 572, 573, 574, 575, 576, 577, 578, 579, 580, 579, 581, 582, 
-    579, 583, 579, 584, 585, 586, 587, 588, 589, 590, 591, 592, 593, 594, 
-    595, 596, 597, 598, 599, 594, 594, 600, 601, 602, 603, 604, 594, 594, 
-    605, 585, 606, 607, 594, 594, 608, 594, 594, 579, 609, 610, 611, 612, 
-    613, 614, 615, 615, 615, 615, 615, 615, 615, 615, 616, 579, 579, 617, 
-    618, 585, 585, 619, 579, 579, 579, 579, 584, 620, 621, 622, 623, 579, 
-    579, 579, 579, 623, 120, 120, 120, 579, 624, 120, 120, 625, 625, 625, 
-    625, 625, 626, 626, 627, 628, 628, 628, 628, 628, 628, 628, 628, 628, 
-    629, 625, 630, 631, 631, 631, 631, 631, 631, 631, 631, 631, 632, 631, 
-    631, 631, 631, 633, 579, 631, 631, 634, 579, 635, 636, 637, 638, 639, 
-    640, 636, 579, 634, 641, 579, 642, 643, 644, 645, 646, 579, 579, 579, 
-    647, 648, 649, 650, 579, 651, 652, 579, 653, 579, 579, 654, 655, 656, 
-    657, 579, 658, 659, 660, 661, 662, 663, 664, 665, 666, 667, 668, 579, 
-    579, 579, 669, 579, 670, 579, 671, 672, 673, 674, 675, 676, 625, 677, 
-    677, 678, 579, 579, 579, 669, 679, 680, 681, 682, 683, 684, 685, 585, 
-    585, 686, 686, 686, 686, 686, 686, 686, 686, 686, 686, 686, 686, 686, 
-    686, 686, 686, 686, 686, 686, 686, 686, 686, 686, 686, 686, 686, 686, 
-    686, 686, 686, 686, 686, 585, 585, 585, 585, 585, 585, 585, 585, 585, 
-    585, 585, 585, 585, 585, 585, 585, 687, 688, 688, 689, 594, 594, 585, 
-    690, 691, 692, 693, 694, 695, 696, 697, 698, 585, 699, 594, 700, 701, 
-    702, 703, 683, 585, 585, 597, 690, 703, 704, 705, 706, 594, 594, 594, 
-    594, 707, 708, 594, 594, 594, 594, 709, 710, 711, 683, 712, 713, 579, 
-    579, 579, 714, 579, 579, 585, 585, 715, 716, 717, 636, 579, 579, 718, 
-    579, 579, 579, 719, 579, 579, 579, 579, 720, 579, 721, 722, 120, 120, 
-    723, 120, 120, 724, 724, 724, 724, 724, 725, 726, 726, 726, 726, 726, 
-    727, 728, 729, 730, 731, 92, 92, 92, 92, 92, 92, 92, 92, 92, 92, 92, 92, 
-    732, 733, 734, 735, 736, 736, 736, 736, 737, 738, 739, 739, 739, 739, 
-    739, 739, 739, 740, 741, 742, 370, 370, 372, 120, 372, 372, 372, 372, 
-    372, 372, 372, 372, 743, 743, 743, 743, 744, 745, 746, 747, 748, 749, 
-    750, 751, 752, 120, 120, 120, 120, 120, 120, 120, 753, 753, 753, 754, 
-    753, 753, 753, 753, 753, 753, 753, 753, 753, 753, 755, 120, 753, 753, 
-    753, 753, 753, 753, 753, 753, 753, 753, 753, 753, 753, 753, 753, 753, 
-    753, 753, 753, 753, 753, 753, 753, 753, 753, 753, 756, 120, 120, 120, 
-    757, 758, 759, 760, 761, 762, 763, 764, 765, 766, 767, 768, 769, 769, 
-    770, 769, 769, 769, 771, 772, 773, 774, 775, 776, 777, 777, 778, 777, 
-    777, 777, 779, 780, 781, 782, 783, 784, 784, 784, 784, 785, 786, 787, 
-    787, 787, 787, 787, 787, 787, 787, 787, 787, 788, 789, 790, 784, 784, 
-    784, 791, 757, 757, 757, 757, 758, 120, 792, 792, 793, 793, 793, 794, 
-    795, 796, 790, 790, 790, 797, 798, 799, 793, 793, 793, 800, 795, 796, 
-    790, 790, 790, 790, 801, 799, 790, 802, 803, 803, 803, 803, 803, 804, 
-    803, 803, 803, 803, 803, 803, 803, 803, 803, 803, 803, 790, 790, 790, 
-    805, 806, 790, 790, 790, 790, 790, 790, 790, 790, 790, 790, 790, 807, 
-    790, 790, 790, 805, 808, 808, 808, 808, 808, 808, 808, 808, 808, 808, 
-    808, 808, 808, 808, 808, 808, 808, 808, 808, 808, 808, 808, 808, 808, 
-    808, 808, 808, 808, 808, 808, 808, 808, 808, 808, 808, 808, 808, 808, 
-    808, 808, 808, 808, 808, 808, 808, 808, 808, 808, 808, 808, 808, 808, 
-    808, 808, 809, 810, 579, 579, 579, 579, 579, 579, 579, 579, 808, 808, 
-    808, 808, 808, 808, 808, 808, 808, 808, 808, 808, 808, 808, 808, 808, 
-    808, 808, 808, 808, 808, 808, 808, 808, 808, 808, 809, 810, 810, 810, 
-    810, 810, 811, 811, 812, 811, 811, 811, 811, 811, 811, 811, 811, 811, 
-    811, 811, 811, 811, 811, 811, 811, 811, 811, 811, 811, 811, 811, 811, 
-    811, 811, 811, 811, 811, 811, 811, 811, 811, 811, 811, 811, 811, 811, 
-    811, 811, 811, 811, 811, 811, 811, 811, 811, 811, 811, 811, 811, 811, 
-    811, 811, 811, 811, 811, 811, 811, 811, 811, 811, 811, 811, 811, 811, 
-    811, 811, 811, 811, 811, 811, 811, 811, 811, 811, 811, 811, 811, 813, 
-    814, 814, 814, 814, 814, 814, 815, 120, 816, 816, 816, 816, 816, 817, 
-    818, 818, 818, 818, 818, 818, 818, 818, 818, 818, 818, 818, 818, 818, 
-    818, 818, 818, 818, 818, 818, 818, 818, 818, 818, 818, 818, 818, 818, 
-    818, 818, 818, 818, 818, 819, 818, 818, 820, 821, 120, 120, 101, 101, 
-    101, 101, 101, 822, 823, 824, 101, 101, 101, 825, 826, 826, 826, 826, 
-    826, 826, 826, 826, 827, 828, 829, 120, 64, 64, 830, 831, 832, 27, 833, 
-    27, 27, 27, 27, 27, 27, 27, 834, 835, 27, 836, 837, 27, 27, 838, 839, 
-    120, 120, 120, 120, 120, 120, 120, 840, 841, 842, 843, 844, 844, 845, 
-    846, 847, 848, 849, 849, 849, 849, 849, 849, 850, 120, 851, 852, 852, 
-    852, 852, 852, 853, 854, 855, 856, 857, 858, 859, 859, 860, 861, 862, 
-    863, 864, 864, 865, 866, 867, 867, 868, 869, 870, 871, 367, 367, 367, 
-    872, 873, 874, 874, 874, 874, 874, 875, 876, 877, 878, 879, 880, 881, 
-    348, 352, 882, 883, 883, 883, 883, 883, 884, 885, 120, 886, 887, 888, 
-    889, 348, 348, 890, 891, 892, 892, 892, 892, 892, 892, 893, 894, 895, 
-    120, 120, 896, 897, 898, 899, 120, 900, 900, 900, 120, 372, 372, 54, 54, 
-    54, 54, 54, 901, 902, 120, 903, 903, 903, 903, 903, 903, 903, 903, 903, 
-    903, 897, 897, 897, 897, 904, 905, 906, 907, 908, 909, 909, 910, 909, 
-    909, 909, 908, 909, 909, 910, 909, 909, 909, 908, 909, 909, 910, 909, 
-    909, 909, 908, 909, 909, 910, 909, 909, 909, 908, 909, 909, 910, 909, 
-    909, 909, 908, 909, 909, 910, 909, 909, 909, 908, 909, 909, 910, 909, 
-    909, 909, 908, 909, 909, 910, 909, 909, 909, 908, 909, 909, 910, 909, 
-    909, 909, 908, 909, 909, 910, 909, 909, 909, 908, 909, 909, 910, 909, 
-    909, 909, 908, 909, 909, 910, 909, 909, 909, 908, 909, 909, 910, 909, 
-    909, 909, 908, 909, 909, 910, 909, 909, 909, 908, 909, 909, 910, 909, 
-    909, 909, 908, 909, 909, 910, 909, 909, 909, 908, 909, 909, 910, 909, 
-    909, 909, 908, 909, 909, 910, 909, 909, 909, 908, 909, 909, 910, 909, 
-    909, 909, 908, 909, 909, 910, 909, 909, 909, 908, 909, 909, 910, 909, 
-    909, 909, 908, 909, 909, 910, 909, 909, 909, 908, 909, 909, 910, 909, 
-    909, 909, 908, 909, 909, 910, 909, 909, 909, 908, 909, 909, 910, 909, 
-    909, 909, 908, 909, 909, 910, 909, 909, 909, 908, 909, 909, 910, 909, 
-    909, 909, 908, 909, 909, 910, 909, 909, 909, 908, 909, 909, 910, 909, 
-    909, 909, 908, 909, 909, 910, 909, 909, 909, 908, 909, 909, 910, 909, 
-    909, 909, 908, 909, 909, 910, 909, 909, 909, 909, 909, 909, 908, 909, 
-    909, 910, 909, 909, 909, 908, 909, 909, 910, 909, 909, 909, 908, 909, 
-    909, 911, 120, 368, 368, 912, 913, 369, 369, 369, 369, 369, 914, 915, 
-    915, 915, 915, 915, 915, 915, 915, 915, 915, 915, 915, 915, 915, 915, 
-    915, 915, 915, 915, 915, 915, 915, 915, 915, 915, 915, 915, 915, 915, 
-    915, 915, 915, 916, 916, 916, 916, 916, 916, 916, 916, 916, 916, 916, 
-    916, 916, 916, 916, 916, 916, 916, 916, 916, 916, 916, 916, 916, 916, 
-    916, 916, 916, 916, 916, 916, 916, 808, 808, 808, 808, 808, 808, 808, 
-    808, 808, 808, 808, 808, 808, 809, 808, 808, 808, 808, 808, 808, 808, 
-    808, 808, 808, 808, 808, 808, 917, 810, 810, 810, 810, 918, 120, 919, 
-    920, 121, 921, 922, 923, 924, 121, 128, 128, 128, 128, 128, 128, 128, 
-    128, 128, 128, 128, 128, 925, 926, 927, 120, 928, 128, 128, 128, 128, 
-    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 
-    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 
-    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 929, 120, 
-    120, 128, 128, 128, 128, 128, 128, 128, 128, 930, 128, 128, 128, 128, 
-    128, 128, 120, 120, 120, 120, 120, 128, 931, 932, 932, 933, 934, 935, 
-    936, 937, 938, 939, 940, 941, 942, 943, 944, 169, 128, 128, 128, 128, 
-    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 945, 946, 
-    947, 948, 949, 950, 951, 951, 952, 953, 954, 954, 955, 956, 957, 958, 
-    959, 959, 959, 959, 960, 961, 961, 961, 962, 963, 963, 963, 964, 965, 
-    966, 120, 967, 968, 969, 968, 968, 970, 968, 968, 971, 968, 972, 968, 
-    972, 120, 120, 120, 120, 968, 968, 968, 968, 968, 968, 968, 968, 968, 
-    968, 968, 968, 968, 968, 968, 973, 974, 975, 975, 975, 975, 975, 976, 
-    615, 977, 977, 977, 977, 977, 977, 978, 979, 980, 981, 579, 982, 983, 
-    120, 120, 120, 120, 120, 615, 615, 615, 615, 615, 984, 120, 120, 120, 
-    120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 985, 
-    985, 985, 986, 987, 987, 987, 987, 987, 987, 988, 120, 989, 990, 990, 
-    991, 992, 992, 992, 992, 993, 120, 994, 994, 995, 996, 997, 997, 997, 
-    997, 998, 999, 1000, 1000, 1000, 1001, 1002, 1002, 1002, 1002, 1003, 
-    1002, 1004, 120, 120, 120, 120, 120, 1005, 1005, 1005, 1005, 1005, 1006, 
-    1006, 1006, 1006, 1006, 1007, 1007, 1007, 1007, 1007, 1007, 1008, 1008, 
-    1008, 1009, 1010, 1011, 1012, 1012, 1012, 1012, 1013, 1014, 1014, 1014, 
-    1014, 1015, 1016, 1016, 1016, 1016, 1016, 120, 1017, 1017, 1017, 1017, 
-    1017, 1017, 1018, 1019, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 
-    120, 120, 120, 120, 120, 120, 120, 120, 1020, 1020, 1020, 1020, 1020, 
-    1020, 1020, 1020, 1020, 1020, 1020, 1020, 1020, 1020, 1020, 1020, 1020, 
-    1020, 1020, 1020, 1020, 1020, 1020, 1020, 1020, 1020, 1020, 1020, 1020, 
-    1020, 1020, 1020, 1020, 1020, 1020, 1020, 1020, 1020, 1021, 120, 1020, 
-    1020, 1022, 120, 1020, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 
-    120, 120, 120, 120, 120, 120, 120, 120, 120, 1023, 1024, 1025, 1025, 
-    1025, 1025, 1026, 1027, 1028, 1028, 1029, 1030, 1031, 1031, 1032, 1033, 
-    1034, 1034, 1034, 1035, 1036, 1037, 120, 120, 120, 120, 120, 120, 1038, 
-    1038, 1039, 1040, 1041, 1041, 1042, 1043, 1044, 1044, 1044, 1045, 120, 
-    120, 120, 120, 120, 120, 120, 120, 1046, 1046, 1046, 1046, 1047, 1047, 
-    1047, 1048, 1049, 1049, 1050, 1049, 1049, 1049, 1049, 1049, 1051, 1052, 
-    1053, 1054, 1055, 1055, 1056, 1057, 1058, 120, 1059, 1060, 1061, 1061, 
-    1061, 1062, 1063, 1063, 1063, 1064, 120, 120, 120, 120, 1065, 1066, 1065, 
-    1065, 1067, 1068, 1069, 120, 1070, 1070, 1070, 1070, 1070, 1070, 1071, 
-    1072, 1073, 1073, 1074, 1075, 1076, 1076, 1077, 1078, 1079, 1079, 1080, 
-    1081, 120, 1082, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 1083, 
-    1083, 1083, 1083, 1083, 1083, 1083, 1083, 1083, 1084, 120, 120, 120, 120, 
-    120, 120, 1085, 1085, 1085, 1085, 1085, 1085, 1086, 120, 1087, 1087, 
-    1087, 1087, 1087, 1087, 1088, 1089, 120, 120, 120, 120, 120, 120, 120, 
-    120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 
-    120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 
-    120, 120, 120, 120, 120, 120, 120, 120, 120, 1090, 1090, 1090, 1091, 120, 
-    120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 
-    120, 1092, 1093, 1093, 1093, 1093, 1093, 1093, 1094, 1095, 1096, 1097, 
-    1098, 1099, 1100, 120, 1101, 1102, 1103, 1103, 1103, 1103, 1103, 1104, 
-    1105, 1106, 120, 1107, 1107, 1107, 1108, 1109, 1110, 1111, 1112, 1112, 
-    1112, 1113, 1114, 1115, 1116, 1117, 120, 1118, 1118, 1118, 1118, 1119, 
-    120, 1120, 1121, 1121, 1121, 1121, 1121, 1122, 1123, 1124, 1125, 1126, 
-    1127, 1128, 1129, 1130, 120, 1131, 1131, 1132, 1131, 1131, 1133, 1134, 
-    1135, 120, 120, 120, 120, 120, 120, 120, 120, 1136, 1137, 1138, 1139, 
-    1138, 1140, 1141, 1141, 1141, 1141, 1141, 1142, 1143, 1144, 1145, 1146, 
-    1147, 1148, 1149, 1150, 1150, 1151, 1152, 1153, 1154, 1155, 1156, 1157, 
-    1158, 1159, 1159, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 
-    120, 120, 120, 120, 120, 120, 1160, 1160, 1160, 1160, 1160, 1160, 1161, 
-    1162, 1163, 1164, 1165, 1166, 120, 120, 120, 120, 1167, 1167, 1167, 1167, 
-    1167, 1167, 1168, 1169, 1170, 120, 1171, 1172, 120, 120, 120, 120, 120, 
-    120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 
-    120, 1173, 1173, 1173, 1173, 1173, 1174, 1175, 1176, 1177, 1178, 1179, 
-    1180, 120, 120, 120, 120, 1181, 1181, 1181, 1181, 1181, 1181, 1182, 1183, 
-    1184, 120, 1185, 1186, 1187, 1188, 120, 120, 1189, 1189, 1189, 1189, 
-    1189, 1190, 1191, 120, 1192, 1193, 120, 120, 120, 120, 120, 120, 1194, 
-    1194, 1194, 1195, 1196, 1197, 1198, 1199, 120, 120, 120, 120, 120, 120, 
-    120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 
-    120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 
-    120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 1200, 1200, 1200, 1200, 
-    1201, 1201, 1201, 1201, 1202, 1203, 1204, 1205, 120, 120, 120, 120, 120, 
-    120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 
-    120, 120, 120, 120, 120, 1206, 1206, 1206, 1206, 1206, 1206, 1206, 1207, 
-    1208, 1209, 1208, 1208, 1208, 1210, 1211, 1212, 1213, 120, 1214, 1215, 
-    1216, 1217, 1218, 1219, 1219, 1219, 1220, 1221, 1221, 1222, 1223, 120, 
-    120, 120, 120, 120, 120, 120, 120, 120, 1224, 1224, 1224, 1224, 1224, 
-    1224, 1224, 1224, 1224, 1224, 1224, 1224, 1224, 1224, 1224, 1224, 1224, 
-    1224, 1224, 1224, 1224, 1224, 1224, 1224, 1224, 1224, 1224, 1224, 1224, 
-    1224, 1224, 1224, 1224, 1224, 1224, 1224, 1224, 1224, 1224, 1224, 1224, 
-    1224, 1224, 1224, 1224, 1224, 1224, 1224, 1224, 1224, 1224, 1225, 120, 
-    120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 1226, 1226, 1226, 
-    1226, 1226, 1226, 1226, 1226, 1226, 1226, 1226, 1226, 1226, 1227, 1228, 
-    120, 1224, 1224, 1224, 1224, 1224, 1224, 1224, 1224, 1224, 1224, 1224, 
-    1224, 1224, 1224, 1224, 1224, 1224, 1224, 1224, 1224, 1224, 1224, 1224, 
-    1224, 1229, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 
-    120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 1230, 1230, 1230, 
-    1230, 1230, 1230, 1230, 1230, 1230, 1230, 1230, 1230, 1230, 1230, 1230, 
-    1230, 1230, 1230, 1230, 1230, 1230, 1230, 1230, 1230, 1230, 1230, 1230, 
-    1230, 1230, 1230, 1230, 1230, 1230, 1230, 1230, 1230, 1230, 1230, 1230, 
-    1230, 1230, 1230, 1230, 1231, 1230, 1230, 1230, 1230, 1232, 1233, 1230, 
-    1230, 1230, 1230, 1230, 1230, 1230, 1230, 1230, 1230, 1230, 1230, 1230, 
-    1230, 1230, 1230, 1230, 1230, 1230, 1230, 1230, 1230, 1230, 1230, 1230, 
-    1230, 1230, 1230, 1230, 1234, 1230, 1230, 1230, 1230, 1230, 1230, 1230, 
-    1230, 1230, 1230, 1230, 1230, 1230, 1230, 1230, 1230, 1230, 1230, 1230, 
-    1230, 1230, 1235, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 
-    120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 
-    120, 1236, 1236, 1236, 1236, 1236, 1236, 1236, 1236, 1236, 1236, 1236, 
-    1236, 1236, 1236, 1236, 1236, 1236, 1236, 1236, 1236, 1236, 1236, 1236, 
-    1236, 1236, 1236, 1236, 1236, 1236, 1236, 1236, 1236, 1236, 1236, 1236, 
-    1236, 1236, 1236, 1236, 1236, 1236, 1236, 1236, 1236, 1236, 1236, 1236, 
-    1236, 1236, 1236, 1236, 1236, 1236, 1236, 1236, 1236, 1236, 1237, 1236, 
-    1236, 1236, 1236, 1236, 1236, 1236, 1236, 1236, 1236, 1236, 1236, 1236, 
-    1236, 1238, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 
-    120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 826, 826, 826, 
-    826, 826, 826, 826, 826, 826, 826, 826, 826, 826, 826, 826, 826, 826, 
-    826, 826, 826, 826, 826, 826, 826, 826, 826, 826, 826, 826, 826, 826, 
-    826, 826, 826, 826, 826, 826, 826, 826, 1239, 1240, 1240, 1240, 1241, 
-    1242, 1243, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 
-    1244, 1244, 1244, 1245, 1246, 120, 1247, 1247, 1247, 1247, 1247, 1247, 
-    1248, 1249, 1250, 120, 1251, 1252, 1253, 1247, 1247, 1254, 1247, 1247, 
-    120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 
-    1255, 1255, 1255, 1255, 1255, 1255, 1255, 1255, 1256, 120, 1257, 1258, 
-    1258, 1258, 1258, 1259, 120, 1260, 1261, 1262, 120, 120, 120, 120, 120, 
-    120, 120, 120, 1263, 120, 120, 120, 1264, 1264, 1264, 1264, 1264, 1264, 
-    1264, 1264, 1264, 1264, 1264, 1264, 1264, 1264, 1264, 1264, 1264, 1264, 
-    1264, 1264, 1264, 1264, 1264, 1264, 1264, 1264, 1264, 1264, 1264, 1264, 
-    1264, 1264, 1264, 1264, 1264, 1264, 1264, 1264, 1264, 1264, 1264, 1264, 
-    1264, 1264, 1264, 1264, 1264, 1264, 1264, 1264, 1264, 1264, 1264, 1264, 
-    1264, 1264, 1264, 1264, 1264, 1264, 1264, 1265, 120, 120, 1264, 1264, 
-    1264, 1264, 1264, 1264, 1264, 1264, 1264, 1264, 1264, 1264, 1264, 1264, 
-    1264, 1264, 1264, 1264, 1264, 1264, 1264, 1264, 1264, 1264, 1264, 1264, 
-    1264, 1264, 1264, 1264, 1266, 120, 1267, 120, 120, 120, 120, 120, 120, 
-    120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 
-    120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 1268, 1268, 1268, 
-    1268, 1268, 1268, 1268, 1268, 1268, 1268, 1268, 1268, 1268, 1269, 1268, 
-    1270, 1268, 1271, 1268, 1272, 1273, 120, 120, 120, 120, 120, 120, 120, 
-    120, 120, 120, 120, 615, 615, 615, 615, 615, 615, 615, 615, 615, 615, 
-    615, 615, 615, 615, 615, 615, 615, 615, 615, 615, 615, 615, 615, 615, 
-    615, 615, 615, 615, 615, 615, 1274, 120, 615, 615, 615, 615, 1275, 1276, 
-    615, 615, 615, 615, 615, 615, 1277, 1278, 1279, 1280, 1281, 1282, 615, 
-    615, 615, 1283, 615, 615, 615, 615, 615, 615, 615, 1284, 120, 120, 980, 
-    980, 980, 980, 980, 980, 980, 980, 1285, 120, 120, 120, 120, 120, 120, 
-    120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 
-    120, 120, 120, 579, 579, 579, 579, 579, 579, 579, 579, 579, 579, 623, 
-    120, 975, 975, 1286, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 
-    120, 120, 120, 120, 120, 120, 120, 1287, 1287, 1287, 1288, 1289, 1289, 
-    1290, 1287, 1287, 1291, 1292, 1289, 1289, 1287, 1287, 1287, 1288, 1289, 
-    1289, 1293, 1294, 1295, 1291, 1296, 1297, 1289, 1287, 1287, 1287, 1288, 
-    1289, 1289, 1298, 1299, 1300, 1301, 1289, 1289, 1289, 1302, 1303, 1304, 
-    1305, 1289, 1289, 1290, 1287, 1287, 1291, 1289, 1289, 1289, 1287, 1287, 
-    1287, 1288, 1289, 1289, 1290, 1287, 1287, 1291, 1289, 1289, 1289, 1287, 
-    1287, 1287, 1288, 1289, 1289, 1290, 1287, 1287, 1291, 1289, 1289, 1289, 
-    1287, 1287, 1287, 1288, 1289, 1289, 1306, 1287, 1287, 1287, 1307, 1289, 
-    1289, 1308, 1309, 1287, 1287, 1310, 1289, 1289, 1311, 1290, 1287, 1287, 
-    1312, 1289, 1289, 1313, 1314, 1287, 1287, 1315, 1289, 1289, 1289, 1316, 
-    1287, 1287, 1287, 1307, 1289, 1289, 1308, 1317, 1318, 1318, 1318, 1318, 
-    1318, 1318, 1319, 1319, 1319, 1319, 1319, 1319, 1319, 1319, 1319, 1319, 
-    1319, 1319, 1319, 1319, 1319, 1319, 1319, 1319, 1319, 1319, 1319, 1319, 
-    1319, 1319, 1319, 1319, 1319, 1319, 1319, 1319, 1319, 1319, 1320, 1320, 
-    1320, 1320, 1320, 1320, 1321, 1322, 1320, 1320, 1320, 1320, 1320, 1323, 
-    1324, 1319, 1325, 1326, 120, 1327, 1328, 1320, 120, 120, 120, 120, 120, 
-    120, 120, 120, 120, 120, 1329, 1330, 1330, 1331, 1332, 1333, 120, 120, 
-    120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 
-    120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 1334, 1334, 1334, 1334, 
-    1334, 1334, 1334, 1334, 1334, 1334, 1334, 1334, 1334, 1334, 1334, 1334, 
-    1334, 1334, 1334, 1334, 1334, 1334, 1334, 1334, 1335, 1336, 1337, 120, 
-    120, 120, 120, 120, 1338, 1338, 1338, 1338, 1339, 1340, 1340, 1340, 1341, 
-    1342, 1343, 1344, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 
-    120, 120, 120, 120, 120, 120, 120, 120, 120, 1345, 128, 128, 128, 1346, 
-    1347, 1348, 1349, 1350, 1351, 1346, 1352, 1346, 1348, 1348, 1353, 128, 
-    1354, 128, 1355, 1356, 1354, 128, 1355, 120, 120, 120, 120, 120, 120, 
-    1357, 120, 1358, 1359, 1359, 1359, 1359, 1360, 1359, 1359, 1359, 1359, 
-    1359, 1359, 1359, 1359, 1359, 1359, 1359, 1359, 1360, 1361, 1359, 1362, 
-    1363, 1359, 1363, 1364, 1363, 1359, 1359, 1359, 1365, 1361, 626, 1366, 
-    628, 628, 628, 1367, 628, 628, 628, 628, 628, 628, 628, 1368, 628, 628, 
-    628, 1369, 1370, 1371, 628, 1372, 1361, 1361, 1361, 1361, 1361, 1361, 
-    1373, 1374, 1374, 1374, 1375, 1361, 790, 790, 790, 790, 790, 1376, 790, 
-    1377, 1378, 1361, 1361, 1361, 1361, 1361, 1361, 1361, 1361, 1361, 1361, 
-    1361, 1361, 1361, 1361, 1361, 1361, 1361, 1361, 1361, 1361, 1361, 757, 
-    757, 757, 757, 1379, 1380, 1381, 757, 757, 757, 757, 757, 757, 757, 757, 
-    1382, 1383, 757, 1384, 1385, 757, 757, 1386, 1387, 1388, 1389, 1384, 
-    1359, 757, 757, 1390, 1391, 757, 757, 757, 757, 757, 757, 757, 1392, 
-    1393, 1394, 1395, 757, 1396, 1397, 1394, 1398, 1399, 757, 757, 757, 1400, 
-    1401, 1402, 757, 757, 757, 757, 757, 757, 757, 757, 1403, 1404, 757, 
-    1405, 649, 1406, 757, 1407, 1408, 579, 1409, 757, 757, 757, 1359, 1410, 
-    1411, 1359, 1359, 1412, 1359, 1358, 1359, 1359, 1359, 1359, 1359, 1413, 
-    1414, 1359, 1359, 1413, 1415, 757, 757, 757, 757, 757, 757, 757, 757, 
-    1416, 1417, 579, 579, 579, 579, 1418, 1419, 757, 757, 757, 757, 1420, 
-    757, 1421, 757, 1422, 1358, 1423, 1361, 1359, 1424, 1425, 1361, 579, 579, 
-    579, 579, 579, 579, 579, 579, 579, 579, 579, 579, 579, 579, 1426, 1361, 
-    579, 579, 579, 579, 579, 579, 579, 579, 579, 579, 1427, 1361, 1361, 1361, 
-    1361, 1361, 579, 1426, 579, 579, 579, 579, 579, 579, 579, 1361, 579, 
-    1428, 579, 579, 579, 579, 579, 1361, 579, 579, 579, 1429, 1361, 1361, 
-    1361, 1361, 1361, 1361, 1361, 1361, 1361, 1361, 1361, 1361, 757, 1430, 
-    1431, 1361, 1432, 1433, 757, 1434, 757, 1435, 1361, 1361, 1361, 1361, 
-    757, 757, 1436, 1361, 1361, 1361, 1361, 1361, 1437, 1361, 1361, 1361, 
-    1361, 1361, 1361, 1361, 1361, 1361, 1361, 1361, 1361, 1361, 1361, 1361, 
-    1361, 1361, 1361, 1361, 1361, 1361, 1361, 1361, 1361, 1361, 1361, 1361, 
-    1361, 1361, 1361, 1361, 1361, 1361, 1361, 1361, 1361, 1361, 1361, 1361, 
-    1361, 1361, 1361, 1361, 1361, 1361, 1361, 1361, 1361, 1361, 1361, 1361, 
-    1361, 1361, 1361, 1361, 1361, 1361, 1361, 1361, 1361, 1361, 1361, 1361, 
-    1361, 1361, 1361, 1361, 1361, 1361, 1361, 1438, 808, 808, 808, 808, 808, 
-    808, 808, 808, 808, 808, 808, 808, 808, 808, 808, 808, 808, 808, 808, 
-    808, 808, 808, 808, 808, 808, 808, 1439, 810, 810, 810, 810, 810, 808, 
-    808, 808, 808, 808, 808, 1440, 810, 808, 808, 808, 808, 808, 808, 808, 
-    808, 808, 808, 808, 808, 808, 808, 808, 808, 808, 808, 808, 808, 808, 
-    808, 808, 808, 808, 808, 808, 809, 808, 808, 808, 808, 808, 808, 808, 
-    808, 808, 808, 808, 808, 808, 808, 808, 808, 808, 808, 808, 808, 808, 
-    808, 808, 808, 808, 808, 808, 808, 808, 808, 808, 808, 808, 808, 808, 
-    808, 808, 808, 808, 808, 808, 808, 808, 808, 808, 808, 808, 808, 917, 
-    810, 810, 810, 810, 810, 810, 810, 810, 810, 810, 810, 810, 810, 810, 
-    810, 810, 810, 810, 810, 810, 810, 810, 810, 810, 810, 810, 810, 810, 
-    810, 810, 810, 810, 810, 810, 810, 810, 810, 810, 810, 810, 810, 810, 
-    810, 808, 808, 808, 809, 810, 810, 810, 810, 810, 810, 810, 810, 810, 
-    810, 810, 810, 810, 810, 810, 810, 810, 810, 810, 810, 810, 810, 810, 
-    810, 810, 810, 810, 810, 810, 810, 810, 810, 810, 810, 810, 810, 810, 
-    810, 810, 810, 810, 810, 810, 810, 810, 810, 810, 810, 810, 810, 810, 
-    810, 810, 810, 810, 810, 810, 810, 810, 1441, 1442, 120, 120, 120, 1443, 
-    1443, 1443, 1443, 1443, 1443, 1443, 1443, 1443, 1443, 1443, 1443, 120, 
-    120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 
-    120, 932, 932, 932, 932, 932, 932, 932, 932, 932, 932, 932, 932, 932, 
-    932, 932, 932, 932, 932, 932, 932, 932, 932, 932, 932, 932, 932, 932, 
-    932, 932, 932, 120, 120, 916, 916, 916, 916, 916, 916, 916, 916, 916, 
-    916, 916, 916, 916, 916, 916, 916, 916, 916, 916, 916, 916, 916, 916, 
-    916, 916, 916, 916, 916, 916, 916, 916, 1444, 
+    47, 48, 49, 27, 50, 27, 27, 27, 27, 49, 51, 27, 52, 53, 54, 55, 56, 57, 
+    56, 57, 57, 57, 57, 57, 58, 57, 57, 57, 59, 60, 61, 62, 63, 64, 65, 66, 
+    67, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79, 80, 81, 82, 83, 
+    84, 85, 86, 87, 88, 89, 90, 91, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 
+    102, 103, 102, 102, 104, 105, 104, 104, 106, 107, 108, 108, 109, 108, 
+    110, 111, 108, 108, 108, 108, 108, 108, 112, 113, 114, 115, 115, 115, 
+    116, 117, 108, 108, 108, 108, 108, 108, 118, 119, 119, 119, 120, 121, 
+    122, 123, 123, 123, 124, 125, 126, 127, 128, 129, 130, 131, 132, 133, 
+    134, 134, 134, 135, 136, 133, 137, 138, 139, 140, 141, 142, 142, 142, 
+    143, 144, 145, 146, 147, 148, 149, 150, 142, 142, 142, 142, 142, 142, 
+    142, 142, 151, 142, 152, 153, 154, 155, 156, 157, 158, 159, 160, 161, 
+    161, 161, 162, 163, 164, 165, 142, 142, 142, 142, 142, 142, 166, 166, 
+    166, 166, 167, 168, 169, 133, 170, 171, 172, 172, 172, 173, 174, 175, 
+    176, 176, 177, 178, 179, 180, 181, 182, 183, 183, 183, 184, 133, 133, 
+    133, 133, 133, 133, 133, 133, 142, 142, 185, 186, 133, 133, 187, 139, 
+    188, 189, 190, 191, 192, 193, 193, 193, 193, 194, 195, 196, 197, 198, 
+    199, 200, 201, 202, 203, 193, 204, 205, 206, 207, 207, 208, 209, 210, 
+    211, 212, 213, 214, 215, 216, 217, 218, 219, 220, 221, 222, 222, 223, 
+    224, 225, 226, 227, 228, 229, 230, 231, 232, 133, 233, 234, 235, 236, 
+    236, 237, 238, 239, 240, 241, 242, 133, 243, 244, 245, 246, 247, 248, 
+    249, 250, 250, 251, 252, 253, 254, 255, 256, 257, 258, 259, 260, 133, 
+    261, 262, 263, 264, 265, 262, 266, 267, 268, 269, 270, 133, 271, 272, 
+    273, 274, 275, 276, 277, 278, 278, 277, 278, 279, 280, 281, 282, 283, 
+    284, 285, 133, 286, 287, 288, 289, 290, 290, 289, 291, 292, 293, 294, 
+    295, 296, 297, 298, 299, 133, 300, 301, 302, 303, 303, 303, 303, 304, 
+    305, 306, 307, 308, 309, 310, 311, 312, 313, 314, 315, 316, 314, 314, 
+    317, 318, 315, 319, 320, 321, 322, 323, 324, 133, 325, 326, 326, 326, 
+    326, 326, 327, 328, 329, 330, 331, 332, 133, 133, 133, 133, 333, 334, 
+    335, 336, 337, 338, 339, 340, 341, 342, 343, 344, 133, 133, 133, 133, 
+    345, 346, 347, 348, 349, 350, 351, 352, 353, 354, 355, 356, 357, 358, 
+    359, 360, 361, 362, 363, 364, 365, 366, 367, 368, 369, 370, 371, 372, 
+    133, 133, 133, 133, 373, 373, 373, 373, 374, 375, 376, 377, 378, 379, 
+    380, 381, 382, 383, 384, 373, 385, 386, 378, 387, 388, 388, 388, 388, 
+    389, 390, 391, 391, 391, 391, 391, 392, 393, 393, 393, 393, 393, 393, 
+    393, 393, 393, 393, 393, 393, 394, 395, 396, 397, 397, 397, 397, 397, 
+    397, 398, 398, 398, 399, 400, 400, 400, 400, 400, 400, 400, 401, 401, 
+    401, 401, 401, 401, 401, 401, 401, 402, 403, 402, 401, 401, 401, 401, 
+    401, 402, 401, 401, 401, 401, 402, 403, 402, 401, 403, 401, 401, 401, 
+    401, 401, 401, 401, 402, 401, 401, 401, 401, 401, 401, 401, 401, 404, 
+    405, 406, 407, 408, 401, 401, 409, 410, 411, 411, 411, 411, 411, 411, 
+    411, 411, 411, 411, 412, 413, 414, 415, 415, 415, 415, 415, 415, 415, 
+    415, 415, 415, 415, 415, 415, 415, 415, 415, 415, 415, 415, 415, 415, 
+    415, 415, 415, 415, 415, 415, 415, 415, 415, 415, 415, 415, 415, 415, 
+    415, 415, 415, 415, 415, 415, 415, 415, 415, 415, 415, 415, 415, 415, 
+    415, 415, 415, 415, 415, 415, 415, 415, 415, 415, 415, 415, 415, 415, 
+    415, 415, 415, 415, 415, 415, 415, 415, 415, 415, 415, 415, 415, 416, 
+    415, 415, 417, 418, 418, 419, 420, 420, 420, 420, 420, 420, 420, 420, 
+    420, 421, 422, 423, 424, 425, 426, 133, 427, 427, 428, 133, 429, 429, 
+    430, 133, 431, 432, 433, 133, 434, 434, 434, 434, 434, 434, 435, 436, 
+    437, 438, 439, 440, 441, 442, 443, 444, 445, 446, 447, 448, 449, 449, 
+    449, 449, 450, 449, 449, 449, 449, 449, 449, 133, 451, 449, 449, 449, 
+    449, 452, 415, 415, 415, 415, 415, 415, 415, 415, 453, 133, 454, 454, 
+    454, 455, 456, 457, 458, 459, 460, 461, 462, 462, 462, 463, 464, 133, 
+    465, 465, 465, 465, 465, 466, 465, 465, 465, 467, 468, 469, 470, 470, 
+    470, 470, 471, 471, 472, 473, 474, 474, 474, 474, 474, 474, 475, 476, 
+    477, 478, 479, 480, 481, 482, 481, 482, 483, 484, 485, 486, 133, 133, 
+    133, 133, 133, 133, 133, 133, 487, 488, 489, 490, 490, 490, 491, 492, 
+    493, 494, 495, 496, 497, 498, 499, 500, 501, 502, 502, 502, 503, 504, 
+    505, 506, 507, 507, 507, 507, 508, 509, 510, 511, 512, 512, 512, 512, 
+    513, 514, 515, 516, 517, 518, 519, 520, 521, 521, 521, 522, 523, 524, 
+    133, 133, 133, 133, 133, 133, 525, 133, 526, 527, 528, 529, 530, 531, 57, 
+    57, 57, 57, 532, 533, 59, 534, 59, 535, 59, 536, 537, 538, 57, 539, 57, 
+    57, 57, 540, 59, 59, 59, 541, 542, 543, 544, 545, 545, 545, 546, 547, 27, 
+    27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 548, 
+    549, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 550, 551, 552, 553, 554, 
+    551, 552, 551, 552, 553, 554, 551, 555, 551, 552, 556, 557, 551, 558, 
+    551, 558, 551, 558, 559, 560, 561, 562, 563, 564, 565, 566, 567, 568, 
+    569, 570, 571, 572, 573, 574, 575, 576, 577, 578, 579, 580, 581, 582, 
+    583, 584, 585, 586, 59, 587, 588, 589, 590, 591, 592, 592, 593, 594, 595, 
+    596, 597, 133, 598, 599, 600, 601, 602, 603, 604, 605, 606, 607, 608, 
+    609, 610, 611, 610, 612, 613, 614, 615, 616, 617, 618, 619, 620, 619, 
+    621, 622, 619, 623, 619, 624, 625, 626, 627, 628, 629, 630, 631, 632, 
+    633, 634, 635, 636, 637, 638, 639, 640, 641, 642, 643, 644, 645, 646, 
+    647, 648, 649, 625, 650, 651, 648, 652, 653, 648, 648, 619, 654, 655, 
+    656, 657, 658, 659, 660, 660, 660, 660, 660, 660, 660, 660, 661, 619, 
+    619, 662, 663, 625, 625, 664, 619, 619, 619, 619, 624, 665, 666, 667, 
+    668, 619, 619, 619, 619, 668, 133, 133, 133, 619, 669, 133, 133, 670, 
+    670, 670, 670, 670, 671, 671, 672, 673, 673, 673, 673, 673, 673, 673, 
+    673, 673, 674, 675, 676, 677, 677, 677, 677, 677, 677, 677, 677, 677, 
+    678, 677, 677, 677, 677, 679, 619, 677, 677, 680, 619, 681, 682, 683, 
+    684, 685, 686, 682, 619, 680, 687, 619, 688, 689, 690, 691, 692, 619, 
+    619, 619, 693, 694, 695, 696, 619, 697, 698, 619, 699, 619, 619, 700, 
+    701, 702, 703, 619, 704, 705, 706, 707, 708, 709, 710, 711, 712, 713, 
+    714, 619, 619, 619, 715, 619, 716, 619, 717, 718, 719, 720, 721, 722, 
+    675, 723, 723, 724, 619, 619, 619, 715, 725, 726, 727, 728, 729, 730, 
+    731, 625, 625, 732, 732, 732, 732, 732, 732, 732, 732, 732, 732, 732, 
+    732, 732, 732, 732, 732, 732, 732, 732, 732, 732, 732, 732, 732, 732, 
+    732, 732, 732, 732, 732, 732, 732, 625, 625, 625, 625, 625, 625, 625, 
+    625, 625, 625, 625, 625, 625, 625, 625, 625, 733, 734, 734, 735, 648, 
+    648, 625, 736, 737, 738, 739, 740, 741, 742, 743, 744, 625, 745, 648, 
+    746, 747, 748, 749, 729, 625, 625, 637, 736, 749, 750, 751, 752, 648, 
+    648, 648, 648, 753, 754, 648, 648, 648, 648, 755, 756, 757, 729, 758, 
+    759, 619, 619, 619, 760, 619, 619, 625, 625, 761, 762, 763, 682, 619, 
+    619, 764, 619, 619, 619, 765, 619, 619, 619, 619, 766, 619, 767, 768, 
+    133, 133, 769, 133, 133, 770, 770, 770, 770, 770, 771, 772, 772, 772, 
+    772, 772, 773, 774, 775, 776, 777, 97, 97, 97, 97, 97, 97, 97, 97, 97, 
+    97, 97, 97, 778, 779, 780, 781, 782, 782, 782, 782, 783, 784, 785, 785, 
+    785, 785, 785, 785, 785, 786, 787, 788, 401, 401, 403, 133, 403, 403, 
+    403, 403, 403, 403, 403, 403, 789, 789, 789, 789, 790, 791, 792, 793, 
+    794, 795, 796, 797, 798, 133, 133, 133, 133, 133, 133, 133, 799, 799, 
+    799, 800, 799, 799, 799, 799, 799, 799, 799, 799, 799, 799, 801, 133, 
+    802, 802, 802, 802, 802, 802, 802, 802, 802, 802, 802, 802, 802, 802, 
+    802, 802, 802, 802, 802, 802, 802, 802, 802, 802, 802, 802, 803, 133, 
+    133, 133, 804, 805, 806, 807, 808, 809, 810, 811, 812, 813, 814, 815, 
+    816, 816, 817, 818, 819, 820, 821, 822, 823, 824, 825, 826, 827, 827, 
+    828, 829, 830, 831, 832, 833, 834, 835, 836, 837, 837, 837, 837, 838, 
+    839, 840, 840, 840, 840, 840, 840, 840, 840, 840, 840, 841, 842, 843, 
+    837, 837, 837, 844, 804, 804, 804, 804, 805, 133, 845, 845, 846, 846, 
+    846, 847, 848, 849, 843, 843, 843, 850, 851, 852, 846, 846, 846, 853, 
+    848, 849, 843, 843, 843, 843, 854, 852, 843, 855, 856, 856, 856, 856, 
+    856, 857, 856, 856, 856, 856, 856, 856, 856, 856, 856, 856, 856, 843, 
+    843, 843, 858, 859, 843, 843, 843, 843, 843, 843, 843, 843, 843, 843, 
+    843, 860, 843, 843, 843, 858, 861, 861, 861, 861, 861, 861, 861, 861, 
+    861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 
+    861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 
+    861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 
+    861, 861, 861, 861, 862, 863, 619, 619, 619, 619, 619, 619, 619, 619, 
+    861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 
+    861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 862, 863, 
+    863, 863, 863, 863, 864, 864, 865, 864, 864, 864, 864, 864, 864, 864, 
+    864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 
+    864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 
+    864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 
+    864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 
+    864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 
+    864, 866, 867, 867, 867, 867, 867, 867, 868, 133, 869, 869, 869, 869, 
+    869, 870, 871, 871, 871, 871, 871, 871, 871, 871, 871, 871, 871, 871, 
+    871, 871, 871, 871, 871, 871, 871, 871, 871, 871, 871, 871, 871, 871, 
+    871, 871, 871, 871, 871, 871, 871, 872, 871, 871, 873, 874, 133, 133, 
+    108, 108, 108, 108, 108, 875, 876, 877, 108, 108, 108, 878, 879, 879, 
+    879, 879, 879, 879, 879, 879, 880, 881, 882, 133, 67, 67, 883, 884, 885, 
+    55, 886, 55, 55, 55, 55, 55, 55, 55, 887, 888, 55, 889, 890, 55, 55, 891, 
+    892, 133, 133, 133, 133, 133, 133, 133, 893, 894, 895, 896, 897, 897, 
+    898, 899, 900, 901, 902, 902, 902, 902, 902, 902, 903, 133, 904, 905, 
+    905, 905, 905, 905, 906, 907, 908, 909, 910, 911, 912, 912, 913, 914, 
+    915, 916, 917, 917, 918, 919, 920, 920, 921, 922, 923, 924, 393, 393, 
+    393, 925, 926, 927, 927, 927, 927, 927, 928, 929, 930, 931, 932, 933, 
+    934, 373, 378, 935, 936, 936, 936, 936, 936, 937, 938, 133, 939, 940, 
+    941, 942, 373, 373, 943, 944, 945, 945, 945, 945, 945, 945, 946, 947, 
+    948, 133, 133, 949, 950, 951, 952, 133, 953, 953, 953, 133, 403, 403, 57, 
+    57, 57, 57, 57, 954, 955, 133, 956, 956, 956, 956, 956, 956, 956, 956, 
+    956, 956, 950, 950, 950, 950, 957, 958, 959, 960, 961, 962, 962, 963, 
+    962, 962, 962, 961, 962, 962, 963, 962, 962, 962, 961, 962, 962, 963, 
+    962, 962, 962, 961, 962, 962, 963, 962, 962, 962, 961, 962, 962, 963, 
+    962, 962, 962, 961, 962, 962, 963, 962, 962, 962, 961, 962, 962, 963, 
+    962, 962, 962, 961, 962, 962, 963, 962, 962, 962, 961, 962, 962, 963, 
+    962, 962, 962, 961, 962, 962, 963, 962, 962, 962, 961, 962, 962, 963, 
+    962, 962, 962, 961, 962, 962, 963, 962, 962, 962, 961, 962, 962, 963, 
+    962, 962, 962, 961, 962, 962, 963, 962, 962, 962, 961, 962, 962, 963, 
+    962, 962, 962, 961, 962, 962, 963, 962, 962, 962, 961, 962, 962, 963, 
+    962, 962, 962, 961, 962, 962, 963, 962, 962, 962, 961, 962, 962, 963, 
+    962, 962, 962, 961, 962, 962, 963, 962, 962, 962, 961, 962, 962, 963, 
+    962, 962, 962, 961, 962, 962, 963, 962, 962, 962, 961, 962, 962, 963, 
+    962, 962, 962, 961, 962, 962, 963, 962, 962, 962, 961, 962, 962, 963, 
+    962, 962, 962, 961, 962, 962, 963, 962, 962, 962, 961, 962, 962, 963, 
+    962, 962, 962, 961, 962, 962, 963, 962, 962, 962, 961, 962, 962, 963, 
+    962, 962, 962, 961, 962, 962, 963, 962, 962, 962, 961, 962, 962, 963, 
+    962, 962, 962, 961, 962, 962, 963, 962, 962, 962, 962, 962, 962, 961, 
+    962, 962, 963, 962, 962, 962, 961, 962, 962, 963, 962, 962, 962, 961, 
+    962, 962, 964, 133, 397, 397, 965, 966, 400, 400, 400, 400, 400, 967, 
+    968, 968, 968, 968, 968, 968, 968, 968, 968, 968, 968, 968, 968, 968, 
+    968, 968, 968, 968, 968, 968, 968, 968, 968, 968, 968, 968, 968, 968, 
+    968, 968, 968, 968, 969, 969, 969, 969, 969, 969, 969, 969, 969, 969, 
+    969, 969, 969, 969, 969, 969, 969, 969, 969, 969, 969, 969, 969, 969, 
+    969, 969, 969, 969, 969, 969, 969, 969, 970, 970, 970, 970, 970, 970, 
+    970, 970, 970, 970, 970, 970, 970, 970, 970, 970, 970, 970, 970, 970, 
+    970, 970, 970, 970, 970, 970, 970, 970, 970, 970, 970, 970, 970, 971, 
+    972, 973, 974, 975, 970, 970, 970, 970, 970, 970, 970, 976, 970, 970, 
+    970, 970, 970, 970, 970, 970, 970, 970, 970, 970, 970, 977, 863, 863, 
+    863, 863, 978, 133, 979, 980, 981, 982, 983, 984, 985, 986, 987, 987, 
+    987, 987, 987, 987, 987, 987, 987, 987, 987, 987, 988, 989, 990, 133, 
+    991, 987, 987, 987, 987, 987, 987, 987, 987, 987, 987, 987, 987, 987, 
+    987, 987, 987, 987, 987, 987, 987, 987, 987, 987, 987, 987, 987, 987, 
+    987, 987, 987, 987, 987, 987, 987, 987, 987, 987, 987, 987, 987, 987, 
+    987, 987, 987, 992, 133, 133, 987, 987, 987, 987, 987, 987, 987, 987, 
+    993, 987, 987, 987, 987, 987, 987, 133, 133, 133, 133, 133, 987, 994, 
+    995, 995, 996, 997, 998, 999, 1000, 1001, 1002, 1003, 1004, 1005, 1006, 
+    1007, 1008, 987, 987, 987, 987, 987, 987, 987, 987, 987, 987, 987, 987, 
+    987, 987, 987, 987, 1009, 1010, 1011, 1012, 1013, 1014, 1015, 1015, 1016, 
+    1017, 1018, 1018, 1019, 1020, 1021, 1022, 1023, 1023, 1023, 1023, 1024, 
+    1025, 1025, 1025, 1026, 1027, 1027, 1027, 1028, 1029, 1030, 133, 1031, 
+    1032, 1033, 1032, 1032, 1034, 1032, 1032, 1035, 1032, 1036, 1032, 1036, 
+    133, 133, 133, 133, 1032, 1032, 1032, 1032, 1032, 1032, 1032, 1032, 1032, 
+    1032, 1032, 1032, 1032, 1032, 1032, 1037, 1038, 1039, 1039, 1039, 1039, 
+    1039, 1040, 660, 1041, 1041, 1041, 1041, 1041, 1041, 1042, 1043, 1044, 
+    1045, 619, 1046, 1047, 133, 133, 133, 133, 133, 660, 660, 660, 660, 660, 
+    1048, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 
+    133, 133, 133, 1049, 1049, 1049, 1050, 1051, 1051, 1051, 1051, 1051, 
+    1051, 1052, 133, 1053, 1054, 1054, 1055, 1056, 1056, 1056, 1056, 1057, 
+    133, 1058, 1058, 1059, 1060, 1061, 1061, 1061, 1061, 1062, 1063, 1064, 
+    1064, 1064, 1065, 1066, 1066, 1066, 1066, 1067, 1066, 1068, 133, 133, 
+    133, 133, 133, 1069, 1069, 1069, 1069, 1069, 1070, 1070, 1070, 1070, 
+    1070, 1071, 1071, 1071, 1071, 1071, 1071, 1072, 1072, 1072, 1073, 1074, 
+    1075, 1076, 1076, 1076, 1076, 1077, 1078, 1078, 1078, 1078, 1079, 1080, 
+    1080, 1080, 1080, 1080, 133, 1081, 1081, 1081, 1081, 1081, 1081, 1082, 
+    1083, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 
+    133, 133, 133, 133, 133, 1084, 1084, 1084, 1084, 1084, 1084, 1084, 1084, 
+    1084, 1084, 1084, 1084, 1084, 1084, 1084, 1084, 1084, 1084, 1084, 1084, 
+    1084, 1084, 1084, 1084, 1084, 1084, 1084, 1084, 1084, 1084, 1084, 1084, 
+    1084, 1084, 1084, 1084, 1084, 1084, 1085, 133, 1084, 1084, 1086, 133, 
+    1084, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 
+    133, 133, 133, 133, 133, 133, 1087, 1088, 1089, 1089, 1089, 1089, 1090, 
+    1091, 1092, 1092, 1093, 1094, 1095, 1095, 1096, 1097, 1098, 1098, 1098, 
+    1099, 1100, 1101, 133, 133, 133, 133, 133, 133, 1102, 1102, 1103, 1104, 
+    1105, 1105, 1106, 1107, 1108, 1108, 1108, 1109, 133, 133, 133, 133, 133, 
+    133, 133, 133, 1110, 1110, 1110, 1110, 1111, 1111, 1111, 1112, 1113, 
+    1113, 1114, 1113, 1113, 1113, 1113, 1113, 1115, 1116, 1117, 1118, 1119, 
+    1119, 1120, 1121, 1122, 133, 1123, 1124, 1125, 1125, 1125, 1126, 1127, 
+    1127, 1127, 1128, 133, 133, 133, 133, 1129, 1130, 1129, 1129, 1131, 1132, 
+    1133, 133, 1134, 1134, 1134, 1134, 1134, 1134, 1135, 1136, 1137, 1137, 
+    1138, 1139, 1140, 1140, 1141, 1142, 1143, 1143, 1144, 1145, 133, 1146, 
+    133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 1147, 1147, 1147, 1147, 
+    1147, 1147, 1147, 1147, 1147, 1148, 133, 133, 133, 133, 133, 133, 1149, 
+    1149, 1149, 1149, 1149, 1149, 1150, 133, 1151, 1151, 1151, 1151, 1151, 
+    1151, 1152, 1153, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 
+    133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 
+    133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 
+    133, 133, 133, 133, 133, 1154, 1154, 1154, 1155, 133, 133, 133, 133, 133, 
+    133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 1156, 1157, 1157, 
+    1157, 1157, 1157, 1157, 1158, 1159, 1160, 1161, 1162, 1163, 1164, 133, 
+    1165, 1166, 1167, 1167, 1168, 1167, 1169, 1170, 1171, 1172, 133, 1173, 
+    1173, 1173, 1174, 1175, 1176, 1177, 1178, 1178, 1178, 1179, 1180, 1181, 
+    1182, 1183, 133, 1184, 1184, 1184, 1184, 1185, 133, 1186, 1187, 1187, 
+    1187, 1187, 1187, 1188, 1189, 1190, 1191, 1192, 1193, 1194, 1195, 1196, 
+    133, 1197, 1197, 1198, 1197, 1197, 1199, 1200, 1201, 133, 133, 133, 133, 
+    133, 133, 133, 133, 1202, 1203, 1204, 1205, 1204, 1206, 1207, 1207, 1207, 
+    1207, 1207, 1208, 1209, 1210, 1211, 1212, 1213, 1214, 1215, 1216, 1216, 
+    1217, 1218, 1219, 1220, 1221, 1222, 1223, 1224, 1225, 1225, 133, 133, 
+    133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 
+    133, 1226, 1226, 1226, 1226, 1226, 1226, 1227, 1228, 1229, 1230, 1231, 
+    1232, 133, 133, 133, 133, 1233, 1233, 1233, 1233, 1233, 1233, 1234, 1235, 
+    1236, 133, 1237, 1238, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 
+    133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 1239, 1239, 1239, 1239, 
+    1239, 1240, 1241, 1242, 1243, 1244, 1245, 1246, 133, 133, 133, 133, 1247, 
+    1247, 1247, 1247, 1247, 1247, 1248, 1249, 1250, 133, 1251, 1252, 1253, 
+    1254, 133, 133, 1255, 1255, 1255, 1255, 1255, 1256, 1257, 133, 1258, 
+    1259, 133, 133, 133, 133, 133, 133, 1260, 1260, 1260, 1261, 1262, 1263, 
+    1264, 1265, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 
+    133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 
+    133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 
+    133, 133, 133, 133, 1266, 1266, 1266, 1266, 1267, 1267, 1267, 1267, 1268, 
+    1269, 1270, 1271, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 
+    133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 1272, 
+    1272, 1272, 1272, 1272, 1272, 1272, 1273, 1274, 1275, 1274, 1274, 1274, 
+    1276, 1277, 1278, 1279, 133, 1280, 1281, 1282, 1283, 1284, 1285, 1285, 
+    1285, 1286, 1287, 1287, 1288, 1289, 133, 133, 133, 133, 133, 133, 133, 
+    133, 133, 1290, 1290, 1290, 1290, 1290, 1290, 1290, 1290, 1290, 1290, 
+    1290, 1290, 1290, 1290, 1290, 1290, 1290, 1290, 1290, 1290, 1290, 1290, 
+    1290, 1290, 1290, 1290, 1290, 1290, 1290, 1290, 1290, 1290, 1290, 1290, 
+    1290, 1290, 1290, 1290, 1290, 1290, 1290, 1290, 1290, 1290, 1290, 1290, 
+    1290, 1290, 1290, 1290, 1290, 1291, 133, 133, 133, 133, 133, 133, 133, 
+    133, 133, 133, 133, 133, 1292, 1292, 1292, 1292, 1292, 1292, 1292, 1292, 
+    1292, 1292, 1292, 1292, 1292, 1293, 1294, 133, 1290, 1290, 1290, 1290, 
+    1290, 1290, 1290, 1290, 1290, 1290, 1290, 1290, 1290, 1290, 1290, 1290, 
+    1290, 1290, 1290, 1290, 1290, 1290, 1290, 1290, 1295, 133, 133, 133, 133, 
+    133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 
+    133, 133, 133, 133, 133, 1296, 1296, 1296, 1296, 1296, 1296, 1296, 1296, 
+    1296, 1296, 1296, 1296, 1296, 1296, 1296, 1296, 1296, 1296, 1296, 1296, 
+    1296, 1296, 1296, 1296, 1296, 1296, 1296, 1296, 1296, 1296, 1296, 1296, 
+    1296, 1296, 1296, 1296, 1296, 1296, 1296, 1296, 1296, 1296, 1296, 1297, 
+    1296, 1296, 1296, 1296, 1298, 1299, 1296, 1296, 1296, 1296, 1296, 1296, 
+    1296, 1296, 1296, 1296, 1296, 1296, 1296, 1296, 1296, 1296, 1296, 1296, 
+    1296, 1296, 1296, 1296, 1296, 1296, 1296, 1296, 1296, 1296, 1296, 1300, 
+    1296, 1296, 1296, 1296, 1296, 1296, 1296, 1296, 1296, 1296, 1296, 1296, 
+    1296, 1296, 1296, 1296, 1296, 1296, 1296, 1296, 1296, 1301, 133, 133, 
+    133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 
+    133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 1302, 1302, 1302, 1302, 
+    1302, 1302, 1302, 1302, 1302, 1302, 1302, 1302, 1302, 1302, 1302, 1302, 
+    1302, 1302, 1302, 1302, 1302, 1302, 1302, 1302, 1302, 1302, 1302, 1302, 
+    1302, 1302, 1302, 1302, 1302, 1302, 1302, 1302, 1302, 1302, 1302, 1302, 
+    1302, 1302, 1302, 1302, 1302, 1302, 1302, 1302, 1302, 1302, 1302, 1302, 
+    1302, 1302, 1302, 1302, 1302, 1303, 1302, 1302, 1302, 1302, 1302, 1302, 
+    1302, 1302, 1302, 1302, 1302, 1302, 1302, 1302, 1304, 133, 133, 133, 133, 
+    133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 
+    133, 133, 133, 133, 133, 879, 879, 879, 879, 879, 879, 879, 879, 879, 
+    879, 879, 879, 879, 879, 879, 879, 879, 879, 879, 879, 879, 879, 879, 
+    879, 879, 879, 879, 879, 879, 879, 879, 879, 879, 879, 879, 879, 879, 
+    879, 879, 1305, 1306, 1306, 1306, 1307, 1308, 1309, 133, 133, 133, 133, 
+    133, 133, 133, 133, 133, 133, 133, 133, 1310, 1310, 1310, 1311, 1312, 
+    133, 1313, 1313, 1313, 1313, 1313, 1313, 1314, 1315, 1316, 133, 1317, 
+    1318, 1319, 1313, 1313, 1320, 1313, 1313, 133, 133, 133, 133, 133, 133, 
+    133, 133, 133, 133, 133, 133, 133, 133, 1321, 1321, 1321, 1321, 1321, 
+    1321, 1321, 1321, 1322, 133, 1323, 1324, 1324, 1324, 1324, 1325, 133, 
+    1326, 1327, 1328, 133, 133, 133, 133, 133, 133, 133, 133, 1329, 133, 133, 
+    133, 1330, 1330, 1330, 1330, 1330, 1330, 1330, 1330, 1330, 1330, 1330, 
+    1330, 1330, 1330, 1330, 1330, 1330, 1330, 1330, 1330, 1330, 1330, 1330, 
+    1330, 1330, 1330, 1330, 1330, 1330, 1330, 1330, 1330, 1330, 1330, 1330, 
+    1330, 1330, 1330, 1330, 1330, 1330, 1330, 1330, 1330, 1330, 1330, 1330, 
+    1330, 1330, 1330, 1330, 1330, 1330, 1330, 1330, 1330, 1330, 1330, 1330, 
+    1330, 1330, 1331, 133, 133, 1330, 1330, 1330, 1330, 1330, 1330, 1330, 
+    1330, 1330, 1330, 1330, 1330, 1330, 1330, 1330, 1330, 1330, 1330, 1330, 
+    1330, 1330, 1330, 1330, 1330, 1330, 1330, 1330, 1330, 1330, 1330, 1332, 
+    133, 1333, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 
+    133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 
+    133, 133, 133, 133, 133, 1334, 1334, 1334, 1334, 1334, 1334, 1334, 1334, 
+    1334, 1334, 1334, 1334, 1334, 1335, 1334, 1336, 1334, 1337, 1334, 1338, 
+    1339, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 660, 660, 
+    660, 660, 660, 660, 660, 660, 660, 660, 660, 660, 660, 660, 660, 660, 
+    660, 660, 660, 660, 660, 660, 660, 660, 660, 660, 660, 660, 660, 660, 
+    1340, 133, 660, 660, 660, 660, 1341, 1342, 660, 660, 660, 660, 660, 1343, 
+    1344, 1345, 1346, 1347, 1348, 1349, 660, 660, 660, 1350, 660, 1351, 1352, 
+    660, 660, 660, 660, 1353, 133, 133, 1044, 1044, 1044, 1044, 1044, 1044, 
+    1044, 1044, 1354, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 
+    133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 619, 619, 
+    619, 619, 619, 619, 619, 619, 619, 619, 668, 133, 1039, 1039, 1355, 133, 
+    133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 
+    133, 133, 1356, 1356, 1356, 1357, 1358, 1358, 1359, 1356, 1356, 1360, 
+    1361, 1358, 1358, 1356, 1356, 1356, 1357, 1358, 1358, 1362, 1363, 1364, 
+    1360, 1365, 1366, 1358, 1356, 1356, 1356, 1357, 1358, 1358, 1367, 1368, 
+    1369, 1370, 1358, 1358, 1358, 1371, 1372, 1373, 1374, 1358, 1358, 1359, 
+    1356, 1356, 1360, 1358, 1358, 1358, 1356, 1356, 1356, 1357, 1358, 1358, 
+    1359, 1356, 1356, 1360, 1358, 1358, 1358, 1356, 1356, 1356, 1357, 1358, 
+    1358, 1359, 1356, 1356, 1360, 1358, 1358, 1358, 1356, 1356, 1356, 1357, 
+    1358, 1358, 1375, 1356, 1356, 1356, 1376, 1358, 1358, 1377, 1378, 1356, 
+    1356, 1379, 1358, 1358, 1380, 1359, 1356, 1356, 1381, 1358, 1358, 1382, 
+    1383, 1356, 1356, 1384, 1358, 1358, 1358, 1385, 1356, 1356, 1356, 1376, 
+    1358, 1358, 1377, 1386, 1387, 1387, 1387, 1387, 1387, 1387, 1388, 1388, 
+    1388, 1388, 1388, 1388, 1388, 1388, 1388, 1388, 1388, 1388, 1388, 1388, 
+    1388, 1388, 1388, 1388, 1388, 1388, 1388, 1388, 1388, 1388, 1388, 1388, 
+    1388, 1388, 1388, 1388, 1388, 1388, 1389, 1389, 1389, 1389, 1389, 1389, 
+    1390, 1391, 1389, 1389, 1389, 1389, 1389, 1392, 1393, 1388, 1394, 1395, 
+    133, 1396, 1397, 1389, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 
+    1398, 1399, 1399, 1400, 1401, 1402, 133, 133, 133, 133, 133, 133, 133, 
+    133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 
+    133, 133, 133, 133, 133, 1403, 1403, 1403, 1403, 1403, 1403, 1403, 1403, 
+    1403, 1403, 1403, 1403, 1403, 1403, 1403, 1403, 1403, 1403, 1403, 1403, 
+    1403, 1403, 1403, 1403, 1404, 1405, 1406, 133, 133, 133, 133, 133, 1407, 
+    1407, 1407, 1407, 1408, 1409, 1409, 1409, 1410, 1411, 1412, 1413, 133, 
+    133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 133, 
+    133, 133, 133, 133, 133, 1414, 987, 987, 987, 1415, 1416, 1417, 1418, 
+    1419, 1420, 1415, 1421, 1415, 1417, 1417, 1422, 987, 1423, 987, 1424, 
+    1425, 1423, 987, 1424, 133, 133, 133, 133, 133, 133, 1426, 133, 1427, 
+    1428, 1428, 1428, 1428, 1429, 1428, 1428, 1428, 1428, 1428, 1428, 1428, 
+    1428, 1428, 1428, 1428, 1428, 1429, 1430, 1428, 1431, 1432, 1428, 1432, 
+    1433, 1432, 1428, 1428, 1428, 1434, 1430, 671, 1435, 673, 673, 673, 1436, 
+    673, 673, 673, 673, 1437, 1437, 1437, 1438, 1437, 1437, 1437, 1439, 1440, 
+    1441, 1437, 1442, 1430, 1430, 1430, 1430, 1430, 1430, 1443, 1444, 1444, 
+    1444, 1445, 1430, 843, 843, 843, 843, 843, 1446, 843, 1447, 1448, 1430, 
+    1430, 1430, 1430, 1430, 1430, 1430, 1430, 1430, 1430, 1430, 1430, 1430, 
+    1430, 1430, 1430, 1430, 1430, 1430, 1430, 1430, 804, 804, 804, 804, 1449, 
+    1450, 1451, 804, 804, 804, 804, 804, 804, 804, 804, 1452, 1453, 804, 
+    1454, 1455, 804, 804, 1456, 1457, 1458, 1459, 1454, 1428, 804, 804, 1460, 
+    1461, 804, 804, 804, 804, 804, 804, 804, 1462, 1463, 1464, 1465, 804, 
+    1466, 1467, 1464, 1468, 1469, 804, 804, 804, 1470, 1471, 1472, 804, 804, 
+    804, 804, 804, 804, 804, 804, 1473, 1474, 804, 1475, 695, 1476, 804, 
+    1477, 1478, 619, 1479, 804, 804, 804, 1428, 1480, 1481, 1428, 1428, 1482, 
+    1428, 1427, 1428, 1428, 1428, 1428, 1428, 1483, 1484, 1428, 1428, 1483, 
+    1485, 804, 804, 804, 804, 804, 804, 804, 804, 1486, 1487, 619, 619, 619, 
+    619, 1488, 1489, 804, 804, 804, 804, 1490, 804, 1491, 804, 1492, 1427, 
+    1493, 1430, 1428, 1494, 1495, 1430, 619, 619, 619, 619, 619, 619, 619, 
+    619, 619, 619, 619, 619, 619, 619, 1496, 1430, 619, 619, 619, 619, 619, 
+    619, 619, 619, 619, 619, 1497, 1430, 1430, 1430, 1430, 1430, 619, 1496, 
+    619, 619, 619, 619, 619, 619, 619, 1430, 619, 1498, 619, 619, 619, 619, 
+    619, 1430, 619, 619, 619, 1499, 1430, 1430, 1430, 1430, 1430, 1430, 1430, 
+    1430, 1430, 1430, 1430, 1430, 804, 1500, 1501, 1430, 1502, 1503, 804, 
+    1504, 804, 1505, 1430, 1430, 1430, 1430, 804, 804, 1506, 1430, 1430, 
+    1430, 1430, 1430, 1507, 1430, 1430, 1430, 1430, 1430, 1430, 1430, 1430, 
+    1430, 1430, 1430, 1430, 1430, 1430, 1430, 1430, 1430, 1430, 1430, 1430, 
+    1430, 1430, 1430, 1430, 1430, 1430, 1430, 1430, 1430, 1430, 1430, 1430, 
+    1430, 1430, 1430, 1430, 1430, 1430, 1430, 1430, 1430, 1430, 1430, 1430, 
+    1430, 1430, 1430, 1430, 1430, 1430, 1430, 1430, 1430, 1430, 1430, 1430, 
+    1430, 1430, 1430, 1430, 1430, 1430, 1430, 1430, 1430, 1430, 1430, 1430, 
+    1430, 1430, 1508, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 
+    861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 
+    861, 1509, 863, 863, 863, 863, 863, 861, 861, 861, 861, 861, 861, 1510, 
+    863, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 
+    861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 
+    862, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 
+    861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 
+    861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 
+    861, 861, 861, 861, 861, 861, 861, 1511, 863, 863, 863, 863, 863, 863, 
+    863, 863, 863, 863, 863, 863, 863, 863, 863, 863, 863, 863, 863, 863, 
+    863, 863, 863, 863, 863, 863, 863, 863, 863, 863, 863, 863, 863, 863, 
+    863, 863, 863, 863, 863, 863, 863, 863, 863, 970, 970, 970, 976, 863, 
+    863, 863, 863, 863, 863, 863, 863, 863, 863, 863, 863, 863, 863, 863, 
+    863, 863, 863, 863, 863, 863, 863, 863, 863, 863, 863, 863, 863, 863, 
+    863, 863, 863, 863, 863, 863, 863, 863, 863, 863, 863, 863, 863, 863, 
+    863, 863, 863, 863, 863, 863, 863, 863, 863, 863, 863, 863, 863, 863, 
+    863, 863, 1512, 1513, 133, 133, 133, 1514, 1514, 1514, 1514, 1514, 1514, 
+    1514, 1514, 1514, 1514, 1514, 1514, 133, 133, 133, 133, 133, 133, 133, 
+    133, 133, 133, 133, 133, 133, 133, 133, 133, 995, 995, 995, 995, 995, 
+    995, 995, 995, 995, 995, 995, 995, 995, 995, 995, 995, 995, 995, 995, 
+    995, 995, 995, 995, 995, 995, 995, 995, 995, 995, 995, 133, 133, 969, 
+    969, 969, 969, 969, 969, 969, 969, 969, 969, 969, 969, 969, 969, 969, 
+    969, 969, 969, 969, 969, 969, 969, 969, 969, 969, 969, 969, 969, 969, 
+    969, 969, 1515, 
 ];
 
-pub static INDEX2: [u16; 11560] = [
+pub static INDEX2: [u16; 12128] = [
     1, 1, 1, 1, 1, 1, 1, 1, 1, 2, 3, 4, 5, 6, 1, 1, 1, 1, 1, 1, 7, 7, 7, 8, 
     9, 10, 11, 12, 13, 14, 15, 11, 16, 17, 15, 18, 19, 20, 19, 21, 22, 22, 
     22, 22, 22, 22, 22, 22, 22, 22, 19, 23, 24, 25, 24, 10, 15, 26, 26, 26, 
     26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 16, 27, 17, 
     28, 29, 28, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 
     30, 30, 30, 16, 31, 32, 25, 1, 1, 1, 1, 1, 1, 33, 1, 1, 34, 35, 36, 13, 
-    37, 13, 38, 39, 40, 41, 42, 43, 25, 44, 45, 28, 46, 47, 48, 48, 49, 50, 
-    39, 39, 40, 48, 42, 51, 52, 52, 52, 35, 53, 53, 53, 53, 53, 53, 54, 53, 
-    53, 53, 53, 53, 53, 53, 53, 53, 54, 53, 53, 53, 53, 53, 53, 55, 54, 53, 
-    53, 53, 53, 53, 54, 56, 56, 56, 57, 57, 57, 57, 56, 57, 56, 56, 56, 57, 
-    56, 56, 57, 57, 56, 57, 56, 56, 57, 57, 57, 55, 56, 56, 56, 57, 56, 57, 
-    56, 57, 53, 56, 53, 57, 53, 57, 53, 57, 53, 57, 53, 57, 53, 57, 53, 57, 
-    53, 56, 53, 56, 53, 57, 53, 57, 53, 57, 53, 56, 53, 57, 53, 57, 53, 57, 
-    53, 57, 53, 57, 54, 56, 53, 56, 54, 56, 53, 57, 53, 57, 56, 53, 57, 53, 
-    57, 53, 57, 54, 56, 54, 56, 53, 56, 53, 57, 53, 56, 56, 54, 56, 53, 56, 
-    53, 57, 53, 57, 54, 56, 53, 57, 53, 57, 53, 53, 57, 53, 57, 53, 57, 57, 
-    57, 53, 53, 57, 53, 57, 53, 53, 57, 53, 53, 53, 57, 57, 53, 53, 53, 53, 
-    57, 53, 53, 57, 53, 53, 53, 57, 57, 57, 53, 53, 57, 53, 53, 57, 53, 57, 
-    53, 57, 53, 53, 57, 53, 57, 57, 53, 57, 53, 53, 57, 53, 53, 53, 57, 53, 
-    57, 53, 53, 57, 57, 58, 53, 57, 57, 57, 58, 58, 58, 58, 53, 59, 57, 53, 
-    59, 57, 53, 59, 57, 53, 56, 53, 56, 53, 56, 53, 56, 53, 56, 53, 56, 53, 
-    56, 53, 56, 57, 53, 57, 57, 53, 59, 57, 53, 57, 53, 53, 53, 57, 53, 57, 
-    57, 57, 57, 57, 57, 57, 53, 53, 57, 53, 53, 57, 57, 53, 57, 53, 53, 53, 
-    53, 57, 57, 56, 57, 57, 57, 57, 57, 57, 57, 57, 57, 57, 57, 57, 57, 57, 
-    57, 57, 57, 57, 58, 57, 57, 57, 60, 60, 60, 60, 60, 60, 60, 60, 60, 61, 
-    61, 62, 62, 62, 62, 62, 62, 62, 63, 63, 64, 63, 61, 65, 66, 65, 65, 65, 
-    66, 65, 61, 61, 67, 62, 63, 63, 63, 63, 63, 63, 40, 40, 40, 40, 63, 40, 
-    63, 49, 60, 60, 60, 60, 60, 63, 63, 63, 63, 63, 68, 68, 61, 63, 62, 63, 
-    63, 63, 63, 63, 63, 63, 63, 63, 69, 69, 69, 69, 69, 69, 69, 69, 69, 69, 
-    69, 69, 69, 70, 71, 71, 71, 71, 70, 72, 71, 71, 71, 71, 71, 73, 73, 71, 
-    71, 71, 71, 73, 73, 71, 71, 71, 71, 71, 71, 71, 71, 71, 71, 71, 74, 74, 
-    74, 74, 74, 71, 71, 71, 71, 69, 69, 69, 69, 69, 69, 69, 69, 75, 69, 71, 
-    71, 71, 69, 69, 69, 71, 71, 76, 69, 69, 69, 71, 71, 71, 71, 69, 70, 71, 
-    71, 69, 77, 78, 78, 77, 78, 78, 77, 69, 69, 69, 69, 69, 79, 80, 79, 80, 
-    61, 81, 79, 80, 82, 82, 83, 80, 80, 80, 84, 79, 82, 82, 82, 82, 81, 63, 
-    79, 85, 79, 79, 79, 82, 79, 82, 79, 79, 80, 86, 86, 86, 86, 86, 86, 86, 
-    86, 86, 86, 86, 86, 86, 86, 86, 86, 86, 82, 86, 86, 86, 86, 86, 86, 86, 
-    79, 79, 80, 80, 80, 80, 80, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 
-    87, 87, 87, 87, 87, 87, 80, 87, 87, 87, 87, 87, 87, 87, 80, 80, 80, 80, 
-    80, 79, 80, 80, 79, 79, 79, 80, 80, 80, 79, 80, 79, 80, 79, 80, 79, 80, 
-    79, 80, 88, 89, 88, 89, 88, 89, 88, 89, 88, 89, 88, 89, 88, 89, 80, 80, 
-    80, 80, 79, 80, 90, 79, 80, 79, 79, 80, 80, 79, 79, 79, 91, 92, 91, 91, 
-    91, 91, 91, 91, 91, 91, 91, 91, 91, 91, 91, 91, 92, 92, 92, 92, 92, 92, 
-    92, 92, 93, 93, 93, 93, 93, 93, 93, 93, 94, 93, 94, 94, 94, 94, 94, 94, 
-    94, 94, 94, 94, 94, 94, 94, 94, 91, 94, 91, 94, 91, 94, 91, 94, 91, 94, 
-    95, 96, 96, 97, 97, 96, 98, 98, 91, 94, 91, 94, 91, 94, 91, 91, 94, 91, 
-    94, 91, 94, 91, 94, 91, 94, 91, 94, 91, 94, 94, 82, 99, 99, 99, 99, 99, 
-    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 82, 
-    82, 100, 101, 101, 101, 101, 101, 101, 82, 102, 102, 102, 102, 102, 102, 
-    102, 102, 102, 102, 102, 102, 102, 102, 102, 82, 103, 104, 82, 82, 105, 
-    105, 106, 82, 107, 108, 108, 108, 108, 107, 108, 108, 108, 109, 107, 108, 
-    108, 108, 108, 108, 108, 107, 107, 107, 107, 107, 107, 108, 108, 107, 
-    108, 108, 109, 110, 108, 111, 112, 113, 114, 115, 116, 117, 118, 119, 
-    120, 120, 121, 122, 123, 124, 125, 126, 127, 128, 126, 108, 107, 129, 
-    119, 82, 82, 82, 82, 82, 82, 82, 82, 130, 130, 130, 130, 130, 130, 130, 
-    130, 130, 130, 130, 82, 82, 82, 82, 82, 130, 130, 130, 126, 126, 82, 82, 
-    82, 131, 131, 131, 131, 131, 132, 133, 133, 134, 135, 135, 136, 137, 138, 
-    139, 139, 140, 140, 140, 140, 140, 140, 140, 140, 141, 142, 143, 144, 
-    145, 82, 146, 144, 147, 147, 147, 147, 147, 147, 147, 147, 148, 147, 147, 
-    147, 147, 147, 147, 147, 147, 147, 147, 149, 150, 151, 152, 153, 154, 
-    155, 156, 97, 97, 157, 158, 140, 140, 140, 140, 140, 158, 140, 140, 158, 
-    159, 159, 159, 159, 159, 159, 159, 159, 159, 159, 135, 160, 160, 161, 
-    147, 147, 162, 147, 147, 147, 147, 147, 147, 147, 147, 147, 147, 147, 
-    146, 147, 140, 140, 140, 140, 140, 140, 140, 132, 139, 140, 140, 140, 
-    140, 158, 140, 163, 163, 140, 140, 139, 158, 140, 140, 158, 147, 147, 
-    164, 164, 164, 164, 164, 164, 164, 164, 164, 164, 147, 147, 147, 165, 
-    165, 147, 166, 166, 166, 166, 166, 166, 166, 166, 166, 166, 166, 166, 
-    166, 166, 82, 167, 168, 169, 168, 168, 168, 168, 168, 168, 168, 168, 168, 
-    168, 168, 168, 168, 168, 170, 171, 170, 170, 171, 170, 170, 171, 171, 
-    171, 170, 171, 171, 170, 171, 170, 170, 170, 171, 170, 171, 170, 171, 
-    170, 171, 170, 170, 82, 82, 168, 168, 168, 172, 172, 172, 172, 172, 172, 
-    172, 172, 172, 172, 172, 172, 172, 172, 173, 173, 173, 173, 173, 173, 
-    173, 173, 173, 173, 173, 172, 82, 82, 82, 82, 82, 82, 174, 174, 174, 174, 
-    174, 174, 174, 174, 174, 174, 175, 175, 175, 175, 175, 175, 175, 175, 
-    175, 175, 175, 175, 175, 175, 175, 175, 175, 176, 176, 176, 176, 176, 
-    176, 176, 177, 176, 178, 178, 179, 180, 181, 182, 178, 82, 82, 82, 82, 
-    82, 183, 183, 183, 183, 183, 183, 183, 183, 183, 183, 183, 183, 183, 183, 
-    184, 184, 184, 184, 185, 184, 184, 184, 184, 184, 184, 184, 184, 184, 
-    185, 184, 184, 184, 185, 184, 184, 184, 184, 184, 82, 82, 186, 186, 186, 
-    186, 186, 186, 186, 186, 186, 186, 186, 186, 186, 186, 186, 82, 187, 187, 
-    187, 187, 187, 187, 187, 187, 187, 188, 188, 188, 82, 82, 189, 82, 147, 
-    147, 147, 147, 147, 82, 147, 147, 147, 147, 147, 147, 147, 147, 82, 82, 
-    82, 82, 82, 82, 140, 140, 140, 140, 140, 140, 132, 158, 140, 140, 158, 
-    140, 140, 158, 140, 140, 140, 158, 158, 158, 190, 191, 192, 140, 140, 
-    140, 158, 140, 140, 158, 158, 140, 140, 140, 140, 140, 193, 193, 193, 
-    194, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 
-    195, 193, 194, 196, 195, 194, 194, 194, 193, 193, 193, 193, 193, 193, 
-    193, 193, 194, 194, 194, 194, 197, 194, 194, 195, 97, 157, 198, 198, 193, 
-    193, 193, 195, 195, 193, 193, 199, 199, 200, 200, 200, 200, 200, 200, 
-    200, 200, 200, 200, 201, 202, 195, 195, 195, 195, 195, 195, 203, 204, 
-    205, 205, 82, 203, 203, 203, 203, 203, 203, 203, 203, 82, 82, 203, 203, 
-    82, 82, 203, 203, 203, 203, 203, 203, 203, 203, 203, 203, 203, 203, 203, 
-    203, 82, 203, 203, 203, 203, 203, 203, 203, 82, 203, 82, 82, 82, 203, 
-    203, 203, 203, 82, 82, 206, 203, 205, 205, 205, 204, 204, 204, 204, 82, 
-    82, 205, 205, 82, 82, 205, 205, 207, 203, 82, 82, 82, 82, 82, 82, 82, 82, 
-    205, 82, 82, 82, 82, 203, 203, 82, 203, 203, 203, 204, 204, 82, 82, 208, 
-    208, 208, 208, 208, 208, 208, 208, 208, 208, 203, 203, 209, 209, 210, 
-    210, 210, 210, 210, 211, 212, 213, 82, 82, 82, 82, 82, 214, 214, 215, 82, 
-    216, 216, 216, 216, 216, 216, 82, 82, 82, 82, 216, 216, 82, 82, 216, 216, 
-    216, 216, 216, 216, 216, 216, 216, 216, 216, 216, 216, 216, 82, 216, 216, 
-    216, 216, 216, 216, 216, 82, 216, 216, 82, 216, 216, 82, 216, 216, 82, 
-    82, 217, 82, 215, 215, 215, 214, 214, 82, 82, 82, 82, 214, 214, 82, 82, 
-    214, 214, 218, 82, 82, 82, 214, 82, 82, 82, 82, 82, 82, 82, 216, 216, 
-    216, 216, 82, 216, 82, 82, 82, 82, 82, 82, 82, 219, 219, 219, 219, 219, 
-    219, 219, 219, 219, 219, 214, 214, 216, 216, 216, 214, 82, 82, 82, 220, 
-    220, 221, 82, 222, 222, 222, 222, 222, 222, 222, 222, 222, 82, 222, 222, 
-    222, 82, 222, 222, 222, 222, 222, 222, 222, 222, 222, 222, 222, 222, 222, 
-    222, 82, 222, 222, 222, 222, 222, 222, 222, 82, 222, 222, 82, 222, 222, 
-    222, 222, 222, 82, 82, 223, 222, 221, 221, 221, 220, 220, 220, 220, 220, 
-    82, 220, 220, 221, 82, 221, 221, 224, 82, 82, 222, 82, 82, 82, 82, 82, 
-    82, 82, 222, 222, 220, 220, 82, 82, 225, 225, 225, 225, 225, 225, 225, 
-    225, 225, 225, 226, 227, 82, 82, 82, 82, 82, 82, 82, 222, 82, 82, 82, 82, 
-    82, 82, 82, 228, 229, 229, 82, 230, 230, 230, 230, 230, 230, 230, 230, 
-    82, 82, 230, 230, 82, 82, 230, 230, 230, 230, 230, 230, 230, 230, 230, 
-    230, 230, 230, 230, 230, 82, 230, 230, 230, 230, 230, 230, 230, 82, 230, 
-    230, 82, 230, 230, 230, 230, 230, 82, 82, 231, 230, 229, 228, 229, 228, 
-    228, 228, 228, 82, 82, 229, 229, 82, 82, 229, 229, 232, 82, 82, 82, 82, 
-    82, 82, 82, 82, 228, 229, 82, 82, 82, 82, 230, 230, 82, 230, 230, 230, 
-    228, 228, 82, 82, 233, 233, 233, 233, 233, 233, 233, 233, 233, 233, 234, 
-    230, 235, 235, 235, 235, 235, 235, 82, 82, 236, 237, 82, 237, 237, 237, 
-    237, 237, 237, 82, 82, 82, 237, 237, 237, 82, 237, 237, 237, 237, 82, 82, 
-    82, 237, 237, 82, 237, 82, 237, 237, 82, 82, 82, 237, 237, 82, 82, 82, 
-    237, 237, 237, 237, 237, 237, 237, 237, 237, 237, 82, 82, 82, 82, 238, 
-    238, 236, 238, 238, 82, 82, 82, 238, 238, 238, 82, 238, 238, 238, 239, 
-    82, 82, 237, 82, 82, 82, 82, 82, 82, 238, 82, 82, 82, 82, 82, 82, 240, 
-    240, 240, 240, 240, 240, 240, 240, 240, 240, 241, 241, 241, 242, 242, 
-    242, 242, 242, 242, 243, 242, 82, 82, 82, 82, 82, 244, 245, 245, 245, 82, 
-    246, 246, 246, 246, 246, 246, 246, 246, 82, 246, 246, 246, 82, 246, 246, 
-    246, 246, 246, 246, 246, 246, 246, 246, 246, 246, 246, 246, 246, 246, 82, 
-    82, 82, 246, 244, 244, 244, 245, 245, 245, 245, 82, 244, 244, 244, 82, 
-    244, 244, 244, 247, 82, 82, 82, 82, 82, 82, 82, 248, 249, 82, 246, 246, 
-    246, 82, 82, 82, 82, 82, 246, 246, 244, 244, 82, 82, 250, 250, 250, 250, 
-    250, 250, 250, 250, 250, 250, 251, 251, 251, 251, 251, 251, 251, 252, 
-    253, 254, 255, 255, 82, 253, 253, 253, 253, 253, 253, 253, 253, 82, 253, 
-    253, 253, 82, 253, 253, 253, 253, 253, 253, 253, 253, 253, 253, 253, 253, 
-    253, 253, 253, 253, 253, 253, 82, 253, 253, 253, 253, 253, 82, 82, 256, 
-    253, 255, 257, 255, 255, 255, 255, 255, 82, 257, 255, 255, 82, 255, 255, 
-    254, 258, 82, 82, 82, 82, 82, 82, 82, 255, 255, 82, 82, 82, 82, 82, 82, 
-    82, 253, 82, 253, 253, 254, 254, 82, 82, 259, 259, 259, 259, 259, 259, 
-    259, 259, 259, 259, 82, 253, 253, 82, 82, 82, 82, 82, 82, 260, 261, 261, 
-    82, 262, 262, 262, 262, 262, 262, 262, 262, 82, 262, 262, 262, 82, 262, 
-    262, 262, 262, 262, 262, 262, 262, 262, 262, 262, 262, 262, 262, 262, 
-    262, 262, 82, 82, 262, 261, 261, 261, 260, 260, 260, 260, 82, 261, 261, 
-    261, 82, 261, 261, 261, 263, 262, 264, 82, 82, 82, 82, 262, 262, 262, 
-    261, 265, 265, 265, 265, 265, 265, 265, 262, 262, 262, 260, 260, 82, 82, 
-    266, 266, 266, 266, 266, 266, 266, 266, 266, 266, 265, 265, 265, 265, 
-    265, 265, 265, 265, 265, 267, 262, 262, 262, 262, 262, 262, 82, 82, 268, 
-    268, 82, 269, 269, 269, 269, 269, 269, 269, 269, 269, 269, 269, 269, 269, 
-    269, 269, 269, 269, 269, 82, 82, 82, 269, 269, 269, 269, 269, 269, 269, 
-    269, 82, 269, 269, 269, 269, 269, 269, 269, 269, 269, 82, 269, 82, 82, 
-    82, 82, 270, 82, 82, 82, 82, 268, 268, 268, 271, 271, 271, 82, 271, 82, 
-    268, 268, 268, 268, 268, 268, 268, 268, 82, 82, 82, 82, 82, 82, 272, 272, 
-    272, 272, 272, 272, 272, 272, 272, 272, 82, 82, 268, 268, 273, 82, 82, 
-    82, 82, 274, 274, 274, 274, 274, 274, 274, 274, 274, 274, 274, 274, 274, 
-    274, 274, 274, 275, 274, 274, 275, 275, 275, 275, 276, 276, 277, 82, 82, 
-    82, 82, 278, 274, 274, 274, 274, 274, 274, 279, 275, 280, 280, 280, 280, 
-    275, 275, 275, 281, 282, 282, 282, 282, 282, 282, 282, 282, 282, 282, 
-    283, 283, 82, 82, 82, 82, 82, 284, 284, 82, 284, 82, 82, 284, 284, 82, 
-    284, 82, 82, 284, 82, 82, 82, 82, 82, 82, 284, 284, 284, 284, 82, 284, 
-    284, 284, 284, 284, 284, 284, 82, 284, 284, 284, 82, 284, 82, 284, 82, 
-    82, 284, 284, 82, 284, 284, 284, 284, 285, 284, 284, 285, 285, 285, 285, 
-    286, 286, 82, 285, 285, 284, 82, 82, 284, 284, 284, 284, 284, 82, 287, 
-    82, 288, 288, 288, 288, 285, 285, 82, 82, 289, 289, 289, 289, 289, 289, 
-    289, 289, 289, 289, 82, 82, 284, 284, 284, 284, 290, 291, 291, 291, 292, 
-    293, 292, 292, 294, 292, 292, 295, 294, 296, 296, 296, 296, 296, 294, 
-    297, 296, 297, 297, 297, 298, 298, 297, 297, 297, 297, 297, 297, 299, 
-    299, 299, 299, 299, 299, 299, 299, 299, 299, 300, 300, 300, 300, 300, 
-    300, 300, 300, 300, 300, 301, 298, 297, 298, 297, 302, 303, 304, 303, 
-    304, 305, 305, 290, 290, 290, 290, 290, 290, 290, 290, 82, 290, 290, 290, 
-    290, 290, 290, 290, 290, 290, 290, 290, 290, 82, 82, 82, 82, 306, 307, 
-    308, 309, 308, 308, 308, 308, 308, 307, 307, 307, 307, 308, 310, 307, 
-    308, 311, 311, 312, 295, 311, 311, 290, 290, 290, 290, 290, 308, 308, 
-    308, 308, 308, 308, 308, 308, 308, 308, 308, 82, 308, 308, 308, 308, 308, 
-    308, 308, 308, 308, 308, 308, 308, 82, 301, 301, 297, 297, 297, 297, 297, 
-    297, 298, 297, 297, 297, 297, 297, 297, 82, 297, 297, 292, 292, 295, 292, 
-    293, 313, 313, 313, 313, 294, 294, 82, 82, 82, 82, 82, 314, 314, 314, 
-    314, 314, 314, 314, 314, 314, 314, 314, 315, 315, 316, 316, 316, 316, 
-    315, 316, 316, 316, 316, 316, 317, 315, 318, 318, 315, 315, 316, 316, 
-    314, 319, 319, 319, 319, 319, 319, 319, 319, 319, 319, 320, 320, 321, 
-    321, 321, 321, 314, 314, 314, 314, 314, 314, 315, 315, 316, 316, 314, 
-    314, 314, 314, 316, 316, 316, 314, 315, 315, 315, 314, 314, 315, 315, 
-    315, 315, 315, 315, 315, 314, 314, 314, 316, 316, 316, 316, 314, 314, 
-    314, 314, 314, 316, 315, 315, 316, 316, 315, 315, 315, 315, 315, 315, 
-    322, 314, 315, 319, 319, 315, 315, 315, 316, 323, 323, 324, 324, 324, 
-    324, 324, 324, 324, 324, 324, 324, 324, 324, 324, 324, 82, 324, 82, 82, 
-    82, 82, 82, 324, 82, 82, 325, 325, 325, 325, 325, 325, 325, 325, 325, 
-    325, 325, 326, 327, 325, 325, 325, 328, 328, 328, 328, 328, 328, 328, 
-    328, 329, 329, 329, 329, 329, 329, 329, 329, 330, 330, 330, 330, 330, 
-    330, 330, 330, 331, 331, 331, 331, 331, 331, 331, 331, 331, 82, 331, 331, 
-    331, 331, 82, 82, 331, 331, 331, 331, 331, 331, 331, 82, 331, 331, 331, 
-    82, 82, 332, 332, 332, 333, 334, 333, 333, 333, 333, 333, 333, 333, 335, 
-    335, 335, 335, 335, 335, 335, 335, 335, 335, 335, 335, 335, 335, 335, 
-    335, 335, 335, 335, 335, 82, 82, 82, 336, 336, 336, 336, 336, 336, 336, 
-    336, 336, 336, 82, 82, 82, 82, 82, 82, 337, 337, 337, 337, 337, 337, 337, 
-    337, 337, 337, 337, 337, 337, 337, 82, 82, 338, 338, 338, 338, 338, 338, 
-    82, 82, 339, 340, 340, 340, 340, 340, 340, 340, 340, 340, 340, 340, 340, 
-    340, 340, 340, 340, 340, 340, 340, 340, 341, 341, 340, 342, 343, 343, 
-    343, 343, 343, 343, 343, 343, 343, 343, 343, 343, 343, 343, 343, 343, 
-    343, 343, 344, 345, 82, 82, 82, 346, 346, 346, 346, 346, 346, 346, 346, 
-    346, 346, 346, 199, 199, 199, 347, 347, 347, 346, 346, 346, 346, 346, 
-    346, 346, 346, 82, 82, 82, 82, 82, 82, 82, 348, 348, 348, 348, 348, 348, 
-    348, 348, 348, 348, 348, 348, 348, 82, 348, 348, 348, 348, 349, 349, 350, 
-    82, 82, 82, 351, 351, 351, 351, 351, 351, 351, 351, 351, 351, 352, 352, 
-    353, 199, 199, 82, 354, 354, 354, 354, 354, 354, 354, 354, 354, 354, 355, 
-    355, 82, 82, 82, 82, 356, 356, 356, 356, 356, 356, 356, 356, 356, 356, 
-    356, 356, 356, 82, 356, 356, 356, 82, 357, 357, 82, 82, 82, 82, 358, 358, 
-    358, 358, 358, 358, 358, 358, 358, 358, 358, 358, 359, 359, 360, 359, 
-    359, 359, 359, 359, 359, 359, 360, 360, 360, 360, 360, 360, 360, 360, 
-    359, 360, 360, 359, 359, 359, 359, 359, 359, 359, 359, 359, 361, 359, 
-    362, 362, 363, 364, 362, 365, 362, 366, 358, 367, 82, 82, 368, 368, 368, 
-    368, 368, 368, 368, 368, 368, 368, 82, 82, 82, 82, 82, 82, 369, 369, 369, 
-    369, 369, 369, 369, 369, 369, 369, 82, 82, 82, 82, 82, 82, 370, 370, 371, 
-    371, 372, 373, 374, 370, 375, 375, 370, 376, 376, 376, 377, 82, 378, 378, 
-    378, 378, 378, 378, 378, 378, 378, 378, 82, 82, 82, 82, 82, 82, 379, 379, 
-    379, 379, 379, 379, 379, 379, 379, 379, 379, 380, 379, 379, 379, 379, 
-    379, 379, 379, 379, 379, 376, 376, 379, 379, 381, 379, 82, 82, 82, 82, 
-    82, 340, 340, 340, 340, 340, 340, 82, 82, 382, 382, 382, 382, 382, 382, 
-    382, 382, 382, 382, 382, 382, 382, 382, 382, 82, 383, 383, 383, 384, 384, 
-    384, 384, 383, 383, 384, 384, 384, 82, 82, 82, 82, 384, 384, 383, 384, 
-    384, 384, 384, 384, 384, 385, 386, 387, 82, 82, 82, 82, 388, 82, 82, 82, 
-    389, 389, 390, 390, 390, 390, 390, 390, 390, 390, 390, 390, 391, 391, 
-    391, 391, 391, 391, 391, 391, 391, 391, 391, 391, 391, 391, 82, 82, 391, 
-    391, 391, 391, 391, 82, 82, 82, 392, 392, 392, 392, 392, 392, 392, 392, 
-    392, 392, 392, 392, 82, 82, 82, 82, 392, 392, 82, 82, 82, 82, 82, 82, 
-    393, 393, 393, 393, 393, 393, 393, 393, 393, 393, 394, 82, 82, 82, 395, 
-    395, 396, 396, 396, 396, 396, 396, 396, 396, 397, 397, 397, 397, 397, 
-    397, 397, 397, 397, 397, 397, 397, 397, 397, 397, 398, 399, 400, 400, 
-    401, 82, 82, 402, 402, 403, 403, 403, 403, 403, 403, 403, 403, 403, 403, 
-    403, 403, 403, 404, 405, 404, 405, 405, 405, 405, 405, 405, 405, 82, 406, 
-    404, 405, 404, 404, 405, 405, 405, 405, 405, 405, 405, 405, 404, 404, 
-    404, 404, 404, 404, 405, 405, 407, 407, 407, 407, 407, 407, 407, 407, 82, 
-    82, 408, 409, 409, 409, 409, 409, 409, 409, 409, 409, 409, 82, 82, 82, 
-    82, 82, 82, 410, 410, 410, 410, 410, 410, 410, 411, 410, 410, 410, 410, 
-    410, 410, 82, 82, 97, 97, 97, 97, 97, 157, 157, 157, 157, 157, 157, 97, 
-    97, 157, 412, 82, 413, 413, 413, 413, 414, 415, 415, 415, 415, 415, 415, 
-    415, 415, 415, 415, 415, 415, 415, 415, 415, 416, 414, 413, 413, 413, 
-    413, 413, 414, 413, 414, 414, 414, 414, 414, 413, 414, 417, 415, 415, 
-    415, 415, 415, 415, 415, 82, 82, 82, 82, 418, 418, 418, 418, 418, 418, 
-    418, 418, 418, 418, 419, 419, 420, 419, 419, 419, 419, 421, 421, 421, 
-    421, 421, 421, 421, 421, 421, 421, 422, 423, 422, 422, 422, 422, 422, 
-    422, 422, 421, 421, 421, 421, 421, 421, 421, 421, 421, 82, 82, 82, 424, 
-    424, 425, 426, 426, 426, 426, 426, 426, 426, 426, 426, 426, 426, 426, 
-    426, 426, 425, 424, 424, 424, 424, 425, 425, 424, 424, 427, 428, 424, 
-    424, 426, 426, 429, 429, 429, 429, 429, 429, 429, 429, 429, 429, 426, 
-    426, 426, 426, 426, 426, 430, 430, 430, 430, 430, 430, 430, 430, 430, 
-    430, 430, 430, 430, 430, 431, 432, 433, 433, 432, 432, 432, 433, 432, 
-    433, 433, 433, 434, 434, 82, 82, 82, 82, 82, 82, 82, 82, 435, 435, 435, 
-    435, 436, 436, 436, 436, 436, 436, 436, 436, 436, 436, 436, 436, 437, 
-    437, 437, 437, 437, 437, 437, 437, 438, 438, 438, 438, 438, 438, 438, 
-    438, 437, 437, 438, 439, 82, 82, 82, 440, 440, 440, 440, 440, 441, 441, 
-    441, 441, 441, 441, 441, 441, 441, 441, 82, 82, 82, 436, 436, 436, 442, 
-    442, 442, 442, 442, 442, 442, 442, 442, 442, 443, 443, 443, 443, 443, 
-    443, 443, 443, 443, 443, 443, 443, 443, 443, 444, 444, 444, 444, 444, 
-    444, 445, 445, 94, 82, 82, 82, 82, 82, 82, 82, 446, 446, 446, 446, 446, 
-    446, 446, 446, 97, 97, 97, 326, 447, 157, 157, 157, 157, 157, 97, 97, 
-    157, 157, 157, 157, 97, 448, 447, 447, 447, 447, 447, 447, 447, 449, 449, 
-    449, 449, 157, 449, 449, 449, 449, 448, 448, 97, 449, 449, 82, 97, 97, 
-    82, 82, 82, 82, 82, 82, 57, 57, 57, 57, 57, 57, 80, 80, 80, 80, 80, 94, 
-    60, 60, 60, 60, 60, 60, 60, 60, 60, 83, 83, 83, 83, 83, 60, 60, 60, 60, 
-    83, 83, 83, 83, 83, 57, 57, 57, 57, 57, 450, 57, 57, 57, 57, 57, 57, 57, 
-    57, 57, 57, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 83, 97, 97, 
-    157, 97, 97, 97, 97, 97, 97, 97, 157, 97, 97, 451, 452, 157, 453, 97, 97, 
-    97, 97, 97, 97, 97, 97, 97, 97, 97, 97, 97, 97, 97, 97, 97, 97, 97, 97, 
-    97, 82, 82, 82, 82, 82, 97, 454, 157, 97, 157, 53, 57, 53, 57, 53, 57, 
-    57, 57, 57, 57, 57, 57, 57, 57, 53, 57, 80, 80, 80, 80, 80, 80, 80, 80, 
-    79, 79, 79, 79, 79, 79, 79, 79, 80, 80, 80, 80, 80, 80, 82, 82, 79, 79, 
-    79, 79, 79, 79, 82, 82, 82, 79, 82, 79, 82, 79, 82, 79, 455, 455, 455, 
-    455, 455, 455, 455, 455, 80, 80, 80, 80, 80, 82, 80, 80, 79, 79, 79, 79, 
-    455, 81, 80, 81, 81, 81, 80, 80, 80, 82, 80, 80, 79, 79, 79, 79, 455, 81, 
-    81, 81, 80, 80, 80, 80, 82, 82, 80, 80, 79, 79, 79, 79, 82, 81, 81, 81, 
-    79, 79, 79, 79, 79, 81, 81, 81, 82, 82, 80, 80, 80, 82, 80, 80, 79, 79, 
-    79, 79, 455, 456, 81, 82, 457, 457, 457, 457, 457, 457, 457, 458, 457, 
-    457, 457, 459, 460, 461, 462, 463, 464, 465, 466, 464, 467, 468, 39, 85, 
-    469, 470, 471, 472, 469, 470, 471, 472, 39, 39, 473, 85, 474, 474, 474, 
-    475, 476, 477, 478, 479, 480, 481, 482, 34, 483, 484, 483, 483, 484, 485, 
-    486, 486, 85, 43, 51, 39, 487, 487, 473, 488, 488, 85, 85, 85, 489, 490, 
-    491, 487, 487, 487, 85, 85, 85, 85, 85, 85, 85, 85, 492, 85, 488, 85, 
-    373, 85, 373, 373, 373, 373, 85, 373, 373, 457, 493, 494, 494, 494, 494, 
-    82, 495, 496, 497, 498, 499, 499, 499, 499, 499, 499, 500, 60, 82, 82, 
-    48, 500, 500, 500, 500, 500, 501, 501, 492, 490, 491, 502, 500, 48, 48, 
-    48, 48, 500, 500, 500, 500, 500, 501, 501, 492, 490, 491, 82, 60, 60, 60, 
-    60, 60, 82, 82, 82, 278, 278, 278, 278, 278, 278, 278, 503, 278, 504, 
-    278, 278, 37, 278, 278, 278, 278, 278, 278, 278, 278, 278, 503, 278, 278, 
-    278, 278, 503, 278, 278, 503, 505, 505, 505, 505, 505, 505, 505, 505, 
-    505, 97, 97, 447, 447, 97, 97, 97, 97, 447, 447, 447, 97, 97, 412, 412, 
-    412, 412, 97, 412, 412, 412, 447, 447, 97, 157, 97, 447, 447, 157, 157, 
-    157, 157, 97, 82, 82, 82, 82, 82, 82, 82, 41, 41, 506, 507, 41, 508, 41, 
-    506, 41, 507, 50, 506, 506, 506, 50, 50, 506, 506, 506, 509, 41, 506, 
-    510, 41, 492, 506, 506, 506, 506, 506, 41, 41, 41, 508, 508, 41, 506, 41, 
-    86, 41, 506, 41, 53, 511, 506, 506, 512, 50, 506, 506, 53, 506, 50, 449, 
-    449, 449, 449, 50, 41, 41, 50, 50, 506, 506, 513, 492, 492, 492, 492, 
-    506, 50, 50, 50, 50, 41, 492, 41, 41, 57, 313, 514, 514, 514, 515, 52, 
-    516, 514, 514, 514, 514, 514, 52, 515, 515, 52, 514, 517, 517, 517, 517, 
-    517, 517, 517, 517, 517, 517, 517, 517, 518, 518, 518, 518, 517, 517, 
-    518, 518, 518, 518, 518, 518, 518, 518, 518, 53, 57, 518, 518, 518, 518, 
-    52, 41, 41, 82, 82, 82, 82, 55, 55, 55, 55, 55, 508, 508, 508, 508, 508, 
-    492, 492, 41, 41, 41, 41, 492, 41, 41, 492, 41, 41, 492, 41, 41, 41, 41, 
-    41, 41, 41, 492, 41, 41, 41, 41, 41, 41, 41, 41, 41, 45, 45, 41, 41, 41, 
-    41, 41, 41, 41, 41, 41, 41, 41, 41, 492, 492, 41, 41, 55, 41, 55, 41, 41, 
-    41, 41, 41, 41, 41, 41, 41, 41, 45, 41, 41, 41, 41, 492, 492, 492, 492, 
-    492, 492, 492, 492, 492, 492, 492, 492, 55, 513, 519, 519, 513, 492, 492, 
-    55, 519, 513, 513, 519, 513, 513, 492, 55, 492, 519, 520, 521, 492, 519, 
-    513, 492, 492, 492, 519, 513, 513, 519, 55, 519, 519, 513, 513, 55, 513, 
-    55, 513, 55, 55, 55, 55, 519, 519, 513, 519, 513, 513, 513, 513, 513, 55, 
-    55, 55, 55, 492, 513, 492, 513, 519, 519, 513, 513, 513, 513, 513, 513, 
-    513, 513, 513, 513, 519, 513, 513, 513, 519, 492, 492, 492, 492, 492, 
-    519, 513, 513, 513, 492, 492, 492, 492, 492, 492, 492, 492, 492, 513, 
-    519, 55, 513, 492, 519, 519, 519, 519, 513, 513, 519, 519, 492, 492, 519, 
-    519, 513, 513, 519, 519, 513, 513, 519, 519, 513, 513, 513, 513, 513, 
-    492, 492, 513, 513, 513, 513, 492, 492, 55, 492, 492, 513, 55, 492, 492, 
-    492, 492, 492, 492, 492, 492, 513, 513, 492, 55, 513, 513, 513, 492, 492, 
-    492, 492, 492, 513, 519, 492, 513, 513, 513, 513, 513, 492, 492, 513, 
-    513, 492, 492, 492, 492, 513, 513, 513, 513, 513, 513, 513, 513, 492, 
-    522, 490, 491, 490, 491, 41, 41, 41, 41, 41, 41, 508, 41, 41, 41, 41, 41, 
-    41, 41, 523, 523, 41, 41, 41, 41, 513, 513, 41, 41, 41, 41, 41, 41, 41, 
-    524, 525, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 313, 313, 313, 313, 
-    313, 313, 313, 313, 313, 313, 313, 313, 313, 41, 492, 41, 41, 41, 41, 41, 
-    41, 41, 41, 313, 41, 41, 41, 41, 41, 492, 492, 492, 492, 492, 492, 492, 
-    492, 492, 41, 41, 41, 41, 492, 492, 41, 41, 41, 41, 41, 41, 41, 526, 526, 
-    526, 526, 41, 41, 41, 523, 527, 527, 523, 41, 41, 41, 41, 41, 41, 41, 41, 
-    41, 41, 41, 82, 41, 41, 41, 82, 82, 82, 82, 82, 52, 52, 52, 52, 52, 52, 
-    52, 52, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 528, 528, 528, 
-    528, 528, 528, 528, 528, 528, 528, 528, 528, 528, 528, 516, 52, 52, 52, 
-    52, 52, 52, 52, 52, 52, 52, 52, 52, 515, 508, 508, 508, 508, 508, 508, 
-    508, 508, 508, 508, 508, 508, 41, 41, 41, 41, 508, 508, 508, 508, 529, 
-    41, 41, 41, 41, 41, 508, 508, 508, 508, 41, 41, 508, 508, 41, 508, 508, 
-    508, 508, 508, 508, 508, 41, 41, 41, 41, 41, 41, 41, 41, 508, 508, 41, 
-    41, 508, 55, 41, 41, 41, 41, 508, 508, 41, 41, 508, 55, 41, 41, 41, 41, 
-    508, 508, 508, 41, 41, 508, 41, 41, 508, 508, 41, 41, 41, 41, 41, 41, 41, 
-    508, 492, 492, 492, 492, 492, 530, 530, 492, 527, 527, 527, 527, 41, 508, 
-    508, 41, 41, 508, 41, 41, 41, 41, 508, 508, 41, 41, 41, 41, 523, 523, 
-    529, 529, 527, 41, 527, 527, 531, 532, 531, 527, 41, 527, 527, 527, 41, 
-    41, 41, 41, 508, 41, 508, 41, 41, 41, 41, 41, 526, 526, 526, 526, 526, 
-    526, 526, 526, 526, 526, 526, 526, 41, 41, 41, 41, 508, 508, 41, 508, 
-    508, 508, 41, 508, 531, 508, 508, 41, 508, 508, 41, 55, 41, 41, 41, 41, 
-    41, 41, 41, 523, 41, 41, 41, 526, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 
-    508, 508, 41, 526, 41, 41, 41, 41, 41, 41, 41, 41, 526, 526, 313, 41, 41, 
-    41, 41, 41, 41, 41, 41, 523, 523, 531, 527, 527, 527, 527, 523, 523, 531, 
-    531, 531, 508, 508, 508, 508, 531, 526, 531, 531, 531, 508, 531, 523, 
-    508, 508, 508, 531, 531, 508, 508, 531, 508, 508, 531, 531, 531, 41, 508, 
-    41, 41, 41, 41, 508, 508, 523, 508, 508, 508, 508, 508, 508, 531, 523, 
-    523, 531, 523, 508, 531, 531, 533, 523, 508, 508, 523, 531, 531, 527, 
-    527, 527, 527, 527, 526, 41, 41, 527, 527, 534, 534, 532, 532, 41, 41, 
-    526, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 45, 41, 41, 41, 41, 
-    41, 41, 526, 41, 526, 41, 41, 41, 41, 526, 526, 526, 41, 535, 41, 41, 41, 
-    536, 536, 536, 536, 536, 536, 41, 537, 537, 527, 41, 41, 41, 490, 491, 
-    490, 491, 490, 491, 490, 491, 490, 491, 490, 491, 490, 491, 52, 52, 516, 
-    516, 516, 516, 516, 516, 516, 516, 516, 516, 516, 516, 41, 526, 526, 526, 
-    41, 41, 41, 41, 41, 41, 41, 526, 513, 492, 492, 513, 513, 490, 491, 492, 
-    513, 513, 492, 513, 513, 513, 492, 492, 492, 492, 492, 513, 513, 513, 
-    513, 492, 492, 492, 492, 492, 513, 513, 513, 492, 492, 492, 513, 513, 
-    513, 513, 16, 32, 16, 32, 16, 32, 16, 32, 490, 491, 538, 538, 538, 538, 
-    538, 538, 538, 538, 492, 492, 492, 490, 491, 16, 32, 490, 491, 490, 491, 
-    490, 491, 490, 491, 490, 491, 492, 492, 513, 513, 513, 513, 513, 513, 
-    492, 492, 492, 492, 492, 492, 492, 513, 513, 513, 513, 513, 513, 492, 
-    492, 492, 513, 492, 492, 492, 492, 513, 513, 513, 513, 513, 492, 513, 
-    513, 492, 492, 490, 491, 490, 491, 513, 492, 492, 492, 492, 513, 492, 
-    513, 513, 513, 492, 492, 513, 513, 492, 492, 492, 492, 492, 492, 492, 
-    492, 492, 492, 513, 513, 513, 513, 513, 513, 492, 492, 490, 491, 492, 
-    492, 492, 492, 513, 513, 513, 513, 513, 513, 513, 513, 513, 513, 513, 
-    492, 513, 513, 513, 513, 492, 492, 513, 492, 513, 492, 492, 513, 492, 
-    513, 513, 513, 513, 492, 492, 492, 492, 492, 513, 513, 492, 492, 492, 
-    492, 513, 513, 513, 513, 492, 513, 513, 492, 492, 513, 513, 492, 492, 
-    492, 492, 513, 513, 513, 513, 513, 513, 513, 513, 513, 513, 513, 492, 
-    492, 513, 513, 513, 513, 513, 513, 513, 513, 492, 513, 513, 513, 513, 
-    513, 513, 513, 513, 492, 492, 492, 492, 492, 513, 492, 513, 492, 492, 
-    492, 513, 513, 513, 513, 513, 492, 492, 492, 492, 513, 492, 492, 492, 
-    513, 513, 513, 513, 513, 492, 513, 492, 492, 41, 41, 41, 526, 526, 41, 
-    41, 41, 492, 492, 492, 492, 492, 41, 41, 492, 492, 492, 492, 492, 492, 
-    41, 41, 41, 526, 41, 41, 41, 41, 535, 508, 508, 41, 41, 41, 41, 82, 82, 
-    41, 41, 41, 41, 41, 41, 41, 41, 82, 82, 41, 41, 82, 82, 82, 41, 41, 41, 
-    41, 82, 41, 41, 41, 41, 41, 41, 41, 41, 82, 82, 82, 82, 82, 82, 82, 82, 
-    82, 82, 41, 41, 41, 41, 539, 539, 539, 539, 539, 539, 539, 539, 539, 539, 
-    539, 539, 539, 539, 539, 82, 540, 540, 540, 540, 540, 540, 540, 540, 540, 
-    540, 540, 540, 540, 540, 540, 82, 53, 57, 53, 53, 53, 57, 57, 53, 57, 53, 
-    57, 53, 57, 53, 53, 53, 53, 57, 53, 57, 57, 53, 57, 57, 57, 57, 57, 57, 
-    60, 60, 53, 53, 88, 89, 88, 89, 89, 541, 541, 541, 541, 541, 541, 88, 89, 
-    88, 89, 542, 542, 542, 88, 89, 82, 82, 82, 82, 82, 543, 544, 544, 544, 
-    545, 543, 544, 546, 546, 546, 546, 546, 546, 546, 546, 546, 546, 546, 
-    546, 546, 546, 82, 546, 82, 82, 82, 82, 82, 546, 82, 82, 547, 547, 547, 
-    547, 547, 547, 547, 547, 82, 82, 82, 82, 82, 82, 82, 548, 549, 82, 82, 
-    82, 82, 82, 82, 82, 82, 82, 82, 82, 82, 82, 82, 550, 96, 96, 96, 96, 96, 
-    96, 96, 96, 551, 551, 43, 51, 43, 51, 551, 551, 551, 43, 51, 551, 43, 51, 
-    373, 373, 373, 373, 373, 373, 373, 373, 85, 466, 552, 373, 553, 85, 43, 
-    51, 85, 85, 43, 51, 490, 491, 490, 491, 490, 491, 490, 491, 373, 373, 
-    373, 373, 371, 61, 373, 373, 85, 373, 373, 85, 85, 85, 85, 85, 554, 554, 
-    373, 373, 373, 85, 466, 373, 471, 373, 373, 82, 82, 82, 555, 555, 555, 
-    555, 555, 555, 555, 555, 555, 555, 82, 555, 555, 555, 555, 555, 555, 555, 
-    555, 555, 82, 82, 82, 82, 555, 555, 555, 555, 555, 555, 82, 82, 523, 523, 
-    523, 523, 523, 523, 523, 523, 523, 523, 523, 523, 82, 82, 82, 82, 556, 
-    557, 557, 558, 523, 559, 560, 561, 524, 525, 524, 525, 524, 525, 524, 
-    525, 524, 525, 523, 523, 524, 525, 524, 525, 524, 525, 524, 525, 562, 
-    563, 564, 564, 523, 561, 561, 561, 561, 561, 561, 561, 561, 561, 565, 
-    566, 567, 568, 569, 569, 570, 571, 571, 571, 571, 572, 523, 523, 561, 
-    561, 561, 559, 573, 558, 523, 527, 82, 574, 575, 574, 575, 574, 575, 574, 
-    575, 574, 575, 575, 575, 575, 575, 575, 575, 575, 575, 575, 575, 575, 
-    575, 575, 575, 575, 575, 574, 575, 575, 575, 575, 575, 575, 575, 574, 
-    575, 574, 575, 574, 575, 575, 575, 575, 575, 575, 574, 575, 575, 575, 
-    575, 575, 575, 574, 574, 82, 82, 576, 576, 577, 577, 578, 578, 575, 562, 
-    579, 580, 579, 580, 579, 580, 579, 580, 579, 580, 580, 580, 580, 580, 
-    580, 580, 580, 580, 580, 580, 580, 580, 580, 580, 580, 580, 579, 580, 
-    580, 580, 580, 580, 580, 580, 579, 580, 579, 580, 579, 580, 580, 580, 
-    580, 580, 580, 579, 580, 580, 580, 580, 580, 580, 579, 579, 580, 580, 
-    580, 580, 581, 582, 583, 583, 580, 82, 82, 82, 82, 82, 584, 584, 584, 
-    584, 584, 584, 584, 584, 584, 584, 584, 584, 584, 584, 584, 584, 584, 82, 
-    82, 82, 585, 585, 585, 585, 585, 585, 585, 585, 585, 585, 585, 585, 585, 
-    585, 585, 585, 585, 585, 585, 585, 585, 585, 82, 586, 586, 587, 587, 587, 
-    587, 586, 586, 586, 586, 586, 586, 586, 586, 586, 586, 584, 584, 584, 82, 
-    82, 82, 82, 82, 579, 579, 579, 579, 579, 579, 579, 579, 588, 588, 588, 
-    588, 588, 588, 588, 588, 588, 588, 588, 588, 588, 589, 589, 82, 587, 587, 
-    587, 587, 587, 587, 587, 587, 587, 587, 586, 586, 586, 586, 586, 586, 
-    590, 590, 590, 590, 590, 590, 590, 590, 523, 591, 591, 591, 591, 591, 
-    591, 591, 591, 591, 591, 591, 591, 591, 591, 591, 588, 588, 588, 588, 
-    589, 589, 589, 586, 586, 591, 591, 591, 591, 591, 591, 591, 586, 586, 
-    586, 586, 523, 523, 523, 523, 592, 592, 592, 592, 592, 592, 592, 592, 
-    592, 592, 592, 592, 592, 592, 592, 82, 586, 586, 586, 586, 586, 586, 586, 
-    523, 523, 523, 523, 586, 586, 586, 586, 586, 586, 586, 586, 586, 586, 
-    586, 523, 523, 593, 593, 593, 593, 593, 593, 593, 593, 593, 593, 593, 
-    593, 593, 593, 594, 594, 594, 594, 594, 594, 594, 594, 594, 594, 595, 
-    595, 595, 595, 595, 595, 595, 595, 595, 595, 595, 595, 595, 596, 595, 
-    595, 595, 595, 595, 595, 595, 82, 82, 82, 597, 597, 597, 597, 597, 597, 
-    597, 597, 597, 597, 597, 597, 597, 597, 597, 82, 598, 598, 598, 598, 598, 
-    598, 598, 598, 599, 599, 599, 599, 599, 599, 600, 600, 601, 601, 601, 
-    601, 601, 601, 601, 601, 601, 601, 601, 601, 602, 603, 604, 603, 605, 
-    605, 605, 605, 605, 605, 605, 605, 605, 605, 601, 601, 82, 82, 82, 82, 
-    91, 94, 91, 94, 91, 94, 606, 96, 98, 98, 98, 607, 96, 96, 96, 96, 96, 96, 
-    96, 96, 96, 96, 607, 608, 91, 94, 91, 94, 450, 450, 96, 96, 609, 609, 
-    609, 609, 609, 609, 609, 609, 609, 609, 609, 609, 609, 609, 610, 610, 
-    610, 610, 610, 610, 610, 610, 610, 610, 611, 611, 612, 613, 613, 613, 
-    613, 613, 63, 63, 63, 63, 63, 63, 63, 61, 61, 61, 61, 61, 61, 61, 61, 61, 
-    63, 63, 53, 57, 53, 57, 53, 57, 57, 57, 53, 57, 53, 57, 53, 57, 60, 57, 
-    57, 57, 57, 57, 57, 57, 57, 53, 57, 53, 57, 53, 53, 57, 61, 614, 614, 53, 
-    57, 53, 57, 58, 53, 57, 53, 57, 57, 57, 53, 57, 53, 57, 53, 53, 53, 53, 
-    53, 82, 53, 53, 53, 53, 53, 57, 53, 57, 82, 82, 82, 82, 82, 82, 82, 58, 
-    60, 60, 57, 58, 58, 58, 58, 58, 615, 615, 616, 615, 615, 615, 617, 615, 
-    615, 615, 615, 616, 615, 615, 615, 615, 615, 615, 615, 615, 615, 615, 
-    615, 615, 615, 615, 615, 618, 618, 616, 616, 618, 619, 619, 619, 619, 82, 
-    82, 82, 82, 620, 620, 620, 620, 620, 620, 313, 313, 503, 512, 82, 82, 82, 
-    82, 82, 82, 621, 621, 621, 621, 621, 621, 621, 621, 621, 621, 621, 621, 
-    622, 622, 623, 623, 624, 624, 625, 625, 625, 625, 625, 625, 625, 625, 
-    625, 625, 625, 625, 625, 625, 625, 625, 625, 625, 624, 624, 624, 624, 
-    624, 624, 624, 624, 624, 624, 624, 624, 624, 624, 624, 624, 626, 627, 82, 
-    82, 82, 82, 82, 82, 82, 82, 628, 628, 629, 629, 629, 629, 629, 629, 629, 
-    629, 629, 629, 82, 82, 82, 82, 82, 82, 198, 198, 198, 198, 198, 198, 198, 
-    198, 198, 198, 195, 195, 195, 195, 195, 195, 201, 201, 201, 195, 630, 
-    195, 82, 82, 631, 631, 631, 631, 631, 631, 631, 631, 631, 631, 632, 632, 
-    632, 632, 632, 632, 632, 632, 632, 632, 632, 632, 632, 632, 632, 632, 
-    632, 632, 632, 632, 633, 633, 633, 633, 633, 634, 634, 634, 199, 635, 
-    636, 636, 636, 636, 636, 636, 636, 636, 636, 636, 636, 636, 636, 636, 
-    636, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 638, 639, 82, 
-    82, 82, 82, 82, 82, 82, 82, 82, 82, 82, 640, 328, 328, 328, 328, 328, 82, 
-    82, 82, 641, 641, 641, 642, 643, 643, 643, 643, 643, 643, 643, 643, 643, 
-    643, 643, 643, 643, 643, 643, 644, 642, 642, 641, 641, 641, 641, 642, 
-    642, 641, 642, 642, 642, 645, 646, 646, 646, 646, 646, 646, 647, 647, 
-    647, 646, 646, 646, 646, 82, 62, 648, 648, 648, 648, 648, 648, 648, 648, 
-    648, 648, 82, 82, 82, 82, 646, 646, 314, 314, 314, 314, 314, 316, 649, 
-    314, 319, 319, 314, 314, 314, 314, 314, 82, 650, 650, 650, 650, 650, 650, 
-    650, 650, 650, 651, 651, 651, 651, 651, 651, 652, 652, 651, 651, 652, 
-    652, 651, 651, 82, 650, 650, 650, 651, 650, 650, 650, 650, 650, 650, 650, 
-    650, 651, 652, 82, 82, 653, 653, 653, 653, 653, 653, 653, 653, 653, 653, 
-    82, 82, 654, 655, 655, 655, 649, 314, 314, 314, 314, 314, 314, 323, 323, 
-    323, 314, 315, 316, 315, 314, 314, 656, 656, 656, 656, 656, 656, 656, 
-    656, 657, 656, 657, 657, 658, 656, 656, 657, 657, 656, 656, 656, 656, 
-    656, 657, 657, 656, 657, 656, 82, 82, 82, 82, 82, 82, 82, 82, 656, 656, 
-    659, 660, 660, 661, 661, 661, 661, 661, 661, 661, 661, 661, 661, 661, 
-    662, 663, 663, 662, 662, 664, 664, 661, 665, 665, 662, 666, 82, 82, 331, 
-    331, 331, 331, 331, 331, 82, 57, 57, 57, 614, 60, 60, 60, 60, 57, 57, 57, 
-    57, 57, 80, 82, 82, 338, 338, 338, 338, 338, 338, 338, 338, 661, 661, 
-    661, 662, 662, 663, 662, 662, 663, 662, 662, 664, 662, 666, 82, 82, 667, 
-    667, 667, 667, 667, 667, 667, 667, 667, 667, 82, 82, 82, 82, 82, 82, 668, 
-    669, 669, 669, 669, 669, 669, 669, 669, 669, 669, 669, 669, 669, 669, 
-    669, 669, 669, 669, 669, 668, 669, 669, 669, 669, 669, 669, 669, 82, 82, 
-    82, 82, 329, 329, 329, 329, 329, 329, 329, 82, 82, 82, 82, 330, 330, 330, 
-    330, 330, 330, 330, 330, 330, 82, 82, 82, 82, 670, 670, 670, 670, 670, 
-    670, 670, 670, 671, 671, 671, 671, 671, 671, 671, 671, 593, 593, 594, 
-    594, 594, 594, 594, 594, 57, 57, 57, 57, 57, 57, 57, 82, 82, 82, 82, 102, 
-    102, 102, 102, 102, 82, 82, 82, 82, 82, 130, 672, 130, 130, 673, 130, 
-    130, 130, 130, 130, 130, 130, 130, 130, 130, 130, 130, 130, 82, 130, 130, 
-    130, 130, 130, 82, 130, 82, 130, 130, 82, 130, 130, 82, 130, 130, 147, 
-    147, 674, 674, 674, 674, 674, 674, 674, 674, 674, 674, 674, 674, 674, 
-    674, 674, 674, 82, 82, 82, 82, 82, 82, 82, 82, 82, 147, 147, 147, 147, 
-    147, 147, 147, 147, 147, 147, 147, 675, 471, 82, 82, 147, 147, 147, 147, 
-    147, 147, 147, 147, 147, 147, 136, 139, 82, 82, 676, 676, 676, 676, 676, 
-    676, 676, 676, 677, 557, 557, 677, 677, 678, 678, 563, 564, 679, 82, 82, 
-    82, 82, 82, 82, 97, 97, 97, 97, 97, 97, 97, 157, 157, 157, 157, 157, 157, 
-    157, 96, 96, 558, 570, 570, 680, 680, 563, 564, 563, 564, 563, 564, 563, 
-    564, 563, 564, 563, 564, 563, 564, 563, 564, 558, 558, 563, 564, 558, 
-    558, 558, 558, 680, 680, 680, 681, 558, 681, 82, 581, 682, 678, 678, 570, 
-    524, 525, 524, 525, 524, 525, 683, 558, 558, 684, 685, 686, 686, 687, 82, 
-    558, 688, 689, 558, 82, 82, 82, 82, 147, 147, 147, 147, 147, 82, 82, 493, 
-    82, 690, 691, 692, 693, 694, 691, 691, 695, 696, 691, 697, 698, 699, 698, 
-    700, 701, 701, 701, 701, 701, 701, 701, 701, 701, 701, 702, 703, 704, 
-    705, 704, 690, 691, 706, 706, 706, 706, 706, 706, 706, 706, 706, 706, 
-    706, 706, 706, 706, 706, 706, 706, 706, 695, 691, 696, 707, 708, 707, 
-    709, 709, 709, 709, 709, 709, 709, 709, 709, 709, 709, 709, 709, 709, 
-    709, 709, 709, 709, 695, 705, 696, 705, 695, 696, 710, 711, 712, 710, 
-    713, 714, 715, 715, 715, 715, 715, 715, 715, 715, 715, 716, 714, 714, 
-    714, 714, 714, 714, 714, 714, 714, 714, 714, 714, 714, 714, 714, 714, 
-    714, 714, 714, 714, 714, 717, 717, 718, 718, 718, 718, 718, 718, 718, 
-    718, 718, 718, 718, 718, 718, 718, 718, 82, 82, 82, 718, 718, 718, 718, 
-    718, 718, 82, 82, 718, 718, 718, 82, 82, 82, 719, 693, 705, 707, 720, 
-    693, 693, 82, 721, 722, 722, 722, 722, 721, 721, 82, 82, 723, 723, 723, 
-    724, 508, 82, 82, 725, 725, 725, 725, 725, 725, 725, 725, 725, 725, 725, 
-    725, 82, 725, 725, 725, 725, 725, 725, 725, 725, 725, 725, 82, 725, 725, 
-    725, 82, 725, 725, 82, 725, 725, 725, 725, 725, 725, 725, 82, 82, 725, 
-    725, 725, 82, 82, 82, 82, 82, 199, 373, 199, 82, 82, 82, 82, 620, 620, 
-    620, 620, 620, 620, 620, 620, 620, 620, 620, 620, 620, 82, 82, 82, 313, 
-    726, 726, 726, 726, 726, 726, 726, 726, 726, 726, 726, 726, 726, 727, 
-    727, 727, 727, 728, 728, 728, 728, 728, 728, 728, 728, 728, 728, 728, 
-    728, 728, 728, 728, 728, 728, 727, 727, 728, 729, 729, 82, 41, 41, 41, 
-    41, 82, 82, 82, 82, 728, 82, 82, 82, 82, 82, 82, 82, 313, 313, 313, 313, 
-    313, 157, 82, 82, 730, 730, 730, 730, 730, 730, 730, 730, 730, 730, 730, 
-    730, 730, 82, 82, 82, 731, 731, 731, 731, 731, 731, 731, 731, 731, 82, 
-    82, 82, 82, 82, 82, 82, 157, 500, 500, 500, 500, 500, 500, 500, 500, 500, 
-    500, 500, 500, 500, 500, 500, 500, 500, 500, 500, 82, 82, 82, 82, 732, 
-    732, 732, 732, 732, 732, 732, 732, 733, 733, 733, 733, 82, 82, 82, 82, 
-    734, 734, 734, 734, 734, 734, 734, 734, 734, 735, 734, 734, 734, 734, 
-    734, 734, 734, 734, 735, 82, 82, 82, 82, 82, 736, 736, 736, 736, 736, 
-    736, 736, 736, 736, 736, 736, 736, 736, 736, 737, 737, 737, 737, 737, 82, 
-    82, 82, 82, 82, 738, 738, 738, 738, 738, 738, 738, 738, 738, 738, 738, 
-    738, 738, 738, 82, 739, 740, 740, 740, 740, 740, 740, 740, 740, 740, 740, 
-    740, 740, 82, 82, 82, 82, 741, 742, 742, 742, 742, 742, 82, 82, 743, 743, 
-    743, 743, 743, 743, 743, 743, 744, 744, 744, 744, 744, 744, 744, 744, 
-    745, 745, 745, 745, 745, 745, 745, 745, 746, 746, 746, 746, 746, 746, 
-    746, 746, 746, 746, 746, 746, 746, 746, 82, 82, 747, 747, 747, 747, 747, 
-    747, 747, 747, 747, 747, 82, 82, 82, 82, 82, 82, 748, 748, 748, 748, 748, 
-    748, 748, 748, 748, 748, 748, 748, 82, 82, 82, 82, 749, 749, 749, 749, 
-    749, 749, 749, 749, 749, 749, 749, 749, 82, 82, 82, 82, 750, 750, 750, 
-    750, 750, 750, 750, 750, 751, 751, 751, 751, 751, 751, 751, 751, 751, 
-    751, 751, 751, 82, 82, 82, 82, 82, 82, 82, 82, 82, 82, 82, 752, 753, 753, 
-    753, 753, 753, 753, 753, 753, 753, 753, 753, 753, 753, 753, 753, 82, 753, 
-    753, 753, 753, 753, 753, 82, 82, 754, 754, 754, 754, 754, 754, 82, 82, 
-    754, 82, 754, 754, 754, 754, 754, 754, 754, 754, 754, 754, 754, 754, 754, 
-    754, 754, 754, 754, 754, 754, 754, 82, 754, 754, 82, 82, 82, 754, 82, 82, 
-    754, 755, 755, 755, 755, 755, 755, 755, 755, 755, 755, 755, 755, 755, 
-    755, 82, 756, 757, 757, 757, 757, 757, 757, 757, 757, 758, 758, 758, 758, 
-    758, 758, 758, 758, 758, 758, 758, 758, 758, 758, 758, 759, 759, 760, 
-    760, 760, 760, 760, 760, 760, 761, 761, 761, 761, 761, 761, 761, 761, 
-    761, 761, 761, 761, 761, 761, 761, 82, 82, 82, 82, 82, 82, 82, 82, 762, 
-    762, 762, 762, 762, 762, 762, 762, 762, 763, 763, 763, 763, 763, 763, 
-    763, 763, 763, 763, 763, 82, 763, 763, 82, 82, 82, 82, 82, 764, 764, 764, 
-    764, 764, 765, 765, 765, 765, 765, 765, 765, 765, 765, 765, 765, 765, 
-    765, 765, 766, 766, 766, 766, 766, 766, 82, 82, 82, 767, 768, 768, 768, 
-    768, 768, 768, 768, 768, 768, 768, 82, 82, 82, 82, 82, 769, 770, 770, 
-    770, 770, 770, 770, 770, 770, 771, 771, 771, 771, 771, 771, 771, 771, 82, 
-    82, 82, 82, 772, 772, 771, 771, 772, 772, 772, 772, 772, 772, 772, 772, 
-    82, 82, 772, 772, 772, 772, 772, 772, 773, 774, 774, 774, 82, 774, 774, 
-    82, 82, 82, 82, 82, 774, 775, 774, 776, 773, 773, 773, 773, 82, 773, 773, 
-    773, 82, 773, 773, 773, 773, 773, 773, 773, 773, 773, 773, 773, 773, 773, 
-    773, 773, 773, 773, 773, 773, 82, 82, 82, 82, 776, 777, 775, 82, 82, 82, 
-    82, 778, 779, 779, 779, 779, 779, 779, 779, 779, 780, 780, 780, 780, 780, 
-    780, 780, 780, 781, 82, 82, 82, 82, 82, 82, 82, 782, 782, 782, 782, 782, 
-    782, 782, 782, 782, 782, 782, 782, 782, 783, 783, 784, 785, 785, 785, 
-    785, 785, 785, 785, 785, 785, 785, 785, 785, 785, 786, 786, 786, 787, 
-    787, 787, 787, 787, 787, 787, 787, 788, 787, 787, 787, 787, 787, 787, 
-    787, 787, 787, 787, 787, 787, 789, 790, 82, 82, 82, 82, 791, 791, 791, 
-    791, 791, 792, 792, 792, 792, 792, 792, 793, 82, 794, 794, 794, 794, 794, 
-    794, 794, 794, 794, 794, 794, 794, 794, 794, 82, 82, 82, 795, 795, 795, 
-    795, 795, 795, 795, 796, 796, 796, 796, 796, 796, 796, 796, 796, 796, 
-    796, 796, 796, 796, 82, 82, 797, 797, 797, 797, 797, 797, 797, 797, 798, 
-    798, 798, 798, 798, 798, 798, 798, 798, 798, 798, 82, 82, 82, 82, 82, 
-    799, 799, 799, 799, 799, 799, 799, 799, 800, 800, 800, 800, 800, 800, 
-    800, 800, 800, 800, 82, 82, 82, 82, 82, 82, 82, 801, 801, 801, 801, 82, 
-    82, 82, 82, 802, 802, 802, 802, 802, 802, 802, 803, 803, 803, 803, 803, 
-    803, 803, 803, 803, 82, 82, 82, 82, 82, 82, 82, 804, 804, 804, 804, 804, 
-    804, 804, 804, 804, 804, 804, 82, 82, 82, 82, 82, 805, 805, 805, 805, 
-    805, 805, 805, 805, 805, 805, 805, 82, 82, 82, 82, 82, 82, 82, 806, 806, 
-    806, 806, 806, 806, 807, 807, 807, 807, 807, 807, 807, 807, 807, 807, 
-    807, 807, 807, 807, 807, 82, 808, 809, 808, 810, 810, 810, 810, 810, 810, 
-    810, 810, 810, 810, 810, 810, 810, 809, 809, 809, 809, 809, 809, 809, 
-    809, 809, 809, 809, 809, 809, 809, 811, 812, 812, 813, 813, 813, 813, 
-    813, 82, 82, 82, 82, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 
-    814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 815, 815, 815, 815, 
-    815, 815, 815, 815, 815, 815, 82, 82, 82, 82, 82, 82, 82, 811, 816, 816, 
-    817, 818, 818, 818, 818, 818, 818, 818, 818, 818, 818, 818, 818, 818, 
-    817, 817, 817, 816, 816, 816, 816, 817, 817, 819, 820, 821, 821, 822, 
-    823, 823, 823, 823, 82, 82, 82, 82, 82, 82, 824, 824, 824, 824, 824, 824, 
-    824, 824, 824, 82, 82, 82, 82, 82, 82, 82, 825, 825, 825, 825, 825, 825, 
-    825, 825, 825, 825, 82, 82, 82, 82, 82, 82, 826, 826, 826, 827, 827, 827, 
-    827, 827, 827, 827, 827, 827, 827, 827, 827, 827, 827, 827, 827, 827, 
-    827, 827, 827, 828, 828, 828, 828, 828, 829, 828, 828, 828, 828, 828, 
-    828, 830, 830, 82, 831, 831, 831, 831, 831, 831, 831, 831, 831, 831, 832, 
-    832, 832, 832, 82, 82, 82, 82, 833, 833, 833, 833, 833, 833, 833, 833, 
-    833, 833, 833, 834, 835, 836, 833, 82, 837, 837, 838, 839, 839, 839, 839, 
-    839, 839, 839, 839, 839, 839, 839, 839, 839, 839, 839, 839, 838, 838, 
-    838, 837, 837, 837, 837, 837, 837, 837, 837, 837, 838, 840, 839, 839, 
-    839, 839, 841, 841, 842, 841, 842, 843, 837, 837, 842, 82, 82, 844, 844, 
-    844, 844, 844, 844, 844, 844, 844, 844, 839, 845, 839, 841, 841, 841, 82, 
-    846, 846, 846, 846, 846, 846, 846, 846, 846, 846, 846, 846, 846, 846, 
-    846, 846, 846, 846, 846, 846, 82, 82, 82, 847, 847, 847, 847, 847, 847, 
-    847, 847, 847, 847, 82, 847, 847, 847, 847, 847, 847, 847, 847, 847, 848, 
-    848, 848, 849, 849, 849, 848, 848, 849, 850, 851, 849, 852, 852, 853, 
-    852, 852, 853, 849, 82, 854, 854, 854, 854, 854, 854, 854, 82, 854, 82, 
-    854, 854, 854, 854, 82, 854, 854, 854, 854, 854, 854, 854, 854, 854, 854, 
-    854, 854, 854, 854, 854, 82, 854, 854, 855, 82, 82, 82, 82, 82, 82, 856, 
-    856, 856, 856, 856, 856, 856, 856, 856, 856, 856, 856, 856, 856, 856, 
-    857, 858, 858, 858, 857, 857, 857, 857, 857, 857, 859, 860, 82, 82, 82, 
-    82, 82, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 82, 82, 82, 82, 
-    82, 82, 862, 862, 863, 863, 82, 864, 864, 864, 864, 864, 864, 864, 864, 
-    82, 82, 864, 864, 82, 82, 864, 864, 864, 864, 864, 864, 864, 864, 864, 
-    864, 864, 864, 864, 864, 82, 864, 864, 864, 864, 864, 864, 864, 82, 864, 
-    864, 82, 864, 864, 864, 864, 864, 82, 82, 865, 864, 863, 863, 862, 863, 
-    863, 863, 863, 82, 82, 863, 863, 82, 82, 863, 863, 866, 82, 82, 864, 82, 
-    82, 82, 82, 82, 82, 863, 82, 82, 82, 82, 82, 864, 864, 864, 864, 864, 
-    863, 863, 82, 82, 867, 867, 867, 867, 867, 867, 867, 82, 82, 82, 868, 
-    868, 868, 868, 868, 868, 868, 868, 868, 868, 868, 868, 868, 869, 869, 
-    869, 870, 870, 870, 870, 870, 870, 870, 870, 869, 869, 871, 870, 870, 
-    869, 872, 868, 868, 868, 868, 873, 873, 873, 873, 874, 875, 875, 875, 
-    875, 875, 875, 875, 875, 875, 875, 82, 873, 82, 874, 82, 82, 876, 876, 
-    876, 876, 876, 876, 876, 876, 877, 877, 877, 878, 878, 878, 878, 878, 
-    878, 877, 878, 877, 877, 877, 877, 878, 878, 877, 879, 880, 876, 876, 
-    881, 876, 882, 882, 882, 882, 882, 882, 882, 882, 882, 882, 82, 82, 82, 
-    82, 82, 82, 883, 883, 883, 883, 883, 883, 883, 883, 883, 883, 883, 883, 
-    883, 883, 883, 884, 884, 884, 885, 885, 885, 885, 82, 82, 884, 884, 884, 
-    884, 885, 885, 884, 886, 887, 888, 889, 889, 890, 890, 891, 891, 891, 
-    889, 889, 889, 889, 889, 889, 889, 889, 889, 889, 889, 889, 889, 889, 
-    889, 883, 883, 883, 883, 885, 885, 82, 82, 892, 892, 892, 892, 892, 892, 
-    892, 892, 893, 893, 893, 894, 894, 894, 894, 894, 894, 894, 894, 893, 
-    893, 894, 893, 895, 894, 896, 896, 897, 892, 82, 82, 82, 898, 898, 898, 
-    898, 898, 898, 898, 898, 898, 898, 82, 82, 82, 82, 82, 82, 899, 899, 899, 
-    899, 899, 899, 899, 899, 899, 899, 899, 899, 899, 82, 82, 82, 900, 900, 
-    900, 900, 900, 900, 900, 900, 900, 900, 900, 901, 902, 901, 902, 902, 
-    901, 901, 901, 901, 901, 901, 903, 904, 905, 905, 905, 905, 905, 905, 
-    905, 905, 905, 905, 82, 82, 82, 82, 82, 82, 906, 906, 906, 906, 906, 906, 
-    906, 906, 906, 906, 82, 82, 82, 907, 907, 907, 908, 908, 907, 907, 907, 
-    907, 908, 907, 907, 907, 907, 909, 82, 82, 82, 82, 910, 910, 910, 910, 
-    910, 910, 910, 910, 910, 910, 911, 911, 912, 912, 912, 913, 914, 914, 
-    914, 914, 914, 914, 914, 914, 915, 915, 915, 915, 915, 915, 915, 915, 
-    916, 916, 916, 916, 916, 916, 916, 916, 916, 916, 917, 917, 917, 917, 
-    917, 917, 917, 917, 917, 82, 82, 82, 82, 82, 82, 82, 82, 82, 82, 82, 82, 
-    918, 919, 919, 919, 919, 919, 919, 919, 919, 919, 82, 82, 82, 82, 82, 82, 
-    82, 920, 920, 920, 920, 920, 920, 920, 920, 920, 82, 920, 920, 920, 920, 
-    920, 920, 920, 920, 920, 920, 920, 920, 920, 921, 922, 922, 922, 922, 
-    922, 922, 922, 82, 922, 922, 922, 922, 922, 922, 921, 923, 920, 924, 924, 
-    924, 924, 924, 82, 82, 925, 925, 925, 925, 925, 925, 925, 925, 925, 925, 
-    926, 926, 926, 926, 926, 926, 926, 926, 926, 926, 926, 926, 926, 926, 
-    926, 926, 926, 926, 926, 82, 82, 82, 927, 928, 929, 929, 929, 929, 929, 
-    929, 929, 929, 929, 929, 929, 929, 929, 929, 82, 82, 930, 930, 930, 930, 
-    930, 930, 930, 930, 930, 930, 930, 930, 930, 930, 82, 931, 930, 930, 930, 
-    930, 930, 930, 930, 931, 930, 930, 931, 930, 930, 82, 932, 932, 932, 932, 
-    932, 932, 932, 932, 932, 932, 82, 82, 82, 82, 82, 82, 933, 933, 933, 933, 
-    933, 933, 933, 933, 933, 933, 933, 933, 933, 933, 933, 82, 934, 934, 934, 
-    934, 934, 82, 82, 82, 932, 932, 932, 932, 82, 82, 82, 82, 935, 935, 935, 
-    935, 935, 935, 935, 935, 936, 936, 936, 937, 937, 937, 935, 935, 935, 
-    935, 937, 935, 935, 935, 936, 937, 936, 937, 935, 935, 935, 935, 935, 
-    935, 935, 936, 937, 937, 935, 935, 935, 935, 935, 935, 935, 935, 935, 
-    935, 935, 82, 938, 938, 938, 938, 938, 938, 938, 938, 938, 938, 938, 938, 
-    938, 938, 939, 940, 938, 938, 938, 938, 938, 938, 938, 82, 609, 82, 82, 
-    82, 82, 82, 82, 82, 941, 941, 941, 941, 941, 941, 941, 941, 941, 941, 
-    941, 941, 941, 941, 941, 82, 942, 942, 942, 942, 942, 942, 942, 942, 942, 
-    942, 82, 82, 82, 82, 943, 943, 944, 944, 944, 944, 944, 944, 944, 944, 
-    944, 944, 944, 944, 944, 944, 82, 82, 945, 945, 945, 945, 945, 946, 82, 
-    82, 947, 947, 947, 947, 947, 947, 947, 947, 948, 948, 948, 948, 948, 948, 
-    948, 949, 949, 949, 950, 950, 951, 951, 951, 951, 952, 952, 952, 952, 
-    949, 951, 82, 82, 953, 953, 953, 953, 953, 953, 953, 953, 953, 953, 82, 
-    954, 954, 954, 954, 954, 954, 954, 82, 947, 947, 947, 947, 947, 82, 82, 
-    82, 82, 82, 947, 947, 947, 955, 955, 955, 955, 955, 955, 955, 955, 955, 
-    955, 955, 955, 955, 82, 82, 82, 955, 956, 956, 956, 956, 956, 956, 956, 
-    956, 956, 956, 956, 956, 956, 956, 956, 956, 956, 956, 956, 956, 956, 
-    956, 82, 82, 82, 82, 82, 82, 82, 82, 957, 957, 957, 957, 958, 958, 958, 
-    958, 958, 958, 958, 958, 958, 958, 958, 958, 958, 959, 82, 82, 82, 82, 
-    82, 82, 82, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 
-    960, 82, 82, 82, 960, 960, 960, 82, 82, 82, 82, 82, 580, 575, 82, 82, 82, 
-    82, 82, 82, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 82, 
-    82, 82, 82, 82, 961, 961, 961, 961, 961, 82, 82, 82, 961, 82, 82, 82, 82, 
-    82, 82, 82, 961, 961, 82, 82, 962, 963, 964, 965, 499, 499, 499, 499, 82, 
-    82, 82, 82, 313, 313, 313, 313, 313, 313, 82, 82, 313, 313, 313, 313, 
-    313, 313, 313, 82, 82, 313, 313, 313, 313, 313, 313, 313, 313, 313, 313, 
-    313, 313, 966, 966, 447, 447, 447, 313, 313, 313, 967, 966, 966, 966, 
-    966, 966, 499, 499, 499, 499, 499, 499, 499, 499, 157, 157, 157, 157, 
-    157, 157, 157, 157, 313, 313, 97, 97, 97, 97, 97, 157, 157, 313, 313, 
-    313, 313, 313, 313, 97, 97, 97, 97, 313, 313, 313, 82, 82, 82, 82, 82, 
-    82, 82, 728, 728, 968, 968, 968, 728, 82, 82, 620, 620, 82, 82, 82, 82, 
-    82, 82, 506, 506, 506, 506, 506, 506, 506, 506, 506, 506, 50, 50, 50, 50, 
-    50, 50, 50, 50, 50, 50, 50, 50, 50, 50, 50, 50, 50, 50, 506, 506, 506, 
-    506, 506, 506, 506, 506, 506, 506, 50, 50, 50, 50, 50, 50, 50, 82, 50, 
-    50, 50, 50, 50, 50, 506, 82, 506, 506, 82, 82, 506, 82, 82, 506, 506, 82, 
-    82, 506, 506, 506, 506, 82, 506, 506, 50, 50, 82, 50, 82, 50, 50, 50, 50, 
-    50, 50, 50, 82, 50, 50, 50, 50, 50, 50, 50, 506, 506, 82, 506, 506, 506, 
-    506, 82, 82, 506, 506, 506, 506, 506, 506, 506, 506, 82, 506, 506, 506, 
-    506, 506, 506, 506, 82, 50, 50, 506, 506, 82, 506, 506, 506, 506, 82, 
-    506, 506, 506, 506, 506, 82, 506, 82, 82, 82, 506, 506, 506, 506, 506, 
-    506, 506, 82, 50, 50, 50, 50, 50, 50, 50, 50, 50, 50, 50, 50, 82, 82, 
-    506, 969, 50, 50, 50, 50, 50, 50, 50, 50, 50, 513, 50, 50, 50, 50, 50, 
-    50, 506, 506, 506, 506, 506, 506, 506, 506, 506, 969, 50, 50, 50, 50, 50, 
-    50, 50, 50, 50, 513, 50, 50, 506, 506, 506, 506, 506, 969, 50, 50, 50, 
-    50, 50, 50, 50, 50, 50, 513, 50, 50, 50, 50, 50, 50, 506, 506, 506, 506, 
-    506, 506, 506, 506, 506, 969, 50, 513, 50, 50, 50, 50, 50, 50, 50, 50, 
-    506, 50, 82, 82, 970, 970, 970, 970, 970, 970, 970, 970, 970, 970, 971, 
-    971, 971, 971, 971, 971, 971, 971, 972, 972, 972, 972, 972, 972, 972, 
-    972, 972, 972, 972, 972, 972, 972, 972, 971, 971, 971, 971, 972, 972, 
-    972, 972, 972, 972, 972, 972, 972, 972, 971, 971, 971, 971, 971, 971, 
-    971, 971, 972, 971, 971, 971, 971, 971, 971, 972, 971, 971, 973, 973, 
-    973, 973, 974, 82, 82, 82, 82, 82, 82, 82, 972, 972, 972, 972, 972, 82, 
-    972, 972, 972, 972, 972, 972, 972, 975, 975, 975, 975, 975, 975, 975, 82, 
-    975, 975, 975, 975, 975, 975, 975, 975, 975, 82, 82, 975, 975, 975, 975, 
-    975, 975, 975, 82, 975, 975, 82, 975, 975, 975, 975, 975, 82, 82, 82, 82, 
-    82, 976, 976, 976, 976, 976, 976, 976, 976, 976, 976, 976, 976, 976, 82, 
-    82, 977, 977, 977, 977, 977, 977, 977, 977, 977, 978, 978, 978, 978, 978, 
-    978, 978, 82, 979, 979, 979, 979, 979, 979, 979, 979, 979, 979, 980, 980, 
-    980, 980, 980, 980, 980, 980, 980, 980, 980, 980, 980, 980, 980, 980, 
-    980, 980, 981, 981, 981, 981, 981, 981, 982, 82, 82, 82, 82, 82, 983, 
-    983, 983, 983, 983, 983, 983, 983, 983, 983, 82, 82, 82, 82, 984, 984, 
-    147, 147, 147, 147, 82, 147, 147, 147, 82, 147, 147, 82, 147, 82, 82, 
-    147, 82, 147, 147, 147, 147, 147, 147, 147, 147, 147, 147, 82, 147, 147, 
-    147, 147, 82, 147, 82, 147, 82, 82, 82, 82, 82, 82, 147, 82, 82, 82, 82, 
-    147, 82, 147, 82, 147, 82, 147, 147, 147, 82, 147, 82, 147, 82, 147, 82, 
-    147, 82, 147, 147, 147, 147, 82, 147, 82, 147, 147, 82, 147, 147, 147, 
-    147, 147, 147, 147, 147, 147, 82, 82, 82, 82, 82, 147, 147, 147, 82, 147, 
-    147, 147, 133, 133, 82, 82, 82, 82, 82, 82, 527, 527, 527, 527, 523, 527, 
-    527, 527, 527, 527, 527, 527, 527, 527, 527, 527, 527, 527, 527, 527, 
-    985, 985, 985, 985, 985, 985, 985, 985, 985, 985, 985, 985, 527, 527, 
-    527, 527, 527, 527, 527, 985, 985, 527, 527, 527, 527, 527, 527, 527, 
-    527, 527, 527, 527, 527, 527, 527, 523, 527, 527, 527, 527, 527, 527, 
-    985, 985, 48, 48, 48, 516, 516, 985, 985, 985, 528, 528, 528, 528, 528, 
-    528, 313, 985, 528, 528, 41, 41, 985, 985, 985, 985, 528, 528, 528, 528, 
-    528, 528, 986, 528, 528, 986, 986, 986, 986, 986, 986, 986, 986, 986, 
-    986, 528, 528, 528, 528, 528, 528, 528, 528, 528, 528, 985, 985, 985, 
-    985, 985, 985, 985, 985, 985, 987, 987, 987, 987, 987, 987, 987, 987, 
-    987, 987, 988, 586, 586, 985, 985, 985, 985, 985, 586, 586, 586, 586, 
-    985, 985, 985, 985, 586, 985, 985, 985, 985, 985, 985, 985, 586, 586, 
-    985, 985, 985, 985, 985, 985, 523, 527, 527, 527, 527, 527, 527, 527, 
-    527, 527, 527, 527, 527, 523, 523, 523, 523, 523, 523, 523, 523, 523, 
-    527, 523, 523, 523, 523, 523, 523, 527, 523, 523, 523, 523, 523, 523, 
-    523, 534, 523, 523, 523, 523, 523, 523, 527, 527, 527, 527, 527, 527, 
-    527, 527, 41, 41, 527, 527, 523, 523, 523, 523, 523, 526, 526, 523, 523, 
-    523, 523, 523, 526, 523, 523, 523, 523, 523, 523, 534, 534, 523, 523, 
-    523, 523, 523, 534, 532, 527, 527, 527, 523, 523, 527, 527, 527, 523, 
-    527, 527, 527, 523, 523, 523, 989, 989, 989, 989, 989, 523, 523, 523, 
-    523, 523, 523, 523, 527, 523, 527, 534, 534, 523, 523, 534, 534, 534, 
-    534, 534, 534, 534, 534, 534, 534, 534, 523, 523, 523, 523, 523, 523, 
-    523, 523, 523, 523, 523, 523, 523, 534, 534, 534, 534, 523, 523, 523, 
-    523, 534, 523, 534, 523, 523, 523, 534, 523, 523, 523, 523, 534, 534, 
-    534, 523, 534, 534, 534, 526, 523, 526, 523, 526, 523, 523, 523, 523, 
-    523, 534, 523, 523, 523, 523, 526, 523, 526, 526, 523, 523, 523, 523, 
-    523, 523, 523, 523, 523, 523, 527, 527, 523, 526, 526, 526, 526, 526, 
-    526, 526, 523, 523, 523, 523, 523, 523, 523, 523, 526, 526, 526, 526, 
-    526, 526, 523, 523, 523, 523, 523, 526, 526, 526, 526, 526, 526, 526, 
-    526, 526, 526, 526, 526, 41, 41, 41, 41, 527, 523, 523, 523, 523, 527, 
-    527, 527, 527, 527, 527, 532, 527, 527, 527, 527, 534, 527, 527, 527, 
-    527, 527, 532, 527, 527, 527, 527, 534, 534, 527, 527, 527, 527, 527, 41, 
-    41, 41, 41, 41, 41, 41, 41, 527, 527, 527, 527, 41, 41, 527, 523, 523, 
-    523, 523, 523, 523, 523, 523, 523, 523, 534, 534, 534, 523, 523, 523, 
-    534, 534, 534, 534, 534, 41, 41, 41, 41, 41, 41, 536, 536, 536, 990, 990, 
-    990, 41, 41, 41, 41, 523, 523, 523, 534, 523, 523, 523, 523, 523, 523, 
-    523, 523, 534, 534, 534, 523, 534, 523, 523, 523, 523, 523, 527, 527, 
-    523, 523, 523, 985, 985, 985, 985, 985, 527, 527, 527, 523, 523, 985, 
-    985, 985, 527, 527, 527, 527, 523, 523, 523, 985, 41, 41, 41, 41, 985, 
-    985, 985, 985, 41, 41, 41, 41, 41, 985, 985, 985, 41, 41, 985, 985, 985, 
-    985, 985, 985, 41, 41, 41, 41, 41, 41, 985, 985, 534, 534, 534, 534, 534, 
-    534, 534, 985, 523, 523, 523, 523, 523, 523, 534, 523, 534, 985, 985, 
-    534, 534, 534, 534, 534, 534, 534, 523, 523, 534, 534, 534, 985, 523, 
-    523, 523, 523, 985, 985, 985, 985, 523, 523, 523, 523, 523, 523, 523, 
-    985, 523, 523, 985, 985, 985, 985, 985, 985, 523, 985, 985, 985, 985, 
-    985, 985, 985, 985, 985, 985, 985, 985, 985, 82, 82, 593, 593, 593, 593, 
-    593, 593, 593, 594, 593, 593, 593, 593, 593, 594, 594, 594, 594, 594, 
-    594, 594, 594, 594, 82, 82, 82, 499, 82, 82, 82, 82, 82, 82, 499, 499, 
-    499, 499, 499, 499, 499, 499, 671, 671, 671, 671, 671, 671, 82, 82, 
+    37, 13, 38, 39, 40, 41, 42, 43, 25, 44, 45, 46, 47, 48, 49, 49, 50, 51, 
+    39, 39, 40, 49, 42, 52, 53, 53, 53, 35, 54, 54, 54, 54, 54, 54, 55, 54, 
+    54, 54, 54, 54, 54, 54, 54, 54, 55, 54, 54, 54, 54, 54, 54, 56, 55, 54, 
+    54, 54, 54, 54, 55, 57, 58, 58, 59, 59, 59, 59, 57, 59, 58, 58, 58, 59, 
+    58, 58, 59, 59, 57, 59, 58, 58, 59, 59, 59, 56, 57, 58, 58, 59, 58, 59, 
+    57, 59, 54, 58, 54, 59, 54, 59, 54, 59, 54, 59, 54, 59, 54, 59, 54, 59, 
+    60, 57, 54, 58, 54, 59, 54, 59, 54, 59, 54, 58, 54, 59, 54, 59, 54, 59, 
+    54, 59, 54, 59, 55, 57, 54, 57, 61, 62, 54, 59, 54, 59, 57, 54, 59, 54, 
+    59, 54, 59, 61, 62, 55, 57, 54, 58, 54, 59, 54, 58, 62, 55, 57, 54, 58, 
+    54, 59, 54, 59, 55, 57, 54, 59, 54, 59, 54, 54, 59, 54, 59, 54, 59, 63, 
+    64, 60, 60, 64, 60, 64, 60, 60, 64, 60, 60, 60, 64, 64, 60, 60, 60, 60, 
+    64, 60, 60, 64, 60, 60, 60, 64, 64, 64, 60, 60, 64, 60, 54, 59, 60, 64, 
+    60, 64, 60, 60, 64, 60, 64, 64, 60, 64, 60, 54, 59, 60, 60, 60, 64, 60, 
+    64, 60, 60, 64, 64, 65, 60, 64, 64, 64, 65, 65, 65, 65, 66, 67, 63, 66, 
+    67, 63, 66, 67, 63, 54, 58, 54, 58, 54, 58, 54, 58, 54, 58, 54, 58, 54, 
+    58, 54, 58, 64, 54, 59, 54, 59, 54, 59, 60, 64, 54, 59, 59, 66, 67, 63, 
+    54, 59, 60, 60, 60, 64, 60, 64, 60, 64, 54, 59, 54, 59, 54, 59, 64, 64, 
+    64, 64, 64, 64, 60, 60, 64, 60, 60, 64, 64, 60, 64, 60, 60, 60, 60, 64, 
+    60, 64, 60, 64, 60, 64, 60, 64, 64, 57, 64, 64, 64, 64, 64, 64, 64, 64, 
+    64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 65, 64, 64, 64, 68, 68, 68, 68, 
+    68, 68, 68, 68, 68, 69, 69, 70, 70, 70, 70, 70, 70, 70, 71, 71, 72, 71, 
+    69, 73, 74, 73, 73, 73, 74, 73, 69, 69, 75, 70, 71, 71, 71, 71, 71, 71, 
+    40, 40, 40, 40, 76, 40, 71, 77, 68, 68, 68, 68, 68, 71, 71, 71, 71, 71, 
+    78, 78, 69, 71, 70, 71, 71, 71, 71, 71, 71, 71, 71, 71, 79, 79, 79, 79, 
+    79, 80, 79, 79, 79, 79, 79, 79, 79, 80, 80, 79, 80, 79, 80, 79, 79, 81, 
+    82, 82, 82, 82, 81, 83, 82, 82, 82, 82, 82, 84, 84, 85, 85, 85, 85, 86, 
+    86, 82, 82, 82, 82, 85, 85, 82, 85, 85, 82, 82, 87, 87, 87, 87, 88, 82, 
+    82, 82, 82, 80, 80, 80, 89, 89, 79, 89, 89, 90, 80, 82, 82, 82, 80, 80, 
+    80, 82, 82, 91, 80, 80, 80, 82, 82, 82, 82, 80, 81, 82, 82, 80, 92, 93, 
+    93, 92, 93, 93, 92, 80, 80, 80, 80, 80, 80, 80, 80, 80, 80, 80, 80, 80, 
+    94, 95, 94, 95, 96, 97, 94, 95, 98, 98, 99, 95, 95, 95, 100, 94, 98, 98, 
+    98, 98, 101, 102, 103, 104, 103, 103, 103, 98, 103, 98, 103, 103, 105, 
+    106, 106, 106, 106, 106, 106, 106, 106, 106, 106, 106, 106, 106, 106, 
+    106, 106, 106, 98, 106, 106, 106, 106, 106, 106, 106, 103, 103, 105, 105, 
+    105, 105, 105, 107, 107, 107, 107, 107, 107, 107, 107, 107, 107, 107, 
+    107, 107, 107, 107, 107, 107, 95, 107, 107, 107, 107, 107, 107, 107, 105, 
+    105, 105, 105, 105, 94, 108, 108, 109, 110, 110, 108, 108, 95, 94, 95, 
+    94, 95, 94, 95, 94, 95, 94, 95, 111, 112, 111, 112, 111, 112, 111, 112, 
+    111, 112, 111, 112, 111, 112, 108, 108, 108, 95, 109, 108, 113, 94, 95, 
+    109, 94, 95, 95, 94, 94, 94, 114, 115, 116, 114, 116, 116, 116, 114, 116, 
+    116, 116, 116, 114, 114, 114, 116, 117, 117, 117, 117, 117, 117, 117, 
+    117, 117, 115, 117, 117, 117, 117, 117, 117, 118, 118, 118, 118, 118, 
+    118, 118, 118, 118, 119, 118, 118, 118, 118, 118, 118, 120, 119, 121, 
+    120, 121, 121, 121, 120, 121, 121, 121, 121, 120, 120, 120, 121, 116, 
+    121, 116, 121, 116, 121, 116, 121, 116, 121, 116, 121, 116, 121, 114, 
+    120, 116, 121, 122, 123, 123, 124, 124, 123, 125, 125, 116, 121, 116, 
+    121, 116, 121, 116, 114, 120, 116, 121, 116, 121, 116, 121, 116, 121, 
+    116, 121, 116, 121, 121, 114, 120, 114, 120, 116, 121, 114, 120, 116, 
+    121, 114, 120, 114, 120, 114, 120, 114, 120, 114, 120, 114, 120, 116, 
+    121, 114, 120, 116, 121, 116, 121, 116, 121, 98, 126, 126, 126, 126, 126, 
+    126, 126, 126, 126, 126, 126, 126, 126, 126, 126, 126, 126, 126, 126, 
+    126, 126, 126, 98, 98, 127, 128, 128, 128, 128, 128, 128, 98, 129, 129, 
+    129, 129, 129, 129, 129, 129, 129, 129, 129, 129, 129, 129, 129, 129, 
+    129, 129, 129, 129, 129, 129, 130, 98, 131, 132, 98, 98, 133, 133, 134, 
+    98, 135, 136, 136, 136, 136, 135, 136, 136, 136, 137, 135, 136, 136, 136, 
+    136, 136, 136, 135, 135, 135, 135, 135, 135, 136, 136, 135, 136, 136, 
+    137, 138, 136, 139, 140, 141, 142, 143, 144, 145, 146, 147, 148, 148, 
+    149, 150, 151, 152, 153, 154, 155, 156, 154, 136, 135, 157, 147, 98, 98, 
+    98, 98, 98, 98, 98, 98, 158, 158, 158, 158, 158, 158, 158, 158, 158, 158, 
+    158, 98, 98, 98, 98, 98, 158, 158, 158, 154, 154, 98, 98, 98, 159, 159, 
+    159, 159, 159, 160, 161, 161, 162, 163, 163, 164, 165, 166, 167, 167, 
+    168, 168, 168, 168, 168, 168, 168, 168, 169, 170, 171, 172, 173, 98, 174, 
+    172, 175, 175, 176, 176, 176, 176, 176, 175, 175, 175, 175, 175, 175, 
+    175, 175, 175, 177, 175, 175, 175, 175, 175, 175, 175, 175, 175, 175, 
+    178, 179, 180, 181, 182, 183, 184, 185, 186, 186, 187, 188, 168, 168, 
+    168, 168, 168, 188, 168, 168, 188, 189, 189, 189, 189, 189, 189, 189, 
+    189, 189, 189, 163, 190, 190, 191, 175, 175, 192, 175, 175, 175, 175, 
+    193, 193, 193, 193, 175, 175, 175, 175, 175, 175, 175, 176, 175, 176, 
+    175, 175, 175, 175, 175, 175, 175, 175, 176, 174, 175, 168, 168, 168, 
+    168, 168, 168, 168, 160, 167, 168, 168, 168, 168, 188, 168, 194, 194, 
+    168, 168, 167, 188, 168, 168, 188, 175, 175, 195, 195, 195, 195, 195, 
+    195, 195, 195, 195, 195, 175, 175, 175, 196, 196, 175, 197, 197, 197, 
+    197, 197, 197, 197, 197, 197, 197, 197, 197, 197, 197, 98, 198, 199, 200, 
+    199, 199, 199, 199, 199, 199, 199, 199, 199, 199, 199, 199, 199, 199, 
+    201, 202, 201, 201, 202, 201, 201, 202, 202, 202, 201, 202, 202, 201, 
+    202, 201, 201, 201, 202, 201, 202, 201, 202, 201, 202, 201, 201, 98, 98, 
+    199, 199, 199, 203, 203, 203, 203, 203, 203, 203, 203, 203, 203, 203, 
+    203, 203, 203, 204, 204, 204, 204, 204, 204, 204, 204, 204, 204, 204, 
+    203, 98, 98, 98, 98, 98, 98, 205, 205, 205, 205, 205, 205, 205, 205, 205, 
+    205, 206, 206, 206, 206, 206, 206, 206, 206, 206, 206, 206, 206, 206, 
+    206, 206, 206, 206, 207, 207, 207, 207, 207, 207, 207, 208, 207, 209, 
+    209, 210, 211, 212, 213, 209, 98, 98, 98, 98, 98, 214, 214, 214, 214, 
+    214, 214, 214, 214, 214, 214, 214, 214, 214, 214, 215, 215, 215, 215, 
+    216, 215, 215, 215, 215, 215, 215, 215, 215, 215, 216, 215, 215, 215, 
+    216, 215, 215, 215, 215, 215, 98, 98, 217, 217, 217, 217, 217, 217, 217, 
+    217, 217, 217, 217, 217, 217, 217, 217, 98, 218, 218, 218, 218, 218, 218, 
+    218, 218, 218, 219, 219, 219, 98, 98, 220, 98, 175, 175, 175, 175, 175, 
+    98, 175, 175, 175, 175, 175, 175, 175, 175, 98, 98, 98, 98, 98, 98, 168, 
+    168, 168, 168, 168, 168, 160, 188, 168, 168, 188, 168, 168, 188, 168, 
+    168, 168, 188, 188, 188, 221, 222, 223, 168, 168, 168, 188, 168, 168, 
+    188, 188, 168, 168, 168, 168, 168, 224, 224, 224, 225, 226, 226, 226, 
+    226, 226, 226, 226, 226, 226, 226, 226, 226, 226, 227, 226, 226, 226, 
+    226, 226, 226, 226, 227, 226, 226, 227, 226, 226, 226, 226, 226, 224, 
+    225, 228, 226, 225, 225, 225, 224, 224, 224, 224, 224, 224, 224, 224, 
+    225, 225, 225, 225, 229, 225, 225, 226, 124, 230, 231, 231, 224, 224, 
+    224, 232, 232, 232, 232, 232, 232, 232, 232, 226, 226, 224, 224, 233, 
+    233, 234, 234, 234, 234, 234, 234, 234, 234, 234, 234, 235, 236, 226, 
+    226, 226, 226, 226, 226, 237, 238, 239, 239, 98, 237, 237, 237, 237, 237, 
+    237, 237, 237, 98, 98, 237, 237, 98, 98, 237, 237, 237, 237, 237, 237, 
+    237, 237, 237, 237, 237, 237, 237, 237, 98, 237, 237, 237, 237, 237, 237, 
+    237, 98, 237, 98, 98, 98, 237, 237, 237, 237, 98, 98, 240, 237, 241, 239, 
+    239, 238, 238, 238, 238, 98, 98, 239, 239, 98, 98, 242, 242, 243, 237, 
+    98, 98, 98, 98, 98, 98, 98, 98, 241, 98, 98, 98, 98, 244, 244, 98, 244, 
+    237, 237, 238, 238, 98, 98, 245, 245, 245, 245, 245, 245, 245, 245, 245, 
+    245, 237, 237, 246, 246, 247, 247, 247, 247, 247, 248, 249, 250, 98, 98, 
+    98, 98, 98, 251, 251, 252, 98, 253, 253, 253, 253, 253, 253, 98, 98, 98, 
+    98, 253, 253, 98, 98, 253, 253, 253, 253, 253, 253, 253, 253, 253, 253, 
+    253, 253, 253, 253, 98, 253, 253, 253, 253, 253, 253, 253, 98, 253, 254, 
+    98, 253, 254, 98, 253, 253, 98, 98, 255, 98, 252, 252, 252, 251, 251, 98, 
+    98, 98, 98, 251, 251, 98, 98, 251, 251, 256, 98, 98, 98, 251, 98, 98, 98, 
+    98, 98, 98, 98, 254, 254, 254, 253, 98, 254, 98, 98, 98, 98, 98, 98, 98, 
+    257, 257, 257, 257, 257, 257, 257, 257, 257, 257, 251, 251, 253, 253, 
+    253, 251, 98, 98, 98, 258, 258, 259, 98, 260, 260, 260, 260, 260, 260, 
+    260, 260, 260, 98, 260, 260, 260, 98, 260, 260, 260, 260, 260, 260, 260, 
+    260, 260, 260, 260, 260, 260, 260, 98, 260, 260, 260, 260, 260, 260, 260, 
+    98, 260, 260, 98, 260, 260, 260, 260, 260, 98, 98, 261, 260, 259, 259, 
+    259, 258, 258, 258, 258, 258, 98, 258, 258, 259, 98, 259, 259, 262, 98, 
+    98, 260, 98, 98, 98, 98, 98, 98, 98, 260, 260, 258, 258, 98, 98, 263, 
+    263, 263, 263, 263, 263, 263, 263, 263, 263, 264, 265, 98, 98, 98, 98, 
+    98, 98, 98, 260, 98, 98, 98, 98, 98, 98, 98, 266, 267, 267, 98, 268, 268, 
+    268, 268, 268, 268, 268, 268, 98, 98, 268, 268, 98, 98, 268, 268, 268, 
+    268, 268, 268, 268, 268, 268, 268, 268, 268, 268, 268, 98, 268, 268, 268, 
+    268, 268, 268, 268, 98, 268, 268, 98, 268, 268, 268, 268, 268, 98, 98, 
+    269, 268, 270, 266, 267, 266, 266, 266, 266, 98, 98, 267, 271, 98, 98, 
+    271, 271, 272, 98, 98, 98, 98, 98, 98, 98, 98, 273, 270, 98, 98, 98, 98, 
+    274, 274, 98, 268, 268, 268, 266, 266, 98, 98, 275, 275, 275, 275, 275, 
+    275, 275, 275, 275, 275, 276, 268, 277, 277, 277, 277, 277, 277, 98, 98, 
+    278, 279, 98, 279, 279, 279, 279, 279, 279, 98, 98, 98, 279, 279, 279, 
+    98, 279, 279, 280, 279, 98, 98, 98, 279, 279, 98, 279, 98, 279, 279, 98, 
+    98, 98, 279, 279, 98, 98, 98, 279, 279, 279, 279, 279, 279, 279, 279, 
+    279, 279, 98, 98, 98, 98, 281, 282, 278, 282, 282, 98, 98, 98, 282, 282, 
+    282, 98, 283, 283, 283, 284, 98, 98, 279, 98, 98, 98, 98, 98, 98, 281, 
+    98, 98, 98, 98, 98, 98, 285, 285, 285, 285, 285, 285, 285, 285, 285, 285, 
+    286, 286, 286, 287, 287, 287, 287, 287, 287, 288, 287, 98, 98, 98, 98, 
+    98, 289, 290, 290, 290, 98, 291, 291, 291, 291, 291, 291, 291, 291, 98, 
+    291, 291, 291, 98, 291, 291, 291, 291, 291, 291, 291, 291, 291, 291, 291, 
+    291, 291, 291, 291, 291, 98, 98, 98, 291, 289, 289, 289, 290, 290, 290, 
+    290, 98, 289, 289, 292, 98, 289, 289, 289, 293, 98, 98, 98, 98, 98, 98, 
+    98, 294, 295, 98, 291, 291, 291, 98, 98, 98, 98, 98, 291, 291, 289, 289, 
+    98, 98, 296, 296, 296, 296, 296, 296, 296, 296, 296, 296, 297, 297, 297, 
+    297, 297, 297, 297, 298, 299, 300, 301, 301, 98, 299, 299, 299, 299, 299, 
+    299, 299, 299, 98, 299, 299, 299, 98, 299, 299, 299, 299, 299, 299, 299, 
+    299, 299, 299, 299, 299, 299, 299, 299, 299, 299, 299, 98, 299, 299, 299, 
+    299, 299, 98, 98, 302, 299, 301, 303, 304, 301, 305, 301, 301, 98, 303, 
+    304, 304, 98, 304, 304, 300, 306, 98, 98, 98, 98, 98, 98, 98, 305, 305, 
+    98, 98, 98, 98, 98, 98, 98, 299, 98, 299, 299, 300, 300, 98, 98, 307, 
+    307, 307, 307, 307, 307, 307, 307, 307, 307, 98, 299, 299, 98, 98, 98, 
+    98, 98, 98, 308, 309, 309, 98, 310, 310, 310, 310, 310, 310, 310, 310, 
+    98, 310, 310, 310, 98, 310, 310, 310, 310, 310, 310, 310, 310, 310, 310, 
+    310, 310, 310, 310, 310, 310, 310, 98, 98, 310, 311, 309, 309, 308, 308, 
+    308, 308, 98, 309, 309, 309, 98, 312, 312, 312, 313, 310, 314, 98, 98, 
+    98, 98, 310, 310, 310, 311, 315, 315, 315, 315, 315, 315, 315, 310, 310, 
+    310, 308, 308, 98, 98, 316, 316, 316, 316, 316, 316, 316, 316, 316, 316, 
+    315, 315, 315, 315, 315, 315, 315, 315, 315, 317, 310, 310, 310, 310, 
+    310, 310, 98, 98, 318, 318, 98, 319, 319, 319, 319, 319, 319, 319, 319, 
+    319, 319, 319, 319, 319, 319, 319, 319, 319, 319, 98, 98, 98, 319, 319, 
+    319, 319, 319, 319, 319, 319, 98, 319, 319, 319, 319, 319, 319, 319, 319, 
+    319, 98, 319, 98, 98, 98, 98, 320, 98, 98, 98, 98, 321, 318, 318, 322, 
+    322, 322, 98, 322, 98, 318, 318, 323, 318, 323, 323, 323, 321, 98, 98, 
+    98, 98, 98, 98, 324, 324, 324, 324, 324, 324, 324, 324, 324, 324, 98, 98, 
+    318, 318, 325, 98, 98, 98, 98, 326, 326, 326, 326, 326, 326, 326, 326, 
+    326, 326, 326, 326, 326, 326, 326, 326, 327, 326, 328, 327, 327, 327, 
+    327, 329, 329, 330, 98, 98, 98, 98, 331, 326, 326, 326, 326, 326, 326, 
+    332, 327, 333, 333, 333, 333, 327, 327, 327, 334, 335, 335, 335, 335, 
+    335, 335, 335, 335, 335, 335, 336, 336, 98, 98, 98, 98, 98, 337, 337, 98, 
+    337, 98, 98, 337, 337, 98, 337, 98, 98, 337, 98, 98, 98, 98, 98, 98, 337, 
+    337, 337, 337, 98, 337, 337, 337, 337, 337, 337, 337, 98, 337, 337, 337, 
+    98, 337, 98, 337, 98, 98, 337, 337, 98, 337, 337, 337, 337, 338, 337, 
+    339, 338, 338, 338, 338, 340, 340, 98, 338, 338, 337, 98, 98, 337, 337, 
+    337, 337, 337, 98, 341, 98, 342, 342, 342, 342, 338, 338, 98, 98, 343, 
+    343, 343, 343, 343, 343, 343, 343, 343, 343, 98, 98, 339, 339, 337, 337, 
+    344, 345, 345, 345, 346, 347, 346, 346, 348, 346, 346, 349, 350, 351, 
+    351, 351, 351, 351, 348, 352, 351, 352, 352, 352, 353, 353, 352, 352, 
+    352, 352, 352, 352, 354, 354, 354, 354, 354, 354, 354, 354, 354, 354, 
+    355, 355, 355, 355, 355, 355, 355, 355, 355, 355, 356, 353, 352, 353, 
+    352, 357, 358, 359, 358, 359, 360, 360, 344, 344, 344, 361, 344, 344, 
+    344, 344, 98, 344, 344, 344, 344, 361, 344, 344, 344, 344, 361, 344, 344, 
+    344, 344, 361, 344, 344, 344, 344, 361, 344, 344, 344, 344, 344, 344, 
+    344, 344, 344, 344, 344, 344, 361, 344, 344, 344, 98, 98, 98, 98, 362, 
+    363, 364, 365, 364, 364, 366, 364, 366, 363, 363, 363, 363, 367, 368, 
+    363, 364, 369, 369, 370, 349, 369, 369, 344, 344, 344, 344, 344, 367, 
+    367, 367, 367, 367, 367, 364, 367, 367, 367, 367, 98, 367, 367, 367, 367, 
+    364, 367, 367, 367, 367, 364, 367, 367, 367, 367, 364, 367, 367, 367, 
+    367, 364, 367, 367, 367, 367, 367, 367, 367, 367, 367, 367, 367, 367, 
+    364, 367, 367, 367, 98, 356, 356, 352, 352, 352, 352, 352, 352, 353, 352, 
+    352, 352, 352, 352, 352, 98, 352, 352, 346, 346, 349, 346, 347, 371, 371, 
+    371, 371, 348, 348, 98, 98, 98, 98, 98, 372, 372, 372, 372, 372, 372, 
+    372, 372, 372, 372, 372, 372, 372, 372, 373, 372, 372, 372, 372, 374, 
+    374, 375, 376, 375, 375, 374, 375, 375, 375, 375, 375, 377, 374, 378, 
+    378, 374, 374, 375, 375, 372, 379, 379, 379, 379, 379, 379, 379, 379, 
+    379, 379, 380, 380, 381, 381, 381, 381, 372, 372, 372, 372, 372, 372, 
+    374, 374, 375, 375, 372, 372, 372, 372, 375, 375, 375, 372, 374, 374, 
+    374, 372, 372, 374, 374, 374, 374, 374, 374, 374, 372, 372, 372, 375, 
+    375, 375, 375, 372, 372, 372, 372, 372, 375, 374, 374, 375, 375, 374, 
+    374, 374, 374, 374, 374, 382, 372, 374, 379, 379, 374, 374, 374, 375, 
+    383, 383, 384, 384, 384, 384, 384, 384, 384, 384, 384, 384, 384, 384, 
+    384, 384, 98, 384, 98, 98, 98, 98, 98, 384, 98, 98, 385, 385, 385, 385, 
+    385, 385, 385, 385, 385, 385, 385, 386, 387, 385, 385, 385, 388, 388, 
+    388, 388, 388, 388, 388, 388, 389, 390, 390, 390, 390, 390, 390, 390, 
+    390, 390, 390, 390, 390, 390, 390, 390, 390, 390, 390, 390, 390, 390, 
+    389, 389, 389, 389, 389, 389, 389, 389, 389, 389, 391, 391, 391, 391, 
+    391, 391, 391, 391, 391, 391, 391, 392, 392, 392, 392, 392, 392, 392, 
+    392, 392, 392, 392, 392, 392, 393, 393, 393, 393, 393, 393, 393, 393, 
+    393, 98, 393, 393, 393, 393, 98, 98, 393, 393, 393, 393, 393, 393, 393, 
+    98, 393, 393, 393, 98, 98, 394, 394, 394, 395, 396, 395, 395, 395, 395, 
+    395, 395, 395, 397, 397, 397, 397, 397, 397, 397, 397, 397, 397, 397, 
+    397, 397, 397, 397, 397, 397, 397, 397, 397, 98, 98, 98, 398, 398, 398, 
+    398, 398, 398, 398, 398, 398, 398, 98, 98, 98, 98, 98, 98, 399, 399, 399, 
+    399, 399, 399, 399, 399, 399, 399, 399, 399, 399, 399, 98, 98, 400, 400, 
+    400, 400, 400, 400, 98, 98, 401, 402, 402, 402, 402, 402, 402, 402, 402, 
+    402, 402, 402, 402, 402, 402, 402, 402, 402, 402, 402, 402, 403, 403, 
+    402, 404, 405, 405, 405, 405, 405, 405, 405, 405, 405, 405, 405, 405, 
+    405, 405, 405, 405, 405, 405, 406, 407, 98, 98, 98, 408, 408, 408, 408, 
+    408, 408, 408, 408, 408, 408, 408, 233, 233, 233, 409, 409, 409, 408, 
+    408, 408, 408, 408, 408, 408, 408, 98, 98, 98, 98, 98, 98, 98, 410, 410, 
+    410, 410, 410, 410, 410, 410, 410, 410, 410, 410, 410, 98, 410, 410, 410, 
+    410, 411, 411, 412, 98, 98, 98, 413, 413, 413, 413, 413, 413, 413, 413, 
+    413, 413, 414, 414, 415, 233, 233, 98, 416, 416, 416, 416, 416, 416, 416, 
+    416, 416, 416, 417, 417, 98, 98, 98, 98, 418, 418, 418, 418, 418, 418, 
+    418, 418, 418, 418, 418, 418, 418, 98, 418, 418, 418, 98, 419, 419, 98, 
+    98, 98, 98, 420, 420, 420, 420, 420, 420, 420, 420, 420, 420, 420, 420, 
+    421, 421, 422, 421, 421, 421, 421, 421, 421, 421, 422, 422, 422, 422, 
+    422, 422, 422, 422, 421, 422, 422, 421, 421, 421, 421, 421, 421, 421, 
+    421, 421, 423, 421, 424, 424, 425, 426, 424, 427, 424, 428, 420, 429, 98, 
+    98, 430, 430, 430, 430, 430, 430, 430, 430, 430, 430, 98, 98, 98, 98, 98, 
+    98, 431, 431, 431, 431, 431, 431, 431, 431, 431, 431, 98, 98, 98, 98, 98, 
+    98, 432, 432, 433, 433, 434, 435, 436, 432, 437, 437, 432, 438, 438, 438, 
+    439, 98, 440, 440, 440, 440, 440, 440, 440, 440, 440, 440, 98, 98, 98, 
+    98, 98, 98, 441, 441, 441, 441, 441, 441, 441, 441, 441, 441, 441, 442, 
+    441, 441, 441, 441, 441, 441, 441, 441, 441, 438, 438, 441, 441, 443, 
+    441, 98, 98, 98, 98, 98, 402, 402, 402, 402, 402, 402, 98, 98, 444, 444, 
+    444, 444, 444, 444, 444, 444, 444, 444, 444, 444, 444, 444, 444, 98, 445, 
+    445, 445, 446, 446, 446, 446, 445, 445, 446, 446, 446, 98, 98, 98, 98, 
+    446, 446, 445, 446, 446, 446, 446, 446, 446, 447, 448, 449, 98, 98, 98, 
+    98, 450, 98, 98, 98, 451, 451, 452, 452, 452, 452, 452, 452, 452, 452, 
+    452, 452, 453, 453, 453, 453, 453, 453, 453, 453, 453, 453, 453, 453, 
+    453, 453, 98, 98, 453, 453, 453, 453, 453, 98, 98, 98, 454, 454, 454, 
+    454, 454, 454, 454, 454, 454, 454, 454, 454, 98, 98, 98, 98, 454, 454, 
+    98, 98, 98, 98, 98, 98, 455, 455, 455, 455, 455, 455, 455, 455, 455, 455, 
+    456, 98, 98, 98, 457, 457, 458, 458, 458, 458, 458, 458, 458, 458, 459, 
+    459, 459, 459, 459, 459, 459, 459, 459, 459, 459, 459, 459, 459, 459, 
+    460, 461, 462, 462, 463, 98, 98, 464, 464, 465, 465, 465, 465, 465, 465, 
+    465, 465, 465, 465, 465, 465, 465, 466, 467, 466, 467, 467, 467, 467, 
+    467, 467, 467, 98, 468, 466, 467, 466, 466, 467, 467, 467, 467, 467, 467, 
+    467, 467, 466, 466, 466, 466, 466, 466, 467, 467, 469, 469, 469, 469, 
+    469, 469, 469, 469, 98, 98, 470, 471, 471, 471, 471, 471, 471, 471, 471, 
+    471, 471, 98, 98, 98, 98, 98, 98, 472, 472, 472, 472, 472, 472, 472, 473, 
+    472, 472, 472, 472, 472, 472, 98, 98, 124, 124, 124, 124, 124, 230, 230, 
+    230, 230, 230, 230, 124, 124, 230, 474, 98, 475, 475, 475, 475, 476, 477, 
+    478, 477, 478, 477, 478, 477, 478, 477, 478, 477, 477, 477, 478, 477, 
+    477, 477, 477, 477, 477, 477, 477, 477, 477, 477, 477, 477, 477, 477, 
+    477, 477, 479, 480, 475, 475, 475, 475, 475, 481, 475, 481, 476, 476, 
+    481, 481, 475, 481, 482, 477, 477, 477, 477, 477, 477, 477, 98, 98, 98, 
+    98, 483, 483, 483, 483, 483, 483, 483, 483, 483, 483, 484, 484, 485, 484, 
+    484, 484, 484, 486, 486, 486, 486, 486, 486, 486, 486, 486, 486, 487, 
+    488, 487, 487, 487, 487, 487, 487, 487, 486, 486, 486, 486, 486, 486, 
+    486, 486, 486, 98, 98, 98, 489, 489, 490, 491, 491, 491, 491, 491, 491, 
+    491, 491, 491, 491, 491, 491, 491, 491, 490, 489, 489, 489, 489, 490, 
+    490, 489, 489, 492, 493, 489, 489, 491, 491, 494, 494, 494, 494, 494, 
+    494, 494, 494, 494, 494, 491, 491, 491, 491, 491, 491, 495, 495, 495, 
+    495, 495, 495, 495, 495, 495, 495, 495, 495, 495, 495, 496, 497, 498, 
+    498, 497, 497, 497, 498, 497, 498, 498, 498, 499, 499, 98, 98, 98, 98, 
+    98, 98, 98, 98, 500, 500, 500, 500, 501, 501, 501, 501, 501, 501, 501, 
+    501, 501, 501, 501, 501, 502, 502, 502, 502, 502, 502, 502, 502, 503, 
+    503, 503, 503, 503, 503, 503, 503, 502, 502, 503, 504, 98, 98, 98, 505, 
+    505, 505, 505, 505, 506, 506, 506, 506, 506, 506, 506, 506, 506, 506, 98, 
+    98, 98, 501, 501, 501, 507, 507, 507, 507, 507, 507, 507, 507, 507, 507, 
+    508, 508, 508, 508, 508, 508, 508, 508, 508, 508, 508, 508, 508, 508, 
+    509, 509, 509, 509, 509, 509, 510, 510, 121, 121, 121, 121, 121, 121, 
+    121, 121, 121, 98, 98, 98, 98, 98, 98, 98, 511, 511, 511, 511, 511, 511, 
+    511, 511, 124, 124, 124, 386, 512, 230, 230, 230, 230, 230, 124, 124, 
+    230, 230, 230, 230, 124, 513, 512, 512, 512, 512, 512, 512, 512, 514, 
+    514, 514, 514, 230, 514, 514, 514, 514, 513, 513, 124, 514, 514, 98, 124, 
+    124, 98, 98, 98, 98, 98, 98, 64, 64, 64, 64, 64, 64, 95, 95, 95, 95, 95, 
+    121, 68, 68, 68, 515, 68, 68, 68, 515, 68, 68, 68, 68, 68, 68, 68, 68, 
+    68, 68, 515, 68, 68, 68, 68, 68, 68, 99, 99, 99, 99, 99, 68, 68, 68, 68, 
+    99, 99, 99, 99, 99, 64, 64, 64, 64, 64, 516, 64, 64, 64, 64, 64, 64, 64, 
+    64, 64, 64, 68, 68, 68, 68, 68, 68, 68, 68, 68, 68, 68, 68, 99, 124, 124, 
+    230, 124, 124, 124, 124, 124, 124, 124, 230, 124, 124, 517, 518, 230, 
+    519, 124, 124, 124, 124, 124, 124, 124, 124, 124, 124, 124, 124, 124, 
+    124, 124, 124, 124, 124, 124, 124, 124, 98, 98, 98, 98, 98, 124, 520, 
+    230, 124, 230, 54, 59, 54, 59, 54, 59, 59, 59, 59, 59, 63, 521, 64, 64, 
+    60, 64, 54, 59, 60, 64, 60, 64, 60, 64, 105, 105, 105, 105, 105, 105, 
+    105, 105, 103, 103, 103, 103, 103, 103, 103, 103, 105, 105, 105, 105, 
+    105, 105, 98, 98, 103, 103, 103, 103, 103, 103, 98, 98, 98, 103, 98, 103, 
+    98, 103, 98, 103, 105, 522, 105, 522, 105, 522, 105, 522, 105, 522, 105, 
+    522, 105, 522, 98, 98, 523, 523, 523, 523, 523, 523, 523, 523, 105, 105, 
+    105, 105, 105, 98, 105, 105, 103, 103, 103, 524, 523, 101, 522, 101, 101, 
+    525, 105, 105, 105, 98, 105, 105, 103, 524, 103, 524, 523, 525, 525, 525, 
+    105, 105, 105, 522, 98, 98, 105, 105, 103, 103, 103, 524, 98, 525, 525, 
+    525, 105, 105, 105, 522, 105, 105, 105, 105, 103, 103, 103, 524, 103, 
+    525, 526, 526, 98, 98, 105, 105, 105, 98, 105, 105, 103, 524, 103, 524, 
+    523, 527, 101, 98, 528, 528, 529, 529, 529, 529, 529, 530, 529, 529, 529, 
+    531, 532, 533, 534, 535, 536, 537, 538, 536, 539, 540, 39, 541, 542, 543, 
+    544, 545, 542, 543, 544, 545, 39, 39, 546, 547, 548, 548, 548, 549, 550, 
+    551, 552, 553, 554, 555, 556, 34, 557, 558, 557, 559, 560, 561, 562, 562, 
+    547, 43, 52, 39, 563, 564, 565, 566, 566, 547, 547, 547, 567, 568, 569, 
+    563, 563, 563, 547, 547, 547, 547, 547, 547, 547, 547, 570, 547, 566, 
+    547, 435, 541, 435, 435, 435, 435, 547, 435, 435, 529, 571, 572, 572, 
+    572, 572, 98, 573, 574, 575, 576, 577, 577, 577, 577, 577, 577, 578, 68, 
+    98, 98, 49, 578, 578, 578, 578, 578, 579, 579, 580, 581, 582, 583, 578, 
+    49, 49, 49, 49, 578, 578, 578, 578, 578, 579, 579, 580, 581, 582, 98, 68, 
+    68, 68, 68, 68, 98, 98, 98, 331, 331, 331, 331, 331, 331, 331, 584, 585, 
+    586, 331, 331, 37, 331, 331, 331, 331, 331, 331, 331, 331, 331, 584, 331, 
+    331, 331, 331, 584, 331, 331, 584, 587, 587, 587, 587, 587, 587, 587, 
+    587, 587, 124, 124, 512, 512, 124, 124, 124, 124, 512, 512, 512, 124, 
+    124, 474, 474, 474, 474, 124, 474, 474, 474, 512, 512, 124, 230, 124, 
+    512, 512, 230, 230, 230, 230, 124, 98, 98, 98, 98, 98, 98, 98, 588, 588, 
+    589, 590, 41, 591, 588, 589, 41, 590, 51, 589, 589, 589, 51, 51, 589, 
+    589, 589, 592, 41, 589, 593, 41, 570, 589, 589, 589, 589, 589, 41, 41, 
+    588, 591, 591, 41, 589, 41, 594, 41, 589, 41, 595, 596, 589, 589, 597, 
+    51, 589, 589, 60, 589, 51, 598, 598, 598, 598, 51, 41, 588, 51, 51, 589, 
+    589, 599, 570, 570, 570, 570, 589, 51, 51, 51, 51, 41, 570, 41, 41, 64, 
+    371, 600, 600, 600, 601, 53, 602, 600, 600, 600, 600, 600, 53, 601, 601, 
+    53, 600, 603, 603, 603, 603, 603, 603, 603, 603, 603, 603, 603, 603, 604, 
+    604, 604, 604, 603, 603, 604, 604, 604, 604, 604, 604, 605, 605, 605, 60, 
+    64, 605, 605, 605, 605, 53, 41, 41, 98, 98, 98, 98, 56, 56, 56, 56, 56, 
+    606, 606, 606, 606, 606, 607, 607, 41, 41, 41, 41, 570, 41, 41, 570, 41, 
+    41, 570, 41, 41, 41, 41, 41, 41, 41, 607, 41, 41, 41, 41, 41, 41, 41, 41, 
+    41, 45, 45, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 608, 607, 607, 
+    41, 41, 56, 41, 56, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 45, 41, 41, 
+    41, 41, 570, 570, 570, 570, 570, 570, 570, 570, 570, 570, 570, 570, 56, 
+    609, 610, 610, 611, 570, 570, 56, 610, 611, 609, 610, 611, 609, 570, 56, 
+    570, 610, 612, 613, 570, 610, 609, 570, 570, 570, 610, 609, 609, 610, 56, 
+    610, 610, 609, 609, 56, 611, 56, 611, 56, 56, 56, 56, 610, 614, 599, 610, 
+    599, 599, 609, 609, 609, 56, 56, 56, 56, 570, 609, 570, 609, 610, 610, 
+    609, 609, 609, 611, 609, 609, 611, 609, 609, 611, 610, 611, 609, 609, 
+    610, 570, 570, 570, 570, 570, 610, 609, 609, 609, 570, 570, 570, 570, 
+    570, 570, 570, 570, 570, 609, 615, 56, 611, 570, 610, 610, 610, 610, 609, 
+    609, 610, 610, 570, 607, 615, 615, 611, 611, 609, 609, 611, 611, 609, 
+    609, 611, 611, 609, 609, 609, 609, 609, 609, 611, 611, 610, 610, 611, 
+    611, 610, 610, 611, 611, 609, 609, 609, 570, 570, 609, 609, 609, 609, 
+    570, 570, 56, 570, 570, 609, 56, 570, 570, 570, 570, 570, 570, 570, 570, 
+    609, 609, 570, 56, 609, 609, 609, 609, 609, 609, 611, 611, 611, 611, 609, 
+    609, 609, 609, 609, 609, 609, 609, 609, 570, 570, 570, 570, 570, 609, 
+    610, 570, 609, 609, 609, 609, 609, 570, 570, 609, 609, 570, 570, 570, 
+    570, 609, 609, 611, 611, 611, 611, 609, 609, 609, 609, 609, 609, 611, 
+    611, 611, 611, 570, 616, 568, 569, 568, 569, 41, 41, 41, 41, 41, 41, 606, 
+    41, 41, 41, 41, 41, 41, 41, 617, 617, 41, 41, 41, 41, 609, 609, 41, 41, 
+    41, 41, 41, 41, 41, 618, 619, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 
+    371, 371, 371, 371, 371, 371, 371, 371, 371, 371, 371, 371, 371, 41, 570, 
+    41, 41, 41, 41, 41, 41, 41, 41, 371, 41, 41, 41, 41, 41, 570, 570, 570, 
+    570, 570, 570, 570, 570, 570, 41, 41, 41, 41, 570, 570, 41, 41, 41, 41, 
+    41, 41, 41, 620, 620, 620, 620, 41, 41, 41, 617, 621, 621, 617, 41, 41, 
+    41, 41, 41, 41, 41, 41, 41, 41, 41, 98, 41, 41, 41, 98, 98, 98, 98, 98, 
+    53, 53, 53, 53, 53, 53, 53, 53, 49, 49, 49, 49, 49, 49, 49, 49, 49, 49, 
+    49, 49, 622, 622, 622, 622, 622, 622, 622, 622, 622, 622, 622, 622, 622, 
+    622, 602, 623, 623, 623, 623, 623, 623, 623, 623, 623, 623, 623, 623, 
+    623, 623, 623, 623, 623, 623, 623, 623, 624, 606, 606, 606, 606, 606, 
+    606, 606, 606, 606, 606, 606, 606, 41, 41, 41, 41, 606, 606, 606, 606, 
+    625, 41, 41, 41, 41, 41, 606, 606, 606, 606, 41, 41, 606, 606, 41, 606, 
+    606, 606, 606, 606, 606, 606, 41, 41, 41, 41, 41, 41, 41, 41, 606, 606, 
+    41, 41, 606, 56, 41, 41, 41, 41, 606, 606, 41, 41, 606, 56, 41, 41, 41, 
+    41, 606, 606, 606, 41, 41, 606, 41, 41, 606, 606, 41, 41, 41, 41, 41, 41, 
+    41, 606, 570, 570, 570, 570, 570, 626, 626, 570, 621, 621, 621, 621, 41, 
+    606, 606, 41, 41, 606, 41, 41, 41, 41, 606, 606, 41, 41, 41, 41, 617, 
+    617, 625, 625, 621, 41, 621, 621, 627, 628, 627, 621, 41, 621, 621, 621, 
+    41, 41, 41, 41, 606, 41, 606, 41, 41, 41, 41, 41, 620, 620, 620, 620, 
+    620, 620, 620, 620, 620, 620, 620, 620, 41, 41, 41, 41, 606, 606, 41, 
+    606, 606, 606, 41, 606, 627, 606, 606, 41, 606, 606, 41, 56, 41, 41, 41, 
+    41, 41, 41, 41, 617, 41, 41, 41, 620, 41, 41, 41, 41, 41, 41, 41, 41, 41, 
+    41, 606, 606, 41, 620, 41, 41, 41, 41, 41, 41, 41, 41, 620, 620, 371, 41, 
+    41, 41, 41, 41, 41, 41, 41, 617, 617, 627, 621, 621, 621, 621, 617, 617, 
+    627, 627, 627, 606, 606, 606, 606, 627, 620, 627, 627, 627, 606, 627, 
+    617, 606, 606, 606, 627, 627, 606, 606, 627, 606, 606, 627, 627, 627, 41, 
+    606, 41, 41, 41, 41, 606, 606, 617, 606, 606, 606, 606, 606, 606, 627, 
+    617, 617, 627, 617, 606, 627, 627, 629, 617, 606, 606, 617, 627, 627, 
+    621, 621, 621, 621, 621, 620, 41, 41, 621, 621, 630, 630, 628, 628, 41, 
+    41, 620, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 45, 41, 41, 41, 
+    41, 41, 41, 620, 41, 620, 41, 41, 41, 41, 620, 620, 620, 41, 631, 41, 41, 
+    41, 632, 632, 632, 632, 632, 632, 41, 633, 633, 621, 41, 41, 41, 568, 
+    569, 568, 569, 568, 569, 568, 569, 568, 569, 568, 569, 568, 569, 623, 
+    623, 634, 634, 634, 634, 634, 634, 634, 634, 634, 634, 634, 634, 41, 620, 
+    620, 620, 41, 41, 41, 41, 41, 41, 41, 620, 609, 570, 570, 609, 609, 568, 
+    569, 570, 609, 609, 570, 609, 609, 609, 570, 570, 570, 570, 570, 609, 
+    609, 609, 609, 570, 570, 570, 570, 570, 609, 609, 609, 570, 570, 570, 
+    609, 609, 609, 609, 16, 32, 16, 32, 16, 32, 16, 32, 568, 569, 635, 635, 
+    635, 635, 635, 635, 635, 635, 570, 570, 570, 568, 569, 16, 32, 568, 569, 
+    568, 569, 568, 569, 568, 569, 568, 569, 570, 570, 609, 609, 609, 609, 
+    609, 609, 570, 570, 570, 570, 570, 570, 570, 609, 609, 609, 609, 609, 
+    609, 570, 570, 570, 609, 570, 570, 570, 570, 609, 609, 609, 609, 609, 
+    570, 609, 609, 570, 570, 568, 569, 568, 569, 609, 570, 570, 570, 570, 
+    609, 570, 609, 609, 609, 570, 570, 609, 609, 570, 570, 570, 570, 570, 
+    570, 570, 570, 570, 570, 609, 609, 609, 609, 609, 609, 570, 570, 568, 
+    569, 570, 570, 570, 570, 609, 609, 599, 609, 609, 609, 609, 609, 609, 
+    609, 609, 570, 609, 609, 609, 609, 570, 570, 609, 570, 609, 570, 570, 
+    609, 570, 609, 609, 609, 609, 570, 570, 570, 570, 570, 609, 609, 570, 
+    570, 570, 570, 609, 609, 609, 609, 570, 609, 609, 570, 570, 609, 599, 
+    580, 580, 570, 570, 609, 609, 609, 609, 609, 609, 609, 609, 609, 609, 
+    609, 570, 570, 609, 609, 609, 609, 609, 609, 609, 609, 570, 609, 609, 
+    609, 609, 609, 609, 609, 609, 570, 570, 570, 570, 570, 636, 570, 609, 
+    570, 570, 570, 609, 609, 609, 609, 609, 570, 570, 570, 570, 609, 570, 
+    570, 570, 609, 609, 609, 609, 609, 570, 609, 570, 570, 41, 41, 41, 620, 
+    620, 41, 41, 41, 570, 570, 570, 570, 570, 41, 41, 570, 570, 570, 570, 
+    570, 570, 41, 41, 41, 620, 41, 41, 41, 41, 631, 606, 606, 41, 41, 41, 41, 
+    98, 98, 41, 41, 41, 41, 41, 41, 41, 41, 98, 98, 41, 41, 98, 98, 98, 41, 
+    41, 41, 41, 98, 41, 41, 41, 41, 41, 41, 41, 41, 98, 98, 98, 98, 98, 98, 
+    98, 98, 98, 98, 41, 41, 41, 41, 637, 637, 637, 637, 637, 637, 637, 637, 
+    637, 637, 637, 637, 637, 637, 637, 98, 638, 638, 638, 638, 638, 638, 638, 
+    638, 638, 638, 638, 638, 638, 638, 638, 98, 60, 64, 60, 60, 60, 64, 64, 
+    60, 64, 60, 64, 60, 64, 60, 60, 60, 60, 64, 60, 64, 64, 60, 64, 64, 64, 
+    64, 64, 64, 68, 68, 60, 60, 111, 112, 111, 112, 112, 639, 639, 639, 639, 
+    639, 639, 111, 112, 111, 112, 640, 640, 640, 111, 112, 98, 98, 98, 98, 
+    98, 641, 642, 642, 642, 643, 641, 642, 644, 644, 644, 644, 644, 644, 644, 
+    644, 644, 644, 644, 644, 644, 644, 98, 644, 98, 98, 98, 98, 98, 644, 98, 
+    98, 645, 645, 645, 645, 645, 645, 645, 645, 98, 98, 98, 98, 98, 98, 98, 
+    646, 647, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 648, 
+    123, 123, 123, 123, 123, 123, 123, 123, 649, 649, 43, 52, 43, 52, 649, 
+    649, 649, 43, 52, 649, 43, 52, 435, 435, 435, 435, 435, 435, 435, 435, 
+    547, 538, 650, 435, 651, 547, 43, 52, 547, 547, 43, 52, 568, 569, 568, 
+    569, 568, 569, 568, 569, 435, 435, 435, 435, 433, 69, 435, 435, 547, 435, 
+    435, 547, 547, 547, 547, 547, 652, 652, 435, 435, 435, 547, 538, 435, 
+    544, 435, 435, 98, 98, 98, 653, 653, 653, 653, 653, 653, 653, 653, 653, 
+    653, 98, 653, 653, 653, 653, 654, 653, 653, 653, 654, 98, 98, 98, 98, 
+    654, 654, 654, 654, 654, 654, 654, 654, 654, 654, 654, 654, 654, 654, 98, 
+    98, 617, 617, 617, 617, 617, 617, 617, 617, 617, 617, 617, 617, 98, 98, 
+    98, 98, 655, 656, 656, 657, 617, 658, 659, 660, 661, 662, 661, 662, 661, 
+    662, 661, 662, 661, 662, 617, 617, 661, 662, 661, 662, 661, 662, 661, 
+    662, 663, 664, 665, 665, 617, 660, 660, 660, 660, 660, 660, 660, 660, 
+    660, 666, 667, 668, 669, 670, 670, 671, 672, 672, 672, 672, 673, 674, 
+    617, 675, 675, 675, 658, 676, 657, 617, 621, 98, 677, 678, 677, 678, 677, 
+    678, 677, 678, 677, 678, 678, 679, 678, 679, 678, 679, 678, 679, 678, 
+    679, 678, 679, 678, 679, 678, 679, 677, 678, 679, 678, 679, 678, 679, 
+    678, 678, 678, 678, 678, 678, 679, 679, 678, 679, 679, 678, 679, 679, 
+    678, 679, 679, 678, 679, 679, 678, 678, 678, 678, 678, 677, 678, 677, 
+    678, 677, 678, 678, 678, 678, 678, 678, 677, 678, 678, 678, 678, 678, 
+    679, 677, 677, 98, 98, 680, 680, 681, 681, 682, 683, 684, 663, 685, 686, 
+    685, 686, 685, 686, 685, 686, 685, 686, 686, 687, 686, 687, 686, 687, 
+    686, 687, 686, 687, 686, 687, 686, 687, 686, 687, 685, 686, 687, 686, 
+    687, 686, 687, 686, 686, 686, 686, 686, 686, 687, 687, 686, 687, 687, 
+    686, 687, 687, 686, 687, 687, 686, 687, 687, 686, 686, 686, 686, 686, 
+    685, 686, 685, 686, 685, 686, 686, 686, 686, 686, 686, 685, 686, 686, 
+    686, 686, 686, 687, 685, 685, 687, 687, 687, 687, 688, 689, 690, 691, 
+    692, 98, 98, 98, 98, 98, 693, 693, 693, 693, 693, 693, 693, 693, 693, 
+    693, 693, 693, 693, 693, 693, 693, 693, 98, 98, 98, 694, 694, 694, 694, 
+    694, 694, 694, 694, 694, 694, 694, 694, 694, 694, 694, 694, 694, 694, 
+    694, 694, 694, 694, 98, 695, 695, 696, 696, 696, 696, 697, 697, 697, 697, 
+    697, 697, 697, 697, 697, 697, 693, 693, 693, 98, 98, 98, 98, 98, 685, 
+    685, 685, 685, 685, 685, 685, 685, 698, 698, 698, 698, 698, 698, 698, 
+    698, 698, 698, 698, 698, 698, 699, 699, 98, 696, 696, 696, 696, 696, 696, 
+    696, 696, 696, 696, 697, 697, 697, 697, 697, 697, 700, 700, 700, 700, 
+    700, 700, 700, 700, 674, 701, 701, 701, 701, 701, 701, 701, 701, 701, 
+    701, 701, 701, 701, 701, 701, 698, 698, 698, 698, 699, 699, 699, 695, 
+    697, 701, 701, 701, 701, 701, 701, 701, 697, 697, 697, 697, 674, 674, 
+    674, 674, 702, 702, 702, 702, 702, 702, 702, 702, 702, 702, 702, 702, 
+    702, 702, 702, 98, 697, 697, 697, 697, 697, 697, 697, 674, 674, 674, 674, 
+    697, 697, 697, 697, 697, 697, 697, 697, 697, 697, 697, 674, 674, 703, 
+    703, 703, 703, 703, 703, 703, 703, 703, 703, 703, 703, 703, 703, 704, 
+    704, 704, 704, 704, 704, 704, 704, 704, 704, 705, 705, 705, 705, 705, 
+    705, 705, 705, 705, 705, 705, 705, 705, 706, 705, 705, 705, 705, 705, 
+    705, 705, 98, 98, 98, 707, 707, 707, 707, 707, 707, 707, 707, 707, 707, 
+    707, 707, 707, 707, 707, 98, 708, 708, 708, 708, 708, 708, 708, 708, 709, 
+    709, 709, 709, 709, 709, 710, 710, 711, 711, 711, 711, 711, 711, 711, 
+    711, 711, 711, 711, 711, 712, 713, 714, 713, 715, 715, 715, 715, 715, 
+    715, 715, 715, 715, 715, 711, 711, 98, 98, 98, 98, 116, 121, 116, 121, 
+    116, 121, 716, 123, 125, 125, 125, 717, 123, 123, 123, 123, 123, 123, 
+    123, 123, 123, 123, 717, 718, 116, 121, 116, 121, 516, 516, 123, 123, 
+    719, 719, 719, 719, 719, 719, 719, 719, 719, 719, 719, 719, 719, 719, 
+    720, 720, 720, 720, 720, 720, 720, 720, 720, 720, 721, 721, 722, 723, 
+    723, 723, 723, 723, 71, 71, 71, 71, 71, 71, 71, 69, 69, 69, 69, 69, 69, 
+    69, 69, 69, 71, 71, 60, 64, 60, 64, 60, 64, 64, 64, 60, 64, 60, 64, 60, 
+    64, 68, 64, 64, 64, 64, 64, 64, 64, 64, 60, 64, 60, 64, 60, 60, 64, 69, 
+    724, 724, 60, 64, 60, 64, 65, 60, 64, 60, 64, 64, 64, 60, 64, 60, 64, 60, 
+    60, 60, 60, 60, 98, 60, 60, 60, 60, 60, 64, 60, 64, 98, 98, 98, 98, 98, 
+    98, 98, 65, 68, 68, 64, 65, 65, 65, 65, 65, 725, 725, 726, 725, 725, 725, 
+    727, 725, 725, 725, 725, 726, 725, 725, 725, 725, 725, 725, 725, 725, 
+    725, 725, 725, 725, 725, 725, 725, 728, 728, 726, 726, 728, 729, 729, 
+    729, 729, 98, 98, 98, 98, 730, 730, 730, 730, 730, 730, 371, 371, 584, 
+    597, 98, 98, 98, 98, 98, 98, 731, 731, 731, 731, 731, 731, 731, 731, 731, 
+    731, 731, 731, 732, 732, 733, 733, 734, 734, 735, 735, 735, 735, 735, 
+    735, 735, 735, 735, 735, 735, 735, 735, 735, 735, 735, 735, 735, 734, 
+    734, 734, 734, 734, 734, 734, 734, 734, 734, 734, 734, 734, 734, 734, 
+    734, 736, 737, 98, 98, 98, 98, 98, 98, 98, 98, 738, 738, 739, 739, 739, 
+    739, 739, 739, 739, 739, 739, 739, 98, 98, 98, 98, 98, 98, 231, 231, 231, 
+    231, 231, 231, 231, 231, 231, 231, 226, 226, 226, 226, 226, 226, 235, 
+    235, 235, 226, 740, 226, 98, 98, 741, 741, 741, 741, 741, 741, 741, 741, 
+    741, 741, 742, 742, 742, 742, 742, 742, 742, 742, 742, 742, 742, 742, 
+    742, 742, 742, 742, 742, 742, 742, 742, 743, 743, 743, 743, 743, 744, 
+    744, 744, 233, 745, 746, 746, 746, 746, 746, 746, 746, 746, 746, 746, 
+    746, 746, 746, 746, 746, 747, 747, 747, 747, 747, 747, 747, 747, 747, 
+    747, 747, 748, 749, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 750, 388, 
+    388, 388, 388, 388, 98, 98, 98, 751, 751, 751, 752, 753, 753, 753, 753, 
+    753, 753, 753, 753, 753, 753, 753, 753, 753, 753, 753, 754, 752, 752, 
+    751, 751, 751, 751, 752, 752, 751, 752, 752, 752, 755, 756, 756, 756, 
+    756, 756, 756, 757, 757, 757, 756, 756, 756, 756, 98, 70, 758, 758, 758, 
+    758, 758, 758, 758, 758, 758, 758, 98, 98, 98, 98, 756, 756, 372, 372, 
+    372, 372, 372, 375, 759, 372, 379, 379, 372, 372, 372, 372, 372, 98, 760, 
+    760, 760, 760, 760, 760, 760, 760, 760, 761, 761, 761, 761, 761, 761, 
+    762, 762, 761, 761, 762, 762, 761, 761, 98, 760, 760, 760, 761, 760, 760, 
+    760, 760, 760, 760, 760, 760, 761, 762, 98, 98, 763, 763, 763, 763, 763, 
+    763, 763, 763, 763, 763, 98, 98, 764, 765, 765, 765, 759, 372, 372, 372, 
+    372, 372, 372, 383, 383, 383, 372, 374, 375, 374, 372, 372, 766, 766, 
+    766, 766, 766, 766, 766, 766, 767, 766, 767, 767, 768, 766, 766, 767, 
+    767, 766, 766, 766, 766, 766, 767, 767, 766, 767, 766, 98, 98, 98, 98, 
+    98, 98, 98, 98, 766, 766, 769, 770, 770, 771, 771, 771, 771, 771, 771, 
+    771, 771, 771, 771, 771, 772, 773, 773, 772, 772, 774, 774, 771, 775, 
+    775, 772, 776, 98, 98, 393, 393, 393, 393, 393, 393, 98, 64, 64, 64, 724, 
+    68, 68, 68, 68, 64, 64, 64, 64, 64, 95, 98, 98, 400, 400, 400, 400, 400, 
+    400, 400, 400, 771, 771, 771, 772, 772, 773, 772, 772, 773, 772, 772, 
+    774, 772, 776, 98, 98, 777, 777, 777, 777, 777, 777, 777, 777, 777, 777, 
+    98, 98, 98, 98, 98, 98, 778, 779, 779, 779, 779, 779, 779, 779, 779, 779, 
+    779, 779, 779, 779, 779, 779, 779, 779, 779, 779, 778, 779, 779, 779, 
+    779, 779, 779, 779, 98, 98, 98, 98, 389, 389, 389, 389, 389, 389, 389, 
+    98, 98, 98, 98, 392, 392, 392, 392, 392, 392, 392, 392, 392, 98, 98, 98, 
+    98, 780, 780, 780, 780, 780, 780, 780, 780, 781, 781, 781, 781, 781, 781, 
+    781, 781, 782, 782, 782, 782, 782, 782, 782, 782, 782, 782, 782, 782, 
+    782, 782, 703, 703, 782, 703, 782, 703, 703, 782, 782, 782, 782, 782, 
+    782, 782, 782, 782, 782, 703, 782, 703, 782, 703, 703, 782, 782, 703, 
+    703, 703, 782, 782, 782, 782, 782, 782, 782, 782, 782, 782, 782, 782, 
+    704, 704, 782, 782, 704, 704, 704, 704, 704, 704, 63, 63, 63, 63, 63, 63, 
+    63, 98, 98, 98, 98, 130, 130, 130, 130, 130, 98, 98, 98, 98, 98, 783, 
+    784, 783, 785, 785, 785, 785, 785, 785, 785, 785, 785, 786, 783, 783, 
+    783, 783, 783, 783, 783, 783, 783, 783, 783, 783, 783, 98, 783, 783, 783, 
+    783, 783, 98, 783, 98, 783, 783, 98, 783, 783, 98, 783, 783, 783, 783, 
+    783, 783, 783, 783, 783, 785, 193, 193, 193, 193, 193, 193, 193, 193, 
+    193, 193, 787, 787, 787, 787, 787, 787, 787, 787, 787, 787, 787, 787, 
+    787, 787, 787, 787, 98, 98, 98, 98, 98, 98, 98, 98, 98, 193, 193, 193, 
+    193, 193, 193, 193, 193, 193, 193, 193, 788, 544, 98, 98, 193, 193, 193, 
+    193, 193, 193, 193, 193, 193, 193, 789, 167, 98, 98, 790, 790, 790, 790, 
+    790, 790, 790, 790, 791, 792, 792, 791, 791, 793, 793, 794, 795, 796, 98, 
+    98, 98, 98, 98, 98, 124, 124, 124, 124, 124, 124, 124, 230, 230, 230, 
+    230, 230, 230, 230, 123, 123, 797, 798, 798, 799, 799, 794, 795, 794, 
+    795, 794, 795, 794, 795, 794, 795, 794, 795, 794, 795, 794, 795, 657, 
+    657, 794, 795, 797, 797, 797, 797, 799, 799, 799, 800, 797, 800, 98, 801, 
+    802, 793, 793, 798, 803, 804, 803, 804, 803, 804, 805, 797, 797, 806, 
+    807, 808, 808, 809, 98, 797, 810, 811, 797, 98, 98, 98, 98, 193, 193, 
+    193, 175, 193, 98, 193, 193, 193, 193, 193, 193, 193, 98, 98, 571, 98, 
+    812, 813, 814, 815, 816, 813, 813, 817, 818, 813, 819, 820, 821, 820, 
+    822, 823, 823, 823, 823, 823, 823, 823, 823, 823, 823, 824, 825, 826, 
+    827, 826, 812, 813, 828, 828, 828, 828, 828, 828, 828, 828, 828, 828, 
+    828, 828, 828, 828, 828, 828, 828, 828, 817, 813, 818, 829, 830, 829, 
+    831, 831, 831, 831, 831, 831, 831, 831, 831, 831, 831, 831, 831, 831, 
+    831, 831, 831, 831, 817, 827, 818, 827, 817, 818, 832, 833, 834, 832, 
+    835, 836, 837, 837, 837, 837, 837, 837, 837, 837, 837, 838, 836, 836, 
+    836, 836, 836, 836, 836, 836, 836, 836, 836, 836, 836, 836, 836, 836, 
+    836, 836, 836, 836, 836, 839, 839, 840, 840, 840, 840, 840, 840, 840, 
+    840, 840, 840, 840, 840, 840, 840, 840, 98, 98, 98, 840, 840, 840, 840, 
+    840, 840, 98, 98, 840, 840, 840, 98, 98, 98, 841, 815, 827, 829, 842, 
+    815, 815, 98, 843, 844, 844, 844, 844, 843, 843, 98, 98, 845, 845, 845, 
+    846, 606, 98, 98, 847, 847, 847, 847, 847, 847, 847, 847, 847, 847, 847, 
+    847, 98, 847, 847, 847, 847, 847, 847, 847, 847, 847, 847, 98, 847, 847, 
+    847, 98, 847, 847, 98, 847, 847, 847, 847, 847, 847, 847, 98, 98, 847, 
+    847, 847, 98, 98, 98, 98, 98, 233, 435, 233, 98, 98, 98, 98, 730, 730, 
+    730, 730, 730, 730, 730, 730, 730, 730, 730, 730, 730, 98, 98, 98, 371, 
+    848, 848, 848, 848, 848, 848, 848, 848, 848, 848, 848, 848, 848, 849, 
+    849, 849, 849, 850, 850, 850, 850, 850, 850, 850, 850, 850, 850, 850, 
+    850, 850, 850, 850, 850, 850, 849, 849, 850, 851, 851, 98, 41, 41, 41, 
+    41, 98, 98, 98, 98, 850, 98, 98, 98, 98, 98, 98, 98, 371, 371, 371, 371, 
+    371, 230, 98, 98, 852, 852, 852, 852, 852, 852, 852, 852, 852, 852, 852, 
+    852, 852, 98, 98, 98, 853, 853, 853, 853, 853, 853, 853, 853, 853, 98, 
+    98, 98, 98, 98, 98, 98, 230, 854, 854, 854, 854, 854, 854, 854, 854, 854, 
+    854, 854, 854, 854, 854, 854, 854, 854, 854, 854, 98, 98, 98, 98, 855, 
+    855, 855, 855, 855, 855, 855, 855, 856, 856, 856, 856, 98, 98, 98, 98, 
+    857, 857, 857, 857, 857, 857, 857, 857, 857, 858, 857, 857, 857, 857, 
+    857, 857, 857, 857, 858, 98, 98, 98, 98, 98, 859, 859, 859, 859, 859, 
+    859, 859, 859, 859, 859, 859, 859, 859, 859, 860, 860, 860, 860, 860, 98, 
+    98, 98, 98, 98, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861, 
+    861, 861, 861, 98, 862, 863, 863, 863, 863, 863, 863, 863, 863, 863, 863, 
+    863, 863, 98, 98, 98, 98, 864, 865, 865, 865, 865, 865, 98, 98, 866, 866, 
+    866, 866, 866, 866, 866, 866, 867, 867, 867, 867, 867, 867, 867, 867, 
+    868, 868, 868, 868, 868, 868, 868, 868, 869, 869, 869, 869, 869, 869, 
+    869, 869, 869, 869, 869, 869, 869, 869, 98, 98, 870, 870, 870, 870, 870, 
+    870, 870, 870, 870, 870, 98, 98, 98, 98, 98, 98, 871, 871, 871, 871, 871, 
+    871, 871, 871, 871, 871, 871, 871, 98, 98, 98, 98, 872, 872, 872, 872, 
+    872, 872, 872, 872, 872, 872, 872, 872, 98, 98, 98, 98, 873, 873, 873, 
+    873, 873, 873, 873, 873, 874, 874, 874, 874, 874, 874, 874, 874, 874, 
+    874, 874, 874, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 875, 876, 876, 
+    876, 876, 876, 876, 876, 876, 876, 876, 876, 876, 876, 876, 876, 98, 876, 
+    876, 876, 876, 876, 876, 98, 98, 877, 877, 877, 877, 877, 877, 98, 98, 
+    877, 98, 877, 877, 877, 877, 877, 877, 877, 877, 877, 877, 877, 877, 877, 
+    877, 877, 877, 877, 877, 877, 877, 98, 877, 877, 98, 98, 98, 877, 98, 98, 
+    877, 878, 878, 878, 878, 878, 878, 878, 878, 878, 878, 878, 878, 878, 
+    878, 98, 879, 880, 880, 880, 880, 880, 880, 880, 880, 881, 881, 881, 881, 
+    881, 881, 881, 881, 881, 881, 881, 881, 881, 881, 881, 882, 882, 883, 
+    883, 883, 883, 883, 883, 883, 884, 884, 884, 884, 884, 884, 884, 884, 
+    884, 884, 884, 884, 884, 884, 884, 98, 98, 98, 98, 98, 98, 98, 98, 885, 
+    885, 885, 885, 885, 885, 885, 885, 885, 886, 886, 886, 886, 886, 886, 
+    886, 886, 886, 886, 886, 98, 886, 886, 98, 98, 98, 98, 98, 887, 887, 887, 
+    887, 887, 888, 888, 888, 888, 888, 888, 888, 888, 888, 888, 888, 888, 
+    888, 888, 889, 889, 889, 889, 889, 889, 98, 98, 98, 890, 891, 891, 891, 
+    891, 891, 891, 891, 891, 891, 891, 98, 98, 98, 98, 98, 892, 893, 893, 
+    893, 893, 893, 893, 893, 893, 894, 894, 894, 894, 894, 894, 894, 894, 98, 
+    98, 98, 98, 895, 895, 894, 894, 895, 895, 895, 895, 895, 895, 895, 895, 
+    98, 98, 895, 895, 895, 895, 895, 895, 896, 897, 897, 897, 98, 897, 897, 
+    98, 98, 98, 98, 98, 897, 898, 897, 899, 896, 896, 896, 896, 98, 896, 896, 
+    896, 98, 896, 896, 896, 896, 896, 896, 896, 896, 896, 896, 896, 896, 896, 
+    896, 896, 896, 896, 896, 896, 98, 98, 98, 98, 899, 900, 898, 98, 98, 98, 
+    98, 901, 902, 902, 902, 902, 902, 902, 902, 902, 903, 903, 903, 903, 903, 
+    903, 903, 903, 904, 98, 98, 98, 98, 98, 98, 98, 905, 905, 905, 905, 905, 
+    905, 905, 905, 905, 905, 905, 905, 905, 906, 906, 907, 908, 908, 908, 
+    908, 908, 908, 908, 908, 908, 908, 908, 908, 908, 909, 909, 909, 910, 
+    910, 910, 910, 910, 910, 910, 910, 911, 910, 910, 910, 910, 910, 910, 
+    910, 910, 910, 910, 910, 910, 912, 913, 98, 98, 98, 98, 914, 914, 914, 
+    914, 914, 915, 915, 915, 915, 915, 915, 916, 98, 917, 917, 917, 917, 917, 
+    917, 917, 917, 917, 917, 917, 917, 917, 917, 98, 98, 98, 918, 918, 918, 
+    918, 918, 918, 918, 919, 919, 919, 919, 919, 919, 919, 919, 919, 919, 
+    919, 919, 919, 919, 98, 98, 920, 920, 920, 920, 920, 920, 920, 920, 921, 
+    921, 921, 921, 921, 921, 921, 921, 921, 921, 921, 98, 98, 98, 98, 98, 
+    922, 922, 922, 922, 922, 922, 922, 922, 923, 923, 923, 923, 923, 923, 
+    923, 923, 923, 923, 98, 98, 98, 98, 98, 98, 98, 924, 924, 924, 924, 98, 
+    98, 98, 98, 925, 925, 925, 925, 925, 925, 925, 926, 926, 926, 926, 926, 
+    926, 926, 926, 926, 98, 98, 98, 98, 98, 98, 98, 927, 927, 927, 927, 927, 
+    927, 927, 927, 927, 927, 927, 98, 98, 98, 98, 98, 928, 928, 928, 928, 
+    928, 928, 928, 928, 928, 928, 928, 98, 98, 98, 98, 98, 98, 98, 929, 929, 
+    929, 929, 929, 929, 930, 930, 930, 930, 930, 930, 930, 930, 930, 930, 
+    930, 930, 930, 930, 930, 98, 931, 932, 931, 933, 933, 933, 933, 933, 933, 
+    933, 933, 933, 933, 933, 933, 933, 932, 932, 932, 932, 932, 932, 932, 
+    932, 932, 932, 932, 932, 932, 932, 934, 935, 935, 936, 936, 936, 936, 
+    936, 98, 98, 98, 98, 937, 937, 937, 937, 937, 937, 937, 937, 937, 937, 
+    937, 937, 937, 937, 937, 937, 937, 937, 937, 937, 938, 938, 938, 938, 
+    938, 938, 938, 938, 938, 938, 98, 98, 98, 98, 98, 98, 98, 934, 939, 939, 
+    940, 941, 941, 941, 941, 941, 941, 941, 941, 941, 941, 941, 941, 941, 
+    941, 941, 942, 941, 942, 941, 941, 941, 941, 941, 941, 942, 941, 941, 
+    941, 941, 940, 940, 940, 939, 939, 939, 939, 940, 940, 943, 944, 945, 
+    945, 946, 947, 947, 947, 947, 98, 98, 98, 98, 98, 98, 948, 948, 948, 948, 
+    948, 948, 948, 948, 948, 98, 98, 98, 98, 98, 98, 98, 949, 949, 949, 949, 
+    949, 949, 949, 949, 949, 949, 98, 98, 98, 98, 98, 98, 950, 950, 950, 951, 
+    951, 951, 951, 951, 951, 951, 951, 951, 951, 951, 951, 951, 951, 951, 
+    951, 951, 951, 951, 951, 952, 953, 953, 953, 953, 954, 953, 955, 955, 
+    953, 953, 953, 956, 956, 98, 957, 957, 957, 957, 957, 957, 957, 957, 957, 
+    957, 958, 958, 958, 958, 98, 98, 98, 98, 959, 959, 959, 959, 959, 959, 
+    959, 959, 959, 959, 959, 960, 961, 962, 959, 98, 963, 963, 964, 965, 965, 
+    965, 965, 965, 965, 965, 965, 965, 965, 965, 965, 965, 965, 965, 965, 
+    964, 964, 964, 963, 963, 963, 963, 963, 963, 963, 963, 963, 964, 966, 
+    965, 965, 965, 965, 967, 967, 968, 967, 968, 969, 963, 963, 968, 98, 98, 
+    970, 970, 970, 970, 970, 970, 970, 970, 970, 970, 965, 971, 965, 967, 
+    967, 967, 98, 972, 972, 972, 972, 972, 972, 972, 972, 972, 972, 972, 972, 
+    972, 972, 972, 972, 972, 972, 972, 972, 98, 98, 98, 973, 973, 973, 973, 
+    973, 973, 973, 973, 973, 973, 98, 973, 973, 973, 973, 973, 973, 973, 973, 
+    973, 974, 974, 974, 975, 975, 975, 974, 974, 975, 976, 977, 975, 978, 
+    978, 979, 978, 978, 979, 975, 98, 980, 980, 980, 980, 980, 980, 980, 98, 
+    980, 98, 980, 980, 980, 980, 98, 980, 980, 980, 980, 980, 980, 980, 980, 
+    980, 980, 980, 980, 980, 980, 980, 98, 980, 980, 981, 98, 98, 98, 98, 98, 
+    98, 982, 982, 982, 982, 982, 982, 982, 982, 982, 982, 982, 982, 982, 982, 
+    982, 983, 984, 984, 984, 983, 983, 983, 983, 983, 983, 985, 986, 98, 98, 
+    98, 98, 98, 987, 987, 987, 987, 987, 987, 987, 987, 987, 987, 98, 98, 98, 
+    98, 98, 98, 988, 988, 989, 989, 98, 990, 990, 990, 990, 990, 990, 990, 
+    990, 98, 98, 990, 990, 98, 98, 990, 990, 990, 990, 990, 990, 990, 990, 
+    990, 990, 990, 990, 990, 990, 98, 990, 990, 990, 990, 990, 990, 990, 98, 
+    990, 990, 98, 990, 990, 990, 990, 990, 98, 98, 991, 990, 992, 989, 988, 
+    989, 989, 989, 989, 98, 98, 989, 989, 98, 98, 993, 993, 994, 98, 98, 990, 
+    98, 98, 98, 98, 98, 98, 992, 98, 98, 98, 98, 98, 990, 990, 990, 990, 990, 
+    989, 989, 98, 98, 995, 995, 995, 995, 995, 995, 995, 98, 98, 98, 996, 
+    996, 996, 996, 996, 996, 996, 996, 996, 996, 996, 996, 996, 997, 997, 
+    997, 998, 998, 998, 998, 998, 998, 998, 998, 997, 997, 999, 998, 998, 
+    997, 1000, 996, 996, 996, 996, 1001, 1001, 1001, 1001, 1002, 1003, 1003, 
+    1003, 1003, 1003, 1003, 1003, 1003, 1003, 1003, 98, 1001, 98, 1002, 98, 
+    98, 1004, 1004, 1004, 1004, 1004, 1004, 1004, 1004, 1005, 1006, 1006, 
+    1007, 1007, 1007, 1007, 1007, 1007, 1006, 1008, 1009, 1009, 1005, 1009, 
+    1007, 1007, 1006, 1010, 1011, 1004, 1004, 1012, 1004, 1013, 1013, 1013, 
+    1013, 1013, 1013, 1013, 1013, 1013, 1013, 98, 98, 98, 98, 98, 98, 1014, 
+    1014, 1014, 1014, 1014, 1014, 1014, 1014, 1014, 1014, 1014, 1014, 1014, 
+    1014, 1014, 1015, 1016, 1016, 1017, 1017, 1017, 1017, 98, 98, 1016, 1016, 
+    1018, 1018, 1017, 1017, 1016, 1019, 1020, 1021, 1022, 1022, 1023, 1023, 
+    1024, 1024, 1024, 1022, 1022, 1022, 1022, 1022, 1022, 1022, 1022, 1022, 
+    1022, 1022, 1022, 1022, 1022, 1022, 1014, 1014, 1014, 1014, 1017, 1017, 
+    98, 98, 1025, 1025, 1025, 1025, 1025, 1025, 1025, 1025, 1026, 1026, 1026, 
+    1027, 1027, 1027, 1027, 1027, 1027, 1027, 1027, 1026, 1026, 1027, 1026, 
+    1028, 1027, 1029, 1029, 1030, 1025, 98, 98, 98, 1031, 1031, 1031, 1031, 
+    1031, 1031, 1031, 1031, 1031, 1031, 98, 98, 98, 98, 98, 98, 1032, 1032, 
+    1032, 1032, 1032, 1032, 1032, 1032, 1032, 1032, 1032, 1032, 1032, 98, 98, 
+    98, 1033, 1033, 1033, 1033, 1033, 1033, 1033, 1033, 1033, 1033, 1033, 
+    1034, 1035, 1034, 1035, 1035, 1034, 1034, 1034, 1034, 1034, 1034, 1036, 
+    1037, 1038, 1038, 1038, 1038, 1038, 1038, 1038, 1038, 1038, 1038, 98, 98, 
+    98, 98, 98, 98, 1039, 1039, 1039, 1039, 1039, 1039, 1039, 1039, 1039, 
+    1039, 98, 98, 98, 1040, 1040, 1040, 1041, 1041, 1040, 1040, 1040, 1040, 
+    1041, 1040, 1040, 1040, 1040, 1042, 98, 98, 98, 98, 1043, 1043, 1043, 
+    1043, 1043, 1043, 1043, 1043, 1043, 1043, 1044, 1044, 1045, 1045, 1045, 
+    1046, 1047, 1047, 1047, 1047, 1047, 1047, 1047, 1047, 1048, 1048, 1048, 
+    1048, 1048, 1048, 1048, 1048, 1049, 1049, 1049, 1049, 1049, 1049, 1049, 
+    1049, 1049, 1049, 1050, 1050, 1050, 1050, 1050, 1050, 1050, 1050, 1050, 
+    98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 1051, 1052, 1052, 1052, 
+    1052, 1052, 1052, 1052, 1052, 1052, 98, 98, 98, 98, 98, 98, 98, 1053, 
+    1053, 1053, 1053, 1053, 1053, 1053, 1053, 1053, 98, 1053, 1053, 1053, 
+    1053, 1053, 1053, 1053, 1053, 1053, 1053, 1053, 1053, 1053, 1054, 1055, 
+    1055, 1055, 1055, 1055, 1055, 1055, 98, 1055, 1055, 1055, 1055, 1055, 
+    1055, 1054, 1056, 1053, 1057, 1057, 1057, 1057, 1057, 98, 98, 1058, 1058, 
+    1058, 1058, 1058, 1058, 1058, 1058, 1058, 1058, 1059, 1059, 1059, 1059, 
+    1059, 1059, 1059, 1059, 1059, 1059, 1059, 1059, 1059, 1059, 1059, 1059, 
+    1059, 1059, 1059, 98, 98, 98, 1060, 1061, 1062, 1062, 1062, 1062, 1062, 
+    1062, 1062, 1062, 1062, 1062, 1062, 1062, 1062, 1062, 98, 98, 1063, 1063, 
+    1063, 1063, 1063, 1063, 1063, 1063, 1063, 1063, 1063, 1063, 1063, 1063, 
+    98, 1064, 1063, 1063, 1063, 1063, 1063, 1063, 1063, 1064, 1063, 1063, 
+    1064, 1063, 1063, 98, 1065, 1065, 1065, 1065, 1065, 1065, 1065, 1065, 
+    1065, 1065, 98, 98, 98, 98, 98, 98, 1066, 1066, 1066, 1066, 1066, 1066, 
+    1066, 1066, 1066, 1066, 1066, 1066, 1066, 1066, 1066, 98, 1067, 1067, 
+    1067, 1067, 1067, 98, 98, 98, 1065, 1065, 1065, 1065, 98, 98, 98, 98, 
+    1068, 1068, 1068, 1068, 1068, 1068, 1068, 1068, 1069, 1069, 1069, 1070, 
+    1070, 1070, 1068, 1068, 1068, 1068, 1070, 1068, 1068, 1068, 1069, 1070, 
+    1069, 1070, 1068, 1068, 1068, 1068, 1068, 1068, 1068, 1069, 1070, 1070, 
+    1068, 1068, 1068, 1068, 1068, 1068, 1068, 1068, 1068, 1068, 1068, 98, 
+    1071, 1071, 1071, 1071, 1071, 1071, 1071, 1071, 1071, 1071, 1071, 1071, 
+    1071, 1071, 1072, 1073, 1071, 1071, 1071, 1071, 1071, 1071, 1071, 98, 
+    719, 98, 98, 98, 98, 98, 98, 98, 1074, 1074, 1074, 1074, 1074, 1074, 
+    1074, 1074, 1074, 1074, 1074, 1074, 1074, 1074, 1074, 98, 1075, 1075, 
+    1075, 1075, 1075, 1075, 1075, 1075, 1075, 1075, 98, 98, 98, 98, 1076, 
+    1076, 1077, 1077, 1077, 1077, 1077, 1077, 1077, 1077, 1077, 1077, 1077, 
+    1077, 1077, 1077, 98, 98, 1078, 1078, 1078, 1078, 1078, 1079, 98, 98, 
+    1080, 1080, 1080, 1080, 1080, 1080, 1080, 1080, 1081, 1081, 1081, 1081, 
+    1081, 1081, 1081, 1082, 1082, 1082, 1083, 1083, 1084, 1084, 1084, 1084, 
+    1085, 1085, 1085, 1085, 1082, 1084, 98, 98, 1086, 1086, 1086, 1086, 1086, 
+    1086, 1086, 1086, 1086, 1086, 98, 1087, 1087, 1087, 1087, 1087, 1087, 
+    1087, 98, 1080, 1080, 1080, 1080, 1080, 98, 98, 98, 98, 98, 1080, 1080, 
+    1080, 1088, 1088, 1088, 1088, 1088, 1088, 1088, 1088, 1088, 1088, 1088, 
+    1088, 1088, 98, 98, 98, 1088, 1089, 1089, 1089, 1089, 1089, 1089, 1089, 
+    1089, 1089, 1089, 1089, 1089, 1089, 1089, 1089, 1089, 1089, 1089, 1089, 
+    1089, 1089, 1089, 98, 98, 98, 98, 98, 98, 98, 98, 1090, 1090, 1090, 1090, 
+    1091, 1091, 1091, 1091, 1091, 1091, 1091, 1091, 1091, 1091, 1091, 1091, 
+    1091, 1092, 98, 98, 98, 98, 98, 98, 98, 1093, 1093, 1093, 1093, 1093, 
+    1093, 1093, 1093, 1093, 1093, 1093, 1093, 1093, 98, 98, 98, 1093, 1093, 
+    1093, 98, 98, 98, 98, 98, 686, 678, 98, 98, 98, 98, 98, 98, 1094, 1094, 
+    1094, 1094, 1094, 1094, 1094, 1094, 1094, 1094, 1094, 98, 98, 98, 98, 98, 
+    1094, 1094, 1094, 1094, 1094, 98, 98, 98, 1094, 98, 98, 98, 98, 98, 98, 
+    98, 1094, 1094, 98, 98, 1095, 1096, 1097, 1098, 577, 577, 577, 577, 98, 
+    98, 98, 98, 371, 371, 371, 371, 371, 371, 98, 98, 371, 371, 371, 371, 
+    371, 371, 371, 98, 98, 371, 371, 371, 371, 371, 371, 371, 371, 371, 371, 
+    371, 371, 371, 1099, 1099, 1099, 1099, 1099, 1099, 1099, 1100, 1100, 512, 
+    512, 512, 371, 371, 371, 1101, 1100, 1100, 1100, 1100, 1100, 577, 577, 
+    577, 577, 577, 577, 577, 577, 230, 230, 230, 230, 230, 230, 230, 230, 
+    371, 371, 124, 124, 124, 124, 124, 230, 230, 371, 371, 371, 371, 371, 
+    371, 124, 124, 124, 124, 371, 371, 371, 371, 371, 1099, 1099, 1099, 1099, 
+    1099, 1099, 371, 371, 371, 371, 371, 371, 371, 371, 98, 98, 98, 98, 98, 
+    98, 98, 850, 850, 1102, 1102, 1102, 850, 98, 98, 730, 730, 98, 98, 98, 
+    98, 98, 98, 589, 589, 589, 589, 589, 589, 589, 589, 589, 589, 51, 51, 51, 
+    51, 51, 51, 51, 51, 51, 51, 51, 51, 51, 51, 51, 51, 51, 51, 589, 589, 
+    589, 589, 589, 589, 589, 589, 589, 589, 51, 51, 51, 51, 51, 51, 51, 98, 
+    51, 51, 51, 51, 51, 51, 589, 98, 589, 589, 98, 98, 589, 98, 98, 589, 589, 
+    98, 98, 589, 589, 589, 589, 98, 589, 589, 51, 51, 98, 51, 98, 51, 51, 51, 
+    51, 51, 51, 51, 98, 51, 51, 51, 51, 51, 51, 51, 589, 589, 98, 589, 589, 
+    589, 589, 98, 98, 589, 589, 589, 589, 589, 589, 589, 589, 98, 589, 589, 
+    589, 589, 589, 589, 589, 98, 51, 51, 589, 589, 98, 589, 589, 589, 589, 
+    98, 589, 589, 589, 589, 589, 98, 589, 98, 98, 98, 589, 589, 589, 589, 
+    589, 589, 589, 98, 51, 51, 51, 51, 51, 51, 51, 51, 51, 51, 51, 51, 98, 
+    98, 589, 1103, 51, 51, 51, 51, 51, 51, 51, 51, 51, 599, 51, 51, 51, 51, 
+    51, 51, 589, 589, 589, 589, 589, 589, 589, 589, 589, 1103, 51, 51, 51, 
+    51, 51, 51, 51, 51, 51, 599, 51, 51, 589, 589, 589, 589, 589, 1103, 51, 
+    51, 51, 51, 51, 51, 51, 51, 51, 599, 51, 51, 51, 51, 51, 51, 589, 589, 
+    589, 589, 589, 589, 589, 589, 589, 1103, 51, 599, 51, 51, 51, 51, 51, 51, 
+    51, 51, 589, 51, 98, 98, 1104, 1104, 1104, 1104, 1104, 1104, 1104, 1104, 
+    1104, 1104, 1105, 1105, 1105, 1105, 1105, 1105, 1105, 1105, 1106, 1106, 
+    1106, 1106, 1106, 1106, 1106, 1106, 1106, 1106, 1106, 1106, 1106, 1106, 
+    1106, 1105, 1105, 1105, 1105, 1106, 1106, 1106, 1106, 1106, 1106, 1106, 
+    1106, 1106, 1106, 1105, 1105, 1105, 1105, 1105, 1105, 1105, 1105, 1106, 
+    1105, 1105, 1105, 1105, 1105, 1105, 1106, 1105, 1105, 1107, 1107, 1107, 
+    1107, 1108, 98, 98, 98, 98, 98, 98, 98, 1106, 1106, 1106, 1106, 1106, 98, 
+    1106, 1106, 1106, 1106, 1106, 1106, 1106, 1109, 1109, 1109, 1109, 1109, 
+    1109, 1109, 98, 1109, 1109, 1109, 1109, 1109, 1109, 1109, 1109, 1109, 98, 
+    98, 1109, 1109, 1109, 1109, 1109, 1109, 1109, 98, 1109, 1109, 98, 1109, 
+    1109, 1109, 1109, 1109, 98, 98, 98, 98, 98, 1110, 1110, 1110, 1110, 1110, 
+    1110, 1110, 1110, 1110, 1110, 1110, 1110, 1110, 98, 98, 1111, 1111, 1111, 
+    1111, 1111, 1111, 1111, 1111, 1111, 1112, 1112, 1112, 1112, 1112, 1112, 
+    1112, 98, 1113, 1113, 1113, 1113, 1113, 1113, 1113, 1113, 1113, 1113, 
+    1114, 1114, 1114, 1114, 1114, 1114, 1114, 1114, 1114, 1114, 1114, 1114, 
+    1114, 1114, 1114, 1114, 1114, 1114, 1115, 1115, 1115, 1115, 1115, 1115, 
+    1116, 98, 98, 98, 98, 98, 1117, 1117, 1117, 1117, 1117, 1117, 1117, 1117, 
+    1117, 1117, 98, 98, 98, 98, 1118, 1118, 193, 193, 193, 193, 98, 193, 193, 
+    193, 98, 193, 193, 98, 193, 98, 98, 193, 98, 193, 193, 193, 193, 193, 
+    193, 193, 193, 193, 193, 98, 193, 193, 193, 193, 98, 193, 98, 193, 98, 
+    98, 98, 98, 98, 98, 193, 98, 98, 98, 98, 193, 98, 193, 98, 193, 98, 193, 
+    193, 193, 98, 193, 98, 193, 98, 193, 98, 193, 98, 193, 193, 193, 193, 98, 
+    193, 98, 193, 193, 98, 193, 193, 193, 193, 193, 193, 193, 193, 193, 98, 
+    98, 98, 98, 98, 193, 193, 193, 98, 193, 193, 193, 161, 161, 98, 98, 98, 
+    98, 98, 98, 621, 621, 621, 621, 617, 621, 621, 621, 621, 621, 621, 621, 
+    621, 621, 621, 621, 621, 621, 621, 621, 1119, 1119, 1119, 1119, 1119, 
+    1119, 1119, 1119, 1119, 1119, 1119, 1119, 621, 621, 621, 621, 621, 621, 
+    621, 1119, 1119, 621, 621, 621, 621, 621, 621, 621, 621, 621, 621, 621, 
+    621, 621, 621, 617, 621, 621, 621, 621, 621, 621, 1119, 1119, 49, 49, 49, 
+    634, 634, 1119, 1119, 1119, 622, 622, 622, 622, 622, 622, 1120, 1119, 
+    1121, 1121, 1121, 1121, 1121, 1121, 1121, 1121, 1121, 1121, 588, 588, 
+    1119, 1119, 1119, 1119, 1121, 1121, 1121, 1121, 1121, 1121, 1122, 1121, 
+    622, 1122, 1122, 1122, 1122, 1122, 1122, 1122, 1122, 1122, 1122, 1121, 
+    1121, 1121, 1121, 1121, 1121, 1121, 1121, 1121, 1121, 1119, 1119, 1119, 
+    1119, 1119, 1119, 1119, 1119, 1119, 1123, 1123, 1123, 1123, 1123, 1123, 
+    1123, 1123, 1123, 1123, 1124, 697, 697, 1119, 1119, 1119, 1119, 1119, 
+    697, 697, 697, 697, 1119, 1119, 1119, 1119, 697, 1119, 1119, 1119, 1119, 
+    1119, 1119, 1119, 697, 697, 1119, 1119, 1119, 1119, 1119, 1119, 617, 621, 
+    621, 621, 621, 621, 621, 621, 621, 621, 621, 621, 621, 617, 617, 617, 
+    617, 617, 617, 617, 617, 617, 621, 617, 617, 617, 617, 617, 617, 621, 
+    617, 617, 617, 617, 617, 617, 617, 630, 617, 617, 617, 617, 617, 617, 
+    621, 621, 621, 621, 621, 621, 621, 621, 41, 41, 621, 621, 617, 617, 617, 
+    617, 617, 620, 620, 617, 617, 617, 617, 617, 620, 617, 617, 617, 617, 
+    617, 617, 630, 630, 617, 617, 617, 617, 617, 630, 628, 621, 621, 621, 
+    617, 617, 621, 621, 621, 617, 621, 621, 621, 617, 617, 617, 1125, 1125, 
+    1125, 1125, 1125, 617, 617, 617, 617, 617, 617, 617, 621, 617, 621, 630, 
+    630, 617, 617, 630, 630, 630, 630, 630, 630, 630, 630, 630, 630, 630, 
+    617, 617, 617, 617, 617, 617, 617, 617, 617, 617, 617, 617, 617, 630, 
+    630, 630, 630, 617, 617, 617, 617, 630, 617, 630, 617, 617, 617, 630, 
+    617, 617, 617, 617, 630, 630, 630, 617, 630, 630, 630, 620, 617, 620, 
+    617, 620, 617, 617, 617, 617, 617, 630, 617, 617, 617, 617, 620, 617, 
+    620, 620, 617, 617, 617, 617, 617, 617, 617, 617, 617, 617, 621, 621, 
+    617, 620, 620, 620, 620, 620, 620, 620, 617, 617, 617, 617, 617, 617, 
+    617, 617, 620, 620, 620, 620, 620, 620, 617, 617, 617, 617, 617, 620, 
+    620, 620, 620, 620, 620, 620, 620, 620, 620, 620, 620, 41, 41, 41, 41, 
+    621, 617, 617, 617, 617, 621, 621, 621, 621, 621, 621, 628, 621, 621, 
+    621, 621, 630, 621, 621, 621, 621, 621, 628, 621, 621, 621, 621, 630, 
+    630, 621, 621, 621, 621, 621, 41, 41, 41, 41, 41, 41, 41, 41, 621, 621, 
+    621, 621, 41, 41, 621, 617, 617, 617, 617, 617, 617, 617, 617, 617, 617, 
+    630, 630, 630, 617, 617, 617, 630, 630, 630, 630, 630, 41, 41, 41, 41, 
+    41, 41, 632, 632, 632, 1126, 1126, 1126, 41, 41, 41, 41, 617, 617, 617, 
+    630, 617, 617, 617, 617, 617, 617, 617, 617, 630, 630, 630, 617, 630, 
+    617, 617, 617, 617, 617, 621, 621, 617, 617, 617, 1119, 1119, 1119, 1119, 
+    1119, 621, 621, 621, 617, 617, 1119, 1119, 1119, 621, 621, 621, 621, 617, 
+    617, 617, 1119, 41, 41, 41, 41, 1119, 1119, 1119, 1119, 41, 41, 41, 41, 
+    41, 1119, 1119, 1119, 41, 41, 1119, 1119, 1119, 1119, 1119, 1119, 41, 41, 
+    41, 41, 41, 41, 1119, 1119, 630, 630, 630, 630, 630, 630, 630, 1119, 617, 
+    617, 617, 617, 617, 617, 630, 617, 630, 1119, 1119, 630, 630, 630, 630, 
+    630, 630, 630, 617, 617, 630, 630, 630, 1119, 617, 617, 617, 617, 1119, 
+    1119, 1119, 1119, 617, 617, 617, 617, 617, 617, 617, 1119, 617, 617, 
+    1119, 1119, 1119, 1119, 1119, 1119, 617, 1119, 1119, 1119, 1119, 1119, 
+    1119, 1119, 1119, 1119, 1119, 1119, 1119, 1119, 98, 98, 703, 703, 703, 
+    703, 703, 703, 703, 704, 703, 703, 703, 703, 703, 704, 704, 704, 703, 
+    703, 704, 704, 704, 704, 704, 704, 704, 704, 704, 704, 704, 704, 98, 98, 
+    98, 577, 98, 98, 98, 98, 98, 98, 577, 577, 577, 577, 577, 577, 577, 577, 
+    781, 781, 781, 781, 781, 781, 98, 98, 
 ];
 
 /* decomposition data */
@@ -5167,6 +5369,1733 @@ pub static DECOMP_INDEX2: [u16; 7648] = [
     14405, 14407, 0, 0, 
 ];
 
+/* full canonical decompositions that need more than one step */
+pub static FULL_DECOMP: [FullDecomp; 281] = [
+    FullDecomp{code: 469, decomposed: [85, 776, 772, 0]},
+    FullDecomp{code: 470, decomposed: [117, 776, 772, 0]},
+    FullDecomp{code: 471, decomposed: [85, 776, 769, 0]},
+    FullDecomp{code: 472, decomposed: [117, 776, 769, 0]},
+    FullDecomp{code: 473, decomposed: [85, 776, 780, 0]},
+    FullDecomp{code: 474, decomposed: [117, 776, 780, 0]},
+    FullDecomp{code: 475, decomposed: [85, 776, 768, 0]},
+    FullDecomp{code: 476, decomposed: [117, 776, 768, 0]},
+    FullDecomp{code: 478, decomposed: [65, 776, 772, 0]},
+    FullDecomp{code: 479, decomposed: [97, 776, 772, 0]},
+    FullDecomp{code: 480, decomposed: [65, 775, 772, 0]},
+    FullDecomp{code: 481, decomposed: [97, 775, 772, 0]},
+    FullDecomp{code: 492, decomposed: [79, 808, 772, 0]},
+    FullDecomp{code: 493, decomposed: [111, 808, 772, 0]},
+    FullDecomp{code: 506, decomposed: [65, 778, 769, 0]},
+    FullDecomp{code: 507, decomposed: [97, 778, 769, 0]},
+    FullDecomp{code: 554, decomposed: [79, 776, 772, 0]},
+    FullDecomp{code: 555, decomposed: [111, 776, 772, 0]},
+    FullDecomp{code: 556, decomposed: [79, 771, 772, 0]},
+    FullDecomp{code: 557, decomposed: [111, 771, 772, 0]},
+    FullDecomp{code: 560, decomposed: [79, 775, 772, 0]},
+    FullDecomp{code: 561, decomposed: [111, 775, 772, 0]},
+    FullDecomp{code: 912, decomposed: [953, 776, 769, 0]},
+    FullDecomp{code: 944, decomposed: [965, 776, 769, 0]},
+    FullDecomp{code: 3275, decomposed: [3270, 3266, 3285, 0]},
+    FullDecomp{code: 3549, decomposed: [3545, 3535, 3530, 0]},
+    FullDecomp{code: 7688, decomposed: [67, 807, 769, 0]},
+    FullDecomp{code: 7689, decomposed: [99, 807, 769, 0]},
+    FullDecomp{code: 7700, decomposed: [69, 772, 768, 0]},
+    FullDecomp{code: 7701, decomposed: [101, 772, 768, 0]},
+    FullDecomp{code: 7702, decomposed: [69, 772, 769, 0]},
+    FullDecomp{code: 7703, decomposed: [101, 772, 769, 0]},
+    FullDecomp{code: 7708, decomposed: [69, 807, 774, 0]},
+    FullDecomp{code: 7709, decomposed: [101, 807, 774, 0]},
+    FullDecomp{code: 7726, decomposed: [73, 776, 769, 0]},
+    FullDecomp{code: 7727, decomposed: [105, 776, 769, 0]},
+    FullDecomp{code: 7736, decomposed: [76, 803, 772, 0]},
+    FullDecomp{code: 7737, decomposed: [108, 803, 772, 0]},
+    FullDecomp{code: 7756, decomposed: [79, 771, 769, 0]},
+    FullDecomp{code: 7757, decomposed: [111, 771, 769, 0]},
+    FullDecomp{code: 7758, decomposed: [79, 771, 776, 0]},
+    FullDecomp{code: 7759, decomposed: [111, 771, 776, 0]},
+    FullDecomp{code: 7760, decomposed: [79, 772, 768, 0]},
+    FullDecomp{code: 7761, decomposed: [111, 772, 768, 0]},
+    FullDecomp{code: 7762, decomposed: [79, 772, 769, 0]},
+    FullDecomp{code: 7763, decomposed: [111, 772, 769, 0]},
+    FullDecomp{code: 7772, decomposed: [82, 803, 772, 0]},
+    FullDecomp{code: 7773, decomposed: [114, 803, 772, 0]},
+    FullDecomp{code: 7780, decomposed: [83, 769, 775, 0]},
+    FullDecomp{code: 7781, decomposed: [115, 769, 775, 0]},
+    FullDecomp{code: 7782, decomposed: [83, 780, 775, 0]},
+    FullDecomp{code: 7783, decomposed: [115, 780, 775, 0]},
+    FullDecomp{code: 7784, decomposed: [83, 803, 775, 0]},
+    FullDecomp{code: 7785, decomposed: [115, 803, 775, 0]},
+    FullDecomp{code: 7800, decomposed: [85, 771, 769, 0]},
+    FullDecomp{code: 7801, decomposed: [117, 771, 769, 0]},
+    FullDecomp{code: 7802, decomposed: [85, 772, 776, 0]},
+    FullDecomp{code: 7803, decomposed: [117, 772, 776, 0]},
+    FullDecomp{code: 7844, decomposed: [65, 770, 769, 0]},
+    FullDecomp{code: 7845, decomposed: [97, 770, 769, 0]},
+    FullDecomp{code: 7846, decomposed: [65, 770, 768, 0]},
+    FullDecomp{code: 7847, decomposed: [97, 770, 768, 0]},
+    FullDecomp{code: 7848, decomposed: [65, 770, 777, 0]},
+    FullDecomp{code: 7849, decomposed: [97, 770, 777, 0]},
+    FullDecomp{code: 7850, decomposed: [65, 770, 771, 0]},
+    FullDecomp{code: 7851, decomposed: [97, 770, 771, 0]},
+    FullDecomp{code: 7852, decomposed: [65, 803, 770, 0]},
+    FullDecomp{code: 7853, decomposed: [97, 803, 770, 0]},
+    FullDecomp{code: 7854, decomposed: [65, 774, 769, 0]},
+    FullDecomp{code: 7855, decomposed: [97, 774, 769, 0]},
+    FullDecomp{code: 7856, decomposed: [65, 774, 768, 0]},
+    FullDecomp{code: 7857, decomposed: [97, 774, 768, 0]},
+    FullDecomp{code: 7858, decomposed: [65, 774, 777, 0]},
+    FullDecomp{code: 7859, decomposed: [97, 774, 777, 0]},
+    FullDecomp{code: 7860, decomposed: [65, 774, 771, 0]},
+    FullDecomp{code: 7861, decomposed: [97, 774, 771, 0]},
+    FullDecomp{code: 7862, decomposed: [65, 803, 774, 0]},
+    FullDecomp{code: 7863, decomposed: [97, 803, 774, 0]},
+    FullDecomp{code: 7870, decomposed: [69, 770, 769, 0]},
+    FullDecomp{code: 7871, decomposed: [101, 770, 769, 0]},
+    FullDecomp{code: 7872, decomposed: [69, 770, 768, 0]},
+    FullDecomp{code: 7873, decomposed: [101, 770, 768, 0]},
+    FullDecomp{code: 7874, decomposed: [69, 770, 777, 0]},
+    FullDecomp{code: 7875, decomposed: [101, 770, 777, 0]},
+    FullDecomp{code: 7876, decomposed: [69, 770, 771, 0]},
+    FullDecomp{code: 7877, decomposed: [101, 770, 771, 0]},
+    FullDecomp{code: 7878, decomposed: [69, 803, 770, 0]},
+    FullDecomp{code: 7879, decomposed: [101, 803, 770, 0]},
+    FullDecomp{code: 7888, decomposed: [79, 770, 769, 0]},
+    FullDecomp{code: 7889, decomposed: [111, 770, 769, 0]},
+    FullDecomp{code: 7890, decomposed: [79, 770, 768, 0]},
+    FullDecomp{code: 7891, decomposed: [111, 770, 768, 0]},
+    FullDecomp{code: 7892, decomposed: [79, 770, 777, 0]},
+    FullDecomp{code: 7893, decomposed: [111, 770, 777, 0]},
+    FullDecomp{code: 7894, decomposed: [79, 770, 771, 0]},
+    FullDecomp{code: 7895, decomposed: [111, 770, 771, 0]},
+    FullDecomp{code: 7896, decomposed: [79, 803, 770, 0]},
+    FullDecomp{code: 7897, decomposed: [111, 803, 770, 0]},
+    FullDecomp{code: 7898, decomposed: [79, 795, 769, 0]},
+    FullDecomp{code: 7899, decomposed: [111, 795, 769, 0]},
+    FullDecomp{code: 7900, decomposed: [79, 795, 768, 0]},
+    FullDecomp{code: 7901, decomposed: [111, 795, 768, 0]},
+    FullDecomp{code: 7902, decomposed: [79, 795, 777, 0]},
+    FullDecomp{code: 7903, decomposed: [111, 795, 777, 0]},
+    FullDecomp{code: 7904, decomposed: [79, 795, 771, 0]},
+    FullDecomp{code: 7905, decomposed: [111, 795, 771, 0]},
+    FullDecomp{code: 7906, decomposed: [79, 795, 803, 0]},
+    FullDecomp{code: 7907, decomposed: [111, 795, 803, 0]},
+    FullDecomp{code: 7912, decomposed: [85, 795, 769, 0]},
+    FullDecomp{code: 7913, decomposed: [117, 795, 769, 0]},
+    FullDecomp{code: 7914, decomposed: [85, 795, 768, 0]},
+    FullDecomp{code: 7915, decomposed: [117, 795, 768, 0]},
+    FullDecomp{code: 7916, decomposed: [85, 795, 777, 0]},
+    FullDecomp{code: 7917, decomposed: [117, 795, 777, 0]},
+    FullDecomp{code: 7918, decomposed: [85, 795, 771, 0]},
+    FullDecomp{code: 7919, decomposed: [117, 795, 771, 0]},
+    FullDecomp{code: 7920, decomposed: [85, 795, 803, 0]},
+    FullDecomp{code: 7921, decomposed: [117, 795, 803, 0]},
+    FullDecomp{code: 7938, decomposed: [945, 787, 768, 0]},
+    FullDecomp{code: 7939, decomposed: [945, 788, 768, 0]},
+    FullDecomp{code: 7940, decomposed: [945, 787, 769, 0]},
+    FullDecomp{code: 7941, decomposed: [945, 788, 769, 0]},
+    FullDecomp{code: 7942, decomposed: [945, 787, 834, 0]},
+    FullDecomp{code: 7943, decomposed: [945, 788, 834, 0]},
+    FullDecomp{code: 7946, decomposed: [913, 787, 768, 0]},
+    FullDecomp{code: 7947, decomposed: [913, 788, 768, 0]},
+    FullDecomp{code: 7948, decomposed: [913, 787, 769, 0]},
+    FullDecomp{code: 7949, decomposed: [913, 788, 769, 0]},
+    FullDecomp{code: 7950, decomposed: [913, 787, 834, 0]},
+    FullDecomp{code: 7951, decomposed: [913, 788, 834, 0]},
+    FullDecomp{code: 7954, decomposed: [949, 787, 768, 0]},
+    FullDecomp{code: 7955, decomposed: [949, 788, 768, 0]},
+    FullDecomp{code: 7956, decomposed: [949, 787, 769, 0]},
+    FullDecomp{code: 7957, decomposed: [949, 788, 769, 0]},
+    FullDecomp{code: 7962, decomposed: [917, 787, 768, 0]},
+    FullDecomp{code: 7963, decomposed: [917, 788, 768, 0]},
+    FullDecomp{code: 7964, decomposed: [917, 787, 769, 0]},
+    FullDecomp{code: 7965, decomposed: [917, 788, 769, 0]},
+    FullDecomp{code: 7970, decomposed: [951, 787, 768, 0]},
+    FullDecomp{code: 7971, decomposed: [951, 788, 768, 0]},
+    FullDecomp{code: 7972, decomposed: [951, 787, 769, 0]},
+    FullDecomp{code: 7973, decomposed: [951, 788, 769, 0]},
+    FullDecomp{code: 7974, decomposed: [951, 787, 834, 0]},
+    FullDecomp{code: 7975, decomposed: [951, 788, 834, 0]},
+    FullDecomp{code: 7978, decomposed: [919, 787, 768, 0]},
+    FullDecomp{code: 7979, decomposed: [919, 788, 768, 0]},
+    FullDecomp{code: 7980, decomposed: [919, 787, 769, 0]},
+    FullDecomp{code: 7981, decomposed: [919, 788, 769, 0]},
+    FullDecomp{code: 7982, decomposed: [919, 787, 834, 0]},
+    FullDecomp{code: 7983, decomposed: [919, 788, 834, 0]},
+    FullDecomp{code: 7986, decomposed: [953, 787, 768, 0]},
+    FullDecomp{code: 7987, decomposed: [953, 788, 768, 0]},
+    FullDecomp{code: 7988, decomposed: [953, 787, 769, 0]},
+    FullDecomp{code: 7989, decomposed: [953, 788, 769, 0]},
+    FullDecomp{code: 7990, decomposed: [953, 787, 834, 0]},
+    FullDecomp{code: 7991, decomposed: [953, 788, 834, 0]},
+    FullDecomp{code: 7994, decomposed: [921, 787, 768, 0]},
+    FullDecomp{code: 7995, decomposed: [921, 788, 768, 0]},
+    FullDecomp{code: 7996, decomposed: [921, 787, 769, 0]},
+    FullDecomp{code: 7997, decomposed: [921, 788, 769, 0]},
+    FullDecomp{code: 7998, decomposed: [921, 787, 834, 0]},
+    FullDecomp{code: 7999, decomposed: [921, 788, 834, 0]},
+    FullDecomp{code: 8002, decomposed: [959, 787, 768, 0]},
+    FullDecomp{code: 8003, decomposed: [959, 788, 768, 0]},
+    FullDecomp{code: 8004, decomposed: [959, 787, 769, 0]},
+    FullDecomp{code: 8005, decomposed: [959, 788, 769, 0]},
+    FullDecomp{code: 8010, decomposed: [927, 787, 768, 0]},
+    FullDecomp{code: 8011, decomposed: [927, 788, 768, 0]},
+    FullDecomp{code: 8012, decomposed: [927, 787, 769, 0]},
+    FullDecomp{code: 8013, decomposed: [927, 788, 769, 0]},
+    FullDecomp{code: 8018, decomposed: [965, 787, 768, 0]},
+    FullDecomp{code: 8019, decomposed: [965, 788, 768, 0]},
+    FullDecomp{code: 8020, decomposed: [965, 787, 769, 0]},
+    FullDecomp{code: 8021, decomposed: [965, 788, 769, 0]},
+    FullDecomp{code: 8022, decomposed: [965, 787, 834, 0]},
+    FullDecomp{code: 8023, decomposed: [965, 788, 834, 0]},
+    FullDecomp{code: 8027, decomposed: [933, 788, 768, 0]},
+    FullDecomp{code: 8029, decomposed: [933, 788, 769, 0]},
+    FullDecomp{code: 8031, decomposed: [933, 788, 834, 0]},
+    FullDecomp{code: 8034, decomposed: [969, 787, 768, 0]},
+    FullDecomp{code: 8035, decomposed: [969, 788, 768, 0]},
+    FullDecomp{code: 8036, decomposed: [969, 787, 769, 0]},
+    FullDecomp{code: 8037, decomposed: [969, 788, 769, 0]},
+    FullDecomp{code: 8038, decomposed: [969, 787, 834, 0]},
+    FullDecomp{code: 8039, decomposed: [969, 788, 834, 0]},
+    FullDecomp{code: 8042, decomposed: [937, 787, 768, 0]},
+    FullDecomp{code: 8043, decomposed: [937, 788, 768, 0]},
+    FullDecomp{code: 8044, decomposed: [937, 787, 769, 0]},
+    FullDecomp{code: 8045, decomposed: [937, 788, 769, 0]},
+    FullDecomp{code: 8046, decomposed: [937, 787, 834, 0]},
+    FullDecomp{code: 8047, decomposed: [937, 788, 834, 0]},
+    FullDecomp{code: 8049, decomposed: [945, 769, 0, 0]},
+    FullDecomp{code: 8051, decomposed: [949, 769, 0, 0]},
+    FullDecomp{code: 8053, decomposed: [951, 769, 0, 0]},
+    FullDecomp{code: 8055, decomposed: [953, 769, 0, 0]},
+    FullDecomp{code: 8057, decomposed: [959, 769, 0, 0]},
+    FullDecomp{code: 8059, decomposed: [965, 769, 0, 0]},
+    FullDecomp{code: 8061, decomposed: [969, 769, 0, 0]},
+    FullDecomp{code: 8064, decomposed: [945, 787, 837, 0]},
+    FullDecomp{code: 8065, decomposed: [945, 788, 837, 0]},
+    FullDecomp{code: 8066, decomposed: [945, 787, 768, 837]},
+    FullDecomp{code: 8067, decomposed: [945, 788, 768, 837]},
+    FullDecomp{code: 8068, decomposed: [945, 787, 769, 837]},
+    FullDecomp{code: 8069, decomposed: [945, 788, 769, 837]},
+    FullDecomp{code: 8070, decomposed: [945, 787, 834, 837]},
+    FullDecomp{code: 8071, decomposed: [945, 788, 834, 837]},
+    FullDecomp{code: 8072, decomposed: [913, 787, 837, 0]},
+    FullDecomp{code: 8073, decomposed: [913, 788, 837, 0]},
+    FullDecomp{code: 8074, decomposed: [913, 787, 768, 837]},
+    FullDecomp{code: 8075, decomposed: [913, 788, 768, 837]},
+    FullDecomp{code: 8076, decomposed: [913, 787, 769, 837]},
+    FullDecomp{code: 8077, decomposed: [913, 788, 769, 837]},
+    FullDecomp{code: 8078, decomposed: [913, 787, 834, 837]},
+    FullDecomp{code: 8079, decomposed: [913, 788, 834, 837]},
+    FullDecomp{code: 8080, decomposed: [951, 787, 837, 0]},
+    FullDecomp{code: 8081, decomposed: [951, 788, 837, 0]},
+    FullDecomp{code: 8082, decomposed: [951, 787, 768, 837]},
+    FullDecomp{code: 8083, decomposed: [951, 788, 768, 837]},
+    FullDecomp{code: 8084, decomposed: [951, 787, 769, 837]},
+    FullDecomp{code: 8085, decomposed: [951, 788, 769, 837]},
+    FullDecomp{code: 8086, decomposed: [951, 787, 834, 837]},
+    FullDecomp{code: 8087, decomposed: [951, 788, 834, 837]},
+    FullDecomp{code: 8088, decomposed: [919, 787, 837, 0]},
+    FullDecomp{code: 8089, decomposed: [919, 788, 837, 0]},
+    FullDecomp{code: 8090, decomposed: [919, 787, 768, 837]},
+    FullDecomp{code: 8091, decomposed: [919, 788, 768, 837]},
+    FullDecomp{code: 8092, decomposed: [919, 787, 769, 837]},
+    FullDecomp{code: 8093, decomposed: [919, 788, 769, 837]},
+    FullDecomp{code: 8094, decomposed: [919, 787, 834, 837]},
+    FullDecomp{code: 8095, decomposed: [919, 788, 834, 837]},
+    FullDecomp{code: 8096, decomposed: [969, 787, 837, 0]},
+    FullDecomp{code: 8097, decomposed: [969, 788, 837, 0]},
+    FullDecomp{code: 8098, decomposed: [969, 787, 768, 837]},
+    FullDecomp{code: 8099, decomposed: [969, 788, 768, 837]},
+    FullDecomp{code: 8100, decomposed: [969, 787, 769, 837]},
+    FullDecomp{code: 8101, decomposed: [969, 788, 769, 837]},
+    FullDecomp{code: 8102, decomposed: [969, 787, 834, 837]},
+    FullDecomp{code: 8103, decomposed: [969, 788, 834, 837]},
+    FullDecomp{code: 8104, decomposed: [937, 787, 837, 0]},
+    FullDecomp{code: 8105, decomposed: [937, 788, 837, 0]},
+    FullDecomp{code: 8106, decomposed: [937, 787, 768, 837]},
+    FullDecomp{code: 8107, decomposed: [937, 788, 768, 837]},
+    FullDecomp{code: 8108, decomposed: [937, 787, 769, 837]},
+    FullDecomp{code: 8109, decomposed: [937, 788, 769, 837]},
+    FullDecomp{code: 8110, decomposed: [937, 787, 834, 837]},
+    FullDecomp{code: 8111, decomposed: [937, 788, 834, 837]},
+    FullDecomp{code: 8114, decomposed: [945, 768, 837, 0]},
+    FullDecomp{code: 8116, decomposed: [945, 769, 837, 0]},
+    FullDecomp{code: 8119, decomposed: [945, 834, 837, 0]},
+    FullDecomp{code: 8123, decomposed: [913, 769, 0, 0]},
+    FullDecomp{code: 8130, decomposed: [951, 768, 837, 0]},
+    FullDecomp{code: 8132, decomposed: [951, 769, 837, 0]},
+    FullDecomp{code: 8135, decomposed: [951, 834, 837, 0]},
+    FullDecomp{code: 8137, decomposed: [917, 769, 0, 0]},
+    FullDecomp{code: 8139, decomposed: [919, 769, 0, 0]},
+    FullDecomp{code: 8146, decomposed: [953, 776, 768, 0]},
+    FullDecomp{code: 8147, decomposed: [953, 776, 769, 0]},
+    FullDecomp{code: 8151, decomposed: [953, 776, 834, 0]},
+    FullDecomp{code: 8155, decomposed: [921, 769, 0, 0]},
+    FullDecomp{code: 8162, decomposed: [965, 776, 768, 0]},
+    FullDecomp{code: 8163, decomposed: [965, 776, 769, 0]},
+    FullDecomp{code: 8167, decomposed: [965, 776, 834, 0]},
+    FullDecomp{code: 8171, decomposed: [933, 769, 0, 0]},
+    FullDecomp{code: 8174, decomposed: [168, 769, 0, 0]},
+    FullDecomp{code: 8178, decomposed: [969, 768, 837, 0]},
+    FullDecomp{code: 8180, decomposed: [969, 769, 837, 0]},
+    FullDecomp{code: 8183, decomposed: [969, 834, 837, 0]},
+    FullDecomp{code: 8185, decomposed: [927, 769, 0, 0]},
+    FullDecomp{code: 8187, decomposed: [937, 769, 0, 0]},
+    FullDecomp{code: 8491, decomposed: [65, 778, 0, 0]},
+    FullDecomp{code: 64300, decomposed: [1513, 1468, 1473, 0]},
+    FullDecomp{code: 64301, decomposed: [1513, 1468, 1474, 0]},
+    FullDecomp{code: 119136, decomposed: [119128, 119141, 119150, 0]},
+    FullDecomp{code: 119137, decomposed: [119128, 119141, 119151, 0]},
+    FullDecomp{code: 119138, decomposed: [119128, 119141, 119152, 0]},
+    FullDecomp{code: 119139, decomposed: [119128, 119141, 119153, 0]},
+    FullDecomp{code: 119140, decomposed: [119128, 119141, 119154, 0]},
+    FullDecomp{code: 119229, decomposed: [119225, 119141, 119150, 0]},
+    FullDecomp{code: 119230, decomposed: [119226, 119141, 119150, 0]},
+    FullDecomp{code: 119231, decomposed: [119225, 119141, 119151, 0]},
+    FullDecomp{code: 119232, decomposed: [119226, 119141, 119151, 0]},
+];
+
+/* NFKC_Casefold mapping data */
+pub static NFKC_CF_DATA: [u16; 9415] = [
+    0, 257, 97, 257, 98, 257, 99, 257, 100, 257, 101, 257, 102, 257, 103, 
+    257, 104, 257, 105, 257, 106, 257, 107, 257, 108, 257, 109, 257, 110, 
+    257, 111, 257, 112, 257, 113, 257, 114, 257, 115, 257, 116, 257, 117, 
+    257, 118, 257, 119, 257, 120, 257, 121, 257, 122, 257, 32, 513, 32, 776, 
+    1, 513, 32, 772, 257, 50, 257, 51, 513, 32, 769, 257, 956, 513, 32, 807, 
+    257, 49, 769, 49, 8260, 52, 769, 49, 8260, 50, 769, 51, 8260, 52, 257, 
+    224, 257, 225, 257, 226, 257, 227, 257, 228, 257, 229, 257, 230, 257, 
+    231, 257, 232, 257, 233, 257, 234, 257, 235, 257, 236, 257, 237, 257, 
+    238, 257, 239, 257, 240, 257, 241, 257, 242, 257, 243, 257, 244, 257, 
+    245, 257, 246, 257, 248, 257, 249, 257, 250, 257, 251, 257, 252, 257, 
+    253, 257, 254, 513, 115, 115, 257, 257, 257, 259, 257, 261, 257, 263, 
+    257, 265, 257, 267, 257, 269, 257, 271, 257, 273, 257, 275, 257, 277, 
+    257, 279, 257, 281, 257, 283, 257, 285, 257, 287, 257, 289, 257, 291, 
+    257, 293, 257, 295, 257, 297, 257, 299, 257, 301, 257, 303, 513, 105, 
+    775, 513, 105, 106, 257, 309, 257, 311, 257, 314, 257, 316, 257, 318, 
+    513, 108, 183, 257, 322, 257, 324, 257, 326, 257, 328, 513, 700, 110, 
+    257, 331, 257, 333, 257, 335, 257, 337, 257, 339, 257, 341, 257, 343, 
+    257, 345, 257, 347, 257, 349, 257, 351, 257, 353, 257, 355, 257, 357, 
+    257, 359, 257, 361, 257, 363, 257, 365, 257, 367, 257, 369, 257, 371, 
+    257, 373, 257, 375, 257, 255, 257, 378, 257, 380, 257, 382, 257, 595, 
+    257, 387, 257, 389, 257, 596, 257, 392, 257, 598, 257, 599, 257, 396, 
+    257, 477, 257, 601, 257, 603, 257, 402, 257, 608, 257, 611, 257, 617, 
+    257, 616, 257, 409, 257, 623, 257, 626, 257, 629, 257, 417, 257, 419, 
+    257, 421, 257, 640, 257, 424, 257, 643, 257, 429, 257, 648, 257, 432, 
+    257, 650, 257, 651, 257, 436, 257, 438, 257, 658, 257, 441, 257, 445, 
+    513, 100, 382, 513, 108, 106, 513, 110, 106, 257, 462, 257, 464, 257, 
+    466, 257, 468, 257, 470, 257, 472, 257, 474, 257, 476, 257, 479, 257, 
+    481, 257, 483, 257, 485, 257, 487, 257, 489, 257, 491, 257, 493, 257, 
+    495, 513, 100, 122, 257, 501, 257, 405, 257, 447, 257, 505, 257, 507, 
+    257, 509, 257, 511, 257, 513, 257, 515, 257, 517, 257, 519, 257, 521, 
+    257, 523, 257, 525, 257, 527, 257, 529, 257, 531, 257, 533, 257, 535, 
+    257, 537, 257, 539, 257, 541, 257, 543, 257, 414, 257, 547, 257, 549, 
+    257, 551, 257, 553, 257, 555, 257, 557, 257, 559, 257, 561, 257, 563, 
+    257, 11365, 257, 572, 257, 410, 257, 11366, 257, 578, 257, 384, 257, 649, 
+    257, 652, 257, 583, 257, 585, 257, 587, 257, 589, 257, 591, 257, 614, 
+    257, 633, 257, 635, 257, 641, 513, 32, 774, 513, 32, 775, 513, 32, 778, 
+    513, 32, 808, 513, 32, 771, 513, 32, 779, 257, 661, 257, 768, 257, 769, 
+    257, 787, 513, 776, 769, 257, 953, 257, 881, 257, 883, 257, 697, 257, 
+    887, 513, 32, 953, 257, 59, 257, 1011, 769, 32, 776, 769, 257, 940, 257, 
+    183, 257, 941, 257, 942, 257, 943, 257, 972, 257, 973, 257, 974, 257, 
+    945, 257, 946, 257, 947, 257, 948, 257, 949, 257, 950, 257, 951, 257, 
+    952, 257, 954, 257, 955, 257, 957, 257, 958, 257, 959, 257, 960, 257, 
+    961, 257, 963, 257, 964, 257, 965, 257, 966, 257, 967, 257, 968, 257, 
+    969, 257, 970, 257, 971, 257, 983, 257, 985, 257, 987, 257, 989, 257, 
+    991, 257, 993, 257, 995, 257, 997, 257, 999, 257, 1001, 257, 1003, 257, 
+    1005, 257, 1007, 257, 1016, 257, 1019, 257, 891, 257, 892, 257, 893, 257, 
+    1104, 257, 1105, 257, 1106, 257, 1107, 257, 1108, 257, 1109, 257, 1110, 
+    257, 1111, 257, 1112, 257, 1113, 257, 1114, 257, 1115, 257, 1116, 257, 
+    1117, 257, 1118, 257, 1119, 257, 1072, 257, 1073, 257, 1074, 257, 1075, 
+    257, 1076, 257, 1077, 257, 1078, 257, 1079, 257, 1080, 257, 1081, 257, 
+    1082, 257, 1083, 257, 1084, 257, 1085, 257, 1086, 257, 1087, 257, 1088, 
+    257, 1089, 257, 1090, 257, 1091, 257, 1092, 257, 1093, 257, 1094, 257, 
+    1095, 257, 1096, 257, 1097, 257, 1098, 257, 1099, 257, 1100, 257, 1101, 
+    257, 1102, 257, 1103, 257, 1121, 257, 1123, 257, 1125, 257, 1127, 257, 
+    1129, 257, 1131, 257, 1133, 257, 1135, 257, 1137, 257, 1139, 257, 1141, 
+    257, 1143, 257, 1145, 257, 1147, 257, 1149, 257, 1151, 257, 1153, 257, 
+    1163, 257, 1165, 257, 1167, 257, 1169, 257, 1171, 257, 1173, 257, 1175, 
+    257, 1177, 257, 1179, 257, 1181, 257, 1183, 257, 1185, 257, 1187, 257, 
+    1189, 257, 1191, 257, 1193, 257, 1195, 257, 1197, 257, 1199, 257, 1201, 
+    257, 1203, 257, 1205, 257, 1207, 257, 1209, 257, 1211, 257, 1213, 257, 
+    1215, 257, 1231, 257, 1218, 257, 1220, 257, 1222, 257, 1224, 257, 1226, 
+    257, 1228, 257, 1230, 257, 1233, 257, 1235, 257, 1237, 257, 1239, 257, 
+    1241, 257, 1243, 257, 1245, 257, 1247, 257, 1249, 257, 1251, 257, 1253, 
+    257, 1255, 257, 1257, 257, 1259, 257, 1261, 257, 1263, 257, 1265, 257, 
+    1267, 257, 1269, 257, 1271, 257, 1273, 257, 1275, 257, 1277, 257, 1279, 
+    257, 1281, 257, 1283, 257, 1285, 257, 1287, 257, 1289, 257, 1291, 257, 
+    1293, 257, 1295, 257, 1297, 257, 1299, 257, 1301, 257, 1303, 257, 1305, 
+    257, 1307, 257, 1309, 257, 1311, 257, 1313, 257, 1315, 257, 1317, 257, 
+    1319, 257, 1321, 257, 1323, 257, 1325, 257, 1327, 257, 1377, 257, 1378, 
+    257, 1379, 257, 1380, 257, 1381, 257, 1382, 257, 1383, 257, 1384, 257, 
+    1385, 257, 1386, 257, 1387, 257, 1388, 257, 1389, 257, 1390, 257, 1391, 
+    257, 1392, 257, 1393, 257, 1394, 257, 1395, 257, 1396, 257, 1397, 257, 
+    1398, 257, 1399, 257, 1400, 257, 1401, 257, 1402, 257, 1403, 257, 1404, 
+    257, 1405, 257, 1406, 257, 1407, 257, 1408, 257, 1409, 257, 1410, 257, 
+    1411, 257, 1412, 257, 1413, 257, 1414, 513, 1381, 1410, 513, 1575, 1652, 
+    513, 1608, 1652, 513, 1735, 1652, 513, 1610, 1652, 513, 2325, 2364, 513, 
+    2326, 2364, 513, 2327, 2364, 513, 2332, 2364, 513, 2337, 2364, 513, 2338, 
+    2364, 513, 2347, 2364, 513, 2351, 2364, 513, 2465, 2492, 513, 2466, 2492, 
+    513, 2479, 2492, 513, 2610, 2620, 513, 2616, 2620, 513, 2582, 2620, 513, 
+    2583, 2620, 513, 2588, 2620, 513, 2603, 2620, 513, 2849, 2876, 513, 2850, 
+    2876, 513, 3661, 3634, 513, 3789, 3762, 513, 3755, 3737, 513, 3755, 3745, 
+    257, 3851, 513, 3906, 4023, 513, 3916, 4023, 513, 3921, 4023, 513, 3926, 
+    4023, 513, 3931, 4023, 513, 3904, 4021, 513, 3953, 3954, 513, 3953, 3956, 
+    513, 4018, 3968, 769, 4018, 3953, 3968, 513, 4019, 3968, 769, 4019, 3953, 
+    3968, 513, 3953, 3968, 513, 3986, 4023, 513, 3996, 4023, 513, 4001, 4023, 
+    513, 4006, 4023, 513, 4011, 4023, 513, 3984, 4021, 257, 11520, 257, 
+    11521, 257, 11522, 257, 11523, 257, 11524, 257, 11525, 257, 11526, 257, 
+    11527, 257, 11528, 257, 11529, 257, 11530, 257, 11531, 257, 11532, 257, 
+    11533, 257, 11534, 257, 11535, 257, 11536, 257, 11537, 257, 11538, 257, 
+    11539, 257, 11540, 257, 11541, 257, 11542, 257, 11543, 257, 11544, 257, 
+    11545, 257, 11546, 257, 11547, 257, 11548, 257, 11549, 257, 11550, 257, 
+    11551, 257, 11552, 257, 11553, 257, 11554, 257, 11555, 257, 11556, 257, 
+    11557, 257, 11559, 257, 11565, 257, 4316, 257, 5104, 257, 5105, 257, 
+    5106, 257, 5107, 257, 5108, 257, 5109, 257, 42571, 257, 592, 257, 593, 
+    257, 7426, 257, 604, 257, 7446, 257, 7447, 257, 7453, 257, 7461, 257, 
+    594, 257, 597, 257, 607, 257, 609, 257, 613, 257, 618, 257, 7547, 257, 
+    669, 257, 621, 257, 7557, 257, 671, 257, 625, 257, 624, 257, 627, 257, 
+    628, 257, 632, 257, 642, 257, 427, 257, 7452, 257, 656, 257, 657, 257, 
+    7681, 257, 7683, 257, 7685, 257, 7687, 257, 7689, 257, 7691, 257, 7693, 
+    257, 7695, 257, 7697, 257, 7699, 257, 7701, 257, 7703, 257, 7705, 257, 
+    7707, 257, 7709, 257, 7711, 257, 7713, 257, 7715, 257, 7717, 257, 7719, 
+    257, 7721, 257, 7723, 257, 7725, 257, 7727, 257, 7729, 257, 7731, 257, 
+    7733, 257, 7735, 257, 7737, 257, 7739, 257, 7741, 257, 7743, 257, 7745, 
+    257, 7747, 257, 7749, 257, 7751, 257, 7753, 257, 7755, 257, 7757, 257, 
+    7759, 257, 7761, 257, 7763, 257, 7765, 257, 7767, 257, 7769, 257, 7771, 
+    257, 7773, 257, 7775, 257, 7777, 257, 7779, 257, 7781, 257, 7783, 257, 
+    7785, 257, 7787, 257, 7789, 257, 7791, 257, 7793, 257, 7795, 257, 7797, 
+    257, 7799, 257, 7801, 257, 7803, 257, 7805, 257, 7807, 257, 7809, 257, 
+    7811, 257, 7813, 257, 7815, 257, 7817, 257, 7819, 257, 7821, 257, 7823, 
+    257, 7825, 257, 7827, 257, 7829, 513, 97, 702, 257, 7841, 257, 7843, 257, 
+    7845, 257, 7847, 257, 7849, 257, 7851, 257, 7853, 257, 7855, 257, 7857, 
+    257, 7859, 257, 7861, 257, 7863, 257, 7865, 257, 7867, 257, 7869, 257, 
+    7871, 257, 7873, 257, 7875, 257, 7877, 257, 7879, 257, 7881, 257, 7883, 
+    257, 7885, 257, 7887, 257, 7889, 257, 7891, 257, 7893, 257, 7895, 257, 
+    7897, 257, 7899, 257, 7901, 257, 7903, 257, 7905, 257, 7907, 257, 7909, 
+    257, 7911, 257, 7913, 257, 7915, 257, 7917, 257, 7919, 257, 7921, 257, 
+    7923, 257, 7925, 257, 7927, 257, 7929, 257, 7931, 257, 7933, 257, 7935, 
+    257, 7936, 257, 7937, 257, 7938, 257, 7939, 257, 7940, 257, 7941, 257, 
+    7942, 257, 7943, 257, 7952, 257, 7953, 257, 7954, 257, 7955, 257, 7956, 
+    257, 7957, 257, 7968, 257, 7969, 257, 7970, 257, 7971, 257, 7972, 257, 
+    7973, 257, 7974, 257, 7975, 257, 7984, 257, 7985, 257, 7986, 257, 7987, 
+    257, 7988, 257, 7989, 257, 7990, 257, 7991, 257, 8000, 257, 8001, 257, 
+    8002, 257, 8003, 257, 8004, 257, 8005, 257, 8017, 257, 8019, 257, 8021, 
+    257, 8023, 257, 8032, 257, 8033, 257, 8034, 257, 8035, 257, 8036, 257, 
+    8037, 257, 8038, 257, 8039, 513, 7936, 953, 513, 7937, 953, 513, 7938, 
+    953, 513, 7939, 953, 513, 7940, 953, 513, 7941, 953, 513, 7942, 953, 513, 
+    7943, 953, 513, 7968, 953, 513, 7969, 953, 513, 7970, 953, 513, 7971, 
+    953, 513, 7972, 953, 513, 7973, 953, 513, 7974, 953, 513, 7975, 953, 513, 
+    8032, 953, 513, 8033, 953, 513, 8034, 953, 513, 8035, 953, 513, 8036, 
+    953, 513, 8037, 953, 513, 8038, 953, 513, 8039, 953, 513, 8048, 953, 513, 
+    945, 953, 513, 940, 953, 513, 8118, 953, 257, 8112, 257, 8113, 257, 8048, 
+    513, 32, 787, 513, 32, 834, 769, 32, 776, 834, 513, 8052, 953, 513, 951, 
+    953, 513, 942, 953, 513, 8134, 953, 257, 8050, 257, 8052, 769, 32, 787, 
+    768, 769, 32, 787, 769, 769, 32, 787, 834, 257, 912, 257, 8144, 257, 
+    8145, 257, 8054, 769, 32, 788, 768, 769, 32, 788, 769, 769, 32, 788, 834, 
+    257, 944, 257, 8160, 257, 8161, 257, 8058, 257, 8165, 769, 32, 776, 768, 
+    257, 96, 513, 8060, 953, 513, 969, 953, 513, 974, 953, 513, 8182, 953, 
+    257, 8056, 257, 8060, 513, 32, 788, 257, 8208, 513, 32, 819, 257, 46, 
+    513, 46, 46, 769, 46, 46, 46, 513, 8242, 8242, 769, 8242, 8242, 8242, 
+    513, 8245, 8245, 769, 8245, 8245, 8245, 513, 33, 33, 513, 32, 773, 513, 
+    63, 63, 513, 63, 33, 513, 33, 63, 1025, 8242, 8242, 8242, 8242, 257, 48, 
+    257, 52, 257, 53, 257, 54, 257, 55, 257, 56, 257, 57, 257, 43, 257, 8722, 
+    257, 61, 257, 40, 257, 41, 513, 114, 115, 769, 97, 47, 99, 769, 97, 47, 
+    115, 513, 176, 99, 769, 99, 47, 111, 769, 99, 47, 117, 513, 176, 102, 
+    513, 110, 111, 513, 115, 109, 769, 116, 101, 108, 513, 116, 109, 257, 
+    8526, 257, 1488, 257, 1489, 257, 1490, 257, 1491, 769, 102, 97, 120, 257, 
+    8721, 769, 49, 8260, 55, 769, 49, 8260, 57, 1025, 49, 8260, 49, 48, 769, 
+    49, 8260, 51, 769, 50, 8260, 51, 769, 49, 8260, 53, 769, 50, 8260, 53, 
+    769, 51, 8260, 53, 769, 52, 8260, 53, 769, 49, 8260, 54, 769, 53, 8260, 
+    54, 769, 49, 8260, 56, 769, 51, 8260, 56, 769, 53, 8260, 56, 769, 55, 
+    8260, 56, 513, 49, 8260, 513, 105, 105, 769, 105, 105, 105, 513, 105, 
+    118, 513, 118, 105, 769, 118, 105, 105, 1025, 118, 105, 105, 105, 513, 
+    105, 120, 513, 120, 105, 769, 120, 105, 105, 257, 8580, 769, 48, 8260, 
+    51, 513, 8747, 8747, 769, 8747, 8747, 8747, 513, 8750, 8750, 769, 8750, 
+    8750, 8750, 257, 12296, 257, 12297, 513, 49, 48, 513, 49, 49, 513, 49, 
+    50, 513, 49, 51, 513, 49, 52, 513, 49, 53, 513, 49, 54, 513, 49, 55, 513, 
+    49, 56, 513, 49, 57, 513, 50, 48, 769, 40, 49, 41, 769, 40, 50, 41, 769, 
+    40, 51, 41, 769, 40, 52, 41, 769, 40, 53, 41, 769, 40, 54, 41, 769, 40, 
+    55, 41, 769, 40, 56, 41, 769, 40, 57, 41, 1025, 40, 49, 48, 41, 1025, 40, 
+    49, 49, 41, 1025, 40, 49, 50, 41, 1025, 40, 49, 51, 41, 1025, 40, 49, 52, 
+    41, 1025, 40, 49, 53, 41, 1025, 40, 49, 54, 41, 1025, 40, 49, 55, 41, 
+    1025, 40, 49, 56, 41, 1025, 40, 49, 57, 41, 1025, 40, 50, 48, 41, 513, 
+    49, 46, 513, 50, 46, 513, 51, 46, 513, 52, 46, 513, 53, 46, 513, 54, 46, 
+    513, 55, 46, 513, 56, 46, 513, 57, 46, 769, 49, 48, 46, 769, 49, 49, 46, 
+    769, 49, 50, 46, 769, 49, 51, 46, 769, 49, 52, 46, 769, 49, 53, 46, 769, 
+    49, 54, 46, 769, 49, 55, 46, 769, 49, 56, 46, 769, 49, 57, 46, 769, 50, 
+    48, 46, 769, 40, 97, 41, 769, 40, 98, 41, 769, 40, 99, 41, 769, 40, 100, 
+    41, 769, 40, 101, 41, 769, 40, 102, 41, 769, 40, 103, 41, 769, 40, 104, 
+    41, 769, 40, 105, 41, 769, 40, 106, 41, 769, 40, 107, 41, 769, 40, 108, 
+    41, 769, 40, 109, 41, 769, 40, 110, 41, 769, 40, 111, 41, 769, 40, 112, 
+    41, 769, 40, 113, 41, 769, 40, 114, 41, 769, 40, 115, 41, 769, 40, 116, 
+    41, 769, 40, 117, 41, 769, 40, 118, 41, 769, 40, 119, 41, 769, 40, 120, 
+    41, 769, 40, 121, 41, 769, 40, 122, 41, 1025, 8747, 8747, 8747, 8747, 
+    769, 58, 58, 61, 513, 61, 61, 769, 61, 61, 61, 513, 10973, 824, 257, 
+    11312, 257, 11313, 257, 11314, 257, 11315, 257, 11316, 257, 11317, 257, 
+    11318, 257, 11319, 257, 11320, 257, 11321, 257, 11322, 257, 11323, 257, 
+    11324, 257, 11325, 257, 11326, 257, 11327, 257, 11328, 257, 11329, 257, 
+    11330, 257, 11331, 257, 11332, 257, 11333, 257, 11334, 257, 11335, 257, 
+    11336, 257, 11337, 257, 11338, 257, 11339, 257, 11340, 257, 11341, 257, 
+    11342, 257, 11343, 257, 11344, 257, 11345, 257, 11346, 257, 11347, 257, 
+    11348, 257, 11349, 257, 11350, 257, 11351, 257, 11352, 257, 11353, 257, 
+    11354, 257, 11355, 257, 11356, 257, 11357, 257, 11358, 257, 11361, 257, 
+    619, 257, 7549, 257, 637, 257, 11368, 257, 11370, 257, 11372, 257, 11379, 
+    257, 11382, 257, 575, 257, 576, 257, 11393, 257, 11395, 257, 11397, 257, 
+    11399, 257, 11401, 257, 11403, 257, 11405, 257, 11407, 257, 11409, 257, 
+    11411, 257, 11413, 257, 11415, 257, 11417, 257, 11419, 257, 11421, 257, 
+    11423, 257, 11425, 257, 11427, 257, 11429, 257, 11431, 257, 11433, 257, 
+    11435, 257, 11437, 257, 11439, 257, 11441, 257, 11443, 257, 11445, 257, 
+    11447, 257, 11449, 257, 11451, 257, 11453, 257, 11455, 257, 11457, 257, 
+    11459, 257, 11461, 257, 11463, 257, 11465, 257, 11467, 257, 11469, 257, 
+    11471, 257, 11473, 257, 11475, 257, 11477, 257, 11479, 257, 11481, 257, 
+    11483, 257, 11485, 257, 11487, 257, 11489, 257, 11491, 257, 11500, 257, 
+    11502, 257, 11507, 257, 11617, 257, 27597, 257, 40863, 257, 19968, 257, 
+    20008, 257, 20022, 257, 20031, 257, 20057, 257, 20101, 257, 20108, 257, 
+    20128, 257, 20154, 257, 20799, 257, 20837, 257, 20843, 257, 20866, 257, 
+    20886, 257, 20907, 257, 20960, 257, 20981, 257, 20992, 257, 21147, 257, 
+    21241, 257, 21269, 257, 21274, 257, 21304, 257, 21313, 257, 21340, 257, 
+    21353, 257, 21378, 257, 21430, 257, 21448, 257, 21475, 257, 22231, 257, 
+    22303, 257, 22763, 257, 22786, 257, 22794, 257, 22805, 257, 22823, 257, 
+    22899, 257, 23376, 257, 23424, 257, 23544, 257, 23567, 257, 23586, 257, 
+    23608, 257, 23662, 257, 23665, 257, 24027, 257, 24037, 257, 24049, 257, 
+    24062, 257, 24178, 257, 24186, 257, 24191, 257, 24308, 257, 24318, 257, 
+    24331, 257, 24339, 257, 24400, 257, 24417, 257, 24435, 257, 24515, 257, 
+    25096, 257, 25142, 257, 25163, 257, 25903, 257, 25908, 257, 25991, 257, 
+    26007, 257, 26020, 257, 26041, 257, 26080, 257, 26085, 257, 26352, 257, 
+    26376, 257, 26408, 257, 27424, 257, 27490, 257, 27513, 257, 27571, 257, 
+    27595, 257, 27604, 257, 27611, 257, 27663, 257, 27668, 257, 27700, 257, 
+    28779, 257, 29226, 257, 29238, 257, 29243, 257, 29247, 257, 29255, 257, 
+    29273, 257, 29275, 257, 29356, 257, 29572, 257, 29577, 257, 29916, 257, 
+    29926, 257, 29976, 257, 29983, 257, 29992, 257, 30000, 257, 30091, 257, 
+    30098, 257, 30326, 257, 30333, 257, 30382, 257, 30399, 257, 30446, 257, 
+    30683, 257, 30690, 257, 30707, 257, 31034, 257, 31160, 257, 31166, 257, 
+    31348, 257, 31435, 257, 31481, 257, 31859, 257, 31992, 257, 32566, 257, 
+    32593, 257, 32650, 257, 32701, 257, 32769, 257, 32780, 257, 32786, 257, 
+    32819, 257, 32895, 257, 32905, 257, 33251, 257, 33258, 257, 33267, 257, 
+    33276, 257, 33292, 257, 33307, 257, 33311, 257, 33390, 257, 33394, 257, 
+    33400, 257, 34381, 257, 34411, 257, 34880, 257, 34892, 257, 34915, 257, 
+    35198, 257, 35211, 257, 35282, 257, 35328, 257, 35895, 257, 35910, 257, 
+    35925, 257, 35960, 257, 35997, 257, 36196, 257, 36208, 257, 36275, 257, 
+    36523, 257, 36554, 257, 36763, 257, 36784, 257, 36789, 257, 37009, 257, 
+    37193, 257, 37318, 257, 37324, 257, 37329, 257, 38263, 257, 38272, 257, 
+    38428, 257, 38582, 257, 38585, 257, 38632, 257, 38737, 257, 38750, 257, 
+    38754, 257, 38761, 257, 38859, 257, 38893, 257, 38899, 257, 38913, 257, 
+    39080, 257, 39131, 257, 39135, 257, 39318, 257, 39321, 257, 39340, 257, 
+    39592, 257, 39640, 257, 39647, 257, 39717, 257, 39727, 257, 39730, 257, 
+    39740, 257, 39770, 257, 40165, 257, 40565, 257, 40575, 257, 40613, 257, 
+    40635, 257, 40643, 257, 40653, 257, 40657, 257, 40697, 257, 40701, 257, 
+    40718, 257, 40723, 257, 40736, 257, 40763, 257, 40778, 257, 40786, 257, 
+    40845, 257, 40860, 257, 40864, 257, 12306, 257, 21316, 257, 21317, 513, 
+    32, 12441, 513, 32, 12442, 513, 12424, 12426, 513, 12467, 12488, 257, 
+    4352, 257, 4353, 257, 4522, 257, 4354, 257, 4524, 257, 4525, 257, 4355, 
+    257, 4356, 257, 4357, 257, 4528, 257, 4529, 257, 4530, 257, 4531, 257, 
+    4532, 257, 4533, 257, 4378, 257, 4358, 257, 4359, 257, 4360, 257, 4385, 
+    257, 4361, 257, 4362, 257, 4363, 257, 4364, 257, 4365, 257, 4366, 257, 
+    4367, 257, 4368, 257, 4369, 257, 4370, 257, 4449, 257, 4450, 257, 4451, 
+    257, 4452, 257, 4453, 257, 4454, 257, 4455, 257, 4456, 257, 4457, 257, 
+    4458, 257, 4459, 257, 4460, 257, 4461, 257, 4462, 257, 4463, 257, 4464, 
+    257, 4465, 257, 4466, 257, 4467, 257, 4468, 257, 4469, 257, 4372, 257, 
+    4373, 257, 4551, 257, 4552, 257, 4556, 257, 4558, 257, 4563, 257, 4567, 
+    257, 4569, 257, 4380, 257, 4573, 257, 4575, 257, 4381, 257, 4382, 257, 
+    4384, 257, 4386, 257, 4387, 257, 4391, 257, 4393, 257, 4395, 257, 4396, 
+    257, 4397, 257, 4398, 257, 4399, 257, 4402, 257, 4406, 257, 4416, 257, 
+    4423, 257, 4428, 257, 4593, 257, 4594, 257, 4439, 257, 4440, 257, 4441, 
+    257, 4484, 257, 4485, 257, 4488, 257, 4497, 257, 4498, 257, 4500, 257, 
+    4510, 257, 4513, 257, 19977, 257, 22235, 257, 19978, 257, 20013, 257, 
+    19979, 257, 30002, 257, 19993, 257, 19969, 257, 22825, 257, 22320, 769, 
+    40, 4352, 41, 769, 40, 4354, 41, 769, 40, 4355, 41, 769, 40, 4357, 41, 
+    769, 40, 4358, 41, 769, 40, 4359, 41, 769, 40, 4361, 41, 769, 40, 4363, 
+    41, 769, 40, 4364, 41, 769, 40, 4366, 41, 769, 40, 4367, 41, 769, 40, 
+    4368, 41, 769, 40, 4369, 41, 769, 40, 4370, 41, 769, 40, 44032, 41, 769, 
+    40, 45208, 41, 769, 40, 45796, 41, 769, 40, 46972, 41, 769, 40, 47560, 
+    41, 769, 40, 48148, 41, 769, 40, 49324, 41, 769, 40, 50500, 41, 769, 40, 
+    51088, 41, 769, 40, 52264, 41, 769, 40, 52852, 41, 769, 40, 53440, 41, 
+    769, 40, 54028, 41, 769, 40, 54616, 41, 769, 40, 51452, 41, 1025, 40, 
+    50724, 51204, 41, 1025, 40, 50724, 54980, 41, 769, 40, 19968, 41, 769, 
+    40, 20108, 41, 769, 40, 19977, 41, 769, 40, 22235, 41, 769, 40, 20116, 
+    41, 769, 40, 20845, 41, 769, 40, 19971, 41, 769, 40, 20843, 41, 769, 40, 
+    20061, 41, 769, 40, 21313, 41, 769, 40, 26376, 41, 769, 40, 28779, 41, 
+    769, 40, 27700, 41, 769, 40, 26408, 41, 769, 40, 37329, 41, 769, 40, 
+    22303, 41, 769, 40, 26085, 41, 769, 40, 26666, 41, 769, 40, 26377, 41, 
+    769, 40, 31038, 41, 769, 40, 21517, 41, 769, 40, 29305, 41, 769, 40, 
+    36001, 41, 769, 40, 31069, 41, 769, 40, 21172, 41, 769, 40, 20195, 41, 
+    769, 40, 21628, 41, 769, 40, 23398, 41, 769, 40, 30435, 41, 769, 40, 
+    20225, 41, 769, 40, 36039, 41, 769, 40, 21332, 41, 769, 40, 31085, 41, 
+    769, 40, 20241, 41, 769, 40, 33258, 41, 769, 40, 33267, 41, 257, 21839, 
+    257, 24188, 257, 31631, 769, 112, 116, 101, 513, 50, 49, 513, 50, 50, 
+    513, 50, 51, 513, 50, 52, 513, 50, 53, 513, 50, 54, 513, 50, 55, 513, 50, 
+    56, 513, 50, 57, 513, 51, 48, 513, 51, 49, 513, 51, 50, 513, 51, 51, 513, 
+    51, 52, 513, 51, 53, 257, 44032, 257, 45208, 257, 45796, 257, 46972, 257, 
+    47560, 257, 48148, 257, 49324, 257, 50500, 257, 51088, 257, 52264, 257, 
+    52852, 257, 53440, 257, 54028, 257, 54616, 513, 52280, 44256, 513, 51452, 
+    51032, 257, 50864, 257, 20116, 257, 20845, 257, 19971, 257, 20061, 257, 
+    26666, 257, 26377, 257, 31038, 257, 21517, 257, 29305, 257, 36001, 257, 
+    31069, 257, 21172, 257, 31192, 257, 30007, 257, 36969, 257, 20778, 257, 
+    21360, 257, 27880, 257, 38917, 257, 20241, 257, 20889, 257, 27491, 257, 
+    24038, 257, 21491, 257, 21307, 257, 23447, 257, 23398, 257, 30435, 257, 
+    20225, 257, 36039, 257, 21332, 257, 22812, 513, 51, 54, 513, 51, 55, 513, 
+    51, 56, 513, 51, 57, 513, 52, 48, 513, 52, 49, 513, 52, 50, 513, 52, 51, 
+    513, 52, 52, 513, 52, 53, 513, 52, 54, 513, 52, 55, 513, 52, 56, 513, 52, 
+    57, 513, 53, 48, 513, 49, 26376, 513, 50, 26376, 513, 51, 26376, 513, 52, 
+    26376, 513, 53, 26376, 513, 54, 26376, 513, 55, 26376, 513, 56, 26376, 
+    513, 57, 26376, 769, 49, 48, 26376, 769, 49, 49, 26376, 769, 49, 50, 
+    26376, 513, 104, 103, 769, 101, 114, 103, 513, 101, 118, 769, 108, 116, 
+    100, 257, 12450, 257, 12452, 257, 12454, 257, 12456, 257, 12458, 257, 
+    12459, 257, 12461, 257, 12463, 257, 12465, 257, 12467, 257, 12469, 257, 
+    12471, 257, 12473, 257, 12475, 257, 12477, 257, 12479, 257, 12481, 257, 
+    12484, 257, 12486, 257, 12488, 257, 12490, 257, 12491, 257, 12492, 257, 
+    12493, 257, 12494, 257, 12495, 257, 12498, 257, 12501, 257, 12504, 257, 
+    12507, 257, 12510, 257, 12511, 257, 12512, 257, 12513, 257, 12514, 257, 
+    12516, 257, 12518, 257, 12520, 257, 12521, 257, 12522, 257, 12523, 257, 
+    12524, 257, 12525, 257, 12527, 257, 12528, 257, 12529, 257, 12530, 1025, 
+    12450, 12497, 12540, 12488, 1025, 12450, 12523, 12501, 12449, 1025, 
+    12450, 12531, 12506, 12450, 769, 12450, 12540, 12523, 1025, 12452, 12491, 
+    12531, 12464, 769, 12452, 12531, 12481, 769, 12454, 12457, 12531, 1281, 
+    12456, 12473, 12463, 12540, 12489, 1025, 12456, 12540, 12459, 12540, 769, 
+    12458, 12531, 12473, 769, 12458, 12540, 12512, 769, 12459, 12452, 12522, 
+    1025, 12459, 12521, 12483, 12488, 1025, 12459, 12525, 12522, 12540, 769, 
+    12460, 12525, 12531, 769, 12460, 12531, 12510, 513, 12462, 12460, 769, 
+    12462, 12491, 12540, 1025, 12461, 12517, 12522, 12540, 1025, 12462, 
+    12523, 12480, 12540, 513, 12461, 12525, 1281, 12461, 12525, 12464, 12521, 
+    12512, 1537, 12461, 12525, 12513, 12540, 12488, 12523, 1281, 12461, 
+    12525, 12527, 12483, 12488, 769, 12464, 12521, 12512, 1281, 12464, 12521, 
+    12512, 12488, 12531, 1281, 12463, 12523, 12476, 12452, 12525, 1025, 
+    12463, 12525, 12540, 12493, 769, 12465, 12540, 12473, 769, 12467, 12523, 
+    12490, 769, 12467, 12540, 12509, 1025, 12469, 12452, 12463, 12523, 1281, 
+    12469, 12531, 12481, 12540, 12512, 1025, 12471, 12522, 12531, 12464, 769, 
+    12475, 12531, 12481, 769, 12475, 12531, 12488, 769, 12480, 12540, 12473, 
+    513, 12487, 12471, 513, 12489, 12523, 513, 12488, 12531, 513, 12490, 
+    12494, 769, 12494, 12483, 12488, 769, 12495, 12452, 12484, 1281, 12497, 
+    12540, 12475, 12531, 12488, 769, 12497, 12540, 12484, 1025, 12496, 12540, 
+    12524, 12523, 1281, 12500, 12450, 12473, 12488, 12523, 769, 12500, 12463, 
+    12523, 513, 12500, 12467, 513, 12499, 12523, 1281, 12501, 12449, 12521, 
+    12483, 12489, 1025, 12501, 12451, 12540, 12488, 1281, 12502, 12483, 
+    12471, 12455, 12523, 769, 12501, 12521, 12531, 1281, 12504, 12463, 12479, 
+    12540, 12523, 513, 12506, 12477, 769, 12506, 12491, 12498, 769, 12504, 
+    12523, 12484, 769, 12506, 12531, 12473, 769, 12506, 12540, 12472, 769, 
+    12505, 12540, 12479, 1025, 12509, 12452, 12531, 12488, 769, 12508, 12523, 
+    12488, 513, 12507, 12531, 769, 12509, 12531, 12489, 769, 12507, 12540, 
+    12523, 769, 12507, 12540, 12531, 1025, 12510, 12452, 12463, 12525, 769, 
+    12510, 12452, 12523, 769, 12510, 12483, 12495, 769, 12510, 12523, 12463, 
+    1281, 12510, 12531, 12471, 12519, 12531, 1025, 12511, 12463, 12525, 
+    12531, 513, 12511, 12522, 1281, 12511, 12522, 12496, 12540, 12523, 513, 
+    12513, 12460, 1025, 12513, 12460, 12488, 12531, 1025, 12513, 12540, 
+    12488, 12523, 769, 12516, 12540, 12489, 769, 12516, 12540, 12523, 769, 
+    12518, 12450, 12531, 1025, 12522, 12483, 12488, 12523, 513, 12522, 12521, 
+    769, 12523, 12500, 12540, 1025, 12523, 12540, 12502, 12523, 513, 12524, 
+    12512, 1281, 12524, 12531, 12488, 12466, 12531, 769, 12527, 12483, 12488, 
+    513, 48, 28857, 513, 49, 28857, 513, 50, 28857, 513, 51, 28857, 513, 52, 
+    28857, 513, 53, 28857, 513, 54, 28857, 513, 55, 28857, 513, 56, 28857, 
+    513, 57, 28857, 769, 49, 48, 28857, 769, 49, 49, 28857, 769, 49, 50, 
+    28857, 769, 49, 51, 28857, 769, 49, 52, 28857, 769, 49, 53, 28857, 769, 
+    49, 54, 28857, 769, 49, 55, 28857, 769, 49, 56, 28857, 769, 49, 57, 
+    28857, 769, 50, 48, 28857, 769, 50, 49, 28857, 769, 50, 50, 28857, 769, 
+    50, 51, 28857, 769, 50, 52, 28857, 769, 104, 112, 97, 513, 100, 97, 513, 
+    97, 117, 769, 98, 97, 114, 513, 111, 118, 513, 112, 99, 513, 100, 109, 
+    769, 100, 109, 50, 769, 100, 109, 51, 513, 105, 117, 513, 24179, 25104, 
+    513, 26157, 21644, 513, 22823, 27491, 513, 26126, 27835, 1025, 26666, 
+    24335, 20250, 31038, 513, 112, 97, 513, 110, 97, 513, 956, 97, 513, 109, 
+    97, 513, 107, 97, 513, 107, 98, 513, 109, 98, 513, 103, 98, 769, 99, 97, 
+    108, 1025, 107, 99, 97, 108, 513, 112, 102, 513, 110, 102, 513, 956, 102, 
+    513, 956, 103, 513, 109, 103, 513, 107, 103, 513, 104, 122, 769, 107, 
+    104, 122, 769, 109, 104, 122, 769, 103, 104, 122, 769, 116, 104, 122, 
+    513, 956, 108, 513, 109, 108, 513, 100, 108, 513, 107, 108, 513, 102, 
+    109, 513, 110, 109, 513, 956, 109, 513, 109, 109, 513, 99, 109, 513, 107, 
+    109, 769, 109, 109, 50, 769, 99, 109, 50, 513, 109, 50, 769, 107, 109, 
+    50, 769, 109, 109, 51, 769, 99, 109, 51, 513, 109, 51, 769, 107, 109, 51, 
+    769, 109, 8725, 115, 1025, 109, 8725, 115, 50, 769, 107, 112, 97, 769, 
+    109, 112, 97, 769, 103, 112, 97, 769, 114, 97, 100, 1281, 114, 97, 100, 
+    8725, 115, 1537, 114, 97, 100, 8725, 115, 50, 513, 112, 115, 513, 110, 
+    115, 513, 956, 115, 513, 109, 115, 513, 112, 118, 513, 110, 118, 513, 
+    956, 118, 513, 109, 118, 513, 107, 118, 513, 112, 119, 513, 110, 119, 
+    513, 956, 119, 513, 109, 119, 513, 107, 119, 513, 107, 969, 513, 109, 
+    969, 1025, 97, 46, 109, 46, 513, 98, 113, 513, 99, 99, 513, 99, 100, 
+    1025, 99, 8725, 107, 103, 769, 99, 111, 46, 513, 100, 98, 513, 103, 121, 
+    513, 104, 97, 513, 104, 112, 513, 105, 110, 513, 107, 107, 513, 107, 116, 
+    513, 108, 109, 513, 108, 110, 769, 108, 111, 103, 513, 108, 120, 769, 
+    109, 105, 108, 769, 109, 111, 108, 513, 112, 104, 1025, 112, 46, 109, 46, 
+    769, 112, 112, 109, 513, 112, 114, 513, 115, 114, 513, 115, 118, 513, 
+    119, 98, 769, 118, 8725, 109, 769, 97, 8725, 109, 513, 49, 26085, 513, 
+    50, 26085, 513, 51, 26085, 513, 52, 26085, 513, 53, 26085, 513, 54, 
+    26085, 513, 55, 26085, 513, 56, 26085, 513, 57, 26085, 769, 49, 48, 
+    26085, 769, 49, 49, 26085, 769, 49, 50, 26085, 769, 49, 51, 26085, 769, 
+    49, 52, 26085, 769, 49, 53, 26085, 769, 49, 54, 26085, 769, 49, 55, 
+    26085, 769, 49, 56, 26085, 769, 49, 57, 26085, 769, 50, 48, 26085, 769, 
+    50, 49, 26085, 769, 50, 50, 26085, 769, 50, 51, 26085, 769, 50, 52, 
+    26085, 769, 50, 53, 26085, 769, 50, 54, 26085, 769, 50, 55, 26085, 769, 
+    50, 56, 26085, 769, 50, 57, 26085, 769, 51, 48, 26085, 769, 51, 49, 
+    26085, 769, 103, 97, 108, 257, 42561, 257, 42563, 257, 42565, 257, 42567, 
+    257, 42569, 257, 42573, 257, 42575, 257, 42577, 257, 42579, 257, 42581, 
+    257, 42583, 257, 42585, 257, 42587, 257, 42589, 257, 42591, 257, 42593, 
+    257, 42595, 257, 42597, 257, 42599, 257, 42601, 257, 42603, 257, 42605, 
+    257, 42625, 257, 42627, 257, 42629, 257, 42631, 257, 42633, 257, 42635, 
+    257, 42637, 257, 42639, 257, 42641, 257, 42643, 257, 42645, 257, 42647, 
+    257, 42649, 257, 42651, 257, 42787, 257, 42789, 257, 42791, 257, 42793, 
+    257, 42795, 257, 42797, 257, 42799, 257, 42803, 257, 42805, 257, 42807, 
+    257, 42809, 257, 42811, 257, 42813, 257, 42815, 257, 42817, 257, 42819, 
+    257, 42821, 257, 42823, 257, 42825, 257, 42827, 257, 42829, 257, 42831, 
+    257, 42833, 257, 42835, 257, 42837, 257, 42839, 257, 42841, 257, 42843, 
+    257, 42845, 257, 42847, 257, 42849, 257, 42851, 257, 42853, 257, 42855, 
+    257, 42857, 257, 42859, 257, 42861, 257, 42863, 257, 42874, 257, 42876, 
+    257, 7545, 257, 42879, 257, 42881, 257, 42883, 257, 42885, 257, 42887, 
+    257, 42892, 257, 42897, 257, 42899, 257, 42903, 257, 42905, 257, 42907, 
+    257, 42909, 257, 42911, 257, 42913, 257, 42915, 257, 42917, 257, 42919, 
+    257, 42921, 257, 620, 257, 670, 257, 647, 257, 43859, 257, 42933, 257, 
+    42935, 257, 43831, 257, 43858, 257, 5024, 257, 5025, 257, 5026, 257, 
+    5027, 257, 5028, 257, 5029, 257, 5030, 257, 5031, 257, 5032, 257, 5033, 
+    257, 5034, 257, 5035, 257, 5036, 257, 5037, 257, 5038, 257, 5039, 257, 
+    5040, 257, 5041, 257, 5042, 257, 5043, 257, 5044, 257, 5045, 257, 5046, 
+    257, 5047, 257, 5048, 257, 5049, 257, 5050, 257, 5051, 257, 5052, 257, 
+    5053, 257, 5054, 257, 5055, 257, 5056, 257, 5057, 257, 5058, 257, 5059, 
+    257, 5060, 257, 5061, 257, 5062, 257, 5063, 257, 5064, 257, 5065, 257, 
+    5066, 257, 5067, 257, 5068, 257, 5069, 257, 5070, 257, 5071, 257, 5072, 
+    257, 5073, 257, 5074, 257, 5075, 257, 5076, 257, 5077, 257, 5078, 257, 
+    5079, 257, 5080, 257, 5081, 257, 5082, 257, 5083, 257, 5084, 257, 5085, 
+    257, 5086, 257, 5087, 257, 5088, 257, 5089, 257, 5090, 257, 5091, 257, 
+    5092, 257, 5093, 257, 5094, 257, 5095, 257, 5096, 257, 5097, 257, 5098, 
+    257, 5099, 257, 5100, 257, 5101, 257, 5102, 257, 5103, 257, 35912, 257, 
+    26356, 257, 36040, 257, 28369, 257, 20018, 257, 21477, 257, 22865, 257, 
+    21895, 257, 22856, 257, 25078, 257, 30313, 257, 32645, 257, 34367, 257, 
+    34746, 257, 35064, 257, 37007, 257, 27138, 257, 27931, 257, 28889, 257, 
+    29662, 257, 33853, 257, 37226, 257, 39409, 257, 20098, 257, 21365, 257, 
+    27396, 257, 29211, 257, 34349, 257, 40478, 257, 23888, 257, 28651, 257, 
+    34253, 257, 35172, 257, 25289, 257, 33240, 257, 34847, 257, 24266, 257, 
+    26391, 257, 28010, 257, 29436, 257, 37070, 257, 20358, 257, 20919, 257, 
+    21214, 257, 25796, 257, 27347, 257, 29200, 257, 30439, 257, 34310, 257, 
+    34396, 257, 36335, 257, 38706, 257, 39791, 257, 40442, 257, 30860, 257, 
+    31103, 257, 32160, 257, 33737, 257, 37636, 257, 35542, 257, 22751, 257, 
+    24324, 257, 31840, 257, 32894, 257, 29282, 257, 30922, 257, 36034, 257, 
+    38647, 257, 22744, 257, 23650, 257, 27155, 257, 28122, 257, 28431, 257, 
+    32047, 257, 32311, 257, 38475, 257, 21202, 257, 32907, 257, 20956, 257, 
+    20940, 257, 31260, 257, 32190, 257, 33777, 257, 38517, 257, 35712, 257, 
+    25295, 257, 35582, 257, 20025, 257, 23527, 257, 24594, 257, 29575, 257, 
+    30064, 257, 21271, 257, 30971, 257, 20415, 257, 24489, 257, 19981, 257, 
+    27852, 257, 25976, 257, 32034, 257, 21443, 257, 22622, 257, 30465, 257, 
+    33865, 257, 35498, 257, 27578, 257, 27784, 257, 25342, 257, 33509, 257, 
+    25504, 257, 30053, 257, 20142, 257, 20841, 257, 20937, 257, 26753, 257, 
+    31975, 257, 33391, 257, 35538, 257, 37327, 257, 21237, 257, 21570, 257, 
+    24300, 257, 26053, 257, 28670, 257, 31018, 257, 38317, 257, 39530, 257, 
+    40599, 257, 40654, 257, 26310, 257, 27511, 257, 36706, 257, 24180, 257, 
+    24976, 257, 25088, 257, 25754, 257, 28451, 257, 29001, 257, 29833, 257, 
+    31178, 257, 32244, 257, 32879, 257, 36646, 257, 34030, 257, 36899, 257, 
+    37706, 257, 21015, 257, 21155, 257, 21693, 257, 28872, 257, 35010, 257, 
+    24265, 257, 24565, 257, 25467, 257, 27566, 257, 31806, 257, 29557, 257, 
+    20196, 257, 22265, 257, 23994, 257, 24604, 257, 29618, 257, 29801, 257, 
+    32666, 257, 32838, 257, 37428, 257, 38646, 257, 38728, 257, 38936, 257, 
+    20363, 257, 31150, 257, 37300, 257, 38584, 257, 24801, 257, 20102, 257, 
+    20698, 257, 23534, 257, 23615, 257, 26009, 257, 29134, 257, 30274, 257, 
+    34044, 257, 36988, 257, 26248, 257, 38446, 257, 21129, 257, 26491, 257, 
+    26611, 257, 27969, 257, 28316, 257, 29705, 257, 30041, 257, 30827, 257, 
+    32016, 257, 39006, 257, 25134, 257, 38520, 257, 20523, 257, 23833, 257, 
+    28138, 257, 36650, 257, 24459, 257, 24900, 257, 26647, 257, 38534, 257, 
+    21033, 257, 21519, 257, 23653, 257, 26131, 257, 26446, 257, 26792, 257, 
+    27877, 257, 29702, 257, 30178, 257, 32633, 257, 35023, 257, 35041, 257, 
+    38626, 257, 21311, 257, 28346, 257, 21533, 257, 29136, 257, 29848, 257, 
+    34298, 257, 38563, 257, 40023, 257, 40607, 257, 26519, 257, 28107, 257, 
+    33256, 257, 31520, 257, 31890, 257, 29376, 257, 28825, 257, 35672, 257, 
+    20160, 257, 33590, 257, 21050, 257, 20999, 257, 24230, 257, 25299, 257, 
+    31958, 257, 23429, 257, 27934, 257, 26292, 257, 36667, 257, 38477, 257, 
+    24275, 257, 20800, 257, 21952, 257, 22618, 257, 26228, 257, 20958, 257, 
+    29482, 257, 30410, 257, 31036, 257, 31070, 257, 31077, 257, 31119, 257, 
+    38742, 257, 31934, 257, 34322, 257, 35576, 257, 36920, 257, 37117, 257, 
+    39151, 257, 39164, 257, 39208, 257, 40372, 257, 37086, 257, 38583, 257, 
+    20398, 257, 20711, 257, 20813, 257, 21193, 257, 21220, 257, 21329, 257, 
+    21917, 257, 22022, 257, 22120, 257, 22592, 257, 22696, 257, 23652, 257, 
+    24724, 257, 24936, 257, 24974, 257, 25074, 257, 25935, 257, 26082, 257, 
+    26257, 257, 26757, 257, 28023, 257, 28186, 257, 28450, 257, 29038, 257, 
+    29227, 257, 29730, 257, 30865, 257, 31049, 257, 31048, 257, 31056, 257, 
+    31062, 257, 31117, 257, 31118, 257, 31296, 257, 31361, 257, 31680, 257, 
+    32265, 257, 32321, 257, 32626, 257, 32773, 257, 33261, 257, 33401, 257, 
+    33879, 257, 35088, 257, 35222, 257, 35585, 257, 35641, 257, 36051, 257, 
+    36104, 257, 36790, 257, 38627, 257, 38911, 257, 38971, 257, 24693, 513, 
+    55376, 57070, 257, 33304, 257, 20006, 257, 20917, 257, 20840, 257, 20352, 
+    257, 20805, 257, 20864, 257, 21191, 257, 21242, 257, 21845, 257, 21913, 
+    257, 21986, 257, 22707, 257, 22852, 257, 22868, 257, 23138, 257, 23336, 
+    257, 24274, 257, 24281, 257, 24425, 257, 24493, 257, 24792, 257, 24910, 
+    257, 24840, 257, 24928, 257, 25140, 257, 25540, 257, 25628, 257, 25682, 
+    257, 25942, 257, 26395, 257, 26454, 257, 28379, 257, 28363, 257, 28702, 
+    257, 30631, 257, 29237, 257, 29359, 257, 29809, 257, 29958, 257, 30011, 
+    257, 30237, 257, 30239, 257, 30427, 257, 30452, 257, 30538, 257, 30528, 
+    257, 30924, 257, 31409, 257, 31867, 257, 32091, 257, 32574, 257, 33618, 
+    257, 33775, 257, 34681, 257, 35137, 257, 35206, 257, 35519, 257, 35531, 
+    257, 35565, 257, 35722, 257, 36664, 257, 36978, 257, 37273, 257, 37494, 
+    257, 38524, 257, 38875, 257, 38923, 257, 39698, 513, 55370, 56394, 513, 
+    55370, 56388, 513, 55372, 57301, 257, 15261, 257, 16408, 257, 16441, 513, 
+    55380, 56905, 513, 55383, 56528, 513, 55391, 57043, 257, 40771, 257, 
+    40846, 513, 102, 102, 513, 102, 105, 513, 102, 108, 769, 102, 102, 105, 
+    769, 102, 102, 108, 513, 115, 116, 513, 1396, 1398, 513, 1396, 1381, 513, 
+    1396, 1387, 513, 1406, 1398, 513, 1396, 1389, 513, 1497, 1460, 513, 1522, 
+    1463, 257, 1506, 257, 1492, 257, 1499, 257, 1500, 257, 1501, 257, 1512, 
+    257, 1514, 513, 1513, 1473, 513, 1513, 1474, 769, 1513, 1468, 1473, 769, 
+    1513, 1468, 1474, 513, 1488, 1463, 513, 1488, 1464, 513, 1488, 1468, 513, 
+    1489, 1468, 513, 1490, 1468, 513, 1491, 1468, 513, 1492, 1468, 513, 1493, 
+    1468, 513, 1494, 1468, 513, 1496, 1468, 513, 1497, 1468, 513, 1498, 1468, 
+    513, 1499, 1468, 513, 1500, 1468, 513, 1502, 1468, 513, 1504, 1468, 513, 
+    1505, 1468, 513, 1507, 1468, 513, 1508, 1468, 513, 1510, 1468, 513, 1511, 
+    1468, 513, 1512, 1468, 513, 1513, 1468, 513, 1514, 1468, 513, 1493, 1465, 
+    513, 1489, 1471, 513, 1499, 1471, 513, 1508, 1471, 513, 1488, 1500, 257, 
+    1649, 257, 1659, 257, 1662, 257, 1664, 257, 1658, 257, 1663, 257, 1657, 
+    257, 1700, 257, 1702, 257, 1668, 257, 1667, 257, 1670, 257, 1671, 257, 
+    1677, 257, 1676, 257, 1678, 257, 1672, 257, 1688, 257, 1681, 257, 1705, 
+    257, 1711, 257, 1715, 257, 1713, 257, 1722, 257, 1723, 257, 1728, 257, 
+    1729, 257, 1726, 257, 1746, 257, 1747, 257, 1709, 257, 1735, 257, 1734, 
+    257, 1736, 257, 1739, 257, 1733, 257, 1737, 257, 1744, 257, 1609, 513, 
+    1574, 1575, 513, 1574, 1749, 513, 1574, 1608, 513, 1574, 1735, 513, 1574, 
+    1734, 513, 1574, 1736, 513, 1574, 1744, 513, 1574, 1609, 257, 1740, 513, 
+    1574, 1580, 513, 1574, 1581, 513, 1574, 1605, 513, 1574, 1610, 513, 1576, 
+    1580, 513, 1576, 1581, 513, 1576, 1582, 513, 1576, 1605, 513, 1576, 1609, 
+    513, 1576, 1610, 513, 1578, 1580, 513, 1578, 1581, 513, 1578, 1582, 513, 
+    1578, 1605, 513, 1578, 1609, 513, 1578, 1610, 513, 1579, 1580, 513, 1579, 
+    1605, 513, 1579, 1609, 513, 1579, 1610, 513, 1580, 1581, 513, 1580, 1605, 
+    513, 1581, 1580, 513, 1581, 1605, 513, 1582, 1580, 513, 1582, 1581, 513, 
+    1582, 1605, 513, 1587, 1580, 513, 1587, 1581, 513, 1587, 1582, 513, 1587, 
+    1605, 513, 1589, 1581, 513, 1589, 1605, 513, 1590, 1580, 513, 1590, 1581, 
+    513, 1590, 1582, 513, 1590, 1605, 513, 1591, 1581, 513, 1591, 1605, 513, 
+    1592, 1605, 513, 1593, 1580, 513, 1593, 1605, 513, 1594, 1580, 513, 1594, 
+    1605, 513, 1601, 1580, 513, 1601, 1581, 513, 1601, 1582, 513, 1601, 1605, 
+    513, 1601, 1609, 513, 1601, 1610, 513, 1602, 1581, 513, 1602, 1605, 513, 
+    1602, 1609, 513, 1602, 1610, 513, 1603, 1575, 513, 1603, 1580, 513, 1603, 
+    1581, 513, 1603, 1582, 513, 1603, 1604, 513, 1603, 1605, 513, 1603, 1609, 
+    513, 1603, 1610, 513, 1604, 1580, 513, 1604, 1581, 513, 1604, 1582, 513, 
+    1604, 1605, 513, 1604, 1609, 513, 1604, 1610, 513, 1605, 1580, 513, 1605, 
+    1581, 513, 1605, 1582, 513, 1605, 1605, 513, 1605, 1609, 513, 1605, 1610, 
+    513, 1606, 1580, 513, 1606, 1581, 513, 1606, 1582, 513, 1606, 1605, 513, 
+    1606, 1609, 513, 1606, 1610, 513, 1607, 1580, 513, 1607, 1605, 513, 1607, 
+    1609, 513, 1607, 1610, 513, 1610, 1580, 513, 1610, 1581, 513, 1610, 1582, 
+    513, 1610, 1605, 513, 1610, 1609, 513, 1610, 1610, 513, 1584, 1648, 513, 
+    1585, 1648, 513, 1609, 1648, 769, 32, 1612, 1617, 769, 32, 1613, 1617, 
+    769, 32, 1614, 1617, 769, 32, 1615, 1617, 769, 32, 1616, 1617, 769, 32, 
+    1617, 1648, 513, 1574, 1585, 513, 1574, 1586, 513, 1574, 1606, 513, 1576, 
+    1585, 513, 1576, 1586, 513, 1576, 1606, 513, 1578, 1585, 513, 1578, 1586, 
+    513, 1578, 1606, 513, 1579, 1585, 513, 1579, 1586, 513, 1579, 1606, 513, 
+    1605, 1575, 513, 1606, 1585, 513, 1606, 1586, 513, 1606, 1606, 513, 1610, 
+    1585, 513, 1610, 1586, 513, 1610, 1606, 513, 1574, 1582, 513, 1574, 1607, 
+    513, 1576, 1607, 513, 1578, 1607, 513, 1589, 1582, 513, 1604, 1607, 513, 
+    1606, 1607, 513, 1607, 1648, 513, 1610, 1607, 513, 1579, 1607, 513, 1587, 
+    1607, 513, 1588, 1605, 513, 1588, 1607, 769, 1600, 1614, 1617, 769, 1600, 
+    1615, 1617, 769, 1600, 1616, 1617, 513, 1591, 1609, 513, 1591, 1610, 513, 
+    1593, 1609, 513, 1593, 1610, 513, 1594, 1609, 513, 1594, 1610, 513, 1587, 
+    1609, 513, 1587, 1610, 513, 1588, 1609, 513, 1588, 1610, 513, 1581, 1609, 
+    513, 1581, 1610, 513, 1580, 1609, 513, 1580, 1610, 513, 1582, 1609, 513, 
+    1582, 1610, 513, 1589, 1609, 513, 1589, 1610, 513, 1590, 1609, 513, 1590, 
+    1610, 513, 1588, 1580, 513, 1588, 1581, 513, 1588, 1582, 513, 1588, 1585, 
+    513, 1587, 1585, 513, 1589, 1585, 513, 1590, 1585, 513, 1575, 1611, 769, 
+    1578, 1580, 1605, 769, 1578, 1581, 1580, 769, 1578, 1581, 1605, 769, 
+    1578, 1582, 1605, 769, 1578, 1605, 1580, 769, 1578, 1605, 1581, 769, 
+    1578, 1605, 1582, 769, 1580, 1605, 1581, 769, 1581, 1605, 1610, 769, 
+    1581, 1605, 1609, 769, 1587, 1581, 1580, 769, 1587, 1580, 1581, 769, 
+    1587, 1580, 1609, 769, 1587, 1605, 1581, 769, 1587, 1605, 1580, 769, 
+    1587, 1605, 1605, 769, 1589, 1581, 1581, 769, 1589, 1605, 1605, 769, 
+    1588, 1581, 1605, 769, 1588, 1580, 1610, 769, 1588, 1605, 1582, 769, 
+    1588, 1605, 1605, 769, 1590, 1581, 1609, 769, 1590, 1582, 1605, 769, 
+    1591, 1605, 1581, 769, 1591, 1605, 1605, 769, 1591, 1605, 1610, 769, 
+    1593, 1580, 1605, 769, 1593, 1605, 1605, 769, 1593, 1605, 1609, 769, 
+    1594, 1605, 1605, 769, 1594, 1605, 1610, 769, 1594, 1605, 1609, 769, 
+    1601, 1582, 1605, 769, 1602, 1605, 1581, 769, 1602, 1605, 1605, 769, 
+    1604, 1581, 1605, 769, 1604, 1581, 1610, 769, 1604, 1581, 1609, 769, 
+    1604, 1580, 1580, 769, 1604, 1582, 1605, 769, 1604, 1605, 1581, 769, 
+    1605, 1581, 1580, 769, 1605, 1581, 1605, 769, 1605, 1581, 1610, 769, 
+    1605, 1580, 1581, 769, 1605, 1580, 1605, 769, 1605, 1582, 1580, 769, 
+    1605, 1582, 1605, 769, 1605, 1580, 1582, 769, 1607, 1605, 1580, 769, 
+    1607, 1605, 1605, 769, 1606, 1581, 1605, 769, 1606, 1581, 1609, 769, 
+    1606, 1580, 1605, 769, 1606, 1580, 1609, 769, 1606, 1605, 1610, 769, 
+    1606, 1605, 1609, 769, 1610, 1605, 1605, 769, 1576, 1582, 1610, 769, 
+    1578, 1580, 1610, 769, 1578, 1580, 1609, 769, 1578, 1582, 1610, 769, 
+    1578, 1582, 1609, 769, 1578, 1605, 1610, 769, 1578, 1605, 1609, 769, 
+    1580, 1605, 1610, 769, 1580, 1581, 1609, 769, 1580, 1605, 1609, 769, 
+    1587, 1582, 1609, 769, 1589, 1581, 1610, 769, 1588, 1581, 1610, 769, 
+    1590, 1581, 1610, 769, 1604, 1580, 1610, 769, 1604, 1605, 1610, 769, 
+    1610, 1581, 1610, 769, 1610, 1580, 1610, 769, 1610, 1605, 1610, 769, 
+    1605, 1605, 1610, 769, 1602, 1605, 1610, 769, 1606, 1581, 1610, 769, 
+    1593, 1605, 1610, 769, 1603, 1605, 1610, 769, 1606, 1580, 1581, 769, 
+    1605, 1582, 1610, 769, 1604, 1580, 1605, 769, 1603, 1605, 1605, 769, 
+    1580, 1581, 1610, 769, 1581, 1580, 1610, 769, 1605, 1580, 1610, 769, 
+    1601, 1605, 1610, 769, 1576, 1581, 1610, 769, 1587, 1582, 1610, 769, 
+    1606, 1580, 1610, 769, 1589, 1604, 1746, 769, 1602, 1604, 1746, 1025, 
+    1575, 1604, 1604, 1607, 1025, 1575, 1603, 1576, 1585, 1025, 1605, 1581, 
+    1605, 1583, 1025, 1589, 1604, 1593, 1605, 1025, 1585, 1587, 1608, 1604, 
+    1025, 1593, 1604, 1610, 1607, 1025, 1608, 1587, 1604, 1605, 769, 1589, 
+    1604, 1609, 4609, 1589, 1604, 1609, 32, 1575, 1604, 1604, 1607, 32, 1593, 
+    1604, 1610, 1607, 32, 1608, 1587, 1604, 1605, 2049, 1580, 1604, 32, 1580, 
+    1604, 1575, 1604, 1607, 1025, 1585, 1740, 1575, 1604, 257, 44, 257, 
+    12289, 257, 12290, 257, 58, 257, 33, 257, 63, 257, 12310, 257, 12311, 
+    257, 8212, 257, 8211, 257, 95, 257, 123, 257, 125, 257, 12308, 257, 
+    12309, 257, 12304, 257, 12305, 257, 12298, 257, 12299, 257, 12300, 257, 
+    12301, 257, 12302, 257, 12303, 257, 91, 257, 93, 257, 35, 257, 38, 257, 
+    42, 257, 45, 257, 60, 257, 62, 257, 92, 257, 36, 257, 37, 257, 64, 513, 
+    32, 1611, 513, 1600, 1611, 513, 32, 1612, 513, 32, 1613, 513, 32, 1614, 
+    513, 1600, 1614, 513, 32, 1615, 513, 1600, 1615, 513, 32, 1616, 513, 
+    1600, 1616, 513, 32, 1617, 513, 1600, 1617, 513, 32, 1618, 513, 1600, 
+    1618, 257, 1569, 257, 1570, 257, 1571, 257, 1572, 257, 1573, 257, 1574, 
+    257, 1575, 257, 1576, 257, 1577, 257, 1578, 257, 1579, 257, 1580, 257, 
+    1581, 257, 1582, 257, 1583, 257, 1584, 257, 1585, 257, 1586, 257, 1587, 
+    257, 1588, 257, 1589, 257, 1590, 257, 1591, 257, 1592, 257, 1593, 257, 
+    1594, 257, 1601, 257, 1602, 257, 1603, 257, 1604, 257, 1605, 257, 1606, 
+    257, 1607, 257, 1608, 257, 1610, 513, 1604, 1570, 513, 1604, 1571, 513, 
+    1604, 1573, 513, 1604, 1575, 257, 34, 257, 39, 257, 47, 257, 94, 257, 
+    124, 257, 126, 257, 10629, 257, 10630, 257, 12539, 257, 12449, 257, 
+    12451, 257, 12453, 257, 12455, 257, 12457, 257, 12515, 257, 12517, 257, 
+    12519, 257, 12483, 257, 12540, 257, 12531, 257, 12441, 257, 12442, 257, 
+    162, 257, 163, 257, 172, 257, 166, 257, 165, 257, 8361, 257, 9474, 257, 
+    8592, 257, 8593, 257, 8594, 257, 8595, 257, 9632, 257, 9675, 513, 55297, 
+    56360, 513, 55297, 56361, 513, 55297, 56362, 513, 55297, 56363, 513, 
+    55297, 56364, 513, 55297, 56365, 513, 55297, 56366, 513, 55297, 56367, 
+    513, 55297, 56368, 513, 55297, 56369, 513, 55297, 56370, 513, 55297, 
+    56371, 513, 55297, 56372, 513, 55297, 56373, 513, 55297, 56374, 513, 
+    55297, 56375, 513, 55297, 56376, 513, 55297, 56377, 513, 55297, 56378, 
+    513, 55297, 56379, 513, 55297, 56380, 513, 55297, 56381, 513, 55297, 
+    56382, 513, 55297, 56383, 513, 55297, 56384, 513, 55297, 56385, 513, 
+    55297, 56386, 513, 55297, 56387, 513, 55297, 56388, 513, 55297, 56389, 
+    513, 55297, 56390, 513, 55297, 56391, 513, 55297, 56392, 513, 55297, 
+    56393, 513, 55297, 56394, 513, 55297, 56395, 513, 55297, 56396, 513, 
+    55297, 56397, 513, 55297, 56398, 513, 55297, 56399, 513, 55297, 56536, 
+    513, 55297, 56537, 513, 55297, 56538, 513, 55297, 56539, 513, 55297, 
+    56540, 513, 55297, 56541, 513, 55297, 56542, 513, 55297, 56543, 513, 
+    55297, 56544, 513, 55297, 56545, 513, 55297, 56546, 513, 55297, 56547, 
+    513, 55297, 56548, 513, 55297, 56549, 513, 55297, 56550, 513, 55297, 
+    56551, 513, 55297, 56552, 513, 55297, 56553, 513, 55297, 56554, 513, 
+    55297, 56555, 513, 55297, 56556, 513, 55297, 56557, 513, 55297, 56558, 
+    513, 55297, 56559, 513, 55297, 56560, 513, 55297, 56561, 513, 55297, 
+    56562, 513, 55297, 56563, 513, 55297, 56564, 513, 55297, 56565, 513, 
+    55297, 56566, 513, 55297, 56567, 513, 55297, 56568, 513, 55297, 56569, 
+    513, 55297, 56570, 513, 55297, 56571, 513, 55299, 56512, 513, 55299, 
+    56513, 513, 55299, 56514, 513, 55299, 56515, 513, 55299, 56516, 513, 
+    55299, 56517, 513, 55299, 56518, 513, 55299, 56519, 513, 55299, 56520, 
+    513, 55299, 56521, 513, 55299, 56522, 513, 55299, 56523, 513, 55299, 
+    56524, 513, 55299, 56525, 513, 55299, 56526, 513, 55299, 56527, 513, 
+    55299, 56528, 513, 55299, 56529, 513, 55299, 56530, 513, 55299, 56531, 
+    513, 55299, 56532, 513, 55299, 56533, 513, 55299, 56534, 513, 55299, 
+    56535, 513, 55299, 56536, 513, 55299, 56537, 513, 55299, 56538, 513, 
+    55299, 56539, 513, 55299, 56540, 513, 55299, 56541, 513, 55299, 56542, 
+    513, 55299, 56543, 513, 55299, 56544, 513, 55299, 56545, 513, 55299, 
+    56546, 513, 55299, 56547, 513, 55299, 56548, 513, 55299, 56549, 513, 
+    55299, 56550, 513, 55299, 56551, 513, 55299, 56552, 513, 55299, 56553, 
+    513, 55299, 56554, 513, 55299, 56555, 513, 55299, 56556, 513, 55299, 
+    56557, 513, 55299, 56558, 513, 55299, 56559, 513, 55299, 56560, 513, 
+    55299, 56561, 513, 55299, 56562, 513, 55302, 56512, 513, 55302, 56513, 
+    513, 55302, 56514, 513, 55302, 56515, 513, 55302, 56516, 513, 55302, 
+    56517, 513, 55302, 56518, 513, 55302, 56519, 513, 55302, 56520, 513, 
+    55302, 56521, 513, 55302, 56522, 513, 55302, 56523, 513, 55302, 56524, 
+    513, 55302, 56525, 513, 55302, 56526, 513, 55302, 56527, 513, 55302, 
+    56528, 513, 55302, 56529, 513, 55302, 56530, 513, 55302, 56531, 513, 
+    55302, 56532, 513, 55302, 56533, 513, 55302, 56534, 513, 55302, 56535, 
+    513, 55302, 56536, 513, 55302, 56537, 513, 55302, 56538, 513, 55302, 
+    56539, 513, 55302, 56540, 513, 55302, 56541, 513, 55302, 56542, 513, 
+    55302, 56543, 1025, 55348, 56663, 55348, 56677, 1025, 55348, 56664, 
+    55348, 56677, 1537, 55348, 56664, 55348, 56677, 55348, 56686, 1537, 
+    55348, 56664, 55348, 56677, 55348, 56687, 1537, 55348, 56664, 55348, 
+    56677, 55348, 56688, 1537, 55348, 56664, 55348, 56677, 55348, 56689, 
+    1537, 55348, 56664, 55348, 56677, 55348, 56690, 1025, 55348, 56761, 
+    55348, 56677, 1025, 55348, 56762, 55348, 56677, 1537, 55348, 56761, 
+    55348, 56677, 55348, 56686, 1537, 55348, 56762, 55348, 56677, 55348, 
+    56686, 1537, 55348, 56761, 55348, 56677, 55348, 56687, 1537, 55348, 
+    56762, 55348, 56677, 55348, 56687, 257, 305, 257, 567, 257, 8711, 257, 
+    8706, 513, 55354, 56610, 513, 55354, 56611, 513, 55354, 56612, 513, 
+    55354, 56613, 513, 55354, 56614, 513, 55354, 56615, 513, 55354, 56616, 
+    513, 55354, 56617, 513, 55354, 56618, 513, 55354, 56619, 513, 55354, 
+    56620, 513, 55354, 56621, 513, 55354, 56622, 513, 55354, 56623, 513, 
+    55354, 56624, 513, 55354, 56625, 513, 55354, 56626, 513, 55354, 56627, 
+    513, 55354, 56628, 513, 55354, 56629, 513, 55354, 56630, 513, 55354, 
+    56631, 513, 55354, 56632, 513, 55354, 56633, 513, 55354, 56634, 513, 
+    55354, 56635, 513, 55354, 56636, 513, 55354, 56637, 513, 55354, 56638, 
+    513, 55354, 56639, 513, 55354, 56640, 513, 55354, 56641, 513, 55354, 
+    56642, 513, 55354, 56643, 257, 1646, 257, 1697, 257, 1647, 513, 48, 46, 
+    513, 48, 44, 513, 49, 44, 513, 50, 44, 513, 51, 44, 513, 52, 44, 513, 53, 
+    44, 513, 54, 44, 513, 55, 44, 513, 56, 44, 513, 57, 44, 769, 12308, 115, 
+    12309, 513, 119, 122, 513, 104, 118, 513, 115, 100, 769, 112, 112, 118, 
+    513, 119, 99, 513, 109, 99, 513, 109, 100, 513, 100, 106, 513, 12411, 
+    12363, 513, 12467, 12467, 257, 23383, 257, 21452, 257, 12487, 257, 22810, 
+    257, 35299, 257, 20132, 257, 26144, 257, 28961, 257, 21069, 257, 24460, 
+    257, 20877, 257, 26032, 257, 21021, 257, 32066, 257, 36009, 257, 22768, 
+    257, 21561, 257, 28436, 257, 25237, 257, 25429, 257, 36938, 257, 25351, 
+    257, 25171, 257, 31105, 257, 31354, 257, 21512, 257, 28288, 257, 30003, 
+    257, 21106, 257, 21942, 257, 37197, 769, 12308, 26412, 12309, 769, 12308, 
+    19977, 12309, 769, 12308, 20108, 12309, 769, 12308, 23433, 12309, 769, 
+    12308, 28857, 12309, 769, 12308, 25171, 12309, 769, 12308, 30423, 12309, 
+    769, 12308, 21213, 12309, 769, 12308, 25943, 12309, 257, 24471, 257, 
+    21487, 257, 20029, 257, 20024, 257, 20033, 513, 55360, 56610, 257, 20320, 
+    257, 20411, 257, 20482, 257, 20602, 257, 20633, 257, 20687, 257, 13470, 
+    513, 55361, 56890, 257, 20820, 257, 20836, 257, 20855, 513, 55361, 56604, 
+    257, 13497, 257, 20839, 513, 55361, 56651, 257, 20887, 257, 20900, 257, 
+    20172, 257, 20908, 513, 55396, 56799, 257, 20995, 257, 13535, 257, 21051, 
+    257, 21062, 257, 21111, 257, 13589, 257, 21253, 257, 21254, 257, 21321, 
+    257, 21338, 257, 21363, 257, 21373, 257, 21375, 513, 55362, 56876, 257, 
+    28784, 257, 21450, 257, 21471, 513, 55362, 57187, 257, 21483, 257, 21489, 
+    257, 21510, 257, 21662, 257, 21560, 257, 21576, 257, 21608, 257, 21666, 
+    257, 21750, 257, 21776, 257, 21843, 257, 21859, 257, 21892, 257, 21931, 
+    257, 21939, 257, 21954, 257, 22294, 257, 22295, 257, 22097, 257, 22132, 
+    257, 22766, 257, 22478, 257, 22516, 257, 22541, 257, 22411, 257, 22578, 
+    257, 22577, 257, 22700, 513, 55365, 56548, 257, 22770, 257, 22775, 257, 
+    22790, 257, 22818, 257, 22882, 513, 55365, 57000, 513, 55365, 57066, 257, 
+    23020, 257, 23067, 257, 23079, 257, 23000, 257, 23142, 257, 14062, 257, 
+    14076, 257, 23304, 257, 23358, 513, 55366, 56776, 257, 23491, 257, 23512, 
+    257, 23539, 513, 55366, 57112, 257, 23551, 257, 23558, 257, 24403, 257, 
+    14209, 257, 23648, 257, 23744, 257, 23693, 513, 55367, 56804, 257, 23875, 
+    513, 55367, 56806, 257, 23918, 257, 23915, 257, 23932, 257, 24033, 257, 
+    24034, 257, 14383, 257, 24061, 257, 24104, 257, 24125, 257, 24169, 257, 
+    14434, 513, 55368, 56707, 257, 14460, 257, 24240, 257, 24243, 257, 24246, 
+    513, 55400, 57234, 513, 55368, 57137, 257, 33281, 257, 24354, 257, 14535, 
+    513, 55372, 57016, 513, 55384, 56794, 257, 24418, 257, 24427, 257, 14563, 
+    257, 24474, 257, 24525, 257, 24535, 257, 24569, 257, 24705, 257, 14650, 
+    257, 14620, 513, 55369, 57044, 257, 24775, 257, 24904, 257, 24908, 257, 
+    24954, 257, 25010, 257, 24996, 257, 25007, 257, 25054, 257, 25104, 257, 
+    25115, 257, 25181, 257, 25265, 257, 25300, 257, 25424, 513, 55370, 57100, 
+    257, 25405, 257, 25340, 257, 25448, 257, 25475, 257, 25572, 513, 55370, 
+    57329, 257, 25634, 257, 25541, 257, 25513, 257, 14894, 257, 25705, 257, 
+    25726, 257, 25757, 257, 25719, 257, 14956, 257, 25964, 513, 55372, 56330, 
+    257, 26083, 257, 26360, 257, 26185, 257, 15129, 257, 15112, 257, 15076, 
+    257, 20882, 257, 20885, 257, 26368, 257, 26268, 257, 32941, 257, 17369, 
+    257, 26401, 257, 26462, 257, 26451, 513, 55372, 57283, 257, 15177, 257, 
+    26618, 257, 26501, 257, 26706, 513, 55373, 56429, 257, 26766, 257, 26655, 
+    257, 26900, 257, 26946, 257, 27043, 257, 27114, 257, 27304, 513, 55373, 
+    56995, 257, 27355, 257, 15384, 257, 27425, 513, 55374, 56487, 257, 27476, 
+    257, 15438, 257, 27506, 257, 27551, 257, 27579, 513, 55374, 56973, 513, 
+    55367, 56587, 513, 55374, 57082, 257, 27726, 513, 55375, 56508, 257, 
+    27839, 257, 27853, 257, 27751, 257, 27926, 257, 27966, 257, 28009, 257, 
+    28024, 257, 28037, 513, 55375, 56606, 257, 27956, 257, 28207, 257, 28270, 
+    257, 15667, 257, 28359, 513, 55375, 57041, 257, 28153, 257, 28526, 513, 
+    55375, 57182, 513, 55375, 57230, 257, 28614, 257, 28729, 257, 28699, 257, 
+    15766, 257, 28746, 257, 28797, 257, 28791, 257, 28845, 513, 55361, 56613, 
+    257, 28997, 513, 55376, 56931, 257, 29084, 513, 55376, 57259, 257, 29224, 
+    257, 29264, 513, 55377, 56840, 257, 29312, 257, 29333, 513, 55377, 57141, 
+    513, 55378, 56340, 257, 29562, 257, 29579, 257, 16044, 257, 29605, 257, 
+    16056, 257, 29767, 257, 29788, 257, 29829, 257, 29898, 257, 16155, 257, 
+    29988, 513, 55379, 56374, 257, 30014, 513, 55379, 56466, 513, 55368, 
+    56735, 257, 30224, 513, 55379, 57249, 513, 55379, 57272, 513, 55380, 
+    56388, 257, 16380, 257, 16392, 513, 55380, 56563, 513, 55380, 56562, 513, 
+    55380, 56601, 513, 55380, 56627, 257, 30494, 257, 30495, 257, 30603, 257, 
+    16454, 257, 16534, 513, 55381, 56349, 257, 30798, 257, 16611, 513, 55381, 
+    56870, 513, 55381, 56986, 513, 55381, 57029, 257, 31211, 257, 16687, 257, 
+    31306, 257, 31311, 513, 55382, 56700, 513, 55382, 56999, 257, 31470, 257, 
+    16898, 513, 55382, 57259, 257, 31686, 257, 31689, 257, 16935, 513, 55383, 
+    56448, 257, 31954, 257, 17056, 257, 31976, 257, 31971, 257, 32000, 513, 
+    55383, 57222, 257, 32099, 257, 17153, 257, 32199, 257, 32258, 257, 32325, 
+    257, 17204, 513, 55384, 56872, 513, 55384, 56903, 257, 17241, 513, 55384, 
+    57049, 257, 32634, 513, 55384, 57150, 257, 32661, 257, 32762, 513, 55385, 
+    56538, 513, 55385, 56611, 257, 32864, 513, 55385, 56744, 257, 32880, 513, 
+    55372, 57183, 257, 17365, 257, 32946, 257, 33027, 257, 17419, 257, 33086, 
+    257, 23221, 513, 55385, 57255, 513, 55385, 57269, 513, 55372, 57235, 513, 
+    55372, 57244, 257, 33284, 257, 36766, 257, 17515, 257, 33425, 257, 33419, 
+    257, 33437, 257, 21171, 257, 33457, 257, 33459, 257, 33469, 257, 33510, 
+    513, 55386, 57148, 257, 33565, 257, 33635, 257, 33709, 257, 33571, 257, 
+    33725, 257, 33767, 257, 33619, 257, 33738, 257, 33740, 257, 33756, 513, 
+    55387, 56374, 513, 55387, 56683, 513, 55387, 56533, 257, 17707, 257, 
+    34033, 257, 34035, 257, 34070, 513, 55388, 57290, 257, 34148, 513, 55387, 
+    57132, 257, 17757, 257, 17761, 513, 55387, 57265, 513, 55388, 56530, 257, 
+    17771, 257, 34384, 257, 34407, 257, 34409, 257, 34473, 257, 34440, 257, 
+    34574, 257, 34530, 257, 34600, 257, 34667, 257, 34694, 257, 17879, 257, 
+    34785, 257, 34817, 257, 17913, 257, 34912, 513, 55389, 56935, 257, 35031, 
+    257, 35038, 257, 17973, 257, 35066, 257, 13499, 513, 55390, 56494, 513, 
+    55390, 56678, 257, 18110, 257, 18119, 257, 35488, 513, 55391, 56488, 257, 
+    36011, 257, 36033, 257, 36123, 257, 36215, 513, 55391, 57135, 513, 55362, 
+    56324, 257, 36299, 257, 36284, 257, 36336, 513, 55362, 56542, 257, 36564, 
+    513, 55393, 56786, 513, 55393, 56813, 257, 37012, 257, 37105, 257, 37137, 
+    513, 55393, 57134, 257, 37147, 257, 37432, 257, 37591, 257, 37592, 257, 
+    37500, 257, 37881, 257, 37909, 513, 55394, 57338, 257, 38283, 257, 18837, 
+    257, 38327, 513, 55395, 56695, 257, 18918, 257, 38595, 257, 23986, 257, 
+    38691, 513, 55396, 56645, 513, 55396, 56858, 257, 19054, 257, 19062, 257, 
+    38880, 513, 55397, 56330, 257, 19122, 513, 55397, 56470, 257, 38953, 513, 
+    55397, 56758, 257, 39138, 257, 19251, 257, 39209, 257, 39335, 257, 39362, 
+    257, 39422, 257, 19406, 513, 55398, 57136, 257, 40000, 257, 40189, 257, 
+    19662, 257, 19693, 257, 40295, 513, 55400, 56526, 257, 19704, 513, 55400, 
+    56581, 513, 55400, 56846, 513, 55400, 56977, 257, 19798, 257, 40702, 257, 
+    40709, 257, 40719, 257, 40726, 513, 55401, 56832, 
+];
+
+/* index tables for the NFKC_Casefold mapping data */
+#[allow(dead_code)] pub const NFKC_CF_SHIFT1: u32 = 6;
+#[allow(dead_code)] pub const NFKC_CF_SHIFT2: u32 = 4;
+pub static NFKC_CF_INDEX0: [u8; 1088] = [
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 14, 15, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 16, 17, 13, 18, 13, 19, 13, 13, 20, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    21, 13, 13, 13, 13, 22, 23, 13, 13, 13, 13, 24, 25, 26, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 27, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 28, 28, 28, 28, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 
+    13, 13, 13, 13, 13, 
+];
+
+pub static NFKC_CF_INDEX1: [u16; 1856] = [
+    0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 3, 4, 5, 6, 0, 0, 7, 8, 9, 10, 11, 12, 13, 
+    14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 0, 0, 0, 0, 0, 0, 
+    28, 0, 29, 30, 0, 0, 0, 0, 0, 31, 0, 0, 32, 33, 34, 35, 0, 36, 37, 38, 
+    39, 40, 41, 42, 0, 0, 0, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 
+    55, 56, 57, 58, 0, 0, 59, 0, 0, 0, 0, 0, 0, 0, 0, 60, 0, 0, 0, 0, 0, 61, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 62, 0, 0, 
+    0, 0, 0, 0, 0, 63, 0, 0, 0, 0, 0, 64, 0, 65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 67, 0, 0, 0, 0, 0, 0, 0, 68, 0, 69, 0, 0, 70, 0, 0, 0, 71, 
+    72, 73, 74, 75, 76, 77, 78, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 79, 
+    80, 81, 0, 0, 82, 0, 0, 0, 0, 0, 83, 84, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 86, 0, 0, 0, 0, 87, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 88, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 89, 90, 91, 92, 93, 94, 0, 95, 96, 97, 0, 0, 0, 
+    0, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 109, 110, 111, 
+    112, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 123, 124, 125, 
+    126, 127, 128, 129, 130, 131, 132, 133, 134, 135, 136, 137, 138, 139, 
+    140, 0, 0, 0, 0, 0, 141, 142, 143, 144, 145, 146, 147, 147, 148, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 149, 150, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    151, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 152, 153, 
+    154, 155, 156, 157, 158, 159, 160, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 161, 0, 0, 0, 
+    0, 0, 0, 162, 0, 0, 0, 0, 0, 163, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 164, 165, 166, 0, 0, 0, 167, 168, 169, 170, 171, 172, 173, 
+    174, 175, 176, 0, 0, 0, 0, 0, 0, 177, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 178, 0, 0, 0, 0, 0, 179, 180, 181, 182, 183, 184, 185, 
+    186, 187, 188, 189, 190, 191, 192, 193, 0, 0, 194, 0, 0, 195, 0, 0, 0, 0, 
+    0, 196, 0, 0, 0, 0, 0, 197, 0, 0, 0, 198, 199, 200, 201, 202, 203, 204, 
+    0, 0, 0, 0, 0, 0, 205, 206, 207, 208, 209, 210, 211, 212, 213, 214, 215, 
+    216, 217, 218, 219, 220, 221, 222, 223, 224, 225, 226, 227, 228, 229, 
+    230, 231, 232, 233, 234, 235, 236, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 237, 238, 239, 0, 240, 241, 
+    0, 0, 0, 0, 0, 0, 0, 0, 242, 243, 244, 245, 246, 247, 248, 249, 250, 251, 
+    0, 0, 0, 252, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 253, 0, 254, 255, 256, 257, 258, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 259, 260, 261, 262, 263, 
+    264, 265, 266, 267, 268, 269, 270, 271, 272, 273, 274, 275, 276, 277, 
+    278, 279, 280, 281, 282, 283, 284, 285, 286, 287, 288, 0, 0, 289, 290, 
+    291, 292, 293, 294, 295, 296, 297, 298, 299, 300, 0, 301, 302, 303, 304, 
+    305, 306, 307, 308, 309, 310, 311, 312, 313, 314, 315, 316, 317, 318, 
+    319, 320, 321, 322, 323, 0, 324, 325, 326, 327, 328, 329, 330, 331, 0, 0, 
+    332, 136, 333, 0, 334, 335, 336, 337, 338, 339, 340, 341, 342, 343, 344, 
+    345, 346, 347, 348, 349, 350, 351, 352, 353, 354, 355, 356, 357, 358, 
+    359, 360, 361, 362, 363, 364, 365, 0, 0, 0, 0, 0, 0, 0, 0, 366, 367, 368, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 369, 370, 371, 372, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 373, 374, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 375, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 376, 377, 378, 0, 0, 0, 379, 380, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    159, 381, 382, 383, 384, 385, 386, 387, 388, 389, 390, 391, 392, 159, 
+    381, 382, 393, 394, 395, 396, 397, 398, 399, 400, 401, 158, 159, 381, 
+    382, 383, 384, 395, 386, 387, 388, 399, 400, 401, 158, 159, 381, 382, 
+    402, 403, 404, 405, 406, 407, 408, 409, 410, 411, 412, 413, 414, 415, 
+    416, 415, 417, 418, 419, 420, 421, 422, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 423, 424, 425, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 426, 427, 428, 429, 430, 
+    431, 432, 433, 434, 435, 436, 435, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 437, 
+    438, 439, 159, 440, 0, 441, 0, 0, 442, 0, 0, 0, 0, 0, 0, 443, 444, 445, 
+    446, 447, 448, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 449, 450, 451, 452, 453, 454, 455, 456, 457, 458, 
+    459, 460, 461, 462, 463, 464, 465, 466, 467, 468, 469, 470, 471, 472, 
+    473, 474, 475, 476, 477, 478, 479, 480, 481, 482, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 136, 
+    136, 136, 136, 136, 136, 136, 136, 136, 136, 136, 136, 136, 136, 136, 
+    136, 136, 136, 136, 136, 136, 136, 136, 136, 136, 136, 136, 136, 136, 
+    136, 136, 136, 136, 136, 136, 136, 136, 136, 136, 136, 136, 136, 136, 
+    136, 136, 136, 136, 136, 136, 136, 136, 136, 136, 136, 136, 136, 136, 
+    136, 136, 136, 136, 136, 136, 136, 
+];
+
+pub static NFKC_CF_INDEX2: [u16; 7728] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 3, 5, 7, 9, 11, 13, 
+    15, 17, 19, 21, 23, 25, 27, 29, 31, 33, 35, 37, 39, 41, 43, 45, 47, 49, 
+    51, 0, 0, 0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 55, 0, 1, 0, 0, 58, 0, 59, 0, 
+    0, 62, 64, 66, 69, 0, 0, 71, 74, 29, 0, 76, 80, 84, 0, 88, 90, 92, 94, 
+    96, 98, 100, 102, 104, 106, 108, 110, 112, 114, 116, 118, 120, 122, 124, 
+    126, 128, 130, 132, 0, 134, 136, 138, 140, 142, 144, 146, 148, 151, 0, 
+    153, 0, 155, 0, 157, 0, 159, 0, 161, 0, 163, 0, 165, 0, 167, 0, 169, 0, 
+    171, 0, 173, 0, 175, 0, 177, 0, 179, 0, 181, 0, 183, 0, 185, 0, 187, 0, 
+    189, 0, 191, 0, 193, 0, 195, 0, 197, 0, 199, 0, 202, 202, 205, 0, 207, 0, 
+    0, 209, 0, 211, 0, 213, 0, 215, 215, 218, 0, 220, 0, 222, 0, 224, 0, 226, 
+    229, 0, 231, 0, 233, 0, 235, 0, 237, 0, 239, 0, 241, 0, 243, 0, 245, 0, 
+    247, 0, 249, 0, 251, 0, 253, 0, 255, 0, 257, 0, 259, 0, 261, 0, 263, 0, 
+    265, 0, 267, 0, 269, 0, 271, 0, 273, 0, 275, 277, 0, 279, 0, 281, 0, 37, 
+    0, 283, 285, 0, 287, 0, 289, 291, 0, 293, 295, 297, 0, 0, 299, 301, 303, 
+    305, 0, 307, 309, 0, 311, 313, 315, 0, 0, 0, 317, 319, 0, 321, 323, 0, 
+    325, 0, 327, 0, 329, 331, 0, 333, 0, 0, 335, 0, 337, 339, 0, 341, 343, 
+    345, 0, 347, 0, 349, 351, 0, 0, 0, 353, 0, 0, 0, 0, 0, 0, 0, 355, 355, 
+    355, 358, 358, 358, 361, 361, 361, 364, 0, 366, 0, 368, 0, 370, 0, 372, 
+    0, 374, 0, 376, 0, 378, 0, 0, 380, 0, 382, 0, 384, 0, 386, 0, 388, 0, 
+    390, 0, 392, 0, 394, 0, 396, 0, 0, 398, 398, 398, 401, 0, 403, 405, 407, 
+    0, 409, 0, 411, 0, 413, 0, 415, 0, 417, 0, 419, 0, 421, 0, 423, 0, 425, 
+    0, 427, 0, 429, 0, 431, 0, 433, 0, 435, 0, 437, 0, 439, 0, 441, 0, 443, 
+    0, 445, 0, 447, 0, 449, 0, 451, 0, 453, 0, 455, 0, 457, 0, 459, 0, 461, 
+    0, 463, 0, 465, 0, 0, 0, 0, 0, 0, 0, 467, 469, 0, 471, 473, 0, 0, 475, 0, 
+    477, 479, 481, 483, 0, 485, 0, 487, 0, 489, 0, 491, 0, 15, 493, 19, 35, 
+    495, 497, 499, 45, 49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 501, 
+    504, 507, 510, 513, 516, 0, 0, 309, 23, 37, 47, 519, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 521, 523, 0, 525, 527, 530, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58, 
+    532, 0, 534, 0, 536, 0, 538, 0, 0, 0, 540, 0, 0, 0, 543, 545, 0, 0, 0, 0, 
+    66, 547, 551, 553, 555, 557, 559, 0, 561, 0, 563, 565, 0, 567, 569, 571, 
+    573, 575, 577, 579, 581, 530, 583, 585, 69, 587, 589, 591, 593, 595, 0, 
+    597, 599, 601, 603, 605, 607, 609, 611, 613, 0, 0, 0, 0, 0, 0, 597, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 615, 569, 581, 601, 563, 613, 603, 593, 0, 
+    617, 0, 619, 0, 621, 0, 623, 0, 625, 0, 627, 0, 629, 0, 631, 0, 633, 0, 
+    635, 0, 637, 0, 639, 0, 583, 595, 597, 0, 581, 575, 0, 641, 0, 597, 643, 
+    0, 0, 645, 647, 649, 651, 653, 655, 657, 659, 661, 663, 665, 667, 669, 
+    671, 673, 675, 677, 679, 681, 683, 685, 687, 689, 691, 693, 695, 697, 
+    699, 701, 703, 705, 707, 709, 711, 713, 715, 717, 719, 721, 723, 725, 
+    727, 729, 731, 733, 735, 737, 739, 741, 743, 745, 747, 0, 749, 0, 751, 0, 
+    753, 0, 755, 0, 757, 0, 759, 0, 761, 0, 763, 0, 765, 0, 767, 0, 769, 0, 
+    771, 0, 773, 0, 775, 0, 777, 0, 779, 0, 0, 0, 0, 0, 0, 0, 0, 0, 781, 0, 
+    783, 0, 785, 0, 787, 0, 789, 0, 791, 0, 793, 0, 795, 0, 797, 0, 799, 0, 
+    801, 0, 803, 0, 805, 0, 807, 0, 809, 0, 811, 0, 813, 0, 815, 0, 817, 0, 
+    819, 0, 821, 0, 823, 0, 825, 0, 827, 0, 829, 0, 831, 0, 833, 0, 835, 837, 
+    0, 839, 0, 841, 0, 843, 0, 845, 0, 847, 0, 849, 0, 0, 851, 0, 853, 0, 
+    855, 0, 857, 0, 859, 0, 861, 0, 863, 0, 865, 0, 867, 0, 869, 0, 871, 0, 
+    873, 0, 875, 0, 877, 0, 879, 0, 881, 0, 883, 0, 885, 0, 887, 0, 889, 0, 
+    891, 0, 893, 0, 895, 0, 897, 0, 899, 0, 901, 0, 903, 0, 905, 0, 907, 0, 
+    909, 0, 911, 0, 913, 0, 915, 0, 917, 0, 919, 0, 921, 0, 923, 0, 925, 0, 
+    927, 0, 929, 0, 931, 0, 933, 0, 935, 0, 937, 0, 939, 0, 941, 0, 943, 0, 
+    945, 0, 0, 947, 949, 951, 953, 955, 957, 959, 961, 963, 965, 967, 969, 
+    971, 973, 975, 977, 979, 981, 983, 985, 987, 989, 991, 993, 995, 997, 
+    999, 1001, 1003, 1005, 1007, 1009, 1011, 1013, 1015, 1017, 1019, 1021, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1023, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58, 0, 0, 0, 0, 0, 0, 0, 0, 1026, 
+    1029, 1032, 1035, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1038, 
+    1041, 1044, 1047, 1050, 1053, 1056, 1059, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 1062, 1065, 0, 1068, 0, 0, 0, 1071, 0, 0, 1074, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1077, 1080, 1083, 0, 0, 1086, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1089, 1092, 0, 0, 0, 0, 0, 1095, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1098, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1101, 1104, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 1107, 0, 0, 0, 0, 0, 0, 1109, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 1112, 0, 0, 0, 0, 1115, 0, 0, 0, 0, 1118, 0, 0, 0, 0, 1121, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1124, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    1127, 0, 1130, 1133, 1136, 1140, 1143, 0, 0, 0, 0, 0, 0, 0, 1147, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1150, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 1153, 0, 0, 0, 0, 1156, 0, 0, 0, 0, 1159, 0, 0, 0, 0, 1162, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1165, 0, 0, 0, 0, 0, 0, 1168, 1170, 1172, 
+    1174, 1176, 1178, 1180, 1182, 1184, 1186, 1188, 1190, 1192, 1194, 1196, 
+    1198, 1200, 1202, 1204, 1206, 1208, 1210, 1212, 1214, 1216, 1218, 1220, 
+    1222, 1224, 1226, 1228, 1230, 1232, 1234, 1236, 1238, 1240, 1242, 0, 
+    1244, 0, 0, 0, 0, 0, 1246, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    1248, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58, 58, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1250, 
+    1252, 1254, 1256, 1258, 1260, 0, 0, 0, 0, 0, 0, 58, 58, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58, 58, 58, 58, 58, 687, 
+    691, 711, 717, 719, 719, 735, 749, 1262, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 100, 3, 0, 7, 9, 299, 13, 15, 17, 19, 21, 23, 
+    25, 27, 0, 29, 449, 31, 35, 39, 41, 45, 1, 1264, 1266, 1268, 3, 7, 9, 
+    301, 303, 1270, 13, 0, 21, 25, 229, 29, 289, 1272, 1274, 31, 39, 41, 
+    1276, 317, 43, 1278, 569, 571, 573, 603, 605, 17, 35, 41, 43, 569, 571, 
+    595, 603, 605, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 709, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1280, 5, 1282, 120, 1270, 11, 
+    1284, 1286, 1288, 313, 311, 1290, 1292, 1294, 1296, 1298, 1300, 1302, 
+    1304, 319, 1306, 1308, 321, 1310, 1312, 333, 1314, 479, 341, 1316, 343, 
+    481, 51, 1318, 1320, 349, 581, 1322, 0, 1324, 0, 1326, 0, 1328, 0, 1330, 
+    0, 1332, 0, 1334, 0, 1336, 0, 1338, 0, 1340, 0, 1342, 0, 1344, 0, 1346, 
+    0, 1348, 0, 1350, 0, 1352, 0, 1354, 0, 1356, 0, 1358, 0, 1360, 0, 1362, 
+    0, 1364, 0, 1366, 0, 1368, 0, 1370, 0, 1372, 0, 1374, 0, 1376, 0, 1378, 
+    0, 1380, 0, 1382, 0, 1384, 0, 1386, 0, 1388, 0, 1390, 0, 1392, 0, 1394, 
+    0, 1396, 0, 1398, 0, 1400, 0, 1402, 0, 1404, 0, 1406, 0, 1408, 0, 1410, 
+    0, 1412, 0, 1414, 0, 1416, 0, 1418, 0, 1420, 0, 1422, 0, 1424, 0, 1426, 
+    0, 1428, 0, 1430, 0, 1432, 0, 1434, 0, 1436, 0, 1438, 0, 1440, 0, 1442, 
+    0, 1444, 0, 1446, 0, 1448, 0, 1450, 0, 1452, 0, 1454, 0, 1456, 0, 1458, 
+    0, 1460, 0, 1462, 0, 1464, 0, 1466, 0, 1468, 0, 1470, 0, 0, 0, 0, 0, 
+    1472, 1418, 0, 0, 148, 0, 1475, 0, 1477, 0, 1479, 0, 1481, 0, 1483, 0, 
+    1485, 0, 1487, 0, 1489, 0, 1491, 0, 1493, 0, 1495, 0, 1497, 0, 1499, 0, 
+    1501, 0, 1503, 0, 1505, 0, 1507, 0, 1509, 0, 1511, 0, 1513, 0, 1515, 0, 
+    1517, 0, 1519, 0, 1521, 0, 1523, 0, 1525, 0, 1527, 0, 1529, 0, 1531, 0, 
+    1533, 0, 1535, 0, 1537, 0, 1539, 0, 1541, 0, 1543, 0, 1545, 0, 1547, 0, 
+    1549, 0, 1551, 0, 1553, 0, 1555, 0, 1557, 0, 1559, 0, 1561, 0, 1563, 0, 
+    1565, 0, 1567, 0, 1569, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1571, 1573, 1575, 
+    1577, 1579, 1581, 1583, 1585, 0, 0, 0, 0, 0, 0, 0, 0, 1587, 1589, 1591, 
+    1593, 1595, 1597, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1599, 1601, 1603, 1605, 
+    1607, 1609, 1611, 1613, 0, 0, 0, 0, 0, 0, 0, 0, 1615, 1617, 1619, 1621, 
+    1623, 1625, 1627, 1629, 0, 0, 0, 0, 0, 0, 0, 0, 1631, 1633, 1635, 1637, 
+    1639, 1641, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1643, 0, 1645, 0, 1647, 0, 
+    1649, 0, 0, 0, 0, 0, 0, 0, 0, 1651, 1653, 1655, 1657, 1659, 1661, 1663, 
+    1665, 0, 551, 0, 555, 0, 557, 0, 559, 0, 561, 0, 563, 0, 565, 0, 0, 1667, 
+    1670, 1673, 1676, 1679, 1682, 1685, 1688, 1667, 1670, 1673, 1676, 1679, 
+    1682, 1685, 1688, 1691, 1694, 1697, 1700, 1703, 1706, 1709, 1712, 1691, 
+    1694, 1697, 1700, 1703, 1706, 1709, 1712, 1715, 1718, 1721, 1724, 1727, 
+    1730, 1733, 1736, 1715, 1718, 1721, 1724, 1727, 1730, 1733, 1736, 0, 0, 
+    1739, 1742, 1745, 0, 0, 1748, 1751, 1753, 1755, 551, 1742, 1757, 530, 
+    1757, 1760, 1763, 1767, 1770, 1773, 0, 0, 1776, 1779, 555, 1781, 557, 
+    1770, 1783, 1787, 1791, 0, 0, 0, 1795, 0, 0, 0, 0, 1797, 1799, 1801, 559, 
+    0, 1803, 1807, 1811, 0, 0, 0, 1815, 0, 0, 0, 0, 1817, 1819, 1821, 563, 
+    1823, 1825, 547, 1829, 0, 0, 1831, 1834, 1837, 0, 0, 1840, 1843, 561, 
+    1845, 565, 1834, 66, 1847, 0, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 
+    58, 58, 58, 58, 58, 0, 1850, 0, 0, 0, 0, 0, 1852, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 1855, 1857, 1860, 0, 0, 0, 58, 58, 58, 58, 58, 53, 0, 0, 0, 
+    1864, 1867, 0, 1871, 1874, 0, 0, 0, 0, 1878, 0, 1881, 0, 0, 0, 0, 0, 0, 
+    0, 0, 1884, 1887, 1890, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1893, 0, 
+    0, 0, 0, 0, 0, 0, 53, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 
+    58, 58, 58, 1898, 17, 0, 0, 1900, 1902, 1904, 1906, 1908, 1910, 1912, 
+    1914, 1916, 1918, 1920, 27, 1898, 74, 62, 64, 1900, 1902, 1904, 1906, 
+    1908, 1910, 1912, 1914, 1916, 1918, 1920, 0, 1, 9, 29, 47, 301, 15, 21, 
+    23, 25, 27, 31, 37, 39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1922, 0, 0, 0, 
+    0, 0, 0, 0, 1925, 1929, 5, 1933, 0, 1936, 1940, 303, 0, 1944, 13, 15, 15, 
+    15, 15, 189, 17, 17, 23, 23, 0, 27, 1947, 0, 0, 31, 33, 35, 35, 35, 0, 0, 
+    1950, 1953, 1957, 0, 51, 0, 609, 0, 51, 0, 21, 98, 3, 5, 0, 9, 9, 11, 
+    1960, 25, 29, 1962, 1964, 1966, 1968, 17, 0, 1970, 593, 571, 571, 593, 
+    1974, 0, 0, 0, 0, 7, 7, 9, 17, 19, 0, 0, 0, 0, 0, 0, 1976, 1980, 1984, 
+    1989, 1993, 1997, 2001, 2005, 2009, 2013, 2017, 2021, 2025, 2029, 2033, 
+    2037, 17, 2040, 2043, 2047, 43, 2050, 2053, 2057, 2062, 47, 2065, 2068, 
+    23, 5, 7, 25, 0, 0, 0, 2072, 0, 0, 0, 0, 0, 2074, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2078, 2081, 0, 2085, 2088, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2092, 2094, 0, 
+    0, 0, 0, 0, 74, 62, 64, 1900, 1902, 1904, 1906, 1908, 1910, 2096, 2099, 
+    2102, 2105, 2108, 2111, 2114, 2117, 2120, 2123, 2126, 2129, 2133, 2137, 
+    2141, 2145, 2149, 2153, 2157, 2161, 2165, 2170, 2175, 2180, 2185, 2190, 
+    2195, 2200, 2205, 2210, 2215, 2220, 2223, 2226, 2229, 2232, 2235, 2238, 
+    2241, 2244, 2247, 2251, 2255, 2259, 2263, 2267, 2271, 2275, 2279, 2283, 
+    2287, 2291, 2295, 2299, 2303, 2307, 2311, 2315, 2319, 2323, 2327, 2331, 
+    2335, 2339, 2343, 2347, 2351, 2355, 2359, 2363, 2367, 2371, 2375, 2379, 
+    2383, 2387, 2391, 1, 3, 5, 7, 9, 11, 13, 15, 17, 19, 21, 23, 25, 27, 29, 
+    31, 33, 35, 37, 39, 41, 43, 45, 47, 49, 51, 1, 3, 5, 7, 9, 11, 13, 15, 
+    17, 19, 21, 23, 25, 27, 29, 31, 33, 35, 37, 39, 41, 43, 45, 47, 49, 51, 
+    1898, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2395, 0, 0, 0, 
+    0, 0, 0, 0, 2400, 2404, 2407, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 2411, 0, 0, 0, 2414, 2416, 2418, 2420, 2422, 2424, 
+    2426, 2428, 2430, 2432, 2434, 2436, 2438, 2440, 2442, 2444, 2446, 2448, 
+    2450, 2452, 2454, 2456, 2458, 2460, 2462, 2464, 2466, 2468, 2470, 2472, 
+    2474, 2476, 2478, 2480, 2482, 2484, 2486, 2488, 2490, 2492, 2494, 2496, 
+    2498, 2500, 2502, 2504, 2506, 0, 2508, 0, 2510, 2512, 2514, 0, 0, 2516, 
+    0, 2518, 0, 2520, 0, 1266, 1302, 1264, 1280, 0, 2522, 0, 0, 2524, 0, 0, 
+    0, 0, 0, 0, 19, 43, 2526, 2528, 2530, 0, 2532, 0, 2534, 0, 2536, 0, 2538, 
+    0, 2540, 0, 2542, 0, 2544, 0, 2546, 0, 2548, 0, 2550, 0, 2552, 0, 2554, 
+    0, 2556, 0, 2558, 0, 2560, 0, 2562, 0, 2564, 0, 2566, 0, 2568, 0, 2570, 
+    0, 2572, 0, 2574, 0, 2576, 0, 2578, 0, 2580, 0, 2582, 0, 2584, 0, 2586, 
+    0, 2588, 0, 2590, 0, 2592, 0, 2594, 0, 2596, 0, 2598, 0, 2600, 0, 2602, 
+    0, 2604, 0, 2606, 0, 2608, 0, 2610, 0, 2612, 0, 2614, 0, 2616, 0, 2618, 
+    0, 2620, 0, 2622, 0, 2624, 0, 2626, 0, 2628, 0, 0, 0, 0, 0, 0, 0, 0, 
+    2630, 0, 2632, 0, 0, 0, 0, 2634, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2636, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 2638, 0, 0, 0, 2640, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 2642, 2644, 2646, 2648, 2650, 2652, 2654, 2656, 2658, 2660, 
+    2662, 2664, 2666, 2668, 2670, 2672, 2674, 2676, 2678, 2680, 2682, 2684, 
+    2686, 2688, 2690, 2692, 2694, 2696, 2698, 2700, 2702, 2704, 2706, 2708, 
+    2710, 2712, 2714, 2716, 2718, 2720, 2722, 2724, 2726, 2728, 2730, 2732, 
+    2734, 2736, 2738, 2740, 2742, 2744, 2746, 2748, 2750, 2752, 2754, 2756, 
+    2758, 2760, 2762, 2764, 2766, 2768, 2770, 2772, 2774, 2776, 2778, 2780, 
+    2782, 2784, 2786, 2788, 2790, 2792, 2794, 2796, 2798, 2800, 2802, 2804, 
+    2806, 2808, 2810, 2812, 2814, 2816, 2818, 2820, 2822, 2824, 2826, 2828, 
+    2830, 2832, 2834, 2836, 2838, 2840, 2842, 2844, 2846, 2848, 2850, 2852, 
+    2854, 2856, 2858, 2860, 2862, 2864, 2866, 2868, 2870, 2872, 2874, 2876, 
+    2878, 2880, 2882, 2884, 2886, 2888, 2890, 2892, 2894, 2896, 2898, 2900, 
+    2902, 2904, 2906, 2908, 2910, 2912, 2914, 2916, 2918, 2920, 2922, 2924, 
+    2926, 2928, 2930, 2932, 2934, 2936, 2938, 2940, 2942, 2944, 2946, 2948, 
+    2950, 2952, 2954, 2956, 2958, 2960, 2962, 2964, 2966, 2968, 2970, 2972, 
+    2974, 2976, 2978, 2980, 2982, 2984, 2986, 2988, 2990, 2992, 2994, 2996, 
+    2998, 3000, 3002, 3004, 3006, 3008, 3010, 3012, 3014, 3016, 3018, 3020, 
+    3022, 3024, 3026, 3028, 3030, 3032, 3034, 3036, 3038, 3040, 3042, 3044, 
+    3046, 3048, 3050, 3052, 3054, 3056, 3058, 3060, 3062, 3064, 3066, 3068, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 3070, 0, 2688, 3072, 3074, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 3076, 3079, 0, 0, 3082, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 3085, 0, 3088, 3090, 3092, 3094, 3096, 3098, 
+    3100, 3102, 3104, 3106, 3108, 3110, 3112, 3114, 3116, 3118, 3120, 3122, 
+    3124, 3126, 3128, 3130, 3132, 3134, 3136, 3138, 3140, 3142, 3144, 3146, 
+    3148, 3150, 3152, 3154, 3156, 3158, 3160, 3162, 3164, 3166, 3168, 3170, 
+    3172, 3174, 3176, 3178, 3180, 3182, 3184, 3186, 3188, 58, 3190, 3192, 
+    3194, 3196, 3198, 3200, 3202, 3204, 3206, 3208, 3210, 3212, 3214, 3216, 
+    3218, 3220, 3222, 3224, 3226, 3228, 3230, 3232, 3234, 3236, 3238, 3240, 
+    3242, 3244, 3246, 3248, 3250, 3252, 3254, 3256, 3258, 3260, 3262, 3264, 
+    3266, 3268, 3270, 3272, 0, 0, 0, 2642, 2654, 3274, 3276, 3278, 3280, 
+    3282, 3284, 2650, 3286, 3288, 3290, 3292, 2658, 3294, 3298, 3302, 3306, 
+    3310, 3314, 3318, 3322, 3326, 3330, 3334, 3338, 3342, 3346, 3350, 3354, 
+    3358, 3362, 3366, 3370, 3374, 3378, 3382, 3386, 3390, 3394, 3398, 3402, 
+    3406, 3410, 3415, 0, 3420, 3424, 3428, 3432, 3436, 3440, 3444, 3448, 
+    3452, 3456, 3460, 3464, 3468, 3472, 3476, 3480, 3484, 3488, 3492, 3496, 
+    3500, 3504, 3508, 3512, 3516, 3520, 3524, 3528, 3532, 3536, 3540, 3544, 
+    3548, 3552, 3556, 3560, 3564, 3566, 2774, 3568, 0, 0, 0, 0, 0, 0, 0, 0, 
+    3570, 3574, 3577, 3580, 3583, 3586, 3589, 3592, 3595, 3598, 3601, 3604, 
+    3607, 3610, 3613, 3616, 3088, 3094, 3100, 3104, 3120, 3122, 3128, 3132, 
+    3134, 3138, 3140, 3142, 3144, 3146, 3619, 3621, 3623, 3625, 3627, 3629, 
+    3631, 3633, 3635, 3637, 3639, 3641, 3643, 3645, 3647, 3650, 3653, 0, 
+    2642, 2654, 3274, 3276, 3655, 3657, 3659, 2664, 3661, 2688, 2788, 2812, 
+    2810, 2790, 2974, 2704, 2784, 3663, 3665, 3667, 3669, 3671, 3673, 3675, 
+    3677, 3679, 3681, 2716, 3683, 3685, 3687, 3689, 3691, 3693, 3695, 3697, 
+    3278, 3280, 3282, 3699, 3701, 3703, 3705, 3707, 3709, 3711, 3713, 3715, 
+    3717, 3719, 3722, 3725, 3728, 3731, 3734, 3737, 3740, 3743, 3746, 3749, 
+    3752, 3755, 3758, 3761, 3764, 3767, 3770, 3773, 3776, 3779, 3782, 3785, 
+    3788, 3791, 3795, 3799, 3803, 3806, 3810, 3813, 3817, 3819, 3821, 3823, 
+    3825, 3827, 3829, 3831, 3833, 3835, 3837, 3839, 3841, 3843, 3845, 3847, 
+    3849, 3851, 3853, 3855, 3857, 3859, 3861, 3863, 3865, 3867, 3869, 3871, 
+    3873, 3875, 3877, 3879, 3881, 3883, 3885, 3887, 3889, 3891, 3893, 3895, 
+    3897, 3899, 3901, 3903, 3905, 3907, 3909, 0, 3911, 3916, 3921, 3926, 
+    3930, 3935, 3939, 3943, 3949, 3954, 3958, 3962, 3966, 3971, 3976, 3980, 
+    3984, 3987, 3991, 3996, 4001, 4004, 4010, 4017, 4023, 4027, 4033, 4039, 
+    4044, 4048, 4052, 4056, 4061, 4067, 4072, 4076, 4080, 4084, 4087, 4090, 
+    4093, 4096, 4100, 4104, 4110, 4114, 4119, 4125, 4129, 4132, 4135, 4141, 
+    4146, 4152, 4156, 4162, 4165, 4169, 4173, 4177, 4181, 4185, 4190, 4194, 
+    4197, 4201, 4205, 4209, 4214, 4218, 4222, 4226, 4232, 4237, 4240, 4246, 
+    4249, 4254, 4259, 4263, 4267, 4271, 4276, 4279, 4283, 4288, 4291, 4297, 
+    4301, 4304, 4307, 4310, 4313, 4316, 4319, 4322, 4325, 4328, 4331, 4335, 
+    4339, 4343, 4347, 4351, 4355, 4359, 4363, 4367, 4371, 4375, 4379, 4383, 
+    4387, 4391, 4395, 4398, 4401, 4405, 4408, 4411, 4414, 4418, 4422, 4425, 
+    4428, 4431, 4434, 4437, 4442, 4445, 4448, 4451, 4454, 4457, 4460, 4463, 
+    4466, 4470, 4475, 4478, 4481, 4484, 4487, 4490, 4493, 4496, 4500, 4504, 
+    4508, 4512, 4515, 4518, 4521, 4524, 4527, 4530, 4533, 4536, 4539, 4542, 
+    4546, 4550, 4553, 4557, 4561, 4565, 4568, 4572, 4576, 4442, 4581, 4585, 
+    4589, 4593, 4597, 4603, 4610, 4613, 4616, 4619, 4622, 4625, 4628, 4631, 
+    4634, 4631, 4637, 4640, 4643, 4646, 4649, 4646, 4652, 4655, 4658, 4663, 
+    4666, 4669, 4672, 4677, 4681, 4684, 4687, 4690, 4693, 4696, 4539, 4699, 
+    4702, 4705, 4708, 4712, 4460, 4715, 4719, 4723, 4726, 4731, 4735, 4738, 
+    4741, 4744, 4747, 4751, 4755, 4758, 4761, 4764, 4767, 4770, 4773, 4776, 
+    4779, 4782, 4786, 4790, 4794, 4798, 4802, 4806, 4810, 4814, 4818, 4822, 
+    4826, 4830, 4834, 4838, 4842, 4846, 4850, 4854, 4858, 4862, 4866, 4870, 
+    4874, 0, 4876, 0, 4878, 0, 4880, 0, 4882, 0, 1262, 0, 4884, 0, 4886, 0, 
+    4888, 0, 4890, 0, 4892, 0, 4894, 0, 4896, 0, 4898, 0, 4900, 0, 4902, 0, 
+    4904, 0, 4906, 0, 4908, 0, 4910, 0, 4912, 0, 4914, 0, 4916, 0, 0, 0, 
+    4918, 0, 4920, 0, 4922, 0, 4924, 0, 4926, 0, 4928, 0, 4930, 0, 4932, 0, 
+    4934, 0, 4936, 0, 4938, 0, 4940, 0, 4942, 0, 4944, 0, 735, 739, 0, 0, 0, 
+    0, 4946, 0, 4948, 0, 4950, 0, 4952, 0, 4954, 0, 4956, 0, 4958, 0, 0, 0, 
+    4960, 0, 4962, 0, 4964, 0, 4966, 0, 4968, 0, 4970, 0, 4972, 0, 4974, 0, 
+    4976, 0, 4978, 0, 4980, 0, 4982, 0, 4984, 0, 4986, 0, 4988, 0, 4990, 0, 
+    4992, 0, 4994, 0, 4996, 0, 4998, 0, 5000, 0, 5002, 0, 5004, 0, 5006, 0, 
+    5008, 0, 5010, 0, 5012, 0, 5014, 0, 5016, 0, 5018, 0, 5020, 0, 5020, 0, 
+    0, 0, 0, 0, 0, 0, 0, 5022, 0, 5024, 0, 5026, 5028, 0, 5030, 0, 5032, 0, 
+    5034, 0, 5036, 0, 0, 0, 0, 5038, 0, 1288, 0, 0, 5040, 0, 5042, 0, 0, 0, 
+    5044, 0, 5046, 0, 5048, 0, 5050, 0, 5052, 0, 5054, 0, 5056, 0, 5058, 0, 
+    5060, 0, 5062, 0, 493, 1270, 1286, 5064, 1290, 0, 5066, 5068, 1294, 5070, 
+    5072, 0, 5074, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 189, 
+    237, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4950, 5076, 
+    2510, 5078, 5080, 5082, 5084, 5086, 5088, 5090, 5092, 5094, 5096, 5098, 
+    5100, 5102, 5104, 5106, 5108, 5110, 5112, 5114, 5116, 5118, 5120, 5122, 
+    5124, 5126, 5128, 5130, 5132, 5134, 5136, 5138, 5140, 5142, 5144, 5146, 
+    5148, 5150, 5152, 5154, 5156, 5158, 5160, 5162, 5164, 5166, 5168, 5170, 
+    5172, 5174, 5176, 5178, 5180, 5182, 5184, 5186, 5188, 5190, 5192, 5194, 
+    5196, 5198, 5200, 5202, 5204, 5206, 5208, 5210, 5212, 5214, 5216, 5218, 
+    5220, 5222, 5224, 5226, 5228, 5230, 5232, 5234, 5236, 5238, 5240, 5242, 
+    2958, 5244, 5246, 5248, 5250, 3066, 3066, 5252, 2974, 5254, 5256, 5258, 
+    5260, 5262, 5264, 5266, 5268, 5270, 5272, 5274, 5276, 5278, 5280, 5282, 
+    5284, 5286, 5288, 5290, 5292, 5294, 5296, 5298, 5300, 5302, 5304, 5306, 
+    5308, 5310, 5312, 5314, 5316, 5318, 5320, 5322, 5324, 5326, 5328, 5330, 
+    5332, 5334, 2890, 5336, 5338, 5340, 5342, 5344, 5346, 5348, 5350, 5352, 
+    5354, 5356, 3036, 5358, 5360, 5362, 5364, 5366, 5368, 5370, 5372, 5374, 
+    5376, 5378, 5380, 5382, 5384, 5386, 5388, 5390, 5392, 5394, 5396, 5398, 
+    5400, 5402, 5404, 5406, 5408, 5410, 5272, 5412, 5414, 5416, 5418, 5420, 
+    5422, 5424, 5426, 5428, 5430, 5432, 5434, 5436, 5438, 5440, 5442, 5444, 
+    5446, 5448, 5450, 2962, 5452, 5454, 5456, 5458, 5460, 5462, 5464, 5466, 
+    5468, 5470, 5472, 5474, 5476, 5478, 5480, 2716, 5482, 5484, 5486, 5488, 
+    5490, 5492, 5494, 5496, 2678, 5498, 5500, 5502, 5504, 5506, 5508, 5510, 
+    5512, 5514, 5516, 5518, 5520, 5522, 5524, 5526, 5528, 5530, 5532, 5534, 
+    5536, 5538, 5540, 5448, 5542, 5544, 5546, 5548, 5550, 5552, 5554, 5556, 
+    5416, 5558, 5560, 5562, 5564, 5566, 5568, 5570, 5572, 5574, 5576, 5578, 
+    5580, 5582, 5584, 5586, 5588, 5590, 5592, 5594, 5596, 5272, 5598, 5600, 
+    5602, 5604, 3064, 5606, 5608, 5610, 5612, 5614, 5616, 5618, 5620, 5622, 
+    5624, 5626, 5628, 3657, 5630, 5632, 5634, 5636, 5638, 5640, 5642, 5644, 
+    5646, 5420, 5648, 5650, 5652, 5654, 5656, 5658, 5660, 5662, 5664, 5666, 
+    5668, 5670, 5672, 2972, 5674, 5676, 5678, 5680, 5682, 5684, 5686, 5688, 
+    5690, 5692, 5694, 5696, 5698, 2874, 5700, 5702, 5704, 5706, 5708, 5710, 
+    5712, 5714, 5716, 5718, 5720, 5722, 5724, 5726, 5728, 5730, 2928, 5732, 
+    2934, 5734, 5736, 5738, 0, 0, 5740, 0, 5742, 0, 0, 5744, 5746, 5748, 
+    5750, 5752, 5754, 5756, 5758, 5760, 2888, 0, 5762, 0, 5764, 0, 0, 5766, 
+    5768, 0, 0, 0, 5770, 5772, 5774, 5776, 5778, 5780, 5782, 5784, 5786, 
+    5788, 5790, 5792, 5794, 5796, 5798, 5800, 5802, 5804, 2730, 5806, 5808, 
+    5810, 5812, 5814, 5816, 5818, 5820, 5822, 5824, 5826, 5828, 5830, 5832, 
+    5834, 3667, 5836, 5838, 5840, 5842, 3675, 5844, 5846, 5848, 5850, 5852, 
+    5520, 5854, 5856, 5858, 5860, 5862, 5864, 5864, 5866, 5868, 5870, 5872, 
+    5874, 5876, 5878, 5880, 5766, 5882, 5884, 5886, 5888, 5890, 5893, 0, 0, 
+    5895, 5897, 5899, 5901, 5903, 5905, 5907, 5909, 5794, 5911, 5913, 5915, 
+    5740, 5917, 5919, 5921, 5923, 5925, 5927, 5929, 5931, 5933, 5935, 5937, 
+    5939, 5810, 5941, 5812, 5943, 5945, 5947, 5949, 5951, 5742, 5314, 5953, 
+    5955, 2796, 5450, 5616, 5957, 5959, 5826, 5961, 5828, 5963, 5965, 5967, 
+    5746, 5969, 5971, 5973, 5975, 5977, 5748, 5979, 5981, 5983, 5985, 5987, 
+    5989, 5852, 5991, 5993, 5520, 5995, 5860, 5997, 5999, 6001, 6003, 6005, 
+    5870, 6007, 5764, 6009, 5872, 5412, 6011, 5874, 6013, 5878, 6015, 6017, 
+    6019, 6021, 6023, 5882, 5758, 6025, 5884, 6027, 5886, 6029, 3066, 6031, 
+    6034, 6037, 6040, 6042, 6044, 6046, 6049, 6052, 6055, 6057, 0, 0, 0, 0, 
+    0, 0, 6059, 6062, 6065, 6068, 6072, 6076, 6076, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 6079, 6082, 6085, 6088, 6091, 0, 0, 0, 0, 0, 6094, 0, 6097, 
+    6100, 1962, 1968, 6102, 6104, 6106, 6108, 6110, 6112, 1912, 6114, 6117, 
+    6120, 6124, 6128, 6131, 6134, 6137, 6140, 6143, 6146, 6149, 6152, 0, 
+    6155, 6158, 6161, 6164, 6167, 0, 6170, 0, 6173, 6176, 0, 6179, 6182, 0, 
+    6185, 6188, 6191, 6194, 6197, 6200, 6203, 6206, 6209, 6212, 6215, 6215, 
+    6217, 6217, 6217, 6217, 6219, 6219, 6219, 6219, 6221, 6221, 6221, 6221, 
+    6223, 6223, 6223, 6223, 6225, 6225, 6225, 6225, 6227, 6227, 6227, 6227, 
+    6229, 6229, 6229, 6229, 6231, 6231, 6231, 6231, 6233, 6233, 6233, 6233, 
+    6235, 6235, 6235, 6235, 6237, 6237, 6237, 6237, 6239, 6239, 6239, 6239, 
+    6241, 6241, 6243, 6243, 6245, 6245, 6247, 6247, 6249, 6249, 6251, 6251, 
+    6253, 6253, 6253, 6253, 6255, 6255, 6255, 6255, 6257, 6257, 6257, 6257, 
+    6259, 6259, 6259, 6259, 6261, 6261, 6263, 6263, 6263, 6263, 6265, 6265, 
+    6267, 6267, 6267, 6267, 6269, 6269, 6269, 6269, 6271, 6271, 6273, 6273, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6275, 6275, 6275, 
+    6275, 6277, 6277, 6279, 6279, 6281, 6281, 1032, 6283, 6283, 6285, 6285, 
+    6287, 6287, 6289, 6289, 6289, 6289, 6291, 6291, 6293, 6293, 6296, 6296, 
+    6299, 6299, 6302, 6302, 6305, 6305, 6308, 6308, 6311, 6311, 6311, 6314, 
+    6314, 6314, 6317, 6317, 6317, 6317, 6319, 6322, 6325, 6314, 6328, 6331, 
+    6334, 6337, 6340, 6343, 6346, 6349, 6352, 6355, 6358, 6361, 6364, 6367, 
+    6370, 6373, 6376, 6379, 6382, 6385, 6388, 6391, 6394, 6397, 6400, 6403, 
+    6406, 6409, 6412, 6415, 6418, 6421, 6424, 6427, 6430, 6433, 6436, 6439, 
+    6442, 6445, 6448, 6451, 6454, 6457, 6460, 6463, 6466, 6469, 6472, 6475, 
+    6478, 6481, 6484, 6487, 6490, 6493, 6496, 6499, 6502, 6505, 6508, 6511, 
+    6514, 6517, 6520, 6523, 6526, 6529, 6532, 6535, 6538, 6541, 6544, 6547, 
+    6550, 6553, 6556, 6559, 6562, 6565, 6568, 6571, 6574, 6577, 6580, 6583, 
+    6586, 6589, 6592, 6595, 6598, 6602, 6606, 6610, 6614, 6618, 6622, 6625, 
+    6325, 6628, 6314, 6328, 6631, 6634, 6340, 6637, 6343, 6346, 6640, 6643, 
+    6358, 6646, 6361, 6364, 6649, 6652, 6370, 6655, 6373, 6376, 6463, 6466, 
+    6475, 6478, 6481, 6493, 6496, 6499, 6502, 6514, 6517, 6520, 6658, 6532, 
+    6661, 6664, 6550, 6667, 6553, 6556, 6595, 6670, 6673, 6580, 6676, 6583, 
+    6586, 6319, 6322, 6679, 6325, 6682, 6331, 6334, 6337, 6340, 6685, 6349, 
+    6352, 6355, 6358, 6688, 6370, 6379, 6382, 6385, 6388, 6391, 6397, 6400, 
+    6403, 6406, 6409, 6412, 6691, 6415, 6418, 6421, 6424, 6427, 6430, 6436, 
+    6439, 6442, 6445, 6448, 6451, 6454, 6457, 6460, 6469, 6472, 6484, 6487, 
+    6490, 6493, 6496, 6505, 6508, 6511, 6514, 6694, 6523, 6526, 6529, 6532, 
+    6541, 6544, 6547, 6550, 6697, 6559, 6562, 6700, 6571, 6574, 6577, 6580, 
+    6703, 6325, 6682, 6340, 6685, 6358, 6688, 6370, 6706, 6409, 6709, 6712, 
+    6715, 6493, 6496, 6514, 6550, 6697, 6580, 6703, 6718, 6722, 6726, 6730, 
+    6733, 6736, 6739, 6742, 6745, 6748, 6751, 6754, 6757, 6760, 6763, 6766, 
+    6769, 6772, 6775, 6778, 6781, 6784, 6787, 6790, 6793, 6796, 6712, 6799, 
+    6802, 6805, 6808, 6730, 6733, 6736, 6739, 6742, 6745, 6748, 6751, 6754, 
+    6757, 6760, 6763, 6766, 6769, 6772, 6775, 6778, 6781, 6784, 6787, 6790, 
+    6793, 6796, 6712, 6799, 6802, 6805, 6808, 6790, 6793, 6796, 6712, 6709, 
+    6715, 6433, 6400, 6403, 6406, 6790, 6793, 6796, 6433, 6436, 6811, 6811, 
+    0, 0, 6814, 6818, 6818, 6822, 6826, 6830, 6834, 6838, 6842, 6842, 6846, 
+    6850, 6854, 6858, 6862, 6866, 6866, 6870, 6874, 6874, 6878, 6878, 6882, 
+    6886, 6886, 6890, 6894, 6894, 6898, 6898, 6902, 6906, 6906, 6910, 6910, 
+    6914, 6918, 6922, 6926, 6926, 6930, 6934, 6938, 6942, 6946, 6946, 6950, 
+    6954, 6958, 6962, 6966, 6970, 6970, 6974, 6974, 6978, 6978, 6982, 6986, 
+    6990, 6994, 6998, 7002, 7006, 0, 0, 7010, 7014, 7018, 7022, 7026, 7030, 
+    7030, 7034, 7038, 7042, 7046, 7046, 7050, 7054, 7058, 7062, 7066, 7070, 
+    7074, 7078, 7082, 7086, 7090, 7094, 7098, 7102, 7106, 7110, 7114, 7118, 
+    7122, 7126, 7130, 7134, 6950, 6958, 7138, 7142, 7146, 7150, 7154, 7158, 
+    7154, 7146, 7162, 7166, 7170, 7174, 7178, 7158, 6922, 6882, 7182, 7186, 
+    0, 0, 0, 0, 0, 0, 0, 0, 7190, 7194, 7198, 7203, 7208, 7213, 7218, 7223, 
+    7228, 7233, 7237, 7256, 7265, 0, 0, 0, 7270, 7272, 7274, 7276, 543, 7278, 
+    7280, 7282, 7284, 1860, 0, 0, 0, 0, 0, 0, 1857, 7286, 7288, 7290, 7290, 
+    1918, 1920, 7292, 7294, 7296, 7298, 7300, 7302, 7304, 7306, 2092, 2094, 
+    7308, 7310, 7312, 7314, 0, 0, 7316, 7318, 1881, 1881, 1881, 1881, 7290, 
+    7290, 7290, 7270, 7272, 1855, 0, 543, 7276, 7280, 7278, 7286, 1918, 1920, 
+    7292, 7294, 7296, 7298, 7320, 7322, 7324, 1912, 7326, 7328, 7330, 1916, 
+    0, 7332, 7334, 7336, 7338, 0, 0, 0, 0, 7340, 7343, 7346, 0, 7349, 0, 
+    7352, 7355, 7358, 7361, 7364, 7367, 7370, 7373, 7376, 7379, 7382, 7384, 
+    7384, 7386, 7386, 7388, 7388, 7390, 7390, 7392, 7392, 7392, 7392, 7394, 
+    7394, 7396, 7396, 7396, 7396, 7398, 7398, 7400, 7400, 7400, 7400, 7402, 
+    7402, 7402, 7402, 7404, 7404, 7404, 7404, 7406, 7406, 7406, 7406, 7408, 
+    7408, 7408, 7408, 7410, 7410, 7412, 7412, 7414, 7414, 7416, 7416, 7418, 
+    7418, 7418, 7418, 7420, 7420, 7420, 7420, 7422, 7422, 7422, 7422, 7424, 
+    7424, 7424, 7424, 7426, 7426, 7426, 7426, 7428, 7428, 7428, 7428, 7430, 
+    7430, 7430, 7430, 7432, 7432, 7432, 7432, 7434, 7434, 7434, 7434, 7436, 
+    7436, 7436, 7436, 7438, 7438, 7438, 7438, 7440, 7440, 7440, 7440, 7442, 
+    7442, 7442, 7442, 7444, 7444, 7444, 7444, 7446, 7446, 7446, 7446, 7448, 
+    7448, 6291, 6291, 7450, 7450, 7450, 7450, 7452, 7452, 7455, 7455, 7458, 
+    7458, 7461, 7461, 0, 0, 58, 0, 7278, 7464, 7320, 7334, 7336, 7322, 7466, 
+    1918, 1920, 7324, 1912, 7270, 7326, 1855, 7468, 1898, 74, 62, 64, 1900, 
+    1902, 1904, 1906, 1908, 1910, 7276, 543, 7328, 1916, 7330, 7280, 7338, 1, 
+    3, 5, 7, 9, 11, 13, 15, 17, 19, 21, 23, 25, 27, 29, 31, 33, 35, 37, 39, 
+    41, 43, 45, 47, 49, 51, 7316, 7332, 7318, 7470, 7290, 1829, 1, 3, 5, 7, 
+    9, 11, 13, 15, 17, 19, 21, 23, 25, 27, 29, 31, 33, 35, 37, 39, 41, 43, 
+    45, 47, 49, 51, 7292, 7472, 7294, 7474, 7476, 7478, 7274, 7308, 7310, 
+    7272, 7480, 3909, 7482, 7484, 7486, 7488, 7490, 7492, 7494, 7496, 7498, 
+    7500, 3817, 3819, 3821, 3823, 3825, 3827, 3829, 3831, 3833, 3835, 3837, 
+    3839, 3841, 3843, 3845, 3847, 3849, 3851, 3853, 3855, 3857, 3859, 3861, 
+    3863, 3865, 3867, 3869, 3871, 3873, 3875, 3877, 3879, 3881, 3883, 3885, 
+    3887, 3889, 3891, 3893, 3895, 3897, 3899, 3901, 3903, 7502, 7504, 7506, 
+    58, 3088, 3090, 3092, 3094, 3096, 3098, 3100, 3102, 3104, 3106, 3108, 
+    3110, 3112, 3114, 3116, 3118, 3120, 3122, 3124, 3126, 3128, 3130, 3132, 
+    3134, 3136, 3138, 3140, 3142, 3144, 3146, 0, 0, 0, 3148, 3150, 3152, 
+    3154, 3156, 3158, 0, 0, 3160, 3162, 3164, 3166, 3168, 3170, 0, 0, 3172, 
+    3174, 3176, 3178, 3180, 3182, 0, 0, 3184, 3186, 3188, 0, 0, 0, 7508, 
+    7510, 7512, 59, 7514, 7516, 7518, 0, 7520, 7522, 7524, 7526, 7528, 7530, 
+    7532, 0, 58, 58, 58, 58, 58, 58, 58, 58, 58, 0, 0, 0, 0, 0, 0, 0, 7534, 
+    7537, 7540, 7543, 7546, 7549, 7552, 7555, 7558, 7561, 7564, 7567, 7570, 
+    7573, 7576, 7579, 7582, 7585, 7588, 7591, 7594, 7597, 7600, 7603, 7606, 
+    7609, 7612, 7615, 7618, 7621, 7624, 7627, 7630, 7633, 7636, 7639, 7642, 
+    7645, 7648, 7651, 0, 0, 0, 0, 0, 0, 0, 0, 7654, 7657, 7660, 7663, 7666, 
+    7669, 7672, 7675, 7678, 7681, 7684, 7687, 7690, 7693, 7696, 7699, 7702, 
+    7705, 7708, 7711, 7714, 7717, 7720, 7723, 7726, 7729, 7732, 7735, 7738, 
+    7741, 7744, 7747, 7750, 7753, 7756, 7759, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 7762, 7765, 7768, 7771, 7774, 7777, 7780, 7783, 7786, 7789, 7792, 
+    7795, 7798, 7801, 7804, 7807, 7810, 7813, 7816, 7819, 7822, 7825, 7828, 
+    7831, 7834, 7837, 7840, 7843, 7846, 7849, 7852, 7855, 7858, 7861, 7864, 
+    7867, 7870, 7873, 7876, 7879, 7882, 7885, 7888, 7891, 7894, 7897, 7900, 
+    7903, 7906, 7909, 7912, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7915, 
+    7918, 7921, 7924, 7927, 7930, 7933, 7936, 7939, 7942, 7945, 7948, 7951, 
+    7954, 7957, 7960, 7963, 7966, 7969, 7972, 7975, 7978, 7981, 7984, 7987, 
+    7990, 7993, 7996, 7999, 8002, 8005, 8008, 58, 58, 58, 58, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8011, 
+    8016, 8021, 8028, 8035, 8042, 8049, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 58, 58, 58, 58, 58, 58, 58, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 8056, 8061, 8066, 8073, 8080, 8087, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 33, 35, 37, 39, 41, 43, 45, 47, 49, 51, 1, 3, 5, 7, 
+    9, 11, 13, 15, 17, 19, 21, 23, 25, 27, 29, 31, 33, 35, 37, 39, 41, 43, 
+    45, 47, 49, 51, 1, 3, 5, 7, 9, 11, 13, 15, 17, 19, 21, 23, 25, 27, 29, 
+    31, 33, 35, 37, 39, 41, 43, 45, 47, 49, 51, 1, 3, 5, 7, 9, 11, 13, 0, 17, 
+    19, 21, 23, 25, 27, 29, 31, 33, 35, 37, 39, 41, 43, 45, 47, 49, 51, 1, 3, 
+    5, 7, 9, 11, 13, 15, 17, 19, 21, 23, 25, 27, 29, 31, 33, 35, 37, 39, 41, 
+    43, 45, 47, 49, 51, 1, 3, 5, 7, 9, 11, 13, 15, 17, 19, 21, 23, 25, 27, 
+    29, 31, 33, 35, 37, 39, 41, 43, 45, 47, 49, 51, 1, 0, 5, 7, 0, 0, 13, 0, 
+    0, 19, 21, 0, 0, 27, 29, 31, 33, 0, 37, 39, 41, 43, 45, 47, 49, 51, 1, 3, 
+    5, 7, 0, 11, 0, 15, 17, 19, 21, 23, 25, 27, 0, 31, 33, 35, 37, 39, 41, 
+    43, 45, 47, 49, 51, 45, 47, 49, 51, 1, 3, 0, 7, 9, 11, 13, 0, 0, 19, 21, 
+    23, 25, 27, 29, 31, 33, 0, 37, 39, 41, 43, 45, 47, 49, 0, 1, 3, 5, 7, 9, 
+    11, 13, 15, 17, 19, 21, 23, 25, 27, 29, 31, 33, 35, 37, 39, 41, 43, 45, 
+    47, 49, 51, 1, 3, 0, 7, 9, 11, 13, 0, 17, 19, 21, 23, 25, 0, 29, 0, 0, 0, 
+    37, 39, 41, 43, 45, 47, 49, 0, 1, 3, 5, 7, 9, 11, 13, 15, 17, 19, 21, 23, 
+    25, 27, 29, 31, 33, 35, 37, 39, 41, 43, 45, 47, 49, 51, 1, 3, 5, 7, 9, 
+    11, 13, 15, 17, 19, 21, 23, 25, 27, 29, 31, 33, 35, 37, 39, 41, 43, 45, 
+    47, 49, 51, 1, 3, 5, 7, 9, 11, 13, 15, 17, 19, 45, 47, 49, 51, 8094, 
+    8096, 0, 0, 567, 569, 571, 573, 575, 577, 579, 581, 530, 583, 585, 69, 
+    587, 589, 591, 593, 595, 581, 597, 599, 601, 603, 605, 607, 609, 8098, 
+    567, 569, 571, 573, 575, 577, 579, 581, 530, 583, 585, 69, 587, 589, 591, 
+    593, 595, 597, 597, 599, 601, 603, 605, 607, 609, 8100, 575, 581, 583, 
+    603, 595, 593, 567, 569, 571, 573, 575, 577, 579, 581, 530, 583, 585, 69, 
+    587, 589, 591, 593, 595, 581, 597, 599, 601, 603, 605, 607, 609, 8098, 
+    567, 569, 571, 573, 575, 577, 579, 581, 530, 583, 585, 69, 587, 589, 591, 
+    593, 595, 597, 597, 599, 601, 603, 605, 607, 609, 8100, 575, 581, 583, 
+    603, 595, 593, 567, 569, 571, 573, 575, 577, 579, 581, 530, 583, 585, 69, 
+    587, 589, 591, 593, 595, 581, 597, 599, 601, 603, 605, 607, 609, 8098, 
+    567, 569, 571, 573, 575, 577, 579, 581, 530, 583, 585, 69, 587, 589, 591, 
+    593, 595, 597, 597, 599, 601, 603, 605, 607, 609, 8100, 575, 581, 583, 
+    603, 595, 593, 567, 569, 571, 573, 575, 577, 579, 581, 530, 583, 585, 69, 
+    587, 589, 591, 593, 595, 581, 597, 599, 601, 603, 605, 607, 609, 8098, 
+    567, 569, 571, 573, 575, 577, 579, 581, 530, 583, 585, 69, 587, 589, 591, 
+    593, 595, 597, 597, 599, 601, 603, 605, 607, 609, 8100, 575, 581, 583, 
+    603, 595, 593, 595, 581, 597, 599, 601, 603, 605, 607, 609, 8098, 567, 
+    569, 571, 573, 575, 577, 579, 581, 530, 583, 585, 69, 587, 589, 591, 593, 
+    595, 597, 597, 599, 601, 603, 605, 607, 609, 8100, 575, 581, 583, 603, 
+    595, 593, 621, 621, 0, 0, 1898, 74, 62, 64, 1900, 1902, 1904, 1906, 1908, 
+    1910, 1898, 74, 62, 64, 1900, 1902, 1904, 1906, 1908, 1910, 1898, 74, 62, 
+    64, 1900, 1902, 1904, 1906, 1908, 1910, 1898, 74, 62, 64, 1900, 1902, 
+    1904, 1906, 1908, 1910, 1898, 74, 62, 64, 1900, 1902, 1904, 1906, 1908, 
+    1910, 8102, 8105, 8108, 8111, 8114, 8117, 8120, 8123, 8126, 8129, 8132, 
+    8135, 8138, 8141, 8144, 8147, 8150, 8153, 8156, 8159, 8162, 8165, 8168, 
+    8171, 8174, 8177, 8180, 8183, 8186, 8189, 8192, 8195, 8198, 8201, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7394, 7396, 7404, 7410, 0, 7448, 
+    7416, 7406, 7426, 7450, 7438, 7440, 7442, 7444, 7418, 7430, 7434, 7422, 
+    7436, 7414, 7420, 7400, 7402, 7408, 7412, 7424, 7428, 7432, 8204, 6261, 
+    8206, 8208, 0, 7396, 7404, 0, 7446, 0, 0, 7406, 0, 7450, 7438, 7440, 
+    7442, 7444, 7418, 7430, 7434, 7422, 7436, 0, 7420, 7400, 7402, 7408, 0, 
+    7424, 0, 7432, 0, 0, 0, 0, 0, 0, 7404, 0, 0, 0, 0, 7406, 0, 7450, 0, 
+    7440, 0, 7444, 7418, 7430, 0, 7422, 7436, 0, 7420, 0, 0, 7408, 0, 7424, 
+    0, 7432, 0, 6261, 0, 8208, 0, 7396, 7404, 0, 7446, 0, 0, 7406, 7426, 
+    7450, 7438, 0, 7442, 7444, 7418, 7430, 7434, 7422, 7436, 0, 7420, 7400, 
+    7402, 7408, 0, 7424, 7428, 7432, 8204, 0, 8206, 0, 7394, 7396, 7404, 
+    7410, 7446, 7448, 7416, 7406, 7426, 7450, 0, 7440, 7442, 7444, 7418, 
+    7430, 7434, 7422, 7436, 7414, 7420, 7400, 7402, 7408, 7412, 7424, 7428, 
+    7432, 0, 0, 0, 0, 0, 7396, 7404, 7410, 0, 7448, 7416, 7406, 7426, 7450, 
+    0, 7440, 7442, 7444, 7418, 7430, 8210, 8213, 8216, 8219, 8222, 8225, 
+    8228, 8231, 8234, 8237, 8240, 0, 0, 0, 0, 0, 2291, 2295, 2299, 2303, 
+    2307, 2311, 2315, 2319, 2323, 2327, 2331, 2335, 2339, 2343, 2347, 2351, 
+    2355, 2359, 2363, 2367, 2371, 2375, 2379, 2383, 2387, 2391, 8243, 5, 35, 
+    4669, 8247, 0, 33, 35, 37, 39, 41, 43, 45, 47, 49, 51, 8250, 4631, 8253, 
+    148, 8256, 8260, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8263, 8266, 0, 0, 0, 0, 
+    8269, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8272, 8275, 3837, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2768, 8278, 8280, 8282, 2654, 8284, 
+    8286, 3290, 8288, 8290, 8292, 5596, 8294, 8296, 8298, 8300, 8302, 8304, 
+    2840, 8306, 8308, 8310, 8312, 8314, 8316, 2642, 3274, 8318, 3699, 3280, 
+    3701, 8320, 2952, 8322, 8324, 8326, 8328, 8330, 3665, 2788, 8332, 8334, 
+    8336, 8338, 0, 0, 0, 0, 8340, 8344, 8348, 8352, 8356, 8360, 8364, 8368, 
+    8372, 0, 0, 0, 0, 0, 0, 0, 8376, 8378, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 8380, 8382, 8384, 8386, 8389, 5782, 8391, 8393, 8395, 8397, 
+    5784, 8399, 8401, 8403, 5786, 8406, 8408, 8410, 8412, 8415, 8417, 8298, 
+    8419, 8422, 8424, 8426, 8428, 5897, 8430, 2674, 8433, 8435, 8437, 8439, 
+    8334, 8441, 8443, 5907, 5788, 5790, 5909, 8445, 8447, 5424, 8449, 5792, 
+    8451, 8453, 8455, 8457, 8457, 8457, 8459, 8462, 8464, 8466, 8468, 8471, 
+    8473, 8475, 8477, 8479, 8481, 8483, 8485, 8487, 8489, 8491, 8493, 8495, 
+    8495, 5913, 8497, 8499, 8501, 8503, 5796, 8505, 8507, 8509, 5716, 8511, 
+    8513, 8515, 8517, 8519, 8521, 8523, 8525, 8527, 8530, 8532, 8534, 8284, 
+    8536, 8538, 8540, 8543, 8546, 8548, 8550, 8552, 8554, 8556, 8558, 8560, 
+    8562, 8562, 8564, 8567, 8569, 5416, 8571, 8573, 8576, 8578, 8580, 2726, 
+    8582, 8584, 2730, 8586, 8588, 8590, 8593, 8595, 8598, 8600, 8602, 8604, 
+    8606, 8608, 8610, 8612, 8614, 8616, 8618, 8620, 8623, 8625, 8627, 8629, 
+    5312, 8631, 2750, 8634, 8634, 8637, 8639, 8639, 8641, 8643, 8646, 8649, 
+    8651, 8653, 8655, 8657, 8659, 8661, 8663, 8665, 8667, 5806, 8669, 8672, 
+    8674, 8676, 5937, 8676, 8678, 5810, 8680, 8682, 8684, 8686, 5812, 5258, 
+    8688, 8690, 8692, 8694, 8696, 8698, 8700, 8703, 8705, 8707, 8709, 8711, 
+    8713, 8716, 8718, 8720, 8722, 8724, 8726, 8728, 8730, 8732, 5814, 8734, 
+    8736, 8739, 8741, 8743, 8745, 5818, 8747, 8749, 8751, 8753, 8755, 8757, 
+    8759, 8761, 5314, 5953, 8763, 8765, 8767, 8769, 8772, 8774, 8776, 8778, 
+    5820, 8780, 8783, 8785, 8787, 6040, 8789, 8791, 8793, 8795, 8797, 8800, 
+    8802, 8804, 8806, 8809, 8811, 8813, 8815, 5450, 8817, 8819, 8822, 8825, 
+    8828, 8830, 8833, 8835, 8837, 8839, 8841, 5822, 5616, 8843, 8845, 8847, 
+    8849, 8852, 8854, 8856, 8858, 5959, 8860, 8862, 8865, 8867, 8869, 8872, 
+    8875, 8877, 5961, 8879, 8881, 8883, 8885, 8887, 8889, 8891, 8894, 8896, 
+    8899, 8901, 8904, 5965, 8906, 8908, 8911, 8913, 8915, 8918, 8921, 8923, 
+    8925, 8927, 8929, 8929, 8931, 8933, 5969, 8935, 8937, 8939, 8941, 8943, 
+    8946, 8948, 5422, 8951, 8954, 8956, 8959, 8962, 8965, 8967, 5981, 8969, 
+    8972, 8975, 8978, 8981, 8983, 8983, 5983, 6044, 8985, 8987, 8989, 8991, 
+    8994, 5348, 5987, 8996, 8998, 5842, 9001, 9004, 5756, 9007, 9009, 5848, 
+    9011, 9013, 9015, 9018, 9018, 9021, 9023, 9025, 9028, 9030, 9032, 9034, 
+    9037, 9039, 9041, 9043, 9045, 9047, 9050, 9052, 9054, 9056, 9058, 9060, 
+    9062, 9065, 9068, 9070, 9073, 9075, 9078, 9080, 5860, 9082, 9085, 9088, 
+    9090, 9093, 9095, 9098, 9100, 9102, 9104, 9106, 9108, 9110, 9113, 9116, 
+    9119, 8637, 9122, 9124, 9126, 9128, 9130, 9132, 9134, 9136, 9138, 9140, 
+    9142, 9144, 5456, 9147, 9149, 9151, 9153, 9155, 9157, 5866, 9159, 9161, 
+    9163, 9165, 9167, 9170, 9173, 9176, 9178, 9180, 9182, 9184, 9187, 9189, 
+    9192, 9194, 9196, 9199, 9202, 9204, 5338, 9206, 9208, 9210, 9212, 9214, 
+    9216, 6001, 9218, 9220, 9222, 9224, 9226, 9228, 9230, 9232, 2930, 9234, 
+    9237, 9239, 9241, 9243, 9245, 9247, 9250, 9253, 9255, 9257, 6011, 6013, 
+    2944, 9259, 9262, 9264, 9266, 9268, 9270, 9273, 9276, 9278, 9280, 9282, 
+    9285, 6015, 9287, 9290, 9293, 9295, 9297, 9299, 9302, 9304, 9306, 9308, 
+    9310, 9312, 9314, 9316, 9319, 9321, 9323, 9325, 9328, 9330, 9332, 9334, 
+    9336, 9339, 9342, 9344, 9346, 9348, 9351, 9353, 6027, 6027, 9356, 9358, 
+    9361, 9363, 9365, 9367, 9369, 9371, 9373, 9375, 6029, 9378, 9380, 9382, 
+    9384, 9386, 9388, 9391, 9393, 9396, 9399, 3040, 9402, 3048, 9404, 9406, 
+    9408, 9410, 3058, 9412, 0, 0, 
+];
+
 /* NFC pairs */
 #[allow(dead_code)] pub const COMP_SHIFT1: u32 = 2;
 #[allow(dead_code)] pub const COMP_SHIFT2: u32 = 1;
@@ -5525,3 +7454,1999 @@ pub static COMP_DATA: [u32; 1464] = [
     69935, 70475, 70476, 70844, 70843, 70846, 0, 0, 71098, 0, 71099, 
 ];
 
+/* character type flags */
+#[allow(dead_code)] pub const ALPHA_MASK: u16 = 0x0001;
+#[allow(dead_code)] pub const DECIMAL_MASK: u16 = 0x0002;
+#[allow(dead_code)] pub const DIGIT_MASK: u16 = 0x0004;
+#[allow(dead_code)] pub const LOWER_MASK: u16 = 0x0008;
+#[allow(dead_code)] pub const LINEBREAK_MASK: u16 = 0x0010;
+#[allow(dead_code)] pub const SPACE_MASK: u16 = 0x0020;
+#[allow(dead_code)] pub const TITLE_MASK: u16 = 0x0040;
+#[allow(dead_code)] pub const UPPER_MASK: u16 = 0x0080;
+#[allow(dead_code)] pub const XID_START_MASK: u16 = 0x0100;
+#[allow(dead_code)] pub const XID_CONTINUE_MASK: u16 = 0x0200;
+#[allow(dead_code)] pub const PRINTABLE_MASK: u16 = 0x0400;
+#[allow(dead_code)] pub const NUMERIC_MASK: u16 = 0x0800;
+#[allow(dead_code)] pub const CASE_IGNORABLE_MASK: u16 = 0x1000;
+#[allow(dead_code)] pub const CASED_MASK: u16 = 0x2000;
+#[allow(dead_code)] pub const EXTENDED_CASE_MASK: u16 = 0x4000;
+#[allow(dead_code)] pub const SOFT_DOTTED_MASK: u16 = 0x8000;
+
+/* a list of unique character type descriptors */
+pub static TYPE_RECORDS: [TypeRecord; 716] = [
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 0, flags: 0},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 0, flags: 0},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 0, flags: 32},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 0, flags: 48},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 0, flags: 1056},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 0, flags: 1024},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 0, flags: 5120},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 1, flags: 3590},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 31, flags: 3590},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 33, flags: 3590},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 35, flags: 3590},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 37, flags: 3590},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 39, flags: 3590},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 41, flags: 3590},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 43, flags: 3590},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 45, flags: 3590},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 47, flags: 3590},
+    TypeRecord{upper: 0, lower: 32, title: 0, fold: 32, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 0, flags: 1536},
+    TypeRecord{upper: -32, lower: 0, title: -32, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: -32, lower: 0, title: -32, fold: 0, extended: 0, numeric: 0, flags: 42761},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 0, flags: 4096},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 33, flags: 3076},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 35, flags: 3076},
+    TypeRecord{upper: 743, lower: 0, title: 743, fold: 775, extended: 1, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 0, flags: 5632},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 31, flags: 3076},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 16, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 21, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 26, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 6, numeric: 0, flags: 26377},
+    TypeRecord{upper: 121, lower: 0, title: 121, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: 0, lower: 1, title: 0, fold: 1, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: -1, lower: 0, title: -1, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: -1, lower: 0, title: -1, fold: 0, extended: 0, numeric: 0, flags: 42761},
+    TypeRecord{upper: 0, lower: -199, title: 0, fold: 0, extended: 14, numeric: 0, flags: 26497},
+    TypeRecord{upper: -232, lower: 0, title: -232, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 21, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: -121, title: 0, fold: -121, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: -300, lower: 0, title: -300, fold: -268, extended: 29, numeric: 0, flags: 26377},
+    TypeRecord{upper: 195, lower: 0, title: 195, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: 0, lower: 210, title: 0, fold: 210, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: 206, title: 0, fold: 206, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: 205, title: 0, fold: 205, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: 79, title: 0, fold: 79, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: 202, title: 0, fold: 202, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: 203, title: 0, fold: 203, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: 207, title: 0, fold: 207, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 97, lower: 0, title: 97, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: 0, lower: 211, title: 0, fold: 211, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: 209, title: 0, fold: 209, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 163, lower: 0, title: 163, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: 0, lower: 213, title: 0, fold: 213, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 130, lower: 0, title: 130, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: 0, lower: 214, title: 0, fold: 214, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: 218, title: 0, fold: 218, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: 217, title: 0, fold: 217, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: 219, title: 0, fold: 219, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 0, flags: 1793},
+    TypeRecord{upper: 56, lower: 0, title: 56, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: 0, lower: 2, title: 1, fold: 2, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: -1, lower: 1, title: 0, fold: 1, extended: 0, numeric: 0, flags: 10049},
+    TypeRecord{upper: -2, lower: 0, title: -1, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: -79, lower: 0, title: -79, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 34, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: -97, title: 0, fold: -97, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: -56, title: 0, fold: -56, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: -130, title: 0, fold: -130, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: 10795, title: 0, fold: 10795, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: -163, title: 0, fold: -163, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: 10792, title: 0, fold: 10792, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 10815, lower: 0, title: 10815, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: 0, lower: -195, title: 0, fold: -195, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: 69, title: 0, fold: 69, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: 71, title: 0, fold: 71, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 10783, lower: 0, title: 10783, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: 10780, lower: 0, title: 10780, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: 10782, lower: 0, title: 10782, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: -210, lower: 0, title: -210, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: -206, lower: 0, title: -206, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: -205, lower: 0, title: -205, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: -202, lower: 0, title: -202, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: -203, lower: 0, title: -203, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: 42319, lower: 0, title: 42319, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: 42315, lower: 0, title: 42315, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: -207, lower: 0, title: -207, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: 42280, lower: 0, title: 42280, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: 42308, lower: 0, title: 42308, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: -209, lower: 0, title: -209, fold: 0, extended: 0, numeric: 0, flags: 42761},
+    TypeRecord{upper: -211, lower: 0, title: -211, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: 10743, lower: 0, title: 10743, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: 42305, lower: 0, title: 42305, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: 10749, lower: 0, title: 10749, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: -213, lower: 0, title: -213, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: -214, lower: 0, title: -214, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: 10727, lower: 0, title: 10727, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: -218, lower: 0, title: -218, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: 42282, lower: 0, title: 42282, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: -69, lower: 0, title: -69, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: -217, lower: 0, title: -217, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: -71, lower: 0, title: -71, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: -219, lower: 0, title: -219, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: 42261, lower: 0, title: 42261, fold: 0, extended: 0, numeric: 0, flags: 42761},
+    TypeRecord{upper: 42258, lower: 0, title: 42258, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 0, flags: 14089},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 0, flags: 46857},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 0, flags: 5889},
+    TypeRecord{upper: 84, lower: 0, title: 84, fold: 116, extended: 42, numeric: 0, flags: 30216},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 0, flags: 13321},
+    TypeRecord{upper: 0, lower: 116, title: 0, fold: 116, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: 38, title: 0, fold: 38, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: 37, title: 0, fold: 37, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: 64, title: 0, fold: 64, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: 63, title: 0, fold: 63, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 47, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38, lower: 0, title: -38, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: -37, lower: 0, title: -37, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 58, numeric: 0, flags: 26377},
+    TypeRecord{upper: -31, lower: 0, title: -31, fold: 1, extended: 69, numeric: 0, flags: 26377},
+    TypeRecord{upper: -64, lower: 0, title: -64, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: -63, lower: 0, title: -63, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: 0, lower: 8, title: 0, fold: 8, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: -62, lower: 0, title: -62, fold: -30, extended: 74, numeric: 0, flags: 26377},
+    TypeRecord{upper: -57, lower: 0, title: -57, fold: -25, extended: 79, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: -47, lower: 0, title: -47, fold: -15, extended: 84, numeric: 0, flags: 26377},
+    TypeRecord{upper: -54, lower: 0, title: -54, fold: -22, extended: 89, numeric: 0, flags: 26377},
+    TypeRecord{upper: -8, lower: 0, title: -8, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: -86, lower: 0, title: -86, fold: -54, extended: 94, numeric: 0, flags: 26377},
+    TypeRecord{upper: -80, lower: 0, title: -80, fold: -48, extended: 99, numeric: 0, flags: 26377},
+    TypeRecord{upper: 7, lower: 0, title: 7, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: -116, lower: 0, title: -116, fold: 0, extended: 0, numeric: 0, flags: 42761},
+    TypeRecord{upper: 0, lower: -60, title: 0, fold: -60, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: -96, lower: 0, title: -96, fold: -64, extended: 104, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: -7, title: 0, fold: -7, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: 80, title: 0, fold: 80, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: -80, lower: 0, title: -80, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: -80, lower: 0, title: -80, fold: 0, extended: 0, numeric: 0, flags: 42761},
+    TypeRecord{upper: 0, lower: 15, title: 0, fold: 15, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: -15, lower: 0, title: -15, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: 0, lower: 48, title: 0, fold: 48, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: -48, lower: 0, title: -48, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 109, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 6, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 10, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 14, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 54, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 48, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 93, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 102, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 1, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 31, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 33, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 35, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 2, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 3, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 4, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 5, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 8, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 12, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 15, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 0, flags: 1537},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 32, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 34, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 36, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 38, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 40, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 42, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 44, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 46, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 0, flags: 3072},
+    TypeRecord{upper: 0, lower: 7264, title: 0, fold: 7264, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 31, flags: 3588},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 33, flags: 3588},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 35, flags: 3588},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 37, flags: 3588},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 39, flags: 3588},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 41, flags: 3588},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 43, flags: 3588},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 45, flags: 3588},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 47, flags: 3588},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 58, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 68, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 78, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 88, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 89, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 90, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 91, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 92, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 111, flags: 3072},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 117, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 122, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 127, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 132, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 137, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 142, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 147, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 152, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 157, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 162, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 167, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 172, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 177, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 182, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 187, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 192, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 197, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 202, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 207, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 212, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 217, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 222, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 227, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 232, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 237, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 242, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 247, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 252, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 257, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 262, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 267, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 272, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 277, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 282, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 287, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 292, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 297, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 302, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 307, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 312, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 317, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 322, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 327, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 332, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 337, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 342, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 347, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 352, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 357, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 362, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 367, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 372, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 377, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 382, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 387, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 392, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 397, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 402, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 407, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 412, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 417, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 422, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 427, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 432, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 437, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 442, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 447, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 452, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 457, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 462, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 467, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 472, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 477, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 482, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 487, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 492, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 497, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 502, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 507, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 38864, title: 0, fold: 0, extended: 512, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 8, title: 0, fold: 0, extended: 517, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 8, title: 0, fold: 0, extended: 522, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 8, title: 0, fold: 0, extended: 527, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 8, title: 0, fold: 0, extended: 532, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 8, title: 0, fold: 0, extended: 537, numeric: 0, flags: 26497},
+    TypeRecord{upper: 0, lower: 8, title: 0, fold: 0, extended: 542, numeric: 0, flags: 26497},
+    TypeRecord{upper: -8, lower: 0, title: -8, fold: -8, extended: 547, numeric: 0, flags: 26377},
+    TypeRecord{upper: -8, lower: 0, title: -8, fold: -8, extended: 552, numeric: 0, flags: 26377},
+    TypeRecord{upper: -8, lower: 0, title: -8, fold: -8, extended: 557, numeric: 0, flags: 26377},
+    TypeRecord{upper: -8, lower: 0, title: -8, fold: -8, extended: 562, numeric: 0, flags: 26377},
+    TypeRecord{upper: -8, lower: 0, title: -8, fold: -8, extended: 567, numeric: 0, flags: 26377},
+    TypeRecord{upper: -8, lower: 0, title: -8, fold: -8, extended: 572, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 55, flags: 3840},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 56, flags: 3840},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 57, flags: 3840},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 37, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 39, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 41, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 43, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 45, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 47, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 0, flags: 5888},
+    TypeRecord{upper: -6254, lower: 0, title: -6254, fold: -6222, extended: 577, numeric: 0, flags: 26377},
+    TypeRecord{upper: -6253, lower: 0, title: -6253, fold: -6221, extended: 582, numeric: 0, flags: 26377},
+    TypeRecord{upper: -6244, lower: 0, title: -6244, fold: -6212, extended: 587, numeric: 0, flags: 26377},
+    TypeRecord{upper: -6242, lower: 0, title: -6242, fold: -6210, extended: 592, numeric: 0, flags: 26377},
+    TypeRecord{upper: -6242, lower: 0, title: -6242, fold: -6210, extended: 597, numeric: 0, flags: 26377},
+    TypeRecord{upper: -6243, lower: 0, title: -6243, fold: -6211, extended: 602, numeric: 0, flags: 26377},
+    TypeRecord{upper: -6236, lower: 0, title: -6236, fold: -6204, extended: 607, numeric: 0, flags: 26377},
+    TypeRecord{upper: -6181, lower: 0, title: -6181, fold: -6180, extended: 612, numeric: 0, flags: 26377},
+    TypeRecord{upper: 35266, lower: 0, title: 35266, fold: 35267, extended: 617, numeric: 0, flags: 26377},
+    TypeRecord{upper: 35332, lower: 0, title: 35332, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: 3814, lower: 0, title: 3814, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 0, flags: 42761},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 622, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 630, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 638, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 646, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 654, numeric: 0, flags: 26377},
+    TypeRecord{upper: -59, lower: 0, title: -59, fold: -58, extended: 662, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: -7615, title: 0, fold: -7615, extended: 667, numeric: 0, flags: 26497},
+    TypeRecord{upper: 8, lower: 0, title: 8, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: 0, lower: -8, title: 0, fold: -8, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 673, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 681, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 692, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 703, numeric: 0, flags: 26377},
+    TypeRecord{upper: 74, lower: 0, title: 74, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: 86, lower: 0, title: 86, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: 100, lower: 0, title: 100, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: 128, lower: 0, title: 128, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: 112, lower: 0, title: 112, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: 126, lower: 0, title: 126, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: 8, lower: 0, title: 8, fold: 0, extended: 714, numeric: 0, flags: 26377},
+    TypeRecord{upper: 8, lower: 0, title: 8, fold: 0, extended: 721, numeric: 0, flags: 26377},
+    TypeRecord{upper: 8, lower: 0, title: 8, fold: 0, extended: 728, numeric: 0, flags: 26377},
+    TypeRecord{upper: 8, lower: 0, title: 8, fold: 0, extended: 735, numeric: 0, flags: 26377},
+    TypeRecord{upper: 8, lower: 0, title: 8, fold: 0, extended: 742, numeric: 0, flags: 26377},
+    TypeRecord{upper: 8, lower: 0, title: 8, fold: 0, extended: 749, numeric: 0, flags: 26377},
+    TypeRecord{upper: 8, lower: 0, title: 8, fold: 0, extended: 756, numeric: 0, flags: 26377},
+    TypeRecord{upper: 8, lower: 0, title: 8, fold: 0, extended: 763, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: -8, title: 0, fold: -8, extended: 770, numeric: 0, flags: 26433},
+    TypeRecord{upper: 0, lower: -8, title: 0, fold: -8, extended: 777, numeric: 0, flags: 26433},
+    TypeRecord{upper: 0, lower: -8, title: 0, fold: -8, extended: 784, numeric: 0, flags: 26433},
+    TypeRecord{upper: 0, lower: -8, title: 0, fold: -8, extended: 791, numeric: 0, flags: 26433},
+    TypeRecord{upper: 0, lower: -8, title: 0, fold: -8, extended: 798, numeric: 0, flags: 26433},
+    TypeRecord{upper: 0, lower: -8, title: 0, fold: -8, extended: 805, numeric: 0, flags: 26433},
+    TypeRecord{upper: 0, lower: -8, title: 0, fold: -8, extended: 812, numeric: 0, flags: 26433},
+    TypeRecord{upper: 0, lower: -8, title: 0, fold: -8, extended: 819, numeric: 0, flags: 26433},
+    TypeRecord{upper: 8, lower: 0, title: 8, fold: 0, extended: 826, numeric: 0, flags: 26377},
+    TypeRecord{upper: 8, lower: 0, title: 8, fold: 0, extended: 833, numeric: 0, flags: 26377},
+    TypeRecord{upper: 8, lower: 0, title: 8, fold: 0, extended: 840, numeric: 0, flags: 26377},
+    TypeRecord{upper: 8, lower: 0, title: 8, fold: 0, extended: 847, numeric: 0, flags: 26377},
+    TypeRecord{upper: 8, lower: 0, title: 8, fold: 0, extended: 854, numeric: 0, flags: 26377},
+    TypeRecord{upper: 8, lower: 0, title: 8, fold: 0, extended: 861, numeric: 0, flags: 26377},
+    TypeRecord{upper: 8, lower: 0, title: 8, fold: 0, extended: 868, numeric: 0, flags: 26377},
+    TypeRecord{upper: 8, lower: 0, title: 8, fold: 0, extended: 875, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: -8, title: 0, fold: -8, extended: 882, numeric: 0, flags: 26433},
+    TypeRecord{upper: 0, lower: -8, title: 0, fold: -8, extended: 889, numeric: 0, flags: 26433},
+    TypeRecord{upper: 0, lower: -8, title: 0, fold: -8, extended: 896, numeric: 0, flags: 26433},
+    TypeRecord{upper: 0, lower: -8, title: 0, fold: -8, extended: 903, numeric: 0, flags: 26433},
+    TypeRecord{upper: 0, lower: -8, title: 0, fold: -8, extended: 910, numeric: 0, flags: 26433},
+    TypeRecord{upper: 0, lower: -8, title: 0, fold: -8, extended: 917, numeric: 0, flags: 26433},
+    TypeRecord{upper: 0, lower: -8, title: 0, fold: -8, extended: 924, numeric: 0, flags: 26433},
+    TypeRecord{upper: 0, lower: -8, title: 0, fold: -8, extended: 931, numeric: 0, flags: 26433},
+    TypeRecord{upper: 8, lower: 0, title: 8, fold: 0, extended: 938, numeric: 0, flags: 26377},
+    TypeRecord{upper: 8, lower: 0, title: 8, fold: 0, extended: 945, numeric: 0, flags: 26377},
+    TypeRecord{upper: 8, lower: 0, title: 8, fold: 0, extended: 952, numeric: 0, flags: 26377},
+    TypeRecord{upper: 8, lower: 0, title: 8, fold: 0, extended: 959, numeric: 0, flags: 26377},
+    TypeRecord{upper: 8, lower: 0, title: 8, fold: 0, extended: 966, numeric: 0, flags: 26377},
+    TypeRecord{upper: 8, lower: 0, title: 8, fold: 0, extended: 973, numeric: 0, flags: 26377},
+    TypeRecord{upper: 8, lower: 0, title: 8, fold: 0, extended: 980, numeric: 0, flags: 26377},
+    TypeRecord{upper: 8, lower: 0, title: 8, fold: 0, extended: 987, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: -8, title: 0, fold: -8, extended: 994, numeric: 0, flags: 26433},
+    TypeRecord{upper: 0, lower: -8, title: 0, fold: -8, extended: 1001, numeric: 0, flags: 26433},
+    TypeRecord{upper: 0, lower: -8, title: 0, fold: -8, extended: 1008, numeric: 0, flags: 26433},
+    TypeRecord{upper: 0, lower: -8, title: 0, fold: -8, extended: 1015, numeric: 0, flags: 26433},
+    TypeRecord{upper: 0, lower: -8, title: 0, fold: -8, extended: 1022, numeric: 0, flags: 26433},
+    TypeRecord{upper: 0, lower: -8, title: 0, fold: -8, extended: 1029, numeric: 0, flags: 26433},
+    TypeRecord{upper: 0, lower: -8, title: 0, fold: -8, extended: 1036, numeric: 0, flags: 26433},
+    TypeRecord{upper: 0, lower: -8, title: 0, fold: -8, extended: 1043, numeric: 0, flags: 26433},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 1050, numeric: 0, flags: 26377},
+    TypeRecord{upper: 9, lower: 0, title: 9, fold: 0, extended: 1058, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 1065, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 1073, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 1081, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: -74, title: 0, fold: -74, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: -9, title: 0, fold: -9, extended: 1092, numeric: 0, flags: 26433},
+    TypeRecord{upper: -7205, lower: 0, title: -7205, fold: -7173, extended: 1099, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 1104, numeric: 0, flags: 26377},
+    TypeRecord{upper: 9, lower: 0, title: 9, fold: 0, extended: 1112, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 1119, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 1127, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 1135, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: -86, title: 0, fold: -86, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: -9, title: 0, fold: -9, extended: 1146, numeric: 0, flags: 26433},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 1153, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 1164, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 1175, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 1183, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: -100, title: 0, fold: -100, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 1194, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 1205, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 1216, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 1224, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 1232, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: -112, title: 0, fold: -112, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 1243, numeric: 0, flags: 26377},
+    TypeRecord{upper: 9, lower: 0, title: 9, fold: 0, extended: 1251, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 1258, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 1266, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 1274, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: -128, title: 0, fold: -128, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: -126, title: 0, fold: -126, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: -9, title: 0, fold: -9, extended: 1285, numeric: 0, flags: 26433},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 1, flags: 3076},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 37, flags: 3076},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 39, flags: 3076},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 41, flags: 3076},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 43, flags: 3076},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 45, flags: 3076},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 47, flags: 3076},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 0, flags: 1792},
+    TypeRecord{upper: 0, lower: -7517, title: 0, fold: -7517, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: -8383, title: 0, fold: -8383, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: -8262, title: 0, fold: -8262, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: 28, title: 0, fold: 28, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: -28, lower: 0, title: -28, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 11, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 9, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 17, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 25, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 19, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 23, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 27, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 13, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 28, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 18, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 24, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 29, flags: 3072},
+    TypeRecord{upper: 0, lower: 16, title: 0, fold: 16, extended: 0, numeric: 31, flags: 12160},
+    TypeRecord{upper: 0, lower: 16, title: 0, fold: 16, extended: 0, numeric: 33, flags: 12160},
+    TypeRecord{upper: 0, lower: 16, title: 0, fold: 16, extended: 0, numeric: 35, flags: 12160},
+    TypeRecord{upper: 0, lower: 16, title: 0, fold: 16, extended: 0, numeric: 37, flags: 12160},
+    TypeRecord{upper: 0, lower: 16, title: 0, fold: 16, extended: 0, numeric: 39, flags: 12160},
+    TypeRecord{upper: 0, lower: 16, title: 0, fold: 16, extended: 0, numeric: 41, flags: 12160},
+    TypeRecord{upper: 0, lower: 16, title: 0, fold: 16, extended: 0, numeric: 43, flags: 12160},
+    TypeRecord{upper: 0, lower: 16, title: 0, fold: 16, extended: 0, numeric: 45, flags: 12160},
+    TypeRecord{upper: 0, lower: 16, title: 0, fold: 16, extended: 0, numeric: 47, flags: 12160},
+    TypeRecord{upper: 0, lower: 16, title: 0, fold: 16, extended: 0, numeric: 48, flags: 12160},
+    TypeRecord{upper: 0, lower: 16, title: 0, fold: 16, extended: 0, numeric: 49, flags: 12160},
+    TypeRecord{upper: 0, lower: 16, title: 0, fold: 16, extended: 0, numeric: 50, flags: 12160},
+    TypeRecord{upper: 0, lower: 16, title: 0, fold: 16, extended: 0, numeric: 88, flags: 12160},
+    TypeRecord{upper: 0, lower: 16, title: 0, fold: 16, extended: 0, numeric: 93, flags: 12160},
+    TypeRecord{upper: 0, lower: 16, title: 0, fold: 16, extended: 0, numeric: 97, flags: 12160},
+    TypeRecord{upper: 0, lower: 16, title: 0, fold: 16, extended: 0, numeric: 102, flags: 12160},
+    TypeRecord{upper: -16, lower: 0, title: -16, fold: 0, extended: 0, numeric: 31, flags: 12040},
+    TypeRecord{upper: -16, lower: 0, title: -16, fold: 0, extended: 0, numeric: 33, flags: 12040},
+    TypeRecord{upper: -16, lower: 0, title: -16, fold: 0, extended: 0, numeric: 35, flags: 12040},
+    TypeRecord{upper: -16, lower: 0, title: -16, fold: 0, extended: 0, numeric: 37, flags: 12040},
+    TypeRecord{upper: -16, lower: 0, title: -16, fold: 0, extended: 0, numeric: 39, flags: 12040},
+    TypeRecord{upper: -16, lower: 0, title: -16, fold: 0, extended: 0, numeric: 41, flags: 12040},
+    TypeRecord{upper: -16, lower: 0, title: -16, fold: 0, extended: 0, numeric: 43, flags: 12040},
+    TypeRecord{upper: -16, lower: 0, title: -16, fold: 0, extended: 0, numeric: 45, flags: 12040},
+    TypeRecord{upper: -16, lower: 0, title: -16, fold: 0, extended: 0, numeric: 47, flags: 12040},
+    TypeRecord{upper: -16, lower: 0, title: -16, fold: 0, extended: 0, numeric: 48, flags: 12040},
+    TypeRecord{upper: -16, lower: 0, title: -16, fold: 0, extended: 0, numeric: 49, flags: 12040},
+    TypeRecord{upper: -16, lower: 0, title: -16, fold: 0, extended: 0, numeric: 50, flags: 12040},
+    TypeRecord{upper: -16, lower: 0, title: -16, fold: 0, extended: 0, numeric: 88, flags: 12040},
+    TypeRecord{upper: -16, lower: 0, title: -16, fold: 0, extended: 0, numeric: 93, flags: 12040},
+    TypeRecord{upper: -16, lower: 0, title: -16, fold: 0, extended: 0, numeric: 97, flags: 12040},
+    TypeRecord{upper: -16, lower: 0, title: -16, fold: 0, extended: 0, numeric: 102, flags: 12040},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 102, flags: 3840},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 106, flags: 3840},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 111, flags: 3840},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 41, flags: 3840},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 88, flags: 3840},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 115, flags: 3840},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 120, flags: 3840},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 49, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 50, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 51, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 52, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 53, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 55, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 56, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 57, flags: 3072},
+    TypeRecord{upper: 0, lower: 26, title: 0, fold: 26, extended: 0, numeric: 0, flags: 9344},
+    TypeRecord{upper: -26, lower: 0, title: -26, fold: 0, extended: 0, numeric: 0, flags: 9224},
+    TypeRecord{upper: 0, lower: -10743, title: 0, fold: -10743, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: -3814, title: 0, fold: -3814, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: -10727, title: 0, fold: -10727, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: -10795, lower: 0, title: -10795, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: -10792, lower: 0, title: -10792, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: 0, lower: -10780, title: 0, fold: -10780, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: -10749, title: 0, fold: -10749, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: -10783, title: 0, fold: -10783, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: -10782, title: 0, fold: -10782, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: -10815, title: 0, fold: -10815, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: -7264, lower: 0, title: -7264, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 0, flags: 5121},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 1, flags: 3840},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 31, flags: 3840},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 33, flags: 3840},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 35, flags: 3840},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 37, flags: 3840},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 39, flags: 3840},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 43, flags: 3840},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 45, flags: 3840},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 47, flags: 3840},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 48, flags: 3840},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 58, flags: 3840},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 68, flags: 3840},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 59, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 60, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 61, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 62, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 63, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 64, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 65, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 66, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 67, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 69, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 70, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 71, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 72, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 73, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 74, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 75, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 76, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 77, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 79, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 80, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 81, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 82, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 83, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 84, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 85, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 86, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 87, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 39, flags: 3841},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 33, flags: 3841},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 43, flags: 3841},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 31, flags: 3841},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 111, flags: 3841},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 35, flags: 3841},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 47, flags: 3841},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 37, flags: 3841},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 132, flags: 3841},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 48, flags: 3841},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 102, flags: 3841},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 93, flags: 3841},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 134, flags: 3841},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 45, flags: 3841},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 41, flags: 3841},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 58, flags: 3841},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 68, flags: 3841},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 78, flags: 3841},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 1, flags: 3841},
+    TypeRecord{upper: 0, lower: -35332, title: 0, fold: -35332, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: -42280, title: 0, fold: -42280, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: -42308, title: 0, fold: -42308, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: -42319, title: 0, fold: -42319, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: -42315, title: 0, fold: -42315, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: -42305, title: 0, fold: -42305, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: -42258, title: 0, fold: -42258, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: -42282, title: 0, fold: -42282, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: -42261, title: 0, fold: -42261, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: 0, lower: 928, title: 0, fold: 928, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: -928, lower: 0, title: -928, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1292, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1297, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1302, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1307, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1312, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1317, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1322, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1327, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1332, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1337, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1342, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1347, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1352, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1357, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1362, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1367, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1372, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1377, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1382, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1387, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1392, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1397, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1402, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1407, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1412, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1417, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1422, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1427, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1432, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1437, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1442, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1447, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1452, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1457, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1462, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1467, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1472, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1477, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1482, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1487, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1492, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1497, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1502, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1507, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1512, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1517, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1522, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1527, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1532, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1537, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1542, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1547, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1552, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1557, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1562, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1567, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1572, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1577, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1582, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1587, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1592, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1597, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1602, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1607, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1612, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1617, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1622, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1627, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1632, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1637, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1642, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1647, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1652, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1657, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1662, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1667, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1672, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1677, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1682, numeric: 0, flags: 26377},
+    TypeRecord{upper: -38864, lower: 0, title: -38864, fold: -38864, extended: 1687, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 1692, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 1700, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 1708, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 1716, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 1727, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 1738, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 1746, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 1754, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 1762, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 1770, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 1778, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 1786, numeric: 0, flags: 26377},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 0, flags: 1025},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 0, flags: 5633},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 94, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 95, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 96, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 97, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 98, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 99, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 100, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 101, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 103, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 104, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 105, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 106, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 107, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 108, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 109, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 110, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 112, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 113, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 114, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 115, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 116, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 117, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 118, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 119, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 16, flags: 3840},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 21, flags: 3840},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 97, flags: 3840},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 93, flags: 3840},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 95, flags: 3840},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 92, flags: 3840},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 101, flags: 3840},
+    TypeRecord{upper: 0, lower: 40, title: 0, fold: 40, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: -40, lower: 0, title: -40, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 30, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 120, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 121, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 123, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 124, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 126, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 127, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 128, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 129, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 130, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 7, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 20, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 22, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 122, flags: 3840},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 125, flags: 3840},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 17, flags: 3840},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 25, flags: 3840},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 28, flags: 3840},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 10, flags: 3840},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 13, flags: 3840},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 78, flags: 3840},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 131, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 132, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 133, flags: 3072},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 134, flags: 3072},
+    TypeRecord{upper: 0, lower: 34, title: 0, fold: 34, extended: 0, numeric: 0, flags: 10113},
+    TypeRecord{upper: -34, lower: 0, title: -34, fold: 0, extended: 0, numeric: 0, flags: 9993},
+    TypeRecord{upper: 0, lower: 0, title: 0, fold: 0, extended: 0, numeric: 0, flags: 9344},
+];
+
+/* extended case mappings */
+pub static EXTENDED_CASE: [u32; 1848] = [
+    0, 16843009, 181, 924, 924, 956, 33686017, 223, 83, 115, 83, 83, 115, 
+    115, 33620226, 105, 775, 304, 304, 105, 775, 33686017, 329, 700, 78, 700, 
+    78, 700, 110, 16843009, 383, 83, 83, 115, 33686017, 496, 74, 780, 74, 
+    780, 106, 780, 16843009, 837, 921, 921, 953, 50529025, 912, 921, 776, 
+    769, 921, 776, 769, 953, 776, 769, 50529025, 944, 933, 776, 769, 933, 
+    776, 769, 965, 776, 769, 16843009, 962, 931, 931, 963, 16843009, 976, 
+    914, 914, 946, 16843009, 977, 920, 920, 952, 16843009, 981, 934, 934, 
+    966, 16843009, 982, 928, 928, 960, 16843009, 1008, 922, 922, 954, 
+    16843009, 1009, 929, 929, 961, 16843009, 1013, 917, 917, 949, 33686017, 
+    1415, 1333, 1410, 1333, 1362, 1381, 1410, 16843009, 43888, 5024, 5024, 
+    5024, 16843009, 43889, 5025, 5025, 5025, 16843009, 43890, 5026, 5026, 
+    5026, 16843009, 43891, 5027, 5027, 5027, 16843009, 43892, 5028, 5028, 
+    5028, 16843009, 43893, 5029, 5029, 5029, 16843009, 43894, 5030, 5030, 
+    5030, 16843009, 43895, 5031, 5031, 5031, 16843009, 43896, 5032, 5032, 
+    5032, 16843009, 43897, 5033, 5033, 5033, 16843009, 43898, 5034, 5034, 
+    5034, 16843009, 43899, 5035, 5035, 5035, 16843009, 43900, 5036, 5036, 
+    5036, 16843009, 43901, 5037, 5037, 5037, 16843009, 43902, 5038, 5038, 
+    5038, 16843009, 43903, 5039, 5039, 5039, 16843009, 43904, 5040, 5040, 
+    5040, 16843009, 43905, 5041, 5041, 5041, 16843009, 43906, 5042, 5042, 
+    5042, 16843009, 43907, 5043, 5043, 5043, 16843009, 43908, 5044, 5044, 
+    5044, 16843009, 43909, 5045, 5045, 5045, 16843009, 43910, 5046, 5046, 
+    5046, 16843009, 43911, 5047, 5047, 5047, 16843009, 43912, 5048, 5048, 
+    5048, 16843009, 43913, 5049, 5049, 5049, 16843009, 43914, 5050, 5050, 
+    5050, 16843009, 43915, 5051, 5051, 5051, 16843009, 43916, 5052, 5052, 
+    5052, 16843009, 43917, 5053, 5053, 5053, 16843009, 43918, 5054, 5054, 
+    5054, 16843009, 43919, 5055, 5055, 5055, 16843009, 43920, 5056, 5056, 
+    5056, 16843009, 43921, 5057, 5057, 5057, 16843009, 43922, 5058, 5058, 
+    5058, 16843009, 43923, 5059, 5059, 5059, 16843009, 43924, 5060, 5060, 
+    5060, 16843009, 43925, 5061, 5061, 5061, 16843009, 43926, 5062, 5062, 
+    5062, 16843009, 43927, 5063, 5063, 5063, 16843009, 43928, 5064, 5064, 
+    5064, 16843009, 43929, 5065, 5065, 5065, 16843009, 43930, 5066, 5066, 
+    5066, 16843009, 43931, 5067, 5067, 5067, 16843009, 43932, 5068, 5068, 
+    5068, 16843009, 43933, 5069, 5069, 5069, 16843009, 43934, 5070, 5070, 
+    5070, 16843009, 43935, 5071, 5071, 5071, 16843009, 43936, 5072, 5072, 
+    5072, 16843009, 43937, 5073, 5073, 5073, 16843009, 43938, 5074, 5074, 
+    5074, 16843009, 43939, 5075, 5075, 5075, 16843009, 43940, 5076, 5076, 
+    5076, 16843009, 43941, 5077, 5077, 5077, 16843009, 43942, 5078, 5078, 
+    5078, 16843009, 43943, 5079, 5079, 5079, 16843009, 43944, 5080, 5080, 
+    5080, 16843009, 43945, 5081, 5081, 5081, 16843009, 43946, 5082, 5082, 
+    5082, 16843009, 43947, 5083, 5083, 5083, 16843009, 43948, 5084, 5084, 
+    5084, 16843009, 43949, 5085, 5085, 5085, 16843009, 43950, 5086, 5086, 
+    5086, 16843009, 43951, 5087, 5087, 5087, 16843009, 43952, 5088, 5088, 
+    5088, 16843009, 43953, 5089, 5089, 5089, 16843009, 43954, 5090, 5090, 
+    5090, 16843009, 43955, 5091, 5091, 5091, 16843009, 43956, 5092, 5092, 
+    5092, 16843009, 43957, 5093, 5093, 5093, 16843009, 43958, 5094, 5094, 
+    5094, 16843009, 43959, 5095, 5095, 5095, 16843009, 43960, 5096, 5096, 
+    5096, 16843009, 43961, 5097, 5097, 5097, 16843009, 43962, 5098, 5098, 
+    5098, 16843009, 43963, 5099, 5099, 5099, 16843009, 43964, 5100, 5100, 
+    5100, 16843009, 43965, 5101, 5101, 5101, 16843009, 43966, 5102, 5102, 
+    5102, 16843009, 43967, 5103, 5103, 5103, 16843009, 5112, 5104, 5104, 
+    5104, 16843009, 5113, 5105, 5105, 5105, 16843009, 5114, 5106, 5106, 5106, 
+    16843009, 5115, 5107, 5107, 5107, 16843009, 5116, 5108, 5108, 5108, 
+    16843009, 5117, 5109, 5109, 5109, 16843009, 5112, 5104, 5104, 5104, 
+    16843009, 5113, 5105, 5105, 5105, 16843009, 5114, 5106, 5106, 5106, 
+    16843009, 5115, 5107, 5107, 5107, 16843009, 5116, 5108, 5108, 5108, 
+    16843009, 5117, 5109, 5109, 5109, 16843009, 7296, 1042, 1042, 1074, 
+    16843009, 7297, 1044, 1044, 1076, 16843009, 7298, 1054, 1054, 1086, 
+    16843009, 7299, 1057, 1057, 1089, 16843009, 7300, 1058, 1058, 1090, 
+    16843009, 7301, 1058, 1058, 1090, 16843009, 7302, 1066, 1066, 1098, 
+    16843009, 7303, 1122, 1122, 1123, 16843009, 7304, 42570, 42570, 42571, 
+    33686017, 7830, 72, 817, 72, 817, 104, 817, 33686017, 7831, 84, 776, 84, 
+    776, 116, 776, 33686017, 7832, 87, 778, 87, 778, 119, 778, 33686017, 
+    7833, 89, 778, 89, 778, 121, 778, 33686017, 7834, 65, 702, 65, 702, 97, 
+    702, 16843009, 7835, 7776, 7776, 7777, 33620225, 223, 7838, 7838, 115, 
+    115, 33686017, 8016, 933, 787, 933, 787, 965, 787, 50529025, 8018, 933, 
+    787, 768, 933, 787, 768, 965, 787, 768, 50529025, 8020, 933, 787, 769, 
+    933, 787, 769, 965, 787, 769, 50529025, 8022, 933, 787, 834, 933, 787, 
+    834, 965, 787, 834, 33685761, 8064, 8072, 7944, 921, 7936, 953, 33685761, 
+    8065, 8073, 7945, 921, 7937, 953, 33685761, 8066, 8074, 7946, 921, 7938, 
+    953, 33685761, 8067, 8075, 7947, 921, 7939, 953, 33685761, 8068, 8076, 
+    7948, 921, 7940, 953, 33685761, 8069, 8077, 7949, 921, 7941, 953, 
+    33685761, 8070, 8078, 7950, 921, 7942, 953, 33685761, 8071, 8079, 7951, 
+    921, 7943, 953, 33685761, 8064, 8072, 7944, 921, 7936, 953, 33685761, 
+    8065, 8073, 7945, 921, 7937, 953, 33685761, 8066, 8074, 7946, 921, 7938, 
+    953, 33685761, 8067, 8075, 7947, 921, 7939, 953, 33685761, 8068, 8076, 
+    7948, 921, 7940, 953, 33685761, 8069, 8077, 7949, 921, 7941, 953, 
+    33685761, 8070, 8078, 7950, 921, 7942, 953, 33685761, 8071, 8079, 7951, 
+    921, 7943, 953, 33685761, 8080, 8088, 7976, 921, 7968, 953, 33685761, 
+    8081, 8089, 7977, 921, 7969, 953, 33685761, 8082, 8090, 7978, 921, 7970, 
+    953, 33685761, 8083, 8091, 7979, 921, 7971, 953, 33685761, 8084, 8092, 
+    7980, 921, 7972, 953, 33685761, 8085, 8093, 7981, 921, 7973, 953, 
+    33685761, 8086, 8094, 7982, 921, 7974, 953, 33685761, 8087, 8095, 7983, 
+    921, 7975, 953, 33685761, 8080, 8088, 7976, 921, 7968, 953, 33685761, 
+    8081, 8089, 7977, 921, 7969, 953, 33685761, 8082, 8090, 7978, 921, 7970, 
+    953, 33685761, 8083, 8091, 7979, 921, 7971, 953, 33685761, 8084, 8092, 
+    7980, 921, 7972, 953, 33685761, 8085, 8093, 7981, 921, 7973, 953, 
+    33685761, 8086, 8094, 7982, 921, 7974, 953, 33685761, 8087, 8095, 7983, 
+    921, 7975, 953, 33685761, 8096, 8104, 8040, 921, 8032, 953, 33685761, 
+    8097, 8105, 8041, 921, 8033, 953, 33685761, 8098, 8106, 8042, 921, 8034, 
+    953, 33685761, 8099, 8107, 8043, 921, 8035, 953, 33685761, 8100, 8108, 
+    8044, 921, 8036, 953, 33685761, 8101, 8109, 8045, 921, 8037, 953, 
+    33685761, 8102, 8110, 8046, 921, 8038, 953, 33685761, 8103, 8111, 8047, 
+    921, 8039, 953, 33685761, 8096, 8104, 8040, 921, 8032, 953, 33685761, 
+    8097, 8105, 8041, 921, 8033, 953, 33685761, 8098, 8106, 8042, 921, 8034, 
+    953, 33685761, 8099, 8107, 8043, 921, 8035, 953, 33685761, 8100, 8108, 
+    8044, 921, 8036, 953, 33685761, 8101, 8109, 8045, 921, 8037, 953, 
+    33685761, 8102, 8110, 8046, 921, 8038, 953, 33685761, 8103, 8111, 8047, 
+    921, 8039, 953, 33686017, 8114, 8122, 837, 8122, 921, 8048, 953, 
+    33685761, 8115, 8124, 913, 921, 945, 953, 33686017, 8116, 902, 837, 902, 
+    921, 940, 953, 33686017, 8118, 913, 834, 913, 834, 945, 834, 50529025, 
+    8119, 913, 834, 837, 913, 834, 921, 945, 834, 953, 33685761, 8115, 8124, 
+    913, 921, 945, 953, 16843009, 8126, 921, 921, 953, 33686017, 8130, 8138, 
+    837, 8138, 921, 8052, 953, 33685761, 8131, 8140, 919, 921, 951, 953, 
+    33686017, 8132, 905, 837, 905, 921, 942, 953, 33686017, 8134, 919, 834, 
+    919, 834, 951, 834, 50529025, 8135, 919, 834, 837, 919, 834, 921, 951, 
+    834, 953, 33685761, 8131, 8140, 919, 921, 951, 953, 50529025, 8146, 921, 
+    776, 768, 921, 776, 768, 953, 776, 768, 50529025, 8147, 921, 776, 769, 
+    921, 776, 769, 953, 776, 769, 33686017, 8150, 921, 834, 921, 834, 953, 
+    834, 50529025, 8151, 921, 776, 834, 921, 776, 834, 953, 776, 834, 
+    50529025, 8162, 933, 776, 768, 933, 776, 768, 965, 776, 768, 50529025, 
+    8163, 933, 776, 769, 933, 776, 769, 965, 776, 769, 33686017, 8164, 929, 
+    787, 929, 787, 961, 787, 33686017, 8166, 933, 834, 933, 834, 965, 834, 
+    50529025, 8167, 933, 776, 834, 933, 776, 834, 965, 776, 834, 33686017, 
+    8178, 8186, 837, 8186, 921, 8060, 953, 33685761, 8179, 8188, 937, 921, 
+    969, 953, 33686017, 8180, 911, 837, 911, 921, 974, 953, 33686017, 8182, 
+    937, 834, 937, 834, 969, 834, 50529025, 8183, 937, 834, 837, 937, 834, 
+    921, 969, 834, 953, 33685761, 8179, 8188, 937, 921, 969, 953, 16843009, 
+    43888, 5024, 5024, 5024, 16843009, 43889, 5025, 5025, 5025, 16843009, 
+    43890, 5026, 5026, 5026, 16843009, 43891, 5027, 5027, 5027, 16843009, 
+    43892, 5028, 5028, 5028, 16843009, 43893, 5029, 5029, 5029, 16843009, 
+    43894, 5030, 5030, 5030, 16843009, 43895, 5031, 5031, 5031, 16843009, 
+    43896, 5032, 5032, 5032, 16843009, 43897, 5033, 5033, 5033, 16843009, 
+    43898, 5034, 5034, 5034, 16843009, 43899, 5035, 5035, 5035, 16843009, 
+    43900, 5036, 5036, 5036, 16843009, 43901, 5037, 5037, 5037, 16843009, 
+    43902, 5038, 5038, 5038, 16843009, 43903, 5039, 5039, 5039, 16843009, 
+    43904, 5040, 5040, 5040, 16843009, 43905, 5041, 5041, 5041, 16843009, 
+    43906, 5042, 5042, 5042, 16843009, 43907, 5043, 5043, 5043, 16843009, 
+    43908, 5044, 5044, 5044, 16843009, 43909, 5045, 5045, 5045, 16843009, 
+    43910, 5046, 5046, 5046, 16843009, 43911, 5047, 5047, 5047, 16843009, 
+    43912, 5048, 5048, 5048, 16843009, 43913, 5049, 5049, 5049, 16843009, 
+    43914, 5050, 5050, 5050, 16843009, 43915, 5051, 5051, 5051, 16843009, 
+    43916, 5052, 5052, 5052, 16843009, 43917, 5053, 5053, 5053, 16843009, 
+    43918, 5054, 5054, 5054, 16843009, 43919, 5055, 5055, 5055, 16843009, 
+    43920, 5056, 5056, 5056, 16843009, 43921, 5057, 5057, 5057, 16843009, 
+    43922, 5058, 5058, 5058, 16843009, 43923, 5059, 5059, 5059, 16843009, 
+    43924, 5060, 5060, 5060, 16843009, 43925, 5061, 5061, 5061, 16843009, 
+    43926, 5062, 5062, 5062, 16843009, 43927, 5063, 5063, 5063, 16843009, 
+    43928, 5064, 5064, 5064, 16843009, 43929, 5065, 5065, 5065, 16843009, 
+    43930, 5066, 5066, 5066, 16843009, 43931, 5067, 5067, 5067, 16843009, 
+    43932, 5068, 5068, 5068, 16843009, 43933, 5069, 5069, 5069, 16843009, 
+    43934, 5070, 5070, 5070, 16843009, 43935, 5071, 5071, 5071, 16843009, 
+    43936, 5072, 5072, 5072, 16843009, 43937, 5073, 5073, 5073, 16843009, 
+    43938, 5074, 5074, 5074, 16843009, 43939, 5075, 5075, 5075, 16843009, 
+    43940, 5076, 5076, 5076, 16843009, 43941, 5077, 5077, 5077, 16843009, 
+    43942, 5078, 5078, 5078, 16843009, 43943, 5079, 5079, 5079, 16843009, 
+    43944, 5080, 5080, 5080, 16843009, 43945, 5081, 5081, 5081, 16843009, 
+    43946, 5082, 5082, 5082, 16843009, 43947, 5083, 5083, 5083, 16843009, 
+    43948, 5084, 5084, 5084, 16843009, 43949, 5085, 5085, 5085, 16843009, 
+    43950, 5086, 5086, 5086, 16843009, 43951, 5087, 5087, 5087, 16843009, 
+    43952, 5088, 5088, 5088, 16843009, 43953, 5089, 5089, 5089, 16843009, 
+    43954, 5090, 5090, 5090, 16843009, 43955, 5091, 5091, 5091, 16843009, 
+    43956, 5092, 5092, 5092, 16843009, 43957, 5093, 5093, 5093, 16843009, 
+    43958, 5094, 5094, 5094, 16843009, 43959, 5095, 5095, 5095, 16843009, 
+    43960, 5096, 5096, 5096, 16843009, 43961, 5097, 5097, 5097, 16843009, 
+    43962, 5098, 5098, 5098, 16843009, 43963, 5099, 5099, 5099, 16843009, 
+    43964, 5100, 5100, 5100, 16843009, 43965, 5101, 5101, 5101, 16843009, 
+    43966, 5102, 5102, 5102, 16843009, 43967, 5103, 5103, 5103, 33686017, 
+    64256, 70, 102, 70, 70, 102, 102, 33686017, 64257, 70, 105, 70, 73, 102, 
+    105, 33686017, 64258, 70, 108, 70, 76, 102, 108, 50529025, 64259, 70, 
+    102, 105, 70, 70, 73, 102, 102, 105, 50529025, 64260, 70, 102, 108, 70, 
+    70, 76, 102, 102, 108, 33686017, 64261, 83, 116, 83, 84, 115, 116, 
+    33686017, 64262, 83, 116, 83, 84, 115, 116, 33686017, 64275, 1348, 1398, 
+    1348, 1350, 1396, 1398, 33686017, 64276, 1348, 1381, 1348, 1333, 1396, 
+    1381, 33686017, 64277, 1348, 1387, 1348, 1339, 1396, 1387, 33686017, 
+    64278, 1358, 1398, 1358, 1350, 1406, 1398, 33686017, 64279, 1348, 1389, 
+    1348, 1341, 1396, 1389, 65794, 105, 775, 73, 73, 65793, 305, 73, 73, 
+    65794, 106, 775, 74, 74, 65793, 105, 304, 304, 65795, 105, 775, 768, 204, 
+    204, 65795, 105, 775, 769, 205, 205, 65795, 105, 775, 771, 296, 296, 
+    65794, 303, 775, 302, 302, 65793, 105, 304, 304, 1, 775, 65792, 775, 775, 
+    65793, 962, 931, 931, 
+];
+
+/* Turkic case folding */
+pub static TURKIC_FOLDING: [CaseFolding; 2] = [
+    CaseFolding{code: 73, folded: 305},
+    CaseFolding{code: 304, folded: 105},
+];
+
+/* conditions of conditional case mappings */
+#[allow(dead_code)] pub const CONDITION_FINAL_SIGMA: u8 = 1;
+#[allow(dead_code)] pub const CONDITION_AFTER_SOFT_DOTTED: u8 = 2;
+#[allow(dead_code)] pub const CONDITION_MORE_ABOVE: u8 = 3;
+#[allow(dead_code)] pub const CONDITION_BEFORE_DOT: u8 = 4;
+#[allow(dead_code)] pub const CONDITION_AFTER_I: u8 = 5;
+#[allow(dead_code)] pub const CONDITION_NOT: u8 = 0x80;
+
+/* conditional case mappings */
+pub static CONDITIONAL_CASING: [ConditionalCasing; 12] = [
+    ConditionalCasing{code: 73, language: 2, condition: 3, mapping: 1794},
+    ConditionalCasing{code: 73, language: 1, condition: 132, mapping: 1799},
+    ConditionalCasing{code: 74, language: 2, condition: 3, mapping: 1803},
+    ConditionalCasing{code: 105, language: 1, condition: 0, mapping: 1808},
+    ConditionalCasing{code: 204, language: 2, condition: 0, mapping: 1812},
+    ConditionalCasing{code: 205, language: 2, condition: 0, mapping: 1818},
+    ConditionalCasing{code: 296, language: 2, condition: 0, mapping: 1824},
+    ConditionalCasing{code: 302, language: 2, condition: 3, mapping: 1830},
+    ConditionalCasing{code: 304, language: 1, condition: 0, mapping: 1835},
+    ConditionalCasing{code: 775, language: 2, condition: 2, mapping: 1839},
+    ConditionalCasing{code: 775, language: 1, condition: 5, mapping: 1841},
+    ConditionalCasing{code: 931, language: 0, condition: 1, mapping: 1844},
+];
+
+/* numeric values as fractions */
+pub static NUMERIC_VALUES: [NumericValue; 135] = [
+    NumericValue{numerator: -1, denominator: 2},
+    NumericValue{numerator: 0, denominator: 1},
+    NumericValue{numerator: 1, denominator: 160},
+    NumericValue{numerator: 1, denominator: 40},
+    NumericValue{numerator: 3, denominator: 80},
+    NumericValue{numerator: 1, denominator: 20},
+    NumericValue{numerator: 1, denominator: 16},
+    NumericValue{numerator: 1, denominator: 12},
+    NumericValue{numerator: 1, denominator: 10},
+    NumericValue{numerator: 1, denominator: 9},
+    NumericValue{numerator: 1, denominator: 8},
+    NumericValue{numerator: 1, denominator: 7},
+    NumericValue{numerator: 3, denominator: 20},
+    NumericValue{numerator: 1, denominator: 6},
+    NumericValue{numerator: 3, denominator: 16},
+    NumericValue{numerator: 1, denominator: 5},
+    NumericValue{numerator: 1, denominator: 4},
+    NumericValue{numerator: 1, denominator: 3},
+    NumericValue{numerator: 3, denominator: 8},
+    NumericValue{numerator: 2, denominator: 5},
+    NumericValue{numerator: 5, denominator: 12},
+    NumericValue{numerator: 1, denominator: 2},
+    NumericValue{numerator: 7, denominator: 12},
+    NumericValue{numerator: 3, denominator: 5},
+    NumericValue{numerator: 5, denominator: 8},
+    NumericValue{numerator: 2, denominator: 3},
+    NumericValue{numerator: 3, denominator: 4},
+    NumericValue{numerator: 4, denominator: 5},
+    NumericValue{numerator: 5, denominator: 6},
+    NumericValue{numerator: 7, denominator: 8},
+    NumericValue{numerator: 11, denominator: 12},
+    NumericValue{numerator: 1, denominator: 1},
+    NumericValue{numerator: 3, denominator: 2},
+    NumericValue{numerator: 2, denominator: 1},
+    NumericValue{numerator: 5, denominator: 2},
+    NumericValue{numerator: 3, denominator: 1},
+    NumericValue{numerator: 7, denominator: 2},
+    NumericValue{numerator: 4, denominator: 1},
+    NumericValue{numerator: 9, denominator: 2},
+    NumericValue{numerator: 5, denominator: 1},
+    NumericValue{numerator: 11, denominator: 2},
+    NumericValue{numerator: 6, denominator: 1},
+    NumericValue{numerator: 13, denominator: 2},
+    NumericValue{numerator: 7, denominator: 1},
+    NumericValue{numerator: 15, denominator: 2},
+    NumericValue{numerator: 8, denominator: 1},
+    NumericValue{numerator: 17, denominator: 2},
+    NumericValue{numerator: 9, denominator: 1},
+    NumericValue{numerator: 10, denominator: 1},
+    NumericValue{numerator: 11, denominator: 1},
+    NumericValue{numerator: 12, denominator: 1},
+    NumericValue{numerator: 13, denominator: 1},
+    NumericValue{numerator: 14, denominator: 1},
+    NumericValue{numerator: 15, denominator: 1},
+    NumericValue{numerator: 16, denominator: 1},
+    NumericValue{numerator: 17, denominator: 1},
+    NumericValue{numerator: 18, denominator: 1},
+    NumericValue{numerator: 19, denominator: 1},
+    NumericValue{numerator: 20, denominator: 1},
+    NumericValue{numerator: 21, denominator: 1},
+    NumericValue{numerator: 22, denominator: 1},
+    NumericValue{numerator: 23, denominator: 1},
+    NumericValue{numerator: 24, denominator: 1},
+    NumericValue{numerator: 25, denominator: 1},
+    NumericValue{numerator: 26, denominator: 1},
+    NumericValue{numerator: 27, denominator: 1},
+    NumericValue{numerator: 28, denominator: 1},
+    NumericValue{numerator: 29, denominator: 1},
+    NumericValue{numerator: 30, denominator: 1},
+    NumericValue{numerator: 31, denominator: 1},
+    NumericValue{numerator: 32, denominator: 1},
+    NumericValue{numerator: 33, denominator: 1},
+    NumericValue{numerator: 34, denominator: 1},
+    NumericValue{numerator: 35, denominator: 1},
+    NumericValue{numerator: 36, denominator: 1},
+    NumericValue{numerator: 37, denominator: 1},
+    NumericValue{numerator: 38, denominator: 1},
+    NumericValue{numerator: 39, denominator: 1},
+    NumericValue{numerator: 40, denominator: 1},
+    NumericValue{numerator: 41, denominator: 1},
+    NumericValue{numerator: 42, denominator: 1},
+    NumericValue{numerator: 43, denominator: 1},
+    NumericValue{numerator: 44, denominator: 1},
+    NumericValue{numerator: 45, denominator: 1},
+    NumericValue{numerator: 46, denominator: 1},
+    NumericValue{numerator: 47, denominator: 1},
+    NumericValue{numerator: 48, denominator: 1},
+    NumericValue{numerator: 49, denominator: 1},
+    NumericValue{numerator: 50, denominator: 1},
+    NumericValue{numerator: 60, denominator: 1},
+    NumericValue{numerator: 70, denominator: 1},
+    NumericValue{numerator: 80, denominator: 1},
+    NumericValue{numerator: 90, denominator: 1},
+    NumericValue{numerator: 100, denominator: 1},
+    NumericValue{numerator: 200, denominator: 1},
+    NumericValue{numerator: 300, denominator: 1},
+    NumericValue{numerator: 400, denominator: 1},
+    NumericValue{numerator: 500, denominator: 1},
+    NumericValue{numerator: 600, denominator: 1},
+    NumericValue{numerator: 700, denominator: 1},
+    NumericValue{numerator: 800, denominator: 1},
+    NumericValue{numerator: 900, denominator: 1},
+    NumericValue{numerator: 1000, denominator: 1},
+    NumericValue{numerator: 2000, denominator: 1},
+    NumericValue{numerator: 3000, denominator: 1},
+    NumericValue{numerator: 4000, denominator: 1},
+    NumericValue{numerator: 5000, denominator: 1},
+    NumericValue{numerator: 6000, denominator: 1},
+    NumericValue{numerator: 7000, denominator: 1},
+    NumericValue{numerator: 8000, denominator: 1},
+    NumericValue{numerator: 9000, denominator: 1},
+    NumericValue{numerator: 10000, denominator: 1},
+    NumericValue{numerator: 20000, denominator: 1},
+    NumericValue{numerator: 30000, denominator: 1},
+    NumericValue{numerator: 40000, denominator: 1},
+    NumericValue{numerator: 50000, denominator: 1},
+    NumericValue{numerator: 60000, denominator: 1},
+    NumericValue{numerator: 70000, denominator: 1},
+    NumericValue{numerator: 80000, denominator: 1},
+    NumericValue{numerator: 90000, denominator: 1},
+    NumericValue{numerator: 100000, denominator: 1},
+    NumericValue{numerator: 200000, denominator: 1},
+    NumericValue{numerator: 216000, denominator: 1},
+    NumericValue{numerator: 300000, denominator: 1},
+    NumericValue{numerator: 400000, denominator: 1},
+    NumericValue{numerator: 432000, denominator: 1},
+    NumericValue{numerator: 500000, denominator: 1},
+    NumericValue{numerator: 600000, denominator: 1},
+    NumericValue{numerator: 700000, denominator: 1},
+    NumericValue{numerator: 800000, denominator: 1},
+    NumericValue{numerator: 900000, denominator: 1},
+    NumericValue{numerator: 1000000, denominator: 1},
+    NumericValue{numerator: 100000000, denominator: 1},
+    NumericValue{numerator: 10000000000, denominator: 1},
+    NumericValue{numerator: 1000000000000, denominator: 1},
+];
+
+/* index tables for the character type descriptors */
+#[allow(dead_code)] pub const TYPE_SHIFT1: u32 = 5;
+#[allow(dead_code)] pub const TYPE_SHIFT2: u32 = 3;
+pub static TYPE_INDEX0: [u8; 4352] = [
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 
+    17, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 33, 33, 
+    36, 33, 33, 33, 37, 38, 39, 40, 41, 42, 43, 44, 33, 45, 17, 17, 17, 46, 
+    17, 17, 47, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 
+    17, 17, 48, 49, 50, 17, 51, 17, 52, 17, 17, 53, 17, 54, 17, 17, 17, 17, 
+    17, 55, 56, 17, 17, 57, 58, 17, 17, 17, 59, 17, 17, 17, 17, 17, 17, 17, 
+    60, 17, 17, 17, 61, 17, 17, 62, 17, 17, 17, 17, 17, 17, 17, 17, 17, 63, 
+    17, 17, 17, 64, 17, 17, 17, 17, 17, 17, 17, 65, 66, 17, 17, 17, 17, 17, 
+    17, 17, 17, 67, 17, 17, 17, 17, 17, 17, 17, 17, 68, 69, 17, 17, 17, 70, 
+    17, 71, 72, 73, 74, 75, 76, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 
+    17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 
+    17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 77, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 79, 80, 81, 82, 83, 84, 
+    85, 86, 87, 88, 89, 90, 91, 17, 92, 93, 94, 95, 96, 97, 78, 98, 78, 99, 
+    100, 101, 102, 103, 104, 105, 106, 107, 78, 108, 78, 109, 78, 78, 78, 17, 
+    17, 17, 110, 111, 112, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 17, 17, 
+    17, 17, 113, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    17, 17, 114, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    17, 17, 115, 116, 78, 78, 78, 117, 17, 17, 17, 17, 17, 17, 17, 17, 17, 
+    17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 118, 17, 17, 119, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 120, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 121, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 122, 123, 
+    124, 125, 126, 127, 128, 129, 33, 33, 130, 78, 78, 78, 78, 78, 131, 78, 
+    78, 78, 78, 78, 78, 78, 132, 133, 78, 78, 78, 78, 134, 78, 135, 136, 137, 
+    138, 33, 33, 139, 140, 141, 142, 78, 78, 78, 78, 78, 78, 143, 144, 17, 
+    17, 17, 17, 17, 17, 17, 145, 146, 147, 17, 17, 17, 17, 17, 17, 17, 17, 
+    17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 148, 17, 17, 
+    17, 17, 17, 149, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 
+    17, 17, 17, 150, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 
+    17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 
+    17, 17, 17, 17, 17, 17, 151, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 
+    17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 
+    17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 
+    17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 
+    17, 17, 152, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 
+    17, 153, 154, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 
+    17, 17, 17, 17, 17, 17, 155, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 156, 17, 157, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 158, 159, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 78, 
+    78, 78, 78, 78, 
+];
+
+pub static TYPE_INDEX1: [u16; 5120] = [
+    0, 1, 0, 2, 3, 4, 5, 6, 7, 8, 8, 9, 10, 11, 12, 13, 14, 0, 0, 0, 15, 16, 
+    17, 18, 8, 8, 19, 20, 12, 12, 21, 22, 23, 23, 23, 23, 23, 24, 25, 26, 27, 
+    28, 23, 23, 23, 23, 23, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 27, 
+    40, 23, 23, 41, 23, 23, 23, 23, 23, 42, 23, 43, 44, 45, 46, 47, 48, 49, 
+    50, 51, 52, 53, 54, 55, 56, 57, 57, 58, 59, 60, 61, 62, 63, 64, 65, 63, 
+    63, 66, 66, 66, 66, 66, 66, 66, 66, 67, 66, 66, 66, 66, 66, 68, 69, 70, 
+    71, 72, 8, 73, 74, 75, 12, 76, 77, 78, 23, 23, 23, 79, 80, 81, 81, 8, 8, 
+    8, 8, 12, 12, 12, 12, 82, 83, 23, 23, 23, 23, 84, 85, 23, 23, 23, 23, 23, 
+    23, 86, 87, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 88, 89, 89, 
+    89, 90, 91, 92, 93, 93, 93, 94, 95, 96, 66, 66, 66, 66, 97, 98, 0, 99, 
+    99, 99, 100, 101, 0, 102, 103, 66, 104, 99, 99, 99, 99, 105, 106, 66, 66, 
+    5, 107, 108, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 109, 110, 111, 
+    112, 5, 113, 103, 114, 115, 99, 99, 99, 66, 66, 66, 116, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 117, 66, 118, 0, 5, 119, 99, 99, 99, 106, 120, 
+    121, 99, 99, 117, 122, 123, 124, 103, 125, 99, 99, 99, 126, 0, 0, 0, 0, 
+    0, 0, 0, 0, 99, 99, 127, 128, 0, 0, 129, 66, 130, 66, 66, 66, 131, 99, 
+    99, 99, 99, 99, 99, 132, 133, 134, 135, 99, 136, 137, 138, 99, 139, 140, 
+    141, 99, 99, 142, 143, 144, 145, 146, 147, 148, 149, 137, 150, 151, 152, 
+    153, 141, 99, 99, 142, 154, 155, 156, 157, 158, 159, 160, 137, 161, 0, 
+    152, 162, 163, 99, 99, 142, 164, 144, 165, 166, 167, 0, 149, 137, 168, 
+    169, 170, 140, 141, 99, 99, 142, 164, 171, 145, 172, 173, 148, 149, 137, 
+    174, 0, 175, 176, 177, 178, 179, 176, 99, 180, 181, 182, 183, 0, 160, 
+    137, 184, 185, 186, 127, 142, 99, 99, 142, 99, 187, 188, 189, 190, 100, 
+    149, 137, 0, 191, 139, 127, 142, 99, 99, 142, 192, 171, 193, 194, 195, 
+    196, 149, 137, 197, 0, 170, 127, 142, 99, 99, 99, 99, 198, 199, 200, 201, 
+    202, 149, 137, 203, 204, 205, 99, 206, 207, 99, 99, 163, 208, 206, 209, 
+    210, 211, 160, 137, 212, 0, 213, 99, 99, 99, 99, 99, 214, 215, 216, 217, 
+    5, 218, 0, 0, 0, 0, 219, 220, 221, 213, 222, 223, 214, 224, 225, 226, 5, 
+    227, 0, 0, 0, 0, 228, 103, 103, 229, 5, 230, 231, 232, 99, 213, 99, 99, 
+    99, 233, 96, 234, 235, 236, 66, 96, 66, 66, 66, 237, 238, 239, 103, 185, 
+    0, 0, 0, 0, 99, 99, 99, 99, 99, 240, 241, 242, 5, 243, 244, 245, 246, 
+    247, 248, 99, 249, 250, 5, 251, 252, 252, 252, 252, 253, 254, 99, 99, 99, 
+    99, 99, 255, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 177, 206, 177, 99, 99, 99, 99, 99, 177, 
+    99, 99, 99, 99, 177, 206, 177, 99, 206, 99, 99, 99, 99, 99, 99, 99, 177, 
+    99, 99, 99, 99, 99, 99, 99, 99, 256, 103, 257, 258, 259, 99, 99, 103, 
+    168, 260, 261, 262, 263, 264, 265, 266, 267, 268, 269, 270, 271, 272, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 273, 99, 99, 274, 99, 99, 275, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 276, 277, 167, 99, 127, 278, 0, 99, 99, 279, 0, 
+    99, 99, 280, 0, 99, 127, 281, 0, 99, 99, 99, 99, 99, 99, 282, 283, 284, 
+    133, 285, 286, 5, 287, 288, 289, 103, 290, 5, 287, 99, 99, 99, 99, 291, 
+    99, 99, 99, 99, 99, 99, 0, 292, 99, 99, 99, 99, 293, 99, 99, 99, 99, 99, 
+    99, 99, 99, 128, 0, 99, 99, 99, 206, 294, 295, 296, 297, 298, 137, 99, 
+    99, 99, 128, 233, 0, 99, 99, 99, 99, 99, 299, 99, 99, 99, 300, 5, 301, 
+    103, 103, 103, 103, 99, 99, 302, 303, 99, 99, 99, 99, 99, 99, 304, 305, 
+    306, 307, 308, 309, 5, 287, 5, 287, 310, 311, 66, 312, 0, 0, 0, 0, 0, 0, 
+    0, 0, 313, 99, 99, 99, 99, 99, 314, 315, 316, 299, 5, 243, 103, 317, 318, 
+    319, 320, 99, 99, 99, 321, 322, 5, 119, 99, 99, 99, 99, 323, 324, 325, 
+    326, 99, 99, 99, 99, 327, 328, 329, 330, 5, 331, 5, 119, 99, 99, 99, 332, 
+    333, 334, 0, 0, 0, 0, 0, 0, 103, 0, 335, 66, 241, 336, 337, 338, 57, 57, 
+    57, 57, 57, 339, 340, 340, 340, 340, 340, 340, 58, 341, 57, 342, 57, 57, 
+    343, 344, 345, 346, 340, 340, 66, 66, 66, 66, 66, 66, 226, 347, 23, 23, 
+    23, 23, 23, 348, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 349, 
+    350, 23, 23, 23, 23, 23, 351, 23, 23, 23, 23, 23, 23, 352, 353, 354, 355, 
+    352, 353, 352, 353, 354, 355, 356, 357, 352, 353, 358, 359, 360, 361, 
+    362, 363, 364, 365, 366, 367, 368, 369, 370, 371, 372, 373, 374, 375, 
+    376, 377, 103, 378, 379, 380, 103, 381, 382, 103, 383, 384, 385, 386, 
+    387, 388, 389, 390, 340, 391, 103, 103, 103, 125, 0, 0, 66, 392, 393, 66, 
+    394, 0, 395, 396, 397, 398, 399, 400, 401, 402, 403, 404, 405, 406, 407, 
+    408, 409, 410, 411, 412, 103, 103, 103, 103, 103, 103, 103, 103, 103, 
+    103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 
+    103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 
+    103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 
+    103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 
+    103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 125, 103, 
+    103, 103, 103, 125, 0, 0, 0, 103, 185, 0, 0, 413, 414, 415, 416, 417, 
+    413, 414, 418, 103, 103, 419, 420, 420, 420, 421, 421, 421, 422, 423, 
+    424, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 
+    103, 425, 426, 413, 427, 428, 103, 103, 103, 103, 103, 103, 103, 103, 
+    103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 
+    103, 103, 103, 103, 103, 429, 103, 103, 103, 311, 103, 103, 103, 103, 
+    430, 103, 431, 168, 0, 0, 326, 0, 0, 89, 89, 89, 89, 89, 90, 93, 93, 93, 
+    93, 93, 432, 433, 434, 435, 436, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 
+    23, 23, 437, 438, 439, 440, 441, 441, 441, 441, 442, 443, 99, 99, 99, 99, 
+    99, 99, 99, 444, 445, 446, 99, 99, 206, 0, 206, 206, 206, 206, 206, 206, 
+    206, 206, 66, 66, 66, 66, 103, 103, 103, 103, 103, 447, 103, 103, 319, 0, 
+    0, 0, 0, 0, 0, 0, 103, 103, 103, 448, 103, 103, 103, 103, 103, 103, 103, 
+    103, 103, 103, 449, 0, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 
+    103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 
+    103, 103, 311, 0, 0, 0, 103, 449, 450, 103, 103, 103, 451, 452, 453, 454, 
+    213, 99, 99, 99, 99, 99, 99, 99, 99, 99, 206, 455, 272, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 456, 457, 99, 99, 99, 99, 128, 213, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 206, 458, 103, 99, 99, 99, 100, 103, 103, 
+    103, 103, 449, 0, 99, 99, 103, 103, 103, 125, 459, 460, 103, 103, 103, 
+    461, 462, 463, 103, 103, 103, 103, 459, 460, 103, 103, 103, 103, 464, 
+    465, 103, 103, 103, 103, 103, 103, 103, 125, 466, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 467, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 468, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 469, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 128, 0, 103, 103, 103, 103, 103, 103, 103, 103, 470, 471, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 472, 99, 99, 99, 99, 99, 473, 474, 99, 
+    99, 99, 99, 475, 476, 99, 99, 477, 99, 478, 99, 99, 99, 466, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 479, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 480, 99, 99, 99, 99, 99, 99, 99, 481, 
+    99, 99, 99, 99, 482, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 483, 484, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 485, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 486, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 487, 487, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 488, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 489, 99, 490, 491, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 492, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 493, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 494, 99, 99, 99, 99, 99, 495, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 496, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 497, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 498, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 499, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 500, 467, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 491, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 501, 99, 99, 99, 502, 503, 99, 99, 99, 99, 99, 504, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 505, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 128, 0, 0, 0, 0, 0, 99, 99, 506, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 233, 103, 103, 103, 103, 103, 103, 125, 0, 99, 99, 
+    99, 99, 99, 332, 99, 507, 99, 99, 5, 508, 0, 0, 23, 23, 23, 23, 23, 509, 
+    510, 511, 23, 23, 23, 512, 99, 99, 99, 99, 99, 99, 99, 99, 513, 514, 229, 
+    0, 63, 63, 515, 61, 85, 23, 516, 23, 23, 23, 23, 23, 23, 23, 517, 518, 
+    23, 519, 520, 23, 23, 521, 522, 0, 0, 0, 0, 0, 0, 0, 523, 524, 525, 526, 
+    99, 99, 527, 449, 528, 168, 99, 99, 99, 99, 99, 99, 529, 0, 530, 99, 99, 
+    99, 99, 99, 327, 211, 531, 532, 5, 287, 66, 66, 533, 534, 5, 119, 99, 99, 
+    117, 535, 99, 99, 302, 66, 325, 536, 99, 99, 99, 233, 131, 99, 99, 99, 
+    99, 99, 537, 538, 382, 539, 5, 540, 541, 99, 5, 542, 99, 99, 99, 99, 99, 
+    543, 544, 0, 526, 545, 5, 546, 99, 99, 547, 548, 99, 99, 99, 99, 99, 99, 
+    549, 550, 293, 0, 0, 551, 99, 552, 553, 0, 554, 554, 554, 0, 206, 206, 
+    57, 57, 57, 57, 555, 556, 557, 0, 558, 559, 560, 561, 562, 563, 564, 565, 
+    566, 567, 99, 99, 99, 99, 568, 569, 5, 287, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 299, 0, 99, 99, 206, 
+    570, 99, 99, 99, 99, 99, 299, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 571, 572, 491, 99, 99, 99, 99, 99, 99, 
+    573, 99, 99, 99, 574, 99, 99, 99, 99, 575, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 128, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 300, 0, 0, 0, 0, 576, 0, 577, 578, 99, 579, 206, 580, 581, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 582, 63, 583, 0, 570, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 584, 585, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 586, 0, 0, 99, 99, 99, 99, 99, 99, 99, 99, 
+    207, 99, 99, 99, 99, 99, 99, 0, 0, 0, 0, 0, 99, 587, 66, 66, 588, 168, 
+    66, 66, 589, 103, 103, 590, 591, 103, 125, 449, 592, 593, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 594, 595, 4, 5, 6, 7, 8, 8, 
+    9, 10, 12, 12, 596, 597, 99, 105, 99, 99, 99, 99, 598, 99, 99, 99, 206, 
+    207, 207, 207, 599, 600, 125, 0, 601, 99, 192, 99, 99, 206, 99, 99, 602, 
+    99, 128, 99, 128, 0, 0, 0, 0, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 100, 603, 604, 461, 605, 606, 607, 608, 103, 609, 610, 
+    611, 612, 613, 614, 615, 616, 103, 617, 103, 449, 445, 0, 0, 0, 0, 0, 
+    103, 103, 103, 103, 103, 618, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 99, 99, 99, 233, 99, 99, 99, 99, 99, 99, 167, 0, 619, 620, 621, 
+    622, 99, 99, 99, 99, 623, 0, 99, 99, 624, 625, 99, 99, 99, 99, 117, 626, 
+    99, 99, 99, 627, 99, 99, 99, 99, 299, 99, 628, 0, 0, 0, 0, 0, 629, 629, 
+    629, 629, 629, 630, 630, 630, 630, 630, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 128, 5, 287, 629, 629, 629, 629, 631, 630, 630, 630, 630, 632, 99, 
+    99, 99, 99, 99, 0, 99, 99, 99, 99, 99, 99, 299, 536, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 99, 99, 99, 99, 99, 99, 206, 0, 99, 99, 
+    128, 0, 99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 
+    142, 99, 99, 99, 99, 162, 633, 99, 99, 627, 634, 99, 99, 635, 636, 99, 
+    99, 99, 206, 637, 638, 0, 0, 0, 0, 0, 0, 99, 99, 639, 640, 99, 99, 641, 
+    642, 99, 99, 99, 643, 0, 0, 0, 0, 0, 0, 0, 0, 99, 99, 99, 99, 99, 99, 99, 
+    644, 459, 645, 646, 647, 648, 649, 650, 651, 652, 129, 192, 213, 99, 99, 
+    299, 653, 654, 0, 103, 445, 99, 99, 99, 655, 99, 99, 99, 656, 0, 0, 0, 0, 
+    99, 272, 99, 99, 657, 640, 125, 0, 99, 99, 99, 99, 99, 99, 128, 658, 99, 
+    99, 128, 659, 99, 99, 100, 659, 99, 99, 300, 660, 0, 661, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 99, 99, 99, 99, 99, 99, 99, 99, 99, 167, 0, 0, 0, 0, 0, 0, 
+    662, 662, 662, 662, 662, 662, 663, 0, 664, 664, 664, 664, 664, 664, 665, 
+    666, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 413, 667, 668, 669, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 670, 99, 99, 99, 99, 99, 99, 66, 217, 
+    311, 671, 672, 673, 137, 0, 446, 320, 99, 99, 99, 99, 99, 674, 675, 168, 
+    0, 99, 99, 99, 167, 5, 287, 676, 99, 99, 99, 302, 677, 678, 137, 449, 0, 
+    99, 99, 99, 99, 679, 0, 320, 99, 99, 99, 99, 99, 680, 234, 681, 682, 5, 
+    683, 684, 620, 685, 0, 99, 99, 163, 99, 99, 686, 687, 688, 0, 0, 0, 0, 0, 
+    0, 0, 0, 206, 689, 99, 162, 99, 690, 99, 99, 99, 99, 99, 302, 308, 626, 
+    5, 287, 691, 140, 141, 99, 99, 142, 164, 144, 692, 693, 183, 457, 694, 
+    695, 695, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 99, 99, 99, 
+    99, 99, 99, 696, 66, 697, 698, 5, 699, 0, 0, 0, 0, 99, 99, 99, 99, 99, 
+    99, 308, 700, 701, 0, 5, 287, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 99, 99, 99, 99, 99, 702, 703, 704, 705, 103, 103, 706, 
+    0, 0, 0, 0, 99, 99, 99, 99, 99, 99, 308, 707, 708, 0, 5, 287, 103, 319, 
+    0, 0, 99, 99, 99, 99, 99, 709, 710, 0, 5, 287, 0, 0, 0, 0, 0, 0, 99, 99, 
+    99, 711, 712, 713, 5, 714, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 8, 8, 8, 8, 12, 12, 12, 12, 5, 715, 716, 523, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 99, 99, 99, 
+    99, 99, 99, 99, 167, 99, 142, 99, 99, 99, 702, 305, 710, 717, 0, 5, 718, 
+    719, 720, 721, 99, 99, 99, 722, 66, 66, 723, 724, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 300, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 725, 726, 727, 728, 729, 
+    730, 731, 732, 733, 734, 735, 736, 737, 738, 319, 0, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 299, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 99, 99, 99, 99, 99, 206, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 99, 99, 99, 99, 99, 99, 99, 99, 206, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 99, 
+    99, 99, 99, 99, 99, 99, 167, 99, 99, 99, 206, 5, 540, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 99, 99, 99, 128, 739, 0, 99, 99, 99, 99, 99, 99, 217, 
+    103, 740, 0, 5, 741, 742, 99, 99, 457, 99, 99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 99, 99, 99, 99, 99, 99, 99, 99, 233, 0, 743, 211, 211, 
+    211, 211, 744, 0, 446, 745, 61, 0, 0, 0, 0, 0, 0, 0, 0, 746, 0, 0, 0, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 233, 0, 0, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 100, 0, 300, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 100, 99, 233, 99, 167, 99, 747, 
+    748, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 103, 103, 103, 103, 103, 103, 103, 
+    103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 
+    103, 103, 103, 103, 103, 103, 103, 103, 103, 311, 0, 103, 103, 103, 103, 
+    125, 658, 103, 103, 103, 103, 103, 103, 749, 750, 751, 752, 753, 318, 
+    103, 103, 103, 754, 103, 103, 103, 103, 103, 103, 103, 445, 0, 0, 103, 
+    103, 103, 103, 103, 103, 103, 103, 682, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 103, 103, 103, 103, 103, 103, 103, 
+    103, 103, 103, 125, 0, 459, 645, 755, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 756, 756, 756, 757, 758, 57, 759, 756, 756, 760, 761, 57, 
+    57, 756, 756, 756, 757, 758, 57, 762, 763, 764, 760, 765, 766, 57, 756, 
+    756, 756, 757, 758, 57, 767, 768, 769, 770, 771, 57, 57, 772, 773, 774, 
+    775, 758, 57, 759, 756, 756, 760, 771, 57, 57, 756, 756, 756, 757, 758, 
+    57, 759, 756, 756, 760, 771, 57, 57, 756, 756, 756, 757, 758, 57, 759, 
+    756, 756, 760, 771, 57, 57, 756, 756, 756, 757, 758, 57, 557, 756, 756, 
+    756, 776, 57, 57, 777, 778, 756, 756, 779, 57, 57, 780, 759, 756, 756, 
+    781, 57, 57, 782, 783, 756, 756, 784, 57, 57, 57, 785, 756, 756, 756, 
+    776, 57, 57, 777, 786, 137, 5, 787, 788, 789, 137, 66, 66, 66, 66, 66, 
+    66, 217, 317, 66, 66, 66, 66, 66, 790, 791, 103, 792, 449, 0, 347, 96, 
+    66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 305, 66, 66, 793, 794, 626, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 795, 604, 305, 0, 0, 0, 0, 0, 796, 796, 796, 796, 797, 
+    798, 798, 798, 799, 626, 5, 540, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 192, 99, 99, 99, 219, 213, 800, 801, 802, 803, 219, 
+    804, 219, 800, 800, 159, 99, 163, 99, 299, 805, 163, 99, 299, 0, 0, 0, 0, 
+    0, 0, 168, 0, 103, 103, 103, 103, 103, 449, 103, 103, 103, 103, 103, 103, 
+    103, 103, 103, 103, 103, 103, 449, 0, 103, 125, 658, 103, 658, 103, 658, 
+    103, 103, 103, 311, 0, 806, 807, 103, 103, 103, 125, 808, 808, 808, 809, 
+    808, 808, 808, 810, 808, 808, 808, 809, 103, 103, 103, 319, 0, 0, 0, 0, 
+    0, 0, 532, 103, 103, 103, 185, 0, 103, 103, 103, 103, 103, 449, 103, 445, 
+    168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 103, 
+    103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 
+    103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 
+    103, 103, 811, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 
+    103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 103, 
+    103, 185, 0, 103, 319, 125, 0, 103, 103, 103, 103, 103, 103, 103, 103, 
+    103, 103, 103, 103, 103, 103, 449, 0, 103, 103, 103, 103, 103, 103, 103, 
+    103, 103, 103, 319, 0, 0, 0, 0, 0, 103, 449, 103, 103, 103, 103, 103, 
+    103, 103, 0, 103, 168, 103, 103, 103, 103, 103, 0, 103, 103, 103, 311, 0, 
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 103, 125, 103, 0, 812, 125, 103, 449, 
+    103, 125, 0, 0, 0, 0, 103, 103, 168, 0, 0, 0, 0, 0, 445, 0, 0, 0, 0, 0, 
+    0, 0, 813, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 814, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 815, 99, 99, 99, 99, 99, 
+    99, 99, 816, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 488, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 817, 484, 99, 484, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 818, 99, 819, 99, 99, 99, 471, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 491, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 478, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 571, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 820, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 206, 0, 0, 0, 0, 0, 99, 
+    99, 99, 99, 99, 99, 233, 0, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 128, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 300, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 99, 821, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 
+    99, 128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    0, 0, 0, 0, 0, 0, 0, 822, 0, 0, 0, 386, 386, 386, 386, 386, 386, 386, 
+    386, 386, 386, 386, 386, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 
+    66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 
+    66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 0, 0, 
+];
+
+pub static TYPE_INDEX2: [u16; 6584] = [
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 2, 3, 3, 3, 3, 1, 1, 1, 1, 1, 1, 3, 3, 3, 2, 
+    4, 5, 5, 5, 5, 5, 5, 6, 5, 5, 5, 5, 5, 5, 6, 5, 7, 8, 9, 10, 11, 12, 13, 
+    14, 15, 16, 6, 5, 5, 5, 5, 5, 5, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 
+    17, 17, 17, 17, 17, 17, 17, 17, 5, 5, 5, 6, 18, 6, 19, 19, 19, 19, 19, 
+    19, 19, 19, 20, 20, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 
+    19, 19, 19, 5, 5, 5, 5, 1, 1, 1, 1, 1, 1, 3, 1, 1, 2, 5, 5, 5, 5, 5, 5, 
+    5, 6, 5, 21, 5, 5, 22, 5, 6, 5, 5, 23, 24, 6, 25, 5, 26, 6, 27, 21, 5, 
+    28, 29, 30, 5, 17, 17, 17, 17, 17, 17, 17, 5, 17, 17, 17, 17, 17, 17, 17, 
+    31, 19, 19, 19, 19, 19, 19, 19, 5, 19, 19, 19, 19, 19, 19, 19, 32, 33, 
+    34, 33, 34, 33, 34, 33, 34, 33, 34, 33, 34, 33, 34, 33, 35, 36, 37, 33, 
+    34, 33, 34, 33, 34, 21, 33, 34, 33, 34, 33, 34, 33, 34, 33, 34, 33, 34, 
+    33, 34, 33, 34, 38, 33, 34, 33, 34, 33, 34, 39, 33, 34, 33, 34, 33, 34, 
+    40, 41, 42, 33, 34, 33, 34, 43, 33, 34, 44, 44, 33, 34, 21, 45, 46, 47, 
+    33, 34, 44, 48, 49, 50, 51, 33, 34, 52, 21, 50, 53, 54, 55, 33, 34, 33, 
+    34, 33, 34, 56, 33, 34, 56, 21, 21, 33, 34, 56, 33, 34, 57, 57, 33, 34, 
+    33, 34, 58, 33, 34, 21, 59, 33, 34, 21, 60, 59, 59, 59, 59, 61, 62, 63, 
+    61, 62, 63, 61, 62, 63, 33, 34, 33, 34, 33, 34, 33, 34, 64, 33, 34, 65, 
+    61, 62, 63, 33, 34, 66, 67, 68, 21, 33, 34, 33, 34, 33, 34, 33, 34, 33, 
+    34, 21, 21, 21, 21, 21, 21, 69, 33, 34, 70, 71, 72, 72, 33, 34, 73, 74, 
+    75, 33, 34, 33, 35, 33, 34, 33, 34, 33, 34, 76, 77, 78, 79, 80, 21, 81, 
+    81, 21, 82, 21, 83, 84, 21, 21, 21, 81, 85, 21, 86, 21, 87, 88, 21, 89, 
+    90, 88, 91, 92, 21, 21, 90, 21, 93, 94, 21, 21, 95, 21, 21, 21, 21, 21, 
+    21, 21, 96, 21, 21, 97, 21, 21, 97, 21, 21, 21, 98, 97, 99, 100, 100, 
+    101, 21, 21, 21, 21, 21, 102, 21, 59, 21, 21, 21, 21, 21, 21, 21, 21, 
+    103, 104, 21, 21, 21, 21, 21, 21, 21, 21, 21, 105, 105, 106, 105, 105, 
+    105, 105, 105, 105, 107, 107, 107, 107, 107, 107, 107, 105, 105, 6, 6, 6, 
+    6, 107, 107, 107, 107, 107, 107, 107, 107, 107, 107, 107, 107, 6, 6, 6, 
+    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 105, 105, 105, 105, 105, 6, 6, 6, 6, 6, 
+    6, 6, 107, 6, 107, 6, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 
+    108, 26, 26, 33, 34, 33, 34, 107, 6, 33, 34, 1, 1, 109, 54, 54, 54, 5, 
+    110, 1, 1, 1, 1, 6, 6, 111, 26, 112, 112, 112, 1, 113, 1, 114, 114, 115, 
+    17, 17, 17, 17, 17, 17, 17, 17, 17, 1, 17, 17, 17, 17, 17, 17, 17, 17, 
+    17, 116, 117, 117, 117, 118, 19, 19, 19, 19, 19, 19, 19, 19, 19, 119, 19, 
+    19, 19, 19, 19, 19, 19, 19, 19, 120, 121, 121, 122, 123, 124, 125, 125, 
+    125, 126, 127, 128, 129, 130, 131, 132, 133, 134, 5, 33, 34, 135, 33, 34, 
+    21, 68, 68, 68, 136, 136, 136, 136, 136, 136, 136, 136, 137, 137, 137, 
+    137, 137, 137, 138, 137, 138, 137, 137, 137, 137, 137, 137, 137, 33, 34, 
+    5, 26, 26, 26, 26, 26, 6, 6, 33, 34, 33, 34, 33, 34, 139, 33, 34, 33, 34, 
+    33, 34, 33, 34, 33, 34, 33, 34, 33, 34, 140, 1, 141, 141, 141, 141, 141, 
+    141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 
+    141, 141, 141, 1, 1, 107, 5, 5, 5, 5, 5, 5, 1, 142, 142, 142, 142, 142, 
+    142, 142, 142, 142, 142, 142, 142, 142, 142, 142, 142, 142, 142, 142, 
+    142, 142, 142, 143, 1, 5, 5, 1, 1, 5, 5, 5, 1, 26, 26, 26, 26, 26, 26, 
+    26, 26, 26, 26, 26, 26, 26, 5, 26, 5, 26, 26, 5, 26, 26, 5, 26, 59, 59, 
+    59, 59, 59, 59, 59, 59, 59, 59, 59, 1, 1, 1, 1, 1, 59, 59, 59, 5, 6, 1, 
+    1, 1, 22, 22, 22, 22, 22, 22, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 26, 26, 26, 
+    5, 22, 1, 5, 5, 107, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 26, 26, 26, 
+    26, 26, 15, 16, 5, 5, 5, 5, 59, 59, 26, 59, 59, 59, 59, 59, 59, 59, 59, 
+    59, 59, 59, 5, 59, 26, 26, 26, 26, 26, 26, 26, 22, 5, 26, 26, 26, 26, 26, 
+    26, 107, 107, 26, 26, 5, 26, 26, 26, 26, 59, 59, 15, 16, 59, 59, 59, 5, 
+    5, 59, 5, 5, 5, 5, 5, 5, 1, 22, 59, 26, 59, 59, 59, 59, 59, 59, 26, 26, 
+    26, 1, 1, 59, 59, 59, 59, 59, 59, 59, 59, 59, 26, 26, 26, 59, 1, 1, 1, 1, 
+    1, 1, 15, 16, 59, 59, 59, 59, 59, 59, 26, 26, 26, 26, 107, 107, 5, 5, 5, 
+    5, 107, 1, 1, 1, 1, 1, 26, 26, 107, 26, 26, 26, 26, 26, 26, 26, 26, 26, 
+    107, 26, 26, 26, 107, 26, 26, 26, 26, 26, 1, 1, 5, 5, 5, 5, 5, 5, 5, 1, 
+    59, 26, 26, 26, 1, 1, 5, 1, 59, 59, 59, 59, 59, 1, 59, 59, 59, 59, 59, 
+    59, 59, 59, 1, 1, 1, 1, 1, 1, 26, 26, 26, 26, 26, 26, 22, 26, 26, 26, 26, 
+    26, 26, 26, 26, 18, 59, 59, 59, 59, 59, 59, 26, 18, 26, 59, 18, 18, 18, 
+    26, 26, 26, 26, 26, 26, 26, 26, 18, 18, 18, 18, 26, 18, 18, 59, 26, 26, 
+    26, 26, 26, 26, 26, 59, 59, 26, 26, 5, 5, 7, 8, 9, 10, 11, 12, 13, 14, 
+    15, 16, 5, 107, 59, 59, 59, 59, 59, 59, 59, 26, 18, 18, 1, 59, 59, 59, 
+    59, 59, 59, 59, 59, 1, 1, 59, 59, 1, 1, 59, 59, 59, 59, 59, 59, 1, 59, 
+    59, 59, 59, 59, 59, 59, 1, 59, 1, 1, 1, 59, 59, 59, 59, 1, 1, 26, 59, 18, 
+    18, 18, 26, 26, 26, 26, 1, 1, 18, 18, 1, 1, 18, 18, 26, 59, 1, 1, 1, 1, 
+    1, 1, 1, 1, 18, 1, 1, 1, 1, 59, 59, 1, 59, 59, 59, 26, 26, 1, 1, 7, 8, 
+    59, 59, 5, 5, 144, 145, 146, 28, 30, 147, 5, 5, 1, 1, 1, 1, 1, 26, 26, 
+    18, 1, 59, 59, 59, 59, 59, 59, 1, 1, 1, 1, 59, 59, 1, 59, 59, 1, 59, 59, 
+    1, 59, 59, 1, 1, 26, 1, 18, 18, 18, 26, 26, 1, 1, 1, 1, 26, 26, 1, 1, 26, 
+    26, 26, 1, 1, 1, 26, 1, 1, 1, 1, 1, 1, 1, 59, 59, 59, 59, 1, 59, 1, 1, 1, 
+    1, 1, 1, 1, 7, 8, 26, 26, 59, 59, 59, 26, 1, 1, 59, 59, 59, 59, 59, 59, 
+    1, 59, 59, 59, 1, 59, 59, 59, 59, 59, 59, 1, 59, 59, 1, 59, 59, 59, 18, 
+    26, 26, 26, 26, 26, 1, 26, 26, 18, 1, 18, 18, 26, 1, 1, 59, 1, 1, 1, 1, 
+    1, 1, 1, 5, 5, 1, 1, 1, 1, 1, 1, 1, 59, 1, 1, 1, 1, 1, 1, 1, 26, 18, 18, 
+    1, 59, 59, 59, 59, 59, 1, 1, 26, 59, 18, 26, 18, 1, 1, 18, 18, 26, 1, 1, 
+    1, 1, 1, 1, 1, 1, 26, 18, 5, 59, 28, 29, 30, 144, 145, 146, 1, 1, 26, 59, 
+    1, 59, 59, 59, 59, 59, 59, 1, 1, 1, 59, 59, 59, 1, 59, 59, 59, 59, 1, 1, 
+    1, 59, 59, 1, 59, 1, 59, 59, 1, 1, 1, 59, 59, 1, 1, 1, 59, 59, 1, 1, 1, 
+    1, 18, 18, 26, 18, 18, 1, 1, 1, 18, 18, 18, 1, 18, 18, 18, 26, 1, 1, 59, 
+    1, 1, 1, 1, 1, 1, 18, 148, 149, 150, 5, 5, 5, 5, 5, 5, 5, 5, 1, 1, 1, 1, 
+    1, 26, 18, 18, 18, 1, 59, 59, 59, 59, 59, 1, 1, 1, 59, 26, 26, 26, 18, 
+    18, 18, 18, 1, 26, 26, 26, 1, 26, 26, 26, 26, 1, 1, 1, 1, 1, 1, 1, 26, 
+    26, 1, 151, 152, 153, 154, 152, 153, 154, 5, 59, 59, 59, 59, 1, 59, 59, 
+    59, 18, 18, 18, 18, 18, 1, 26, 18, 18, 1, 18, 18, 26, 26, 1, 1, 1, 1, 1, 
+    1, 1, 18, 18, 1, 1, 1, 1, 1, 1, 1, 59, 1, 1, 59, 59, 1, 1, 1, 1, 1, 59, 
+    59, 59, 1, 1, 59, 18, 18, 18, 26, 26, 26, 26, 1, 18, 18, 18, 1, 18, 18, 
+    18, 26, 59, 5, 1, 1, 1, 1, 59, 59, 59, 18, 155, 156, 157, 158, 159, 160, 
+    161, 59, 148, 149, 150, 28, 29, 30, 144, 145, 146, 5, 59, 59, 59, 59, 59, 
+    59, 1, 1, 18, 18, 1, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 1, 1, 1, 59, 
+    59, 59, 59, 59, 59, 59, 59, 59, 59, 1, 59, 1, 1, 1, 1, 26, 1, 1, 1, 1, 
+    18, 18, 18, 26, 26, 26, 1, 26, 1, 18, 18, 18, 18, 18, 18, 18, 18, 1, 1, 
+    18, 18, 5, 1, 1, 1, 1, 59, 59, 59, 59, 59, 59, 59, 59, 26, 59, 162, 26, 
+    26, 26, 26, 26, 26, 26, 1, 1, 1, 1, 5, 59, 59, 59, 59, 59, 59, 107, 26, 
+    26, 26, 26, 26, 26, 26, 26, 5, 15, 16, 5, 5, 1, 1, 1, 1, 1, 59, 59, 1, 
+    59, 1, 1, 59, 59, 1, 59, 1, 1, 59, 1, 1, 1, 1, 1, 1, 59, 59, 59, 59, 1, 
+    59, 59, 59, 1, 59, 1, 59, 1, 1, 59, 59, 1, 59, 59, 59, 26, 26, 1, 26, 26, 
+    59, 1, 1, 59, 59, 59, 59, 59, 1, 107, 1, 26, 26, 26, 26, 26, 26, 1, 1, 
+    15, 16, 1, 1, 59, 59, 59, 59, 59, 5, 5, 5, 5, 5, 5, 5, 26, 26, 5, 5, 5, 
+    5, 5, 5, 15, 16, 29, 163, 164, 165, 166, 167, 168, 169, 170, 171, 5, 26, 
+    5, 26, 5, 26, 5, 5, 5, 5, 18, 18, 59, 59, 59, 59, 59, 1, 1, 1, 26, 26, 
+    26, 26, 26, 26, 26, 18, 26, 26, 26, 26, 26, 5, 26, 26, 59, 59, 59, 59, 
+    59, 26, 26, 26, 26, 26, 26, 26, 26, 1, 5, 5, 5, 5, 5, 5, 5, 5, 26, 5, 5, 
+    5, 5, 5, 5, 1, 5, 5, 59, 59, 59, 18, 18, 26, 26, 26, 26, 18, 26, 26, 26, 
+    26, 26, 26, 18, 26, 26, 18, 18, 26, 26, 59, 15, 16, 5, 5, 5, 5, 5, 5, 59, 
+    59, 59, 59, 59, 59, 18, 18, 26, 26, 59, 59, 59, 59, 26, 26, 26, 59, 18, 
+    18, 18, 59, 59, 18, 18, 18, 18, 18, 18, 18, 59, 59, 59, 26, 26, 26, 26, 
+    59, 59, 59, 59, 59, 26, 18, 18, 26, 26, 18, 18, 18, 18, 18, 18, 26, 59, 
+    18, 15, 16, 18, 18, 18, 26, 5, 5, 172, 172, 172, 172, 172, 172, 172, 172, 
+    172, 172, 172, 172, 172, 172, 1, 172, 1, 1, 1, 1, 1, 172, 1, 1, 59, 59, 
+    59, 5, 107, 59, 59, 59, 59, 59, 59, 1, 1, 26, 26, 26, 5, 173, 174, 175, 
+    176, 177, 178, 179, 180, 181, 148, 182, 183, 184, 185, 186, 187, 188, 
+    189, 149, 190, 1, 1, 1, 191, 192, 193, 194, 195, 196, 197, 198, 199, 200, 
+    201, 202, 203, 204, 205, 206, 207, 208, 209, 210, 211, 212, 213, 214, 
+    215, 216, 217, 218, 219, 220, 221, 222, 223, 224, 225, 226, 227, 228, 
+    229, 230, 231, 232, 233, 234, 235, 236, 237, 238, 239, 240, 241, 242, 
+    243, 244, 245, 246, 247, 248, 249, 250, 251, 252, 253, 254, 255, 256, 
+    257, 258, 259, 260, 261, 262, 263, 264, 265, 266, 267, 268, 269, 270, 
+    271, 272, 273, 274, 275, 276, 1, 1, 277, 278, 279, 280, 281, 282, 1, 1, 
+    5, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 5, 5, 59, 2, 59, 59, 
+    59, 59, 59, 59, 59, 59, 59, 59, 5, 5, 1, 1, 1, 59, 59, 59, 5, 5, 5, 283, 
+    284, 285, 59, 59, 59, 59, 59, 59, 59, 59, 59, 26, 26, 26, 1, 1, 1, 59, 
+    59, 26, 26, 26, 5, 5, 1, 59, 59, 26, 26, 1, 1, 1, 1, 59, 1, 26, 26, 1, 1, 
+    1, 1, 59, 59, 59, 59, 26, 26, 18, 26, 26, 26, 26, 26, 26, 26, 18, 18, 18, 
+    18, 18, 18, 18, 18, 26, 18, 26, 26, 26, 26, 5, 5, 5, 107, 5, 5, 5, 5, 59, 
+    26, 1, 1, 15, 16, 1, 1, 1, 1, 1, 1, 151, 152, 153, 154, 286, 287, 288, 
+    289, 290, 291, 1, 1, 1, 1, 1, 1, 5, 5, 5, 26, 26, 26, 22, 1, 59, 59, 59, 
+    107, 59, 59, 59, 59, 59, 59, 59, 59, 59, 292, 292, 59, 59, 26, 59, 1, 1, 
+    1, 1, 1, 26, 26, 26, 18, 18, 18, 18, 26, 26, 18, 18, 18, 1, 1, 1, 1, 18, 
+    18, 26, 18, 18, 18, 18, 18, 18, 26, 26, 26, 1, 1, 1, 1, 5, 1, 1, 1, 5, 5, 
+    7, 8, 59, 59, 59, 59, 1, 1, 1, 1, 59, 59, 1, 1, 1, 1, 1, 1, 15, 16, 173, 
+    1, 1, 1, 5, 5, 59, 59, 59, 59, 59, 59, 59, 26, 26, 18, 18, 26, 1, 1, 5, 
+    5, 59, 59, 59, 59, 59, 18, 26, 18, 26, 26, 26, 26, 26, 26, 26, 1, 26, 18, 
+    26, 18, 18, 26, 26, 26, 26, 26, 26, 26, 26, 18, 18, 18, 18, 18, 18, 26, 
+    26, 26, 26, 26, 26, 26, 26, 26, 26, 1, 1, 26, 5, 5, 5, 5, 5, 5, 5, 107, 
+    5, 5, 5, 5, 5, 5, 1, 1, 26, 26, 26, 26, 26, 26, 6, 1, 26, 26, 26, 26, 18, 
+    59, 59, 59, 59, 59, 59, 59, 26, 18, 26, 26, 26, 26, 26, 18, 26, 18, 18, 
+    18, 18, 18, 26, 18, 18, 59, 59, 59, 5, 5, 5, 26, 26, 26, 26, 26, 26, 26, 
+    26, 26, 5, 5, 5, 5, 5, 5, 5, 5, 5, 1, 1, 1, 26, 26, 18, 59, 59, 59, 59, 
+    59, 59, 18, 26, 26, 26, 26, 18, 18, 26, 26, 18, 26, 26, 26, 59, 59, 59, 
+    59, 59, 59, 59, 59, 26, 18, 26, 26, 18, 18, 18, 26, 18, 26, 26, 26, 18, 
+    18, 1, 1, 1, 1, 1, 1, 1, 1, 5, 5, 5, 5, 59, 59, 59, 59, 18, 18, 18, 18, 
+    18, 18, 18, 18, 26, 26, 26, 26, 26, 26, 26, 26, 18, 18, 26, 26, 1, 1, 1, 
+    5, 5, 5, 5, 5, 15, 16, 1, 1, 1, 59, 59, 59, 107, 107, 107, 107, 107, 107, 
+    5, 5, 293, 294, 295, 296, 297, 298, 299, 300, 301, 1, 1, 1, 1, 1, 1, 1, 
+    26, 26, 26, 5, 26, 26, 26, 26, 26, 59, 59, 59, 59, 26, 59, 59, 59, 59, 
+    18, 18, 26, 59, 59, 1, 26, 26, 1, 1, 1, 1, 1, 1, 21, 21, 21, 21, 105, 
+    105, 105, 105, 105, 105, 105, 105, 105, 105, 105, 105, 105, 105, 105, 21, 
+    21, 21, 21, 21, 105, 302, 21, 21, 21, 303, 21, 21, 21, 21, 21, 21, 21, 
+    21, 304, 21, 21, 21, 21, 105, 105, 105, 105, 105, 105, 105, 105, 105, 
+    106, 105, 105, 105, 106, 105, 105, 105, 105, 105, 105, 105, 1, 1, 1, 26, 
+    26, 26, 26, 26, 33, 34, 33, 34, 33, 35, 33, 34, 33, 34, 33, 34, 33, 34, 
+    305, 306, 307, 308, 309, 310, 21, 21, 311, 21, 33, 34, 33, 35, 33, 34, 
+    33, 34, 312, 312, 312, 312, 312, 312, 312, 312, 313, 313, 313, 313, 313, 
+    313, 313, 313, 312, 312, 312, 312, 312, 312, 1, 1, 313, 313, 313, 313, 
+    313, 313, 1, 1, 314, 312, 315, 312, 316, 312, 317, 312, 1, 313, 1, 313, 
+    1, 313, 1, 313, 318, 318, 319, 319, 319, 319, 320, 320, 321, 321, 322, 
+    322, 323, 323, 1, 1, 324, 325, 326, 327, 328, 329, 330, 331, 332, 333, 
+    334, 335, 336, 337, 338, 339, 340, 341, 342, 343, 344, 345, 346, 347, 
+    348, 349, 350, 351, 352, 353, 354, 355, 356, 357, 358, 359, 360, 361, 
+    362, 363, 364, 365, 366, 367, 368, 369, 370, 371, 312, 312, 372, 373, 
+    374, 1, 375, 376, 313, 313, 377, 377, 378, 6, 379, 6, 6, 6, 380, 381, 
+    382, 1, 383, 384, 385, 385, 385, 385, 386, 6, 6, 6, 312, 312, 387, 388, 
+    1, 1, 389, 390, 313, 313, 391, 391, 1, 6, 6, 6, 312, 312, 392, 393, 394, 
+    131, 395, 396, 313, 313, 397, 397, 135, 6, 6, 6, 1, 1, 398, 399, 400, 1, 
+    401, 402, 403, 403, 404, 404, 405, 6, 6, 1, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 
+    2, 22, 22, 22, 22, 22, 6, 6, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 6, 5, 5, 6, 3, 
+    3, 22, 22, 22, 22, 22, 2, 5, 5, 5, 5, 5, 5, 5, 18, 18, 5, 5, 5, 5, 5, 5, 
+    5, 5, 5, 5, 5, 18, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 2, 22, 22, 22, 22, 22, 
+    1, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 406, 106, 1, 1, 407, 408, 409, 
+    410, 411, 412, 5, 5, 5, 5, 5, 105, 406, 27, 23, 24, 407, 408, 409, 410, 
+    411, 412, 5, 5, 5, 5, 5, 1, 105, 105, 105, 105, 105, 1, 1, 1, 26, 26, 26, 
+    26, 26, 6, 6, 6, 6, 26, 6, 6, 6, 26, 26, 26, 26, 1, 1, 1, 1, 1, 1, 1, 5, 
+    5, 125, 5, 5, 5, 5, 125, 5, 5, 21, 125, 125, 125, 21, 21, 125, 125, 125, 
+    21, 5, 125, 5, 5, 413, 125, 125, 125, 125, 125, 5, 5, 5, 5, 5, 5, 125, 5, 
+    414, 5, 125, 5, 415, 416, 125, 125, 413, 21, 125, 125, 417, 125, 21, 59, 
+    59, 59, 59, 21, 5, 5, 21, 21, 125, 125, 5, 5, 5, 5, 5, 125, 21, 21, 304, 
+    304, 5, 5, 5, 5, 418, 5, 419, 420, 159, 421, 422, 161, 423, 424, 425, 
+    426, 427, 145, 428, 429, 430, 152, 431, 432, 433, 434, 435, 436, 437, 
+    438, 439, 440, 441, 442, 443, 444, 445, 446, 447, 448, 449, 450, 451, 
+    452, 453, 454, 455, 456, 457, 458, 459, 460, 461, 462, 463, 464, 465, 33, 
+    34, 466, 467, 468, 469, 151, 5, 5, 1, 1, 1, 1, 27, 23, 24, 407, 408, 409, 
+    410, 411, 412, 148, 470, 471, 472, 473, 474, 147, 475, 476, 477, 182, 27, 
+    23, 24, 407, 408, 409, 410, 411, 412, 148, 470, 471, 472, 473, 474, 147, 
+    475, 476, 477, 182, 475, 476, 477, 182, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 
+    478, 478, 478, 478, 478, 478, 478, 478, 478, 478, 479, 479, 479, 479, 
+    479, 479, 479, 479, 479, 479, 406, 470, 471, 472, 473, 474, 147, 475, 
+    476, 477, 182, 27, 23, 24, 407, 408, 409, 410, 411, 412, 148, 406, 5, 5, 
+    5, 5, 5, 5, 27, 23, 24, 407, 408, 409, 410, 411, 412, 148, 412, 148, 27, 
+    23, 24, 407, 408, 409, 410, 411, 412, 148, 5, 5, 5, 5, 5, 5, 5, 5, 1, 1, 
+    5, 5, 5, 5, 1, 1, 1, 5, 5, 5, 5, 1, 5, 5, 5, 5, 5, 5, 142, 142, 142, 142, 
+    142, 142, 142, 1, 33, 34, 480, 481, 482, 483, 484, 33, 34, 33, 34, 33, 
+    34, 485, 486, 487, 488, 21, 33, 34, 21, 33, 34, 21, 21, 21, 21, 21, 106, 
+    105, 489, 489, 33, 34, 33, 34, 21, 5, 5, 5, 5, 5, 5, 33, 34, 33, 34, 26, 
+    26, 26, 33, 34, 1, 1, 1, 1, 1, 5, 5, 5, 5, 29, 5, 5, 490, 490, 490, 490, 
+    490, 490, 490, 490, 490, 490, 490, 490, 490, 490, 1, 490, 1, 1, 1, 1, 1, 
+    490, 1, 1, 1, 1, 1, 1, 1, 1, 1, 107, 5, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 
+    1, 1, 1, 26, 5, 5, 5, 5, 5, 5, 5, 491, 5, 5, 1, 5, 5, 5, 5, 5, 5, 5, 5, 
+    5, 1, 1, 1, 1, 2, 5, 5, 5, 5, 107, 59, 492, 5, 493, 494, 495, 496, 497, 
+    466, 498, 499, 500, 26, 26, 26, 26, 18, 18, 5, 107, 107, 107, 107, 107, 
+    5, 5, 501, 502, 503, 107, 59, 5, 5, 5, 1, 26, 26, 6, 6, 107, 107, 59, 59, 
+    59, 59, 5, 107, 107, 107, 59, 1, 1, 1, 1, 1, 59, 59, 59, 5, 5, 152, 153, 
+    154, 286, 5, 5, 152, 153, 154, 286, 287, 288, 289, 290, 291, 148, 5, 5, 
+    5, 5, 5, 5, 148, 182, 183, 184, 185, 186, 187, 188, 5, 504, 505, 506, 
+    507, 508, 509, 510, 511, 512, 183, 513, 514, 515, 516, 517, 5, 518, 519, 
+    520, 521, 184, 522, 523, 524, 525, 526, 527, 528, 529, 530, 185, 59, 59, 
+    59, 59, 59, 531, 59, 59, 59, 59, 59, 532, 59, 59, 59, 59, 59, 59, 531, 
+    59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 533, 59, 59, 534, 59, 59, 533, 
+    59, 59, 59, 535, 59, 536, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 
+    537, 59, 59, 59, 59, 59, 59, 532, 59, 59, 59, 59, 59, 59, 59, 531, 59, 
+    538, 59, 59, 59, 59, 59, 59, 59, 59, 539, 540, 59, 59, 59, 59, 59, 59, 
+    59, 59, 59, 59, 59, 59, 59, 59, 541, 536, 59, 59, 59, 59, 59, 59, 59, 
+    542, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 539, 59, 59, 59, 59, 59, 
+    59, 59, 59, 59, 543, 59, 59, 532, 59, 544, 59, 545, 59, 59, 59, 540, 59, 
+    541, 546, 547, 59, 59, 59, 59, 59, 59, 548, 59, 59, 59, 59, 536, 536, 
+    536, 536, 59, 59, 59, 59, 59, 59, 538, 59, 59, 59, 59, 59, 534, 59, 59, 
+    59, 59, 59, 59, 59, 59, 534, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 
+    537, 546, 59, 59, 59, 59, 534, 532, 536, 59, 532, 59, 59, 59, 59, 59, 59, 
+    59, 59, 59, 59, 59, 59, 59, 540, 59, 59, 59, 59, 59, 544, 59, 59, 59, 59, 
+    59, 533, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 533, 59, 59, 59, 59, 
+    59, 59, 59, 537, 59, 59, 59, 59, 59, 59, 59, 542, 59, 59, 59, 59, 59, 59, 
+    59, 538, 59, 59, 59, 59, 59, 535, 59, 59, 59, 59, 59, 59, 59, 59, 59, 
+    532, 59, 59, 541, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 545, 
+    59, 59, 59, 59, 59, 542, 59, 59, 59, 545, 59, 59, 59, 59, 59, 59, 59, 59, 
+    59, 59, 59, 59, 59, 549, 59, 59, 59, 59, 59, 59, 107, 59, 59, 59, 59, 59, 
+    59, 107, 5, 5, 5, 15, 16, 59, 59, 1, 1, 1, 1, 33, 34, 33, 34, 33, 34, 59, 
+    26, 6, 6, 6, 5, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 5, 107, 33, 34, 
+    33, 34, 105, 105, 26, 26, 59, 59, 59, 59, 59, 59, 493, 494, 495, 496, 
+    497, 466, 498, 499, 500, 492, 6, 6, 6, 6, 6, 6, 6, 107, 21, 21, 33, 34, 
+    33, 34, 33, 34, 105, 21, 21, 21, 21, 21, 21, 21, 21, 33, 34, 33, 34, 550, 
+    33, 34, 107, 6, 6, 33, 34, 551, 21, 59, 33, 34, 33, 34, 21, 21, 33, 34, 
+    33, 34, 552, 553, 554, 555, 552, 1, 556, 557, 558, 559, 33, 34, 33, 34, 
+    1, 1, 1, 1, 1, 1, 1, 59, 105, 105, 21, 59, 59, 59, 59, 59, 59, 59, 26, 
+    59, 59, 59, 26, 59, 59, 59, 59, 26, 59, 59, 59, 59, 59, 59, 59, 18, 18, 
+    26, 26, 18, 28, 29, 30, 144, 145, 146, 5, 5, 59, 59, 59, 59, 5, 5, 5, 5, 
+    18, 18, 59, 59, 59, 59, 59, 59, 18, 18, 18, 18, 26, 26, 1, 1, 1, 1, 1, 1, 
+    1, 1, 5, 5, 26, 26, 59, 59, 59, 59, 59, 59, 5, 5, 5, 59, 5, 59, 1, 1, 26, 
+    26, 26, 26, 26, 26, 5, 5, 1, 1, 1, 1, 1, 1, 1, 5, 59, 59, 59, 26, 18, 18, 
+    26, 26, 26, 26, 18, 18, 26, 18, 18, 18, 5, 5, 5, 5, 5, 5, 1, 107, 15, 16, 
+    1, 1, 1, 1, 5, 5, 59, 59, 59, 59, 59, 26, 107, 59, 15, 16, 59, 59, 59, 
+    59, 59, 1, 59, 26, 26, 26, 26, 26, 26, 18, 18, 26, 26, 18, 18, 26, 26, 1, 
+    59, 59, 59, 59, 26, 18, 1, 1, 15, 16, 1, 1, 5, 5, 5, 5, 107, 59, 59, 59, 
+    59, 59, 59, 5, 5, 5, 59, 18, 26, 18, 59, 59, 26, 59, 26, 26, 26, 59, 59, 
+    26, 26, 59, 59, 59, 59, 59, 26, 26, 1, 1, 1, 59, 59, 107, 5, 5, 59, 59, 
+    59, 18, 26, 26, 18, 18, 5, 5, 59, 107, 107, 18, 26, 1, 1, 59, 59, 59, 59, 
+    59, 59, 1, 21, 21, 21, 560, 21, 21, 21, 21, 21, 21, 21, 6, 105, 105, 105, 
+    105, 21, 21, 21, 21, 21, 21, 1, 1, 561, 562, 563, 564, 565, 566, 567, 
+    568, 569, 570, 571, 572, 573, 574, 575, 576, 577, 578, 579, 580, 581, 
+    582, 583, 584, 585, 586, 587, 588, 589, 590, 591, 592, 593, 594, 595, 
+    596, 597, 598, 599, 600, 601, 602, 603, 604, 605, 606, 607, 608, 609, 
+    610, 611, 612, 613, 614, 615, 616, 617, 618, 619, 620, 621, 622, 623, 
+    624, 625, 626, 627, 628, 629, 630, 631, 632, 633, 634, 635, 636, 637, 
+    638, 639, 640, 59, 59, 59, 18, 18, 26, 18, 18, 26, 18, 18, 5, 18, 26, 1, 
+    1, 1, 1, 1, 59, 59, 59, 59, 59, 59, 59, 59, 536, 59, 59, 59, 59, 59, 59, 
+    59, 540, 59, 59, 59, 59, 59, 59, 549, 59, 59, 59, 59, 59, 59, 545, 59, 
+    545, 59, 59, 59, 59, 59, 59, 59, 59, 59, 540, 59, 59, 641, 642, 643, 644, 
+    645, 646, 647, 1, 1, 1, 1, 648, 649, 650, 651, 652, 1, 1, 1, 1, 1, 59, 
+    26, 59, 59, 5, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 1, 59, 1, 59, 
+    59, 1, 59, 59, 1, 59, 59, 59, 59, 6, 6, 6, 6, 6, 6, 6, 6, 1, 1, 1, 1, 1, 
+    1, 59, 59, 59, 59, 59, 59, 653, 653, 653, 653, 653, 653, 59, 59, 59, 59, 
+    59, 59, 59, 59, 59, 59, 5, 5, 59, 59, 653, 653, 5, 5, 1, 1, 5, 5, 5, 6, 
+    5, 5, 5, 5, 5, 5, 5, 18, 18, 5, 5, 5, 5, 5, 5, 5, 5, 18, 18, 18, 5, 5, 6, 
+    1, 5, 6, 5, 5, 653, 59, 653, 59, 653, 1, 653, 59, 653, 59, 653, 59, 653, 
+    59, 653, 59, 59, 59, 59, 59, 59, 1, 1, 22, 1, 5, 5, 5, 5, 5, 5, 6, 19, 
+    19, 19, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 59, 59, 59, 59, 59, 59, 59, 59, 
+    654, 654, 1, 1, 59, 59, 59, 1, 1, 1, 5, 5, 5, 6, 5, 5, 5, 1, 1, 22, 22, 
+    22, 5, 5, 1, 1, 59, 59, 59, 1, 59, 59, 1, 59, 5, 5, 5, 1, 1, 1, 1, 152, 
+    153, 154, 286, 287, 288, 289, 290, 291, 189, 149, 655, 656, 657, 658, 
+    659, 660, 661, 662, 150, 663, 664, 665, 666, 667, 668, 669, 670, 190, 
+    671, 672, 673, 674, 675, 676, 677, 678, 1, 1, 1, 5, 679, 680, 493, 497, 
+    467, 681, 464, 468, 497, 501, 467, 682, 681, 463, 464, 497, 501, 467, 
+    682, 681, 463, 465, 468, 501, 493, 493, 493, 494, 494, 494, 494, 497, 
+    501, 501, 501, 501, 501, 503, 467, 467, 467, 467, 682, 683, 681, 681, 
+    681, 681, 681, 463, 464, 497, 467, 29, 29, 422, 30, 5, 5, 5, 5, 5, 5, 5, 
+    5, 5, 151, 28, 5, 5, 5, 1, 5, 5, 5, 5, 5, 26, 1, 1, 26, 152, 153, 154, 
+    286, 287, 288, 289, 290, 291, 148, 182, 183, 184, 185, 186, 187, 188, 
+    189, 149, 655, 656, 657, 658, 659, 660, 661, 662, 1, 1, 1, 1, 152, 287, 
+    148, 185, 1, 1, 1, 1, 59, 684, 59, 59, 59, 59, 59, 59, 59, 59, 685, 1, 1, 
+    1, 1, 1, 26, 26, 26, 1, 1, 1, 1, 1, 59, 59, 59, 59, 59, 59, 1, 5, 5, 493, 
+    494, 501, 502, 682, 1, 1, 686, 686, 686, 686, 686, 686, 686, 686, 687, 
+    687, 687, 687, 687, 687, 687, 687, 686, 686, 686, 686, 1, 1, 1, 1, 687, 
+    687, 687, 687, 1, 1, 1, 1, 59, 1, 1, 1, 59, 1, 1, 59, 152, 153, 154, 148, 
+    182, 149, 150, 190, 59, 59, 59, 59, 59, 59, 59, 5, 5, 152, 153, 154, 286, 
+    287, 148, 182, 1, 1, 1, 1, 1, 1, 1, 152, 153, 154, 286, 286, 287, 148, 
+    182, 149, 59, 59, 59, 1, 59, 59, 1, 1, 1, 1, 1, 152, 287, 148, 182, 149, 
+    59, 59, 59, 59, 59, 59, 152, 148, 182, 149, 153, 154, 1, 1, 1, 5, 59, 59, 
+    1, 1, 1, 1, 1, 5, 1, 1, 1, 1, 688, 29, 59, 59, 291, 148, 182, 183, 184, 
+    185, 186, 187, 1, 1, 149, 655, 656, 657, 658, 659, 660, 661, 662, 150, 
+    663, 664, 665, 666, 667, 668, 669, 670, 190, 671, 672, 673, 674, 675, 
+    676, 677, 678, 689, 690, 691, 692, 693, 694, 695, 696, 697, 698, 426, 28, 
+    421, 699, 29, 700, 422, 30, 427, 59, 26, 26, 26, 1, 26, 26, 1, 26, 26, 
+    26, 1, 1, 1, 1, 26, 27, 23, 24, 407, 148, 182, 149, 150, 59, 59, 59, 59, 
+    59, 152, 185, 5, 59, 59, 59, 59, 59, 152, 148, 182, 59, 59, 59, 59, 59, 
+    26, 26, 1, 1, 5, 5, 5, 5, 5, 5, 5, 152, 153, 154, 286, 148, 182, 149, 
+    150, 1, 5, 5, 5, 5, 1, 1, 1, 1, 152, 153, 154, 286, 148, 182, 149, 113, 
+    113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 1, 1, 1, 1, 1, 120, 
+    120, 120, 120, 120, 120, 120, 120, 120, 120, 120, 1, 1, 1, 1, 1, 1, 1, 
+    152, 287, 148, 185, 149, 150, 412, 148, 182, 183, 184, 185, 186, 187, 
+    188, 189, 149, 655, 656, 657, 658, 659, 660, 661, 662, 29, 28, 421, 422, 
+    1, 18, 26, 18, 59, 59, 59, 59, 59, 1, 1, 27, 23, 24, 407, 408, 409, 410, 
+    411, 412, 148, 182, 183, 184, 185, 186, 187, 188, 189, 149, 150, 7, 8, 
+    18, 18, 18, 26, 26, 26, 26, 18, 18, 26, 26, 5, 5, 22, 5, 5, 26, 26, 26, 
+    59, 59, 59, 59, 59, 26, 26, 26, 26, 18, 26, 26, 26, 26, 26, 26, 26, 26, 
+    1, 7, 8, 59, 59, 59, 26, 5, 5, 59, 1, 59, 59, 59, 18, 18, 18, 26, 26, 18, 
+    59, 59, 59, 59, 5, 5, 5, 5, 5, 26, 26, 26, 5, 1, 1, 15, 16, 59, 5, 59, 5, 
+    5, 5, 1, 152, 153, 154, 286, 287, 288, 289, 187, 188, 189, 149, 150, 1, 
+    1, 1, 59, 59, 59, 59, 18, 18, 18, 26, 26, 26, 18, 18, 26, 18, 26, 26, 5, 
+    5, 5, 5, 5, 5, 26, 1, 59, 1, 59, 59, 59, 59, 1, 59, 59, 5, 1, 1, 1, 1, 1, 
+    1, 26, 26, 18, 18, 1, 59, 59, 59, 26, 18, 18, 18, 18, 1, 1, 18, 18, 1, 1, 
+    18, 18, 18, 1, 1, 59, 59, 18, 18, 1, 1, 26, 26, 26, 26, 26, 26, 26, 1, 1, 
+    1, 59, 59, 59, 59, 59, 18, 18, 18, 18, 18, 26, 26, 26, 18, 26, 59, 59, 
+    59, 59, 5, 5, 5, 5, 5, 15, 16, 1, 5, 1, 5, 1, 1, 26, 18, 26, 18, 18, 18, 
+    18, 26, 26, 18, 26, 26, 59, 59, 5, 59, 59, 59, 59, 59, 59, 59, 59, 18, 
+    18, 18, 26, 26, 26, 26, 1, 1, 18, 18, 18, 18, 26, 26, 18, 26, 26, 5, 5, 
+    5, 5, 5, 5, 5, 59, 59, 59, 59, 26, 26, 1, 1, 26, 26, 26, 18, 18, 26, 18, 
+    26, 26, 5, 5, 5, 59, 1, 1, 1, 59, 59, 59, 26, 18, 26, 18, 18, 26, 26, 26, 
+    26, 26, 26, 18, 26, 59, 59, 1, 1, 1, 26, 26, 26, 18, 18, 26, 26, 26, 26, 
+    18, 26, 26, 26, 26, 26, 1, 1, 1, 1, 15, 16, 148, 182, 5, 5, 5, 5, 15, 16, 
+    148, 182, 183, 184, 185, 186, 187, 188, 189, 1, 1, 1, 1, 1, 59, 5, 5, 5, 
+    5, 5, 1, 1, 15, 16, 152, 153, 154, 286, 287, 288, 289, 290, 291, 148, 
+    182, 183, 184, 185, 186, 187, 188, 189, 149, 1, 1, 1, 5, 5, 59, 59, 59, 
+    59, 59, 59, 1, 1, 26, 26, 26, 26, 26, 26, 1, 18, 26, 26, 26, 26, 26, 26, 
+    26, 18, 26, 26, 18, 26, 26, 1, 494, 495, 496, 497, 466, 498, 499, 500, 
+    495, 496, 497, 466, 498, 499, 500, 496, 497, 466, 498, 499, 500, 493, 
+    494, 495, 496, 497, 466, 498, 499, 500, 493, 494, 495, 496, 497, 494, 
+    495, 495, 496, 497, 466, 498, 499, 500, 493, 494, 495, 495, 496, 497, 
+    701, 702, 493, 494, 495, 495, 496, 497, 495, 495, 496, 496, 496, 496, 
+    466, 498, 498, 498, 499, 499, 500, 500, 500, 500, 494, 495, 496, 497, 
+    466, 493, 494, 495, 496, 496, 497, 497, 494, 495, 493, 494, 703, 704, 
+    705, 703, 704, 706, 679, 707, 679, 679, 680, 703, 704, 708, 467, 496, 
+    497, 466, 498, 499, 500, 1, 26, 26, 26, 26, 26, 5, 1, 1, 107, 107, 107, 
+    107, 5, 5, 1, 1, 15, 16, 1, 148, 149, 190, 709, 710, 711, 712, 1, 59, 59, 
+    59, 59, 59, 59, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 
+    1, 26, 26, 26, 107, 107, 107, 107, 107, 107, 1, 1, 1, 1, 1, 1, 1, 59, 59, 
+    1, 1, 5, 26, 26, 5, 22, 22, 22, 22, 1, 1, 1, 1, 5, 5, 5, 5, 5, 18, 18, 
+    26, 26, 26, 5, 5, 5, 18, 18, 18, 18, 18, 18, 22, 22, 22, 22, 22, 22, 22, 
+    22, 26, 26, 26, 26, 26, 26, 26, 26, 5, 5, 26, 26, 26, 5, 5, 26, 26, 26, 
+    26, 5, 5, 188, 189, 1, 1, 1, 1, 1, 1, 125, 125, 125, 125, 125, 125, 125, 
+    125, 125, 125, 21, 21, 21, 21, 21, 21, 21, 21, 304, 304, 21, 21, 21, 21, 
+    21, 21, 21, 21, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 21, 21, 
+    21, 21, 21, 21, 21, 1, 304, 304, 21, 21, 21, 21, 125, 1, 125, 125, 1, 1, 
+    125, 1, 1, 125, 125, 1, 1, 125, 125, 125, 125, 1, 125, 125, 21, 21, 1, 
+    21, 1, 21, 304, 304, 21, 21, 21, 21, 1, 21, 21, 21, 21, 21, 21, 21, 125, 
+    125, 1, 125, 125, 125, 125, 1, 1, 125, 125, 125, 125, 125, 125, 125, 125, 
+    1, 125, 125, 125, 125, 125, 125, 125, 1, 21, 21, 21, 21, 21, 21, 21, 21, 
+    304, 304, 125, 125, 1, 125, 125, 125, 125, 1, 125, 125, 125, 125, 125, 1, 
+    125, 1, 1, 1, 125, 125, 125, 125, 125, 125, 125, 1, 21, 21, 21, 21, 21, 
+    21, 125, 5, 21, 21, 21, 21, 21, 21, 21, 21, 21, 5, 21, 21, 21, 21, 21, 
+    21, 125, 125, 125, 125, 125, 125, 125, 125, 125, 5, 21, 21, 21, 21, 21, 
+    21, 21, 21, 21, 5, 21, 21, 125, 125, 125, 125, 125, 5, 21, 21, 21, 21, 
+    21, 21, 21, 21, 21, 5, 21, 21, 21, 21, 21, 21, 125, 125, 125, 125, 125, 
+    125, 125, 125, 125, 5, 21, 5, 21, 21, 21, 21, 21, 21, 21, 21, 125, 21, 1, 
+    1, 7, 8, 15, 16, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 7, 8, 9, 10, 11, 
+    12, 13, 14, 15, 16, 7, 8, 26, 26, 26, 26, 26, 5, 5, 5, 5, 5, 5, 5, 5, 26, 
+    5, 5, 5, 5, 5, 5, 26, 5, 5, 5, 26, 1, 1, 26, 26, 26, 26, 26, 26, 26, 1, 
+    26, 26, 1, 26, 26, 59, 59, 59, 59, 59, 1, 1, 152, 713, 713, 713, 713, 
+    713, 713, 713, 713, 713, 713, 714, 714, 714, 714, 714, 714, 714, 714, 
+    714, 714, 714, 714, 714, 714, 714, 714, 714, 714, 26, 26, 26, 26, 59, 59, 
+    59, 1, 59, 59, 59, 59, 1, 59, 1, 59, 1, 1, 1, 1, 1, 1, 59, 1, 1, 1, 1, 
+    59, 1, 59, 1, 59, 1, 59, 59, 59, 1, 59, 1, 59, 1, 59, 1, 59, 1, 59, 59, 
+    59, 1, 59, 59, 59, 406, 406, 27, 23, 24, 407, 408, 409, 410, 411, 412, 
+    151, 151, 1, 1, 1, 715, 715, 715, 715, 715, 715, 715, 715, 715, 715, 5, 
+    5, 5, 5, 5, 5, 715, 715, 5, 5, 1, 1, 1, 1, 5, 5, 5, 6, 6, 6, 6, 6, 5, 1, 
+    1, 5, 5, 5, 5, 5, 59, 533, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 538, 
+    59, 59, 59, 59, 59, 538, 59, 59, 59, 59, 59, 59, 531, 59, 59, 59, 59, 59, 
+    59, 59, 59, 59, 547, 59, 59, 59, 59, 59, 59, 545, 59, 59, 59, 59, 59, 59, 
+    59, 59, 59, 59, 536, 59, 59, 59, 59, 59, 59, 59, 538, 59, 59, 537, 59, 
+    59, 59, 59, 59, 59, 59, 1, 22, 1, 1, 1, 1, 1, 1, 
+];
+
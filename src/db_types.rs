@@ -27,7 +27,39 @@ pub struct UCDRecord {
     pub mirrored: u8,
     pub east_asian_width: u8,
     pub script: u8,
-    pub linebreak_class: u8
+    pub linebreak_class: u8,
+    pub quickcheck: u8
+}
+
+#[derive(Copy, Clone)]
+pub struct TypeRecord {
+    pub upper: i32,
+    pub lower: i32,
+    pub title: i32,
+    pub fold: i32,
+    pub extended: u16,
+    pub numeric: u8,
+    pub flags: u16
+}
+
+#[derive(Copy, Clone)]
+pub struct NumericValue {
+    pub numerator: i64,
+    pub denominator: u32
+}
+
+#[derive(Copy, Clone)]
+pub struct CaseFolding {
+    pub code: u32,
+    pub folded: u32
+}
+
+#[derive(Copy, Clone)]
+pub struct ConditionalCasing {
+    pub code: u32,
+    pub language: u8,
+    pub condition: u8,
+    pub mapping: u16
 }
 
 #[derive(Copy, Clone)]
@@ -42,6 +74,31 @@ pub struct BracketPair {
     pub bracket_type: u8
 }
 
+#[derive(Copy, Clone)]
+pub struct FullDecomp {
+    pub code: u32,
+    pub decomposed: [u32; 4]
+}
+
+#[derive(Copy, Clone)]
+pub struct NameAlias {
+    pub code: u32,
+    pub alias_type: u8
+}
+
+#[derive(Copy, Clone)]
+pub struct NamedSequence {
+    pub len: u8,
+    pub seq: [u32; 4]
+}
+
+#[derive(Copy, Clone)]
+pub struct NameRange {
+    pub start: u32,
+    pub end: u32,
+    pub prefix: &'static str
+}
+
 #[derive(Copy, Clone)]
 pub struct ReIndex {
     pub start: u32,
@@ -30,10 +30,17 @@ use std::convert::TryFrom;
 use std::cmp::Ordering;
 
 mod db;
+mod name_db;
 pub mod types;
 pub use types::*;
 mod db_types;
 use db_types::*;
+pub mod normalize;
+pub use normalize::*;
+pub mod names;
+pub use names::*;
+pub mod case;
+pub use case::*;
 pub mod c_interface;
 
 /* Generic Unicode */
@@ -66,6 +73,19 @@ fn get_ucd_record(code: u32) -> Result<UCDRecord, &'static str> {
     }
 }
 
+fn get_type_record(code: u32) -> Result<TypeRecord, &'static str> {
+    if code >= UNICODE_MAX_CODEPOINT {
+        Err("invalid char")
+    } else {
+        let index  = (db::TYPE_INDEX0[(code >> (db::TYPE_SHIFT1+db::TYPE_SHIFT2)) as usize] as usize) << db::TYPE_SHIFT1;
+        let offset = ((code >> db::TYPE_SHIFT2) & ((1<<db::TYPE_SHIFT1) - 1)) as usize;
+        let index2  = (db::TYPE_INDEX1[index + offset] as usize) << db::TYPE_SHIFT2;
+        let offset2 = (code & ((1<<db::TYPE_SHIFT2) - 1)) as usize;
+        let index3  = db::TYPE_INDEX2[index2 + offset2] as usize;
+        Ok(db::TYPE_RECORDS[index3])
+    }
+}
+
 fn get_decomp_record(code: u32) -> [u16; 19] {
     let mut index: usize;
 
@@ -85,7 +105,47 @@ fn get_decomp_record(code: u32) -> [u16; 19] {
     record
 }
 
+fn get_nfkc_casefold_record(code: u32) -> [u16; 19] {
+    let mut index: usize;
+
+    if code >= UNICODE_MAX_CODEPOINT {
+        index = 0;
+    } else {
+        index = (db::NFKC_CF_INDEX0[(code >> (db::NFKC_CF_SHIFT1+db::NFKC_CF_SHIFT2)) as usize] as usize) << db::NFKC_CF_SHIFT1;
+        let offset = ((code >> db::NFKC_CF_SHIFT2) & ((1u32 << db::NFKC_CF_SHIFT1) - 1)) as usize;
+        index = (db::NFKC_CF_INDEX1[index + offset] as usize) << db::NFKC_CF_SHIFT2;
+        let offset2 = (code & ((1 << db::NFKC_CF_SHIFT2) - 1)) as usize;
+        index = db::NFKC_CF_INDEX2[index + offset2] as usize;
+    }
+
+    let record_len = (db::NFKC_CF_DATA[index] >> 8) as usize;
+    let mut record: [u16; 19] = [0; 19];
+    record[0..record_len + 1].clone_from_slice(&db::NFKC_CF_DATA[index .. index + record_len + 1]);
+    record
+}
+
+/* Decode the UTF-16 payload of a decomposition or mapping record */
+fn decode_record(record: &[u16; 19]) -> (usize, [u32; 18]) {
+    let record_len = (record[0] >> 8) as usize;
+    let mut decoded: [u32; 18] = [0; 18];
+    let mut step: usize = 0;
+    let mut i = 0;
+    while step < record_len {
+        let (c, s) = if (record_len - step) < 2 {
+            (record[step+1] as u32, 1)
+        } else {
+            decode_utf16(&record[step + 1 .. step + 3])
+        };
+        decoded[i] = c;
+        step += s;
+        i += 1;
+    }
+    (i, decoded)
+}
+
 fn get_comp_index(code: u32, idx: &[ReIndex]) -> Option<usize> {
+    // the last entry only terminates the table
+    let idx = &idx[..idx.len() - 1];
     let res = idx.binary_search_by(|probe| {
         if code < probe.start {
             Ordering::Greater
@@ -121,7 +181,7 @@ fn hangul_pair_decompose(code: u32) -> Option<(u32, u32)> {
 }
 
 fn hangul_pair_compose(a: u32, b: u32) -> Option<u32> {
-    if a >= SBASE && a < (SBASE + SCOUNT) && b >= TBASE && b < (TBASE + TCOUNT) {
+    if a >= SBASE && a < (SBASE + SCOUNT) && (a - SBASE) % TCOUNT == 0 && b > TBASE && b < (TBASE + TCOUNT) {
         /* LV,T */
         Some(a + (b - TBASE))
     } else if a >= LBASE && a < (LBASE + LCOUNT) && b >= VBASE && b < (VBASE + VCOUNT) {
@@ -160,6 +220,32 @@ pub fn get_combining_class(code: u32) -> Result<u8, &'static str> {
     }  
 }
 
+/**
+ * Get lead combining class of a codepoint as defined in UTN#5. This
+ * is the combining class of the first codepoint of the full canonical
+ * decomposition.
+ *
+ */
+pub fn get_lead_combining_class(code: u32) -> Result<u8, &'static str> {
+    match decompose_full(code) {
+        Ok((_, decomposed)) => get_combining_class(decomposed[0]),
+        Err(_) => get_combining_class(code)
+    }
+}
+
+/**
+ * Get trail combining class of a codepoint as defined in UTN#5. This
+ * is the combining class of the last codepoint of the full canonical
+ * decomposition.
+ *
+ */
+pub fn get_trail_combining_class(code: u32) -> Result<u8, &'static str> {
+    match decompose_full(code) {
+        Ok((len, decomposed)) => get_combining_class(decomposed[len - 1]),
+        Err(_) => get_combining_class(code)
+    }
+}
+
 /**
  * Get east-asian width of a codepoint as defined in UAX#11.
  *
@@ -257,6 +343,75 @@ pub fn get_resolved_linebreak_class(code: u32) -> LinebreakClass {
     }
 }
 
+/**
+ * Get normalization quick check property of a codepoint for a
+ * normalization form as defined in UAX#15.
+ *
+ */
+pub fn get_quick_check(code: u32, form: NormalizationForm) -> Result<QuickCheck, &'static str> {
+    match get_ucd_record(code) {
+        Ok(v) => QuickCheck::try_from((v.quickcheck >> (form as u8 * 2)) & 3),
+        Err(e) => Err(e)
+    }
+}
+
+/**
+ * Get Hangul syllable type of a codepoint as defined in UAX#44.
+ *
+ */
+pub fn get_hangul_syllable_type(code: u32) -> Result<HangulSyllableType, &'static str> {
+    match code {
+        0x1100..=0x115F | 0xA960..=0xA97C => Ok(HangulSyllableType::L),
+        0x1160..=0x11A7 | 0xD7B0..=0xD7C6 => Ok(HangulSyllableType::V),
+        0x11A8..=0x11FF | 0xD7CB..=0xD7FB => Ok(HangulSyllableType::T),
+        _ if code >= SBASE && code < (SBASE + SCOUNT) => {
+            if (code - SBASE) % TCOUNT == 0 {
+                Ok(HangulSyllableType::LV)
+            } else {
+                Ok(HangulSyllableType::LVT)
+            }
+        },
+        _ if code < UNICODE_MAX_CODEPOINT => Ok(HangulSyllableType::NA),
+        _ => Err("invalid char")
+    }
+}
+
+/**
+ * Get numeric type of a codepoint as defined in UAX#44.
+ *
+ */
+pub fn get_numeric_type(code: u32) -> Result<NumericType, &'static str> {
+    match get_type_record(code) {
+        Ok(v) => if v.flags & db::DECIMAL_MASK != 0 {
+            Ok(NumericType::Decimal)
+        } else if v.flags & db::DIGIT_MASK != 0 {
+            Ok(NumericType::Digit)
+        } else if v.flags & db::NUMERIC_MASK != 0 {
+            Ok(NumericType::Numeric)
+        } else {
+            Ok(NumericType::None)
+        },
+        Err(e) => Err(e)
+    }
+}
+
+/**
+ * Get numeric value of a codepoint as defined in UAX#44. The value is
+ * returned as a fraction (numerator, denominator), so that values like
+ * 1/3 are exact. Returns Err if the codepoint has no numeric value.
+ *
+ */
+pub fn get_numeric_value(code: u32) -> Result<(i64, u32), &'static str> {
+    match get_type_record(code) {
+        Ok(v) if v.flags & db::NUMERIC_MASK != 0 => {
+            let value = db::NUMERIC_VALUES[v.numeric as usize];
+            Ok((value.numerator, value.denominator))
+        },
+        Ok(_) => Err("no numeric value"),
+        Err(e) => Err(e)
+    }
+}
+
 /**
  * Mirror a codepoint. Returns Err if no mirroring exists.
  *
@@ -330,6 +485,45 @@ pub fn decompose(code: u32) -> Result<(u32, u32), &'static str> {
     }
 }
 
+/**
+ * Full canonical decomposition of a codepoint. This is the result of
+ * recursively applying decompose(), including Hangul Jamo
+ * decomposition. Returns the length of the decomposition and the
+ * decomposed codepoints.
+ *
+ */
+pub fn decompose_full(code: u32) -> Result<(usize, [u32; 4]), &'static str> {
+    let mut decomposed: [u32; 4] = [0; 4];
+
+    // Hangul is decomposed arithmetically
+    if let Ok((l, v, t)) = decompose_hangul_full(code) {
+        decomposed[0] = l;
+        decomposed[1] = v;
+        if let Some(t) = t {
+            decomposed[2] = t;
+            return Ok((3, decomposed))
+        }
+        return Ok((2, decomposed))
+    }
+
+    // Decompositions that need more than one step are precomputed
+    let res = db::FULL_DECOMP.binary_search_by(|probe| probe.code.cmp(&code));
+    if let Ok(v) = res {
+        let full = db::FULL_DECOMP[v].decomposed;
+        let len = full.iter().take_while(|&&c| c != 0).count();
+        return Ok((len, full))
+    }
+
+    match decompose(code) {
+        Ok((a, b)) => {
+            decomposed[0] = a;
+            decomposed[1] = b;
+            Ok((if b != 0 { 2 } else { 1 }, decomposed))
+        },
+        Err(e) => Err(e)
+    }
+}
+
 /**
  * Get paired bracket type for a codepoint as defined in UAX#9.
  *
@@ -372,35 +566,135 @@ pub fn compose(a: u32, b: u32) -> Result<u32, &'static str> {
     let l = get_comp_index(a, &db::NFC_FIRST);
     let r = get_comp_index(b, &db::NFC_LAST);
     match (l, r) {
-        (Some(l), Some(r)) => Ok(get_comp_data(l, r)),
+        (Some(l), Some(r)) => match get_comp_data(l, r) {
+            0 => Err("no composition found"),
+            v => Ok(v)
+        },
         _ => Err("no composition found")
     }    
 }
 
+/**
+ * Decompose a precomposed Hangul syllable into its leading consonant,
+ * vowel and optional trailing consonant Jamo in one step.
+ *
+ */
+pub fn decompose_hangul_full(code: u32) -> Result<(u32, u32, Option<u32>), &'static str> {
+    if code < SBASE || code >= (SBASE + SCOUNT) {
+        return Err("not a Hangul syllable")
+    }
+
+    let si = code - SBASE;
+    let t = if (si % TCOUNT) != 0 { Some(TBASE + si % TCOUNT) } else { None };
+    Ok((LBASE + si / NCOUNT, VBASE + (si % NCOUNT) / TCOUNT, t))
+}
+
+/**
+ * Get decomposition type of a codepoint as defined in UAX#44. This
+ * includes canonical decomposition of Hangul syllables.
+ *
+ */
+pub fn get_decomposition_type(code: u32) -> Result<DecompositionType, &'static str> {
+    if code >= UNICODE_MAX_CODEPOINT {
+        return Err("invalid char")
+    }
+    if hangul_pair_decompose(code).is_some() {
+        return Ok(DecompositionType::Canonical)
+    }
+
+    let record = get_decomp_record(code);
+    if (record[0] >> 8) == 0 {
+        Ok(DecompositionType::None)
+    } else {
+        DecompositionType::try_from((record[0] & 0xff) as u8)
+    }
+}
+
+/**
+ * Check if codepoint is excluded from composition as listed in
+ * CompositionExclusions.txt. These are script-specific and
+ * post composition version exclusions, and do not include
+ * singletons and non-starter decompositions.
+ *
+ */
+pub fn is_composition_exclusion(code: u32) -> Result<bool, &'static str> {
+    if !is_full_composition_exclusion(code)? {
+        return Ok(false)
+    }
+    match decompose(code) {
+        Ok((a, b)) => Ok(b != 0 && get_combining_class(code)? == 0 && get_combining_class(a)? == 0),
+        Err(_) => Ok(false)
+    }
+}
+
+/**
+ * Check if codepoint has the Full_Composition_Exclusion property as
+ * defined in UAX#44. These codepoints have a canonical decomposition,
+ * but are never produced by canonical composition.
+ *
+ */
+pub fn is_full_composition_exclusion(code: u32) -> Result<bool, &'static str> {
+    match get_quick_check(code, NormalizationForm::NFC) {
+        Ok(v) => Ok(v == QuickCheck::No),
+        Err(e) => Err(e)
+    }
+}
+
 /**
  * Compatibility decomposition of a codepoint.
  *
  */
 pub fn compat_decompose(code: u32) -> Result<(usize, [u32; 18]), &'static str>  {
+    match compat_decompose_tagged(code) {
+        Ok((_, len, decomposed)) => Ok((len, decomposed)),
+        Err(e) => Err(e)
+    }
+}
+
+/**
+ * Compatibility decomposition of a codepoint, together with the
+ * decomposition type. This allows to distinguish e.g. ligatures
+ * (Compat) from font variants (Font).
+ *
+ */
+pub fn compat_decompose_tagged(code: u32) -> Result<(DecompositionType, usize, [u32; 18]), &'static str>  {
     let record = get_decomp_record(code);
     let record_len = (record[0] >> 8) as usize;
-    let mut decomposed: [u32; 18] = [0; 18];
 
     if record_len == 0 {
         Err("no decomposition found")
     } else {
-        let mut step: usize = 0;
-        let mut i = 0;
-        while step < record_len {
-            let (c, s) = if (record_len - step) < 2 {
-                (record[step+1] as u32, 1)
-            } else {
-                decode_utf16(&record[step + 1 .. step + 3])
-            };
-            decomposed[i] = c;
-            step += s;
-            i += 1;
+        let (len, decomposed) = decode_record(&record);
+        match DecompositionType::try_from((record[0] & 0xff) as u8) {
+            Ok(t) => Ok((t, len, decomposed)),
+            Err(e) => Err(e)
         }
-        Ok((i, decomposed))
     }
 }
+
+/**
+ * NFKC_Casefold mapping of a codepoint as defined in UAX#44. The
+ * mapping may be empty, e.g. for default ignorable codepoints.
+ *
+ */
+pub fn nfkc_casefold(code: u32) -> Result<(usize, [u32; 18]), &'static str> {
+    let record = get_nfkc_casefold_record(code);
+
+    if (record[0] & 0xff) == 0 {
+        Err("no mapping found")
+    } else {
+        Ok(decode_record(&record))
+    }
+}
+
+/**
+ * Check if codepoint has the Changes_When_NFKC_Casefolded property
+ * as defined in UAX#44.
+ *
+ */
+pub fn changes_when_nfkc_casefolded(code: u32) -> Result<bool, &'static str> {
+    if code >= UNICODE_MAX_CODEPOINT {
+        return Err("invalid char")
+    }
+    Ok(nfkc_casefold(code).is_ok())
+}
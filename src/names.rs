@@ -0,0 +1,377 @@
+/* Copyright (c) 2017 Grigori Goronzy <greg@chown.ath.cx>
+ * 
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ * 
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ * 
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Character names as defined in UAX#44.
+
+use super::*;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+/* Short names of the Hangul Jamo, used for syllable names */
+static JAMO_L: [&str; 19] = [
+    "G", "GG", "N", "D", "DD", "R", "M", "B", "BB", "S", "SS", "", "J", "JJ",
+    "C", "K", "T", "P", "H"
+];
+
+static JAMO_V: [&str; 21] = [
+    "A", "AE", "YA", "YAE", "EO", "E", "YEO", "YE", "O", "WA", "WAE", "OE", "YO",
+    "U", "WEO", "WE", "WI", "YU", "EU", "YI", "I"
+];
+
+static JAMO_T: [&str; 28] = [
+    "", "G", "GG", "GS", "N", "NJ", "NH", "D", "L", "LG", "LM", "LB", "LS", "LT",
+    "LP", "LH", "M", "B", "BS", "S", "SS", "NG", "J", "C", "K", "T", "P", "H"
+];
+
+fn get_phrasebook_offset(code: u32) -> usize {
+    if code >= UNICODE_MAX_CODEPOINT {
+        return 0
+    }
+    let index = (name_db::PHRASEBOOK_INDEX0[(code >> (name_db::PHRASEBOOK_SHIFT1+name_db::PHRASEBOOK_SHIFT2)) as usize] as usize) << name_db::PHRASEBOOK_SHIFT1;
+    let offset = ((code >> name_db::PHRASEBOOK_SHIFT2) & ((1u32 << name_db::PHRASEBOOK_SHIFT1) - 1)) as usize;
+    let index = (name_db::PHRASEBOOK_INDEX1[index + offset] as usize) << name_db::PHRASEBOOK_SHIFT2;
+    let offset2 = (code & ((1 << name_db::PHRASEBOOK_SHIFT2) - 1)) as usize;
+    name_db::PHRASEBOOK_INDEX2[index + offset2] as usize
+}
+
+/* Append a word from the lexicon. Bit 7 marks the last character of a
+ * word, 0x80 the end of a name. Returns true at the end of a name. */
+fn push_word(s: &mut String, word: usize) -> bool {
+    for &b in &name_db::LEXICON[name_db::LEXICON_OFFSET[word] as usize..] {
+        if b == 0x80 {
+            return true
+        }
+        s.push((b & 0x7f) as char);
+        if b & 0x80 != 0 {
+            return false
+        }
+    }
+    true
+}
+
+/* Assemble a name from the words in the phrasebook */
+fn decode_phrasebook(mut offset: usize) -> String {
+    let short = name_db::PHRASEBOOK_SHORT as usize;
+    let mut name = String::new();
+    loop {
+        // frequent words have a one byte index, the rest two bytes
+        let mut word = name_db::PHRASEBOOK[offset] as usize;
+        offset += 1;
+        if word >= short {
+            word = ((word - short) << 8) + name_db::PHRASEBOOK[offset] as usize;
+            offset += 1;
+        }
+        if !name.is_empty() {
+            name.push(' ');
+        }
+        if push_word(&mut name, word) {
+            return name
+        }
+    }
+}
+
+/* Aliases and named sequences are stored in the phrasebook under
+ * private use codepoints */
+fn get_alias_index(code: u32) -> Option<usize> {
+    match code.checked_sub(name_db::NAME_ALIASES_START) {
+        Some(i) if (i as usize) < name_db::NAME_ALIASES.len() => Some(i as usize),
+        _ => None
+    }
+}
+
+fn get_sequence_index(code: u32) -> Option<usize> {
+    match code.checked_sub(name_db::NAMED_SEQUENCES_START) {
+        Some(i) if (i as usize) < name_db::NAMED_SEQUENCES.len() => Some(i as usize),
+        _ => None
+    }
+}
+
+fn is_noncharacter(code: u32) -> bool {
+    (0xfdd0..=0xfdef).contains(&code) || (code & 0xfffe) == 0xfffe
+}
+
+/* Code point label as defined in chapter 4.8 of the Unicode core
+ * specification, for codepoints without a name */
+fn get_label(code: u32) -> Option<String> {
+    let label = match get_general_category(code) {
+        Ok(GeneralCategory::CC) => "control",
+        Ok(GeneralCategory::CO) => "private-use",
+        Ok(GeneralCategory::CS) => "surrogate",
+        Ok(GeneralCategory::CN) if is_noncharacter(code) => "noncharacter",
+        Ok(GeneralCategory::CN) => "reserved",
+        _ => return None
+    };
+    Some(format!("<{}-{:04X}>", label, code))
+}
+
+/**
+ * Get the name of a codepoint as defined in UAX#44. Names of Hangul
+ * syllables and unified ideographs are derived from the codepoint.
+ * Codepoints without a name, like controls and unassigned
+ * codepoints, get a label such as "<control-0009>" instead. Returns
+ * None for invalid codepoints.
+ *
+ */
+pub fn get_name(code: u32) -> Option<String> {
+    if let Ok((l, v, t)) = decompose_hangul_full(code) {
+        let mut name = String::from("HANGUL SYLLABLE ");
+        name.push_str(JAMO_L[(l - LBASE) as usize]);
+        name.push_str(JAMO_V[(v - VBASE) as usize]);
+        if let Some(t) = t {
+            name.push_str(JAMO_T[(t - TBASE) as usize]);
+        }
+        return Some(name)
+    }
+
+    for range in name_db::IDEOGRAPH_RANGES.iter() {
+        if code >= range.start && code <= range.end {
+            return Some(format!("{}{:04X}", range.prefix, code))
+        }
+    }
+
+    if get_alias_index(code).is_some() || get_sequence_index(code).is_some() {
+        return get_label(code)
+    }
+    match get_phrasebook_offset(code) {
+        0 => get_label(code),
+        offset => Some(decode_phrasebook(offset))
+    }
+}
+
+/**
+ * Get the name aliases of a codepoint as defined in UAX#44, together
+ * with their types. Aliases provide corrections for misspelled names
+ * and names for control characters, among others.
+ *
+ */
+pub fn get_name_aliases(code: u32) -> Vec<(String, NameAliasType)> {
+    name_db::NAME_ALIASES.iter().enumerate()
+        .filter(|&(_, alias)| alias.code == code)
+        .filter_map(|(i, alias)| {
+            let name = decode_phrasebook(get_phrasebook_offset(name_db::NAME_ALIASES_START + i as u32));
+            NameAliasType::try_from(alias.alias_type).ok().map(|t| (name, t))
+        })
+        .collect()
+}
+
+/* Loose matching key of a name as defined in UAX44-LM2: ignore case,
+ * whitespace, underscores and medial hyphens */
+fn loose_key(name: &str) -> String {
+    let mut key = String::with_capacity(name.len());
+    let mut strict = String::with_capacity(name.len());
+    let mut prev = ' ';
+    let mut chars = name.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '_' && !c.is_whitespace() {
+            let medial = c == '-' && prev.is_ascii_alphanumeric() &&
+                matches!(chars.peek(), Some(n) if n.is_ascii_alphanumeric());
+            if !medial {
+                key.push(c.to_ascii_uppercase());
+            }
+            strict.push(c.to_ascii_uppercase());
+        }
+        prev = c;
+    }
+
+    // the hyphen of U+1180 HANGUL JUNGSEONG O-E is significant, as
+    // U+116C is HANGUL JUNGSEONG OE
+    if strict == "HANGULJUNGSEONGO-E" {
+        strict
+    } else {
+        key
+    }
+}
+
+fn name_hash(key: &str) -> u32 {
+    let mut h: u32 = 0;
+    for b in key.bytes() {
+        h = h * name_db::NAME_HASH_MAGIC + b as u32;
+        let ix = h & 0xff000000;
+        if ix != 0 {
+            h = (h ^ ((ix >> 24) & 0xff)) & 0x00ffffff;
+        }
+    }
+    h
+}
+
+fn lookup_hash(key: &str) -> Option<u32> {
+    let mask = name_db::NAME_HASH_SIZE - 1;
+    let h = name_hash(key);
+    let mut i = !h & mask;
+    let mut incr = (h ^ (h >> 3)) & mask;
+    if incr == 0 {
+        incr = mask;
+    }
+    loop {
+        let code = name_db::NAME_HASH[i as usize];
+        if code == 0 {
+            return None
+        }
+        if loose_key(&decode_phrasebook(get_phrasebook_offset(code))) == key {
+            return Some(code)
+        }
+        i = (i + incr) & mask;
+        incr <<= 1;
+        if incr > mask {
+            incr ^= name_db::NAME_HASH_POLY;
+        }
+    }
+}
+
+/* Find the index and length of the longest Jamo short name at the start of s */
+fn find_jamo(s: &str, names: &[&str]) -> Option<(u32, usize)> {
+    names.iter().enumerate()
+        .filter(|&(_, name)| s.starts_with(name))
+        .max_by_key(|&(_, name)| name.len())
+        .map(|(i, name)| (i as u32, name.len()))
+}
+
+fn lookup_hangul(key: &str) -> Option<u32> {
+    let (l, n) = find_jamo(key, &JAMO_L)?;
+    let key = &key[n..];
+    let (v, n) = find_jamo(key, &JAMO_V)?;
+    let key = &key[n..];
+    let (t, n) = find_jamo(key, &JAMO_T)?;
+    if n != key.len() {
+        return None
+    }
+    Some(SBASE + (l * VCOUNT + v) * TCOUNT + t)
+}
+
+fn lookup_ideograph(key: &str) -> Option<u32> {
+    for range in name_db::IDEOGRAPH_RANGES.iter() {
+        let prefix = loose_key(range.prefix.trim_end_matches('-'));
+        let hex = match key.strip_prefix(prefix.as_str()) {
+            Some(hex) => hex,
+            None => continue
+        };
+        if let Ok(code) = u32::from_str_radix(hex, 16) {
+            if code >= range.start && code <= range.end && format!("{:04X}", code) == hex {
+                return Some(code)
+            }
+        }
+    }
+    None
+}
+
+/**
+ * Look up a codepoint by its name or one of its name aliases, using
+ * the loose matching rule UAX44-LM2: case, whitespace, underscores and
+ * medial hyphens are ignored. Names of Hangul syllables and unified
+ * ideographs are supported as well. Code point labels like
+ * "<control-0009>" are not names and do not match.
+ *
+ */
+pub fn lookup_by_name(name: &str) -> Option<u32> {
+    let key = loose_key(name);
+    if let Some(code) = lookup_hash(&key) {
+        if let Some(i) = get_alias_index(code) {
+            return Some(name_db::NAME_ALIASES[i].code)
+        }
+        if get_sequence_index(code).is_some() {
+            return None
+        }
+        return Some(code)
+    }
+    if let Some(syllable) = key.strip_prefix("HANGULSYLLABLE") {
+        return lookup_hangul(syllable)
+    }
+    lookup_ideograph(&key)
+}
+
+/**
+ * Look up a named sequence by its name, using the loose matching rule
+ * UAX44-LM2. Named sequences are sequences of codepoints that have a
+ * name of their own, like "LATIN CAPITAL LETTER A WITH MACRON AND
+ * GRAVE".
+ *
+ */
+pub fn lookup_named_sequence(name: &str) -> Option<&'static [u32]> {
+    let i = get_sequence_index(lookup_hash(&loose_key(name))?)?;
+    let sequence = &name_db::NAMED_SEQUENCES[i];
+    Some(&sequence.seq[..sequence.len as usize])
+}
+
+fn get_lexicon_word(word: u16) -> String {
+    let mut s = String::new();
+    push_word(&mut s, word as usize);
+    s
+}
+
+/* Find the characters with a word in their name or aliases that starts
+ * with the given prefix, and whether any such word matches exactly */
+fn search_word(prefix: &str) -> HashMap<u32, bool> {
+    // words are sorted, so all words with the prefix are adjacent
+    let words = &name_db::SEARCH_WORDS;
+    let start = words.partition_point(|&w| get_lexicon_word(w).as_str() < prefix);
+    let end = start + words[start..].partition_point(|&w| get_lexicon_word(w).starts_with(prefix));
+
+    let mut matches = HashMap::new();
+    for (i, &w) in words.iter().enumerate().take(end).skip(start) {
+        let exact = get_lexicon_word(w) == prefix;
+        let postings = name_db::SEARCH_OFFSET[i] as usize..name_db::SEARCH_OFFSET[i + 1] as usize;
+        for &code in &name_db::SEARCH_POSTINGS[postings] {
+            *matches.entry(code).or_insert(false) |= exact;
+        }
+    }
+    matches
+}
+
+/**
+ * Search characters by words in their names and name aliases. Every
+ * word of the query must match the start of a word in the name, so
+ * "arrow double left" finds LEFTWARDS DOUBLE ARROW, among others. The
+ * results are ranked by the number of exactly matching words, then by
+ * the length of the name. Only characters of the given general
+ * categories and scripts are returned, unless the lists are empty.
+ * Names derived from the codepoint, like those of Hangul syllables and
+ * unified ideographs, are not searched.
+ *
+ */
+pub fn name_search_filtered(query: &str, categories: &[GeneralCategory], scripts: &[Script]) -> Vec<u32> {
+    let mut results: Option<HashMap<u32, usize>> = None;
+    for word in query.split(|c: char| c.is_whitespace() || c == '_').filter(|w| !w.is_empty()) {
+        let matches = search_word(&word.to_ascii_uppercase());
+        results = Some(match results {
+            None => matches.into_iter().map(|(code, exact)| (code, exact as usize)).collect(),
+            Some(results) => results.into_iter()
+                .filter_map(|(code, n)| matches.get(&code).map(|&exact| (code, n + exact as usize)))
+                .collect()
+        });
+    }
+
+    let mut ranked: Vec<(usize, usize, u32)> = results.unwrap_or_default().into_iter()
+        .filter(|&(code, _)| categories.is_empty() || matches!(get_general_category(code), Ok(c) if categories.contains(&c)))
+        .filter(|&(code, _)| scripts.is_empty() || matches!(get_script(code), Ok(s) if scripts.contains(&s)))
+        .map(|(code, exact)| (exact, get_name(code).map_or(0, |name| name.split(' ').count()), code))
+        .collect();
+    ranked.sort_by_key(|&(exact, words, code)| (Reverse(exact), words, code));
+    ranked.into_iter().map(|(_, _, code)| code).collect()
+}
+
+/**
+ * Search characters by words in their names and name aliases, see
+ * name_search_filtered().
+ *
+ */
+pub fn name_search(query: &str) -> Vec<u32> {
+    name_search_filtered(query, &[], &[])
+}
@@ -0,0 +1,995 @@
+/* Copyright (c) 2017 Grigori Goronzy <greg@chown.ath.cx>
+ * 
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ * 
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ * 
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! String normalization as defined in UAX#15.
+
+use super::*;
+use std::borrow::Cow;
+use std::char;
+
+fn combining_class(code: u32) -> u8 {
+    get_combining_class(code).unwrap_or(0)
+}
+
+/* Compatibility decomposition masks, one bit per DecompositionType */
+const COMPAT_NONE: u32 = 0;
+const COMPAT_ALL: u32 = !0;
+
+/* Recursively apply canonical decomposition and compatibility
+ * decompositions of the types selected in the mask, and hand out the
+ * resulting codepoints one by one. */
+fn decompose_recursive<F: FnMut(u32)>(code: u32, compat: u32, emit: &mut F) {
+    if let Ok((len, decomposed)) = decompose_full(code) {
+        for &d in &decomposed[..len] {
+            if compat != COMPAT_NONE {
+                decompose_recursive(d, compat, emit);
+            } else {
+                emit(d);
+            }
+        }
+    } else if compat != COMPAT_NONE {
+        match compat_decompose_tagged(code) {
+            Ok((t, len, decomposed)) if compat & (1 << t as u32) != 0 => {
+                for &d in &decomposed[..len] {
+                    decompose_recursive(d, compat, emit);
+                }
+            },
+            _ => emit(code)
+        }
+    } else {
+        emit(code)
+    }
+}
+
+/**
+ * Iterator adaptor that yields the canonical or compatibility
+ * decomposition of a sequence of characters, in canonical order.
+ * See to_nfd_chars() and to_nfkd_chars().
+ *
+ */
+pub struct Decompositions<I> {
+    iter: I,
+    compat: u32,
+    buffer: Vec<(u8, u32)>,
+    ready: usize,
+    pos: usize
+}
+
+impl<I: Iterator<Item=char>> Decompositions<I> {
+    fn push(&mut self, code: u32) {
+        let class = combining_class(code);
+        if class == 0 {
+            // a starter terminates the pending run of combining marks
+            self.sort_pending();
+            self.ready = self.buffer.len();
+        }
+        self.buffer.push((class, code));
+    }
+
+    fn sort_pending(&mut self) {
+        // canonical ordering, the sort is stable
+        self.buffer[self.ready..].sort_by_key(|&(class, _)| class);
+    }
+}
+
+impl<I: Iterator<Item=char>> Iterator for Decompositions<I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        while self.ready == 0 {
+            match self.iter.next() {
+                Some(c) => {
+                    let mut pieces: [u32; 18] = [0; 18];
+                    let mut len = 0;
+                    decompose_recursive(c as u32, self.compat, &mut |d| { pieces[len] = d; len += 1; });
+                    for &d in &pieces[..len] {
+                        self.push(d);
+                    }
+                },
+                None => {
+                    if self.buffer.is_empty() {
+                        return None
+                    }
+                    self.sort_pending();
+                    self.ready = self.buffer.len();
+                }
+            }
+        }
+
+        let (_, code) = self.buffer[self.pos];
+        self.pos += 1;
+        if self.pos == self.ready {
+            self.buffer.drain(..self.ready);
+            self.ready = 0;
+            self.pos = 0;
+        }
+        char::from_u32(code)
+    }
+}
+
+fn decompose_chars<I: Iterator<Item=char>>(iter: I, compat: u32) -> Decompositions<I> {
+    Decompositions {
+        iter,
+        compat,
+        buffer: Vec::new(),
+        ready: 0,
+        pos: 0
+    }
+}
+
+/**
+ * Canonical decomposition (NFD) of a sequence of characters. This
+ * recursively decomposes every character, including Hangul syllables,
+ * and puts combining marks into canonical order.
+ *
+ */
+pub fn to_nfd_chars<I: Iterator<Item=char>>(iter: I) -> Decompositions<I> {
+    decompose_chars(iter, COMPAT_NONE)
+}
+
+/**
+ * Canonical decomposition (NFD) of a string.
+ *
+ */
+pub fn to_nfd(s: &str) -> String {
+    to_nfd_chars(s.chars()).collect()
+}
+
+/**
+ * Compatibility decomposition (NFKD) of a sequence of characters. This
+ * works like to_nfd_chars(), but applies compatibility decompositions
+ * as well.
+ *
+ */
+pub fn to_nfkd_chars<I: Iterator<Item=char>>(iter: I) -> Decompositions<I> {
+    decompose_chars(iter, COMPAT_ALL)
+}
+
+/**
+ * Compatibility decomposition (NFKD) of a string.
+ *
+ */
+pub fn to_nfkd(s: &str) -> String {
+    to_nfkd_chars(s.chars()).collect()
+}
+
+#[derive(Copy, Clone)]
+enum RecompositionState {
+    Composing,
+    Purging(usize),
+    Finished(usize)
+}
+
+/**
+ * Iterator adaptor that yields the canonical composition of a
+ * decomposed sequence of characters. See to_nfc_chars() and
+ * to_nfkc_chars().
+ *
+ */
+pub struct Recompositions<I> {
+    iter: Decompositions<I>,
+    contiguous: bool,
+    state: RecompositionState,
+    buffer: Vec<char>,
+    starter: Option<char>,
+    last_class: Option<u8>
+}
+
+impl<I: Iterator<Item=char>> Iterator for Recompositions<I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        loop {
+            match self.state {
+                RecompositionState::Composing => {
+                    for c in self.iter.by_ref() {
+                        let class = combining_class(c as u32);
+                        let starter = match self.starter {
+                            Some(s) => s,
+                            None => {
+                                if class != 0 {
+                                    // combining marks without a starter are passed through
+                                    return Some(c)
+                                }
+                                self.starter = Some(c);
+                                continue
+                            }
+                        };
+
+                        // a mark is blocked if a mark of the same or higher class
+                        // came between it and the starter, or any mark at all for
+                        // contiguous composition
+                        let blocked = match self.last_class {
+                            Some(last) => self.contiguous || last >= class,
+                            None => false
+                        };
+                        if !blocked {
+                            if let Ok(v) = compose(starter as u32, c as u32) {
+                                self.starter = char::from_u32(v);
+                                continue
+                            }
+                        }
+
+                        if class == 0 {
+                            // new starter, flush the previous one and any marks
+                            self.starter = Some(c);
+                            self.last_class = None;
+                            self.state = RecompositionState::Purging(0);
+                            return Some(starter)
+                        }
+                        self.buffer.push(c);
+                        self.last_class = Some(class);
+                    }
+                    self.state = RecompositionState::Finished(0);
+                    if self.starter.is_some() {
+                        return self.starter.take()
+                    }
+                },
+                RecompositionState::Purging(next) => {
+                    if next < self.buffer.len() {
+                        self.state = RecompositionState::Purging(next + 1);
+                        return Some(self.buffer[next])
+                    }
+                    self.buffer.clear();
+                    self.state = RecompositionState::Composing;
+                },
+                RecompositionState::Finished(next) => {
+                    if next < self.buffer.len() {
+                        self.state = RecompositionState::Finished(next + 1);
+                        return Some(self.buffer[next])
+                    }
+                    self.buffer.clear();
+                    return self.starter.take()
+                }
+            }
+        }
+    }
+}
+
+fn recompose<I: Iterator<Item=char>>(iter: Decompositions<I>, contiguous: bool) -> Recompositions<I> {
+    Recompositions {
+        iter,
+        contiguous,
+        state: RecompositionState::Composing,
+        buffer: Vec::new(),
+        starter: None,
+        last_class: None
+    }
+}
+
+/**
+ * Canonical composition (NFC) of a sequence of characters. The input
+ * is canonically decomposed first, then combined again with the
+ * canonical composition algorithm, including Hangul syllables.
+ * Blocked combining marks are not composed.
+ *
+ */
+pub fn to_nfc_chars<I: Iterator<Item=char>>(iter: I) -> Recompositions<I> {
+    recompose(to_nfd_chars(iter), false)
+}
+
+/**
+ * Canonical composition (NFC) of a string.
+ *
+ */
+pub fn to_nfc(s: &str) -> String {
+    to_nfc_chars(s.chars()).collect()
+}
+
+/**
+ * Compatibility composition (NFKC) of a sequence of characters. The
+ * input is decomposed with compatibility decompositions first, then
+ * combined again with the canonical composition algorithm.
+ *
+ */
+pub fn to_nfkc_chars<I: Iterator<Item=char>>(iter: I) -> Recompositions<I> {
+    recompose(to_nfkd_chars(iter), false)
+}
+
+/**
+ * Compatibility composition (NFKC) of a string.
+ *
+ */
+pub fn to_nfkc(s: &str) -> String {
+    to_nfkc_chars(s.chars()).collect()
+}
+
+/**
+ * Fast C Contiguous (FCC) composition of a sequence of characters as
+ * defined in UTN#5. This works like to_nfc_chars(), but a mark is
+ * only composed with the preceding starter if there are no other
+ * uncomposed marks between them. The result is always FCD.
+ *
+ */
+pub fn to_fcc_chars<I: Iterator<Item=char>>(iter: I) -> Recompositions<I> {
+    recompose(to_nfd_chars(iter), true)
+}
+
+/**
+ * Fast C Contiguous (FCC) composition of a string.
+ *
+ */
+pub fn to_fcc(s: &str) -> String {
+    to_fcc_chars(s.chars()).collect()
+}
+
+/**
+ * Check whether a string is in FCD form as defined in UTN#5. Text in
+ * FCD form is canonically ordered after canonical decomposition, so
+ * algorithms like collation can process it without full
+ * normalization.
+ *
+ */
+pub fn is_fcd(s: &str) -> bool {
+    let mut last_trail = 0;
+    for c in s.chars() {
+        let lead = get_lead_combining_class(c as u32).unwrap_or(0);
+        if lead != 0 && last_trail > lead {
+            return false
+        }
+        last_trail = get_trail_combining_class(c as u32).unwrap_or(0);
+    }
+    true
+}
+
+/* Lazily decomposed codepoints of a sequence of characters, without
+ * canonical ordering. This is cheap to clone, so positions can be
+ * saved. */
+#[derive(Clone)]
+struct Pieces<I> {
+    chars: I,
+    compat: u32,
+    buffer: [u32; 18],
+    len: usize,
+    pos: usize
+}
+
+impl<I: Iterator<Item=char> + Clone> Pieces<I> {
+    fn new(chars: I, compat: u32) -> Pieces<I> {
+        Pieces {
+            chars,
+            compat,
+            buffer: [0; 18],
+            len: 0,
+            pos: 0
+        }
+    }
+
+    /* Advance past a run of combining marks and return its length */
+    fn skip_marks(&mut self) -> usize {
+        let mut n = 0;
+        loop {
+            let mut next = self.clone();
+            match next.next() {
+                Some(d) if combining_class(d) != 0 => {
+                    *self = next;
+                    n += 1;
+                },
+                _ => return n
+            }
+        }
+    }
+}
+
+impl<I: Iterator<Item=char> + Clone> Iterator for Pieces<I> {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        if self.pos == self.len {
+            let c = self.chars.next()?;
+            let compat = self.compat;
+            let buffer = &mut self.buffer;
+            let len = &mut self.len;
+            *len = 0;
+            decompose_recursive(c as u32, compat, &mut |d| { buffer[*len] = d; *len += 1; });
+            self.pos = 0;
+        }
+        self.pos += 1;
+        Some(self.buffer[self.pos - 1])
+    }
+}
+
+/* Compare two runs of n combining marks after canonical ordering. The
+ * runs are equal if the marks of each combining class are equal, so
+ * compare class by class instead of sorting. */
+fn marks_equivalent<I: Iterator<Item=char> + Clone>(a: &Pieces<I>, b: &Pieces<I>, n: usize) -> bool {
+    let mut class = 0;
+    loop {
+        match a.clone().take(n).map(combining_class).filter(|&k| k > class).min() {
+            Some(k) => class = k,
+            None => return true
+        }
+        let marks_a = a.clone().take(n).filter(|&d| combining_class(d) == class);
+        let marks_b = b.clone().take(n).filter(|&d| combining_class(d) == class);
+        if !marks_a.eq(marks_b) {
+            return false
+        }
+    }
+}
+
+fn equivalent(a: &str, b: &str, compat: u32) -> bool {
+    if a == b {
+        return true
+    }
+
+    let mut pa = Pieces::new(a.chars(), compat);
+    let mut pb = Pieces::new(b.chars(), compat);
+    loop {
+        // starters must match exactly
+        match (pa.clone().next(), pb.clone().next()) {
+            (None, None) => return true,
+            (Some(ca), Some(cb)) => {
+                if combining_class(ca) == 0 || combining_class(cb) == 0 {
+                    if ca != cb {
+                        return false
+                    }
+                    pa.next();
+                    pb.next();
+                }
+            },
+            _ => return false
+        }
+
+        // combining marks up to the next starter may be reordered
+        let (marks_a, marks_b) = (pa.clone(), pb.clone());
+        let n = pa.skip_marks();
+        if n != pb.skip_marks() || !marks_equivalent(&marks_a, &marks_b, n) {
+            return false
+        }
+    }
+}
+
+/**
+ * Check if two strings are canonically equivalent, i.e. have the same
+ * NFD form. Both strings are decomposed lazily, without allocation.
+ *
+ */
+pub fn canonically_equivalent(a: &str, b: &str) -> bool {
+    equivalent(a, b, COMPAT_NONE)
+}
+
+/**
+ * Check if two strings are compatibility equivalent, i.e. have the
+ * same NFKD form. Both strings are decomposed lazily, without
+ * allocation.
+ *
+ */
+pub fn compatibility_equivalent(a: &str, b: &str) -> bool {
+    equivalent(a, b, COMPAT_ALL)
+}
+
+/* Canonically ordered decomposition of a sequence of characters. Runs
+ * of combining marks are emitted by scanning them once per combining
+ * class, so no buffer is needed. */
+#[derive(Clone)]
+struct OrderedPieces<I> {
+    pieces: Pieces<I>,
+    run: Pieces<I>,
+    run_len: usize,
+    class: u8,
+    scan: Pieces<I>,
+    scan_left: usize
+}
+
+impl<I: Iterator<Item=char> + Clone> OrderedPieces<I> {
+    fn new(chars: I, compat: u32) -> OrderedPieces<I> {
+        let pieces = Pieces::new(chars, compat);
+        OrderedPieces {
+            run: pieces.clone(),
+            scan: pieces.clone(),
+            pieces,
+            run_len: 0,
+            class: 0,
+            scan_left: 0
+        }
+    }
+}
+
+impl<I: Iterator<Item=char> + Clone> Iterator for OrderedPieces<I> {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        loop {
+            if self.run_len > 0 {
+                while self.scan_left > 0 {
+                    self.scan_left -= 1;
+                    match self.scan.next() {
+                        Some(d) if combining_class(d) == self.class => return Some(d),
+                        _ => {}
+                    }
+                }
+
+                // continue with the next higher class in the run
+                let class = self.class;
+                match self.run.clone().take(self.run_len).map(combining_class).filter(|&k| k > class).min() {
+                    Some(k) => {
+                        self.class = k;
+                        self.scan = self.run.clone();
+                        self.scan_left = self.run_len;
+                    },
+                    None => self.run_len = 0
+                }
+                continue
+            }
+
+            let mut next = self.pieces.clone();
+            let d = next.next()?;
+            if combining_class(d) == 0 {
+                self.pieces = next;
+                return Some(d)
+            }
+
+            // start of a run of combining marks
+            self.run = self.pieces.clone();
+            self.run_len = self.pieces.skip_marks();
+            self.class = 0;
+            self.scan_left = 0;
+        }
+    }
+}
+
+/* Canonical composition of an ordered decomposition without a buffer.
+ * Every starter is combined with the rest of its segment first, then
+ * the segment is walked again to emit the marks left uncomposed. */
+#[derive(Clone)]
+struct ComposedPieces<I> {
+    ordered: OrderedPieces<I>,
+    marks: OrderedPieces<I>,
+    marks_left: usize,
+    starter: u32,
+    last_class: Option<u8>
+}
+
+impl<I: Iterator<Item=char> + Clone> ComposedPieces<I> {
+    fn new(chars: I, compat: u32) -> ComposedPieces<I> {
+        let ordered = OrderedPieces::new(chars, compat);
+        ComposedPieces {
+            marks: ordered.clone(),
+            ordered,
+            marks_left: 0,
+            starter: 0,
+            last_class: None
+        }
+    }
+
+    /* Try to compose the next codepoint of a segment with the starter */
+    fn compose_next(&mut self, code: u32) -> bool {
+        let class = combining_class(code);
+        let blocked = match self.last_class {
+            Some(last) => last >= class,
+            None => false
+        };
+        if !blocked {
+            if let Ok(v) = compose(self.starter, code) {
+                self.starter = v;
+                return true
+            }
+        }
+        self.last_class = Some(class);
+        false
+    }
+}
+
+impl<I: Iterator<Item=char> + Clone> Iterator for ComposedPieces<I> {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        while self.marks_left > 0 {
+            self.marks_left -= 1;
+            let d = self.marks.next()?;
+            if !self.compose_next(d) {
+                return Some(d)
+            }
+        }
+
+        let starter = self.ordered.next()?;
+        if combining_class(starter) != 0 {
+            // combining marks without a starter are passed through
+            return Some(starter)
+        }
+
+        // find the end of the segment, i.e. the next starter that is
+        // not composed, and remember where the segment started
+        self.marks = self.ordered.clone();
+        self.starter = starter;
+        self.last_class = None;
+        loop {
+            let mut next = self.ordered.clone();
+            match next.next() {
+                Some(d) => {
+                    if !self.compose_next(d) && combining_class(d) == 0 {
+                        break
+                    }
+                    self.ordered = next;
+                    self.marks_left += 1;
+                },
+                None => break
+            }
+        }
+
+        let composed = self.starter;
+        self.starter = starter;
+        self.last_class = None;
+        Some(composed)
+    }
+}
+
+/* Code units normalized text can be written in */
+trait CodeUnit: Copy {
+    fn encoded_len(c: char) -> usize;
+    fn encode(c: char, out: &mut [Self]);
+}
+
+impl CodeUnit for u32 {
+    fn encoded_len(_: char) -> usize {
+        1
+    }
+
+    fn encode(c: char, out: &mut [u32]) {
+        out[0] = c as u32;
+    }
+}
+
+impl CodeUnit for u16 {
+    fn encoded_len(c: char) -> usize {
+        c.len_utf16()
+    }
+
+    fn encode(c: char, out: &mut [u16]) {
+        c.encode_utf16(out);
+    }
+}
+
+impl CodeUnit for u8 {
+    fn encoded_len(c: char) -> usize {
+        c.len_utf8()
+    }
+
+    fn encode(c: char, out: &mut [u8]) {
+        c.encode_utf8(out);
+    }
+}
+
+/* Write as many characters as fit and count the code units needed */
+fn write_units<T: CodeUnit, J: Iterator<Item=u32>>(iter: J, out: &mut [T]) -> Result<usize, usize> {
+    let mut len = 0;
+    let mut overflow = false;
+    for c in iter.filter_map(char::from_u32) {
+        let n = T::encoded_len(c);
+        if !overflow && len + n <= out.len() {
+            T::encode(c, &mut out[len..len + n]);
+        } else {
+            overflow = true;
+        }
+        len += n;
+    }
+    if overflow { Err(len) } else { Ok(len) }
+}
+
+fn normalize_units<T, I>(form: NormalizationForm, iter: I, out: &mut [T]) -> Result<usize, usize>
+    where T: CodeUnit, I: Iterator<Item=char> + Clone {
+    match form {
+        NormalizationForm::NFD => write_units(OrderedPieces::new(iter, COMPAT_NONE), out),
+        NormalizationForm::NFKD => write_units(OrderedPieces::new(iter, COMPAT_ALL), out),
+        NormalizationForm::NFC => write_units(ComposedPieces::new(iter, COMPAT_NONE), out),
+        NormalizationForm::NFKC => write_units(ComposedPieces::new(iter, COMPAT_ALL), out)
+    }
+}
+
+/**
+ * Normalize a sequence of characters into a caller-provided UTF-32
+ * buffer, without allocation. Returns the number of codepoints
+ * written. If the buffer is too small, it is filled with as many
+ * characters as fit and the required length is returned as error,
+ * similar to snprintf(). The input is scanned more than once, so the
+ * iterator must be cloneable.
+ *
+ */
+pub fn normalize_utf32<I>(form: NormalizationForm, iter: I, out: &mut [u32]) -> Result<usize, usize>
+    where I: Iterator<Item=char> + Clone {
+    normalize_units(form, iter, out)
+}
+
+/**
+ * Normalize a sequence of characters into a caller-provided UTF-16
+ * buffer, without allocation. This works like normalize_utf32(), with
+ * lengths counted in UTF-16 code units. Surrogate pairs are never
+ * split.
+ *
+ */
+pub fn normalize_utf16<I>(form: NormalizationForm, iter: I, out: &mut [u16]) -> Result<usize, usize>
+    where I: Iterator<Item=char> + Clone {
+    normalize_units(form, iter, out)
+}
+
+/**
+ * Normalize a sequence of characters into a caller-provided UTF-8
+ * buffer, without allocation. This works like normalize_utf32(), with
+ * lengths counted in bytes. Multi-byte sequences are never split.
+ *
+ */
+pub fn normalize_utf8<I>(form: NormalizationForm, iter: I, out: &mut [u8]) -> Result<usize, usize>
+    where I: Iterator<Item=char> + Clone {
+    normalize_units(form, iter, out)
+}
+
+/* Check if NFC text can be split before a codepoint, i.e. nothing in
+ * front of it can reorder or compose with it or with anything after it */
+fn has_nfc_boundary_before(c: char) -> bool {
+    c < '\u{300}' || (get_lead_combining_class(c as u32) == Ok(0) &&
+        get_quick_check(c as u32, NormalizationForm::NFC) == Ok(QuickCheck::Yes))
+}
+
+/* Append NFC text to NFC text, renormalizing only around the boundary */
+fn append_nfc(dest: &mut String, s: &str) {
+    // the part of s that may interact with dest
+    let head = match s.char_indices().find(|&(_, c)| has_nfc_boundary_before(c)) {
+        Some((i, _)) => i,
+        None => s.len()
+    };
+    if head == 0 {
+        dest.push_str(s);
+        return
+    }
+
+    // the part of dest that may interact with s
+    let tail = match dest.char_indices().rev().find(|&(_, c)| has_nfc_boundary_before(c)) {
+        Some((i, _)) => i,
+        None => 0
+    };
+
+    let mut boundary = dest[tail..].to_string();
+    boundary.push_str(&s[..head]);
+    dest.truncate(tail);
+    dest.extend(to_nfc_chars(boundary.chars()));
+    dest.push_str(&s[head..]);
+}
+
+/**
+ * Concatenate two strings in NFC form. The result is in NFC form as
+ * well. Only the text around the boundary between both strings is
+ * normalized again.
+ *
+ */
+pub fn concat_nfc(a: &str, b: &str) -> String {
+    let mut s = String::with_capacity(a.len() + b.len());
+    s.push_str(a);
+    append_nfc(&mut s, b);
+    s
+}
+
+/**
+ * A string that is always in NFC form. Appending to it only
+ * normalizes the appended text and the boundary region, instead of
+ * the whole string.
+ *
+ */
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct NfcString {
+    s: String
+}
+
+impl NfcString {
+    /**
+     * Create an empty string.
+     *
+     */
+    pub fn new() -> NfcString {
+        NfcString { s: String::new() }
+    }
+
+    /**
+     * Append a string, which does not need to be normalized.
+     *
+     */
+    pub fn push_str(&mut self, s: &str) {
+        append_nfc(&mut self.s, &normalize(NormalizationForm::NFC, s));
+    }
+
+    /**
+     * Append a single character.
+     *
+     */
+    pub fn push(&mut self, c: char) {
+        let mut buf = [0; 4];
+        self.push_str(c.encode_utf8(&mut buf));
+    }
+
+    /**
+     * Get the normalized string.
+     *
+     */
+    pub fn as_str(&self) -> &str {
+        &self.s
+    }
+
+    /**
+     * Convert into the normalized string.
+     *
+     */
+    pub fn into_string(self) -> String {
+        self.s
+    }
+}
+
+impl<'a> From<&'a str> for NfcString {
+    fn from(s: &'a str) -> NfcString {
+        NfcString { s: to_nfc(s) }
+    }
+}
+
+impl AsRef<str> for NfcString {
+    fn as_ref(&self) -> &str {
+        &self.s
+    }
+}
+
+/**
+ * NFKC_Casefold transformation of a string as defined in chapter 3.13
+ * of the Unicode core specification. This applies compatibility
+ * decomposition and full case folding, and removes default ignorable
+ * codepoints. It is used for caseless identifier matching (UAX#31).
+ *
+ */
+pub fn to_nfkc_casefold(s: &str) -> String {
+    let mut mapped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match nfkc_casefold(c as u32) {
+            Ok((len, mapping)) => mapped.extend(mapping[..len].iter().filter_map(|&m| char::from_u32(m))),
+            Err(_) => mapped.push(c)
+        }
+    }
+    to_nfc(&mapped)
+}
+
+/**
+ * Normalizer that only applies compatibility decompositions of
+ * selected types, in addition to canonical decomposition. For
+ * instance, this can fold Wide and Narrow forms, but keep Super
+ * and Fraction forms intact. With all types selected, this is
+ * equivalent to NFKD and NFKC.
+ *
+ */
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct CompatNormalizer {
+    compat: u32
+}
+
+impl CompatNormalizer {
+    /**
+     * Create a normalizer that applies compatibility decompositions
+     * of the given types.
+     *
+     */
+    pub fn new(types: &[DecompositionType]) -> CompatNormalizer {
+        let mut compat = COMPAT_NONE;
+        for &t in types {
+            compat |= 1 << t as u32;
+        }
+        CompatNormalizer { compat }
+    }
+
+    /**
+     * Decompose a sequence of characters, in canonical order.
+     *
+     */
+    pub fn to_decomposed_chars<I: Iterator<Item=char>>(&self, iter: I) -> Decompositions<I> {
+        decompose_chars(iter, self.compat)
+    }
+
+    /**
+     * Decompose a string, in canonical order.
+     *
+     */
+    pub fn to_decomposed(&self, s: &str) -> String {
+        self.to_decomposed_chars(s.chars()).collect()
+    }
+
+    /**
+     * Decompose a sequence of characters and apply canonical
+     * composition afterwards.
+     *
+     */
+    pub fn to_composed_chars<I: Iterator<Item=char>>(&self, iter: I) -> Recompositions<I> {
+        recompose(self.to_decomposed_chars(iter), false)
+    }
+
+    /**
+     * Decompose a string and apply canonical composition afterwards.
+     *
+     */
+    pub fn to_composed(&self, s: &str) -> String {
+        self.to_composed_chars(s.chars()).collect()
+    }
+}
+
+/**
+ * Normalize a string to the given normalization form.
+ *
+ */
+pub fn to_normalized(form: NormalizationForm, s: &str) -> String {
+    match form {
+        NormalizationForm::NFD => to_nfd(s),
+        NormalizationForm::NFKD => to_nfkd(s),
+        NormalizationForm::NFC => to_nfc(s),
+        NormalizationForm::NFKC => to_nfkc(s)
+    }
+}
+
+/**
+ * Quick check whether a string is normalized, using the algorithm
+ * from UAX#15. Returns Maybe if the check is inconclusive, in which
+ * case the string must be normalized to get a definite answer.
+ *
+ */
+pub fn quick_check(form: NormalizationForm, s: &str) -> QuickCheck {
+    let mut last_class = 0;
+    let mut result = QuickCheck::Yes;
+    for c in s.chars() {
+        // ASCII is always normalized and never reordered
+        if c < '\u{80}' {
+            last_class = 0;
+            continue
+        }
+        let class = combining_class(c as u32);
+        if last_class > class && class != 0 {
+            return QuickCheck::No
+        }
+        match get_quick_check(c as u32, form) {
+            Ok(QuickCheck::No) => return QuickCheck::No,
+            Ok(QuickCheck::Maybe) => result = QuickCheck::Maybe,
+            _ => {}
+        }
+        last_class = class;
+    }
+    result
+}
+
+/**
+ * Check whether a string is normalized. This only normalizes the
+ * string if the quick check is inconclusive.
+ *
+ */
+pub fn is_normalized(form: NormalizationForm, s: &str) -> bool {
+    match quick_check(form, s) {
+        QuickCheck::Yes => true,
+        QuickCheck::No => false,
+        QuickCheck::Maybe => to_normalized(form, s) == s
+    }
+}
+
+/**
+ * Normalize a string to the given normalization form. The input is
+ * borrowed if it is already normalized, so no allocation happens in
+ * the common case.
+ *
+ */
+pub fn normalize<'a>(form: NormalizationForm, s: &'a str) -> Cow<'a, str> {
+    if quick_check(form, s) == QuickCheck::Yes {
+        return Cow::Borrowed(s)
+    }
+    let normalized = to_normalized(form, s);
+    if normalized == s {
+        Cow::Borrowed(s)
+    } else {
+        Cow::Owned(normalized)
+    }
+}
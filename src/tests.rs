@@ -20,6 +20,7 @@
  */
 
 use super::*;
+use std::borrow::Cow;
 
 /* What to test:
     * - Normal cases, possibly all the different paths
@@ -93,6 +94,18 @@ fn test_decomposition() {
         Err(_) => assert!(false)
     }
 
+    /* full decomposition */
+    assert_eq!(decompose_full(0xfb2c), Ok((3, [0x05e9, 0x05bc, 0x05c1, 0]))); // multi-part sequence
+    assert_eq!(decompose_full(0x1f87), Ok((4, [0x03b1, 0x0314, 0x0342, 0x0345]))); // longest decomposition
+    assert_eq!(decompose_full(0x00c4), Ok((2, [0x0041, 0x0308, 0, 0]))); // single step
+    assert_eq!(decompose_full(0x2126), Ok((1, [0x03a9, 0, 0, 0]))); // singleton
+    assert_eq!(decompose_full(0xac00), Ok((2, [0x1100, 0x1161, 0, 0]))); // Hangul LV
+    assert_eq!(decompose_full(0xd7a3), Ok((3, [0x1112, 0x1175, 0x11c2, 0]))); // Hangul LVT
+    assert_eq!(decompose_full(0x1d15f), Ok((2, [0x1d158, 0x1d165, 0, 0]))); // outside BMP
+    assert_eq!(decompose_full(0x1d160), Ok((3, [0x1d158, 0x1d165, 0x1d16e, 0]))); // outside BMP, multi-part
+    assert_eq!(decompose_full(0xfb01), Err("no decomposition")); // only compatibility
+    assert_eq!(decompose_full(0x0065), Err("no decomposition")); // no decomposition
+
     /* outside BMP */
     assert_eq!(decompose(0x1109a), Ok((0x11099, 0x110ba)));
     cmp[0..2].clone_from_slice(&[0x2a600, 0]);
@@ -101,6 +114,28 @@ fn test_decomposition() {
     /* very long decomposition */
     cmp[0..18].clone_from_slice(&[0x0635, 0x0644, 0x0649, 0x0020, 0x0627, 0x0644, 0x0644, 0x0647, 0x0020, 0x0639, 0x0644, 0x064A, 0x0647, 0x0020, 0x0648, 0x0633, 0x0644, 0x0645]);
     assert_eq!(compat_decompose(0xfdfa), Ok((18, cmp)));
+    assert_eq!(compat_decompose_tagged(0xfdfa), Ok((DecompositionType::Isolated, 18, cmp)));
+
+    /* decomposition types */
+    cmp = [0; 18];
+    cmp[0..2].clone_from_slice(&[0x0066, 0x0069]);
+    assert_eq!(compat_decompose_tagged(0xfb01), Ok((DecompositionType::Compat, 2, cmp))); // ligature
+    cmp[0..2].clone_from_slice(&[0x0041, 0]);
+    assert_eq!(compat_decompose_tagged(0x1d400), Ok((DecompositionType::Font, 1, cmp))); // font variant
+    cmp[0..2].clone_from_slice(&[0x0041, 0x0308]);
+    assert_eq!(compat_decompose_tagged(0x00c4), Ok((DecompositionType::Canonical, 2, cmp))); // canonical
+    assert_eq!(compat_decompose_tagged(0x0065), Err("no decomposition found"));  // no decomposition
+
+    assert_eq!(get_decomposition_type(0x00c4), Ok(DecompositionType::Canonical));
+    assert_eq!(get_decomposition_type(0x00a0), Ok(DecompositionType::NoBreak));
+    assert_eq!(get_decomposition_type(0x00b2), Ok(DecompositionType::Super));
+    assert_eq!(get_decomposition_type(0x00bd), Ok(DecompositionType::Fraction));
+    assert_eq!(get_decomposition_type(0x2460), Ok(DecompositionType::Circle));
+    assert_eq!(get_decomposition_type(0xff21), Ok(DecompositionType::Wide));
+    assert_eq!(get_decomposition_type(0xff76), Ok(DecompositionType::Narrow));
+    assert_eq!(get_decomposition_type(0xac01), Ok(DecompositionType::Canonical)); // Hangul syllable
+    assert_eq!(get_decomposition_type(0x0065), Ok(DecompositionType::None)); // no decomposition
+    assert_eq!(get_decomposition_type(0x200000), Err("invalid char")); // outside Unicode
 }
 
 #[test]
@@ -108,12 +143,15 @@ fn test_composition() {
     assert_eq!(compose(0x0041, 0x0308), Ok(0x00c4)); //  normal case
     assert_eq!(compose(0x0066, 0x0069), Err("no composition found")); // compatibility decomposition forms don't have a recomposition
     assert_eq!(compose(0x0028, 0x0028), Err("no composition found")); // no composition exists
+    assert_eq!(compose(0x0042, 0x0301), Err("no composition found")); // both characters compose, but not with each other
     assert_eq!(compose(0x200000, 0x0028), Err("no composition found")); // outside Unicode
 
     /* Hangul Jamo */
     assert_eq!(compose(0xac00, 0x11a8), Ok(0xac01)); // normal case (LV,T)
     assert_eq!(compose(0x1100, 0x1161), Ok(0xac00)); // normal case (L,V)
     assert_eq!(compose(0xd788, 0x11a3), Err("no composition found")); // invalid Jamo combinaton (LV,T with invalid T)
+    assert_eq!(compose(0xac00, 0x11a7), Err("no composition found")); // invalid Jamo combination (LV,T with invalid T)
+    assert_eq!(compose(0xac01, 0x11a8), Err("no composition found")); // invalid Jamo combination (LVT,T)
 
     /* multi-part sequence */
     let ab  = compose(0x0041, 0x0308);
@@ -132,6 +170,7 @@ fn test_composition() {
 
     /* outside BMP */
     assert_eq!(compose(0x11099, 0x110ba), Ok(0x1109A));
+    assert_eq!(compose(0x115b8, 0x115af), Ok(0x115ba)); // last entry in composition tables
 }
 
 #[test]
@@ -190,4 +229,647 @@ fn test_resolved_linebreak_class() {
 
     /* error case */
     assert_eq!(get_resolved_linebreak_class(0x200000), LinebreakClass::XX); // outside of Unicode
-}
\ No newline at end of file
+}
+#[test]
+fn test_nfd() {
+    assert_eq!(to_nfd("abc"), "abc"); // nothing to do
+    assert_eq!(to_nfd("\u{00c4}"), "A\u{0308}"); // normal case
+    assert_eq!(to_nfd("\u{fb2c}"), "\u{05e9}\u{05bc}\u{05c1}"); // recursive decomposition
+    assert_eq!(to_nfd("\u{1e0b}\u{0323}"), "d\u{0323}\u{0307}"); // reordering of decomposed marks
+    assert_eq!(to_nfd("a\u{0301}\u{0316}b\u{0307}\u{0323}"), "a\u{0316}\u{0301}b\u{0323}\u{0307}"); // multiple runs
+    assert_eq!(to_nfd("\u{0301}\u{0316}"), "\u{0316}\u{0301}"); // leading combining marks
+    assert_eq!(to_nfd("\u{fb01}"), "\u{fb01}"); // compatibility decompositions are not applied
+    assert_eq!(to_nfd(""), "");
+
+    /* Hangul Jamo */
+    assert_eq!(to_nfd("\u{ac00}"), "\u{1100}\u{1161}"); // LV
+    assert_eq!(to_nfd("\u{d7a3}"), "\u{1112}\u{1175}\u{11c2}"); // LVT
+
+    /* outside BMP */
+    assert_eq!(to_nfd("\u{1109a}"), "\u{11099}\u{110ba}");
+
+    /* iterator adaptor */
+    let v: Vec<char> = to_nfd_chars("\u{01de}x".chars()).collect();
+    assert_eq!(v, ['A', '\u{0308}', '\u{0304}', 'x']);
+}
+
+#[test]
+fn test_nfc() {
+    assert_eq!(to_nfc("abc"), "abc"); // nothing to do
+    assert_eq!(to_nfc("A\u{0308}"), "\u{00c4}"); // normal case
+    assert_eq!(to_nfc("A\u{0308}\u{0304}"), "\u{01de}"); // multiple compositions
+    assert_eq!(to_nfc("\u{00c4}\u{0304}"), "\u{01de}"); // precomposed input
+    assert_eq!(to_nfc("d\u{0307}\u{0323}"), "\u{1e0d}\u{0307}"); // reordering before composition
+    assert_eq!(to_nfc("a\u{0316}\u{0301}"), "\u{00e1}\u{0316}"); // mark with lower class is not blocking
+    assert_eq!(to_nfc("a\u{0301}\u{0301}"), "\u{00e1}\u{0301}"); // blocked by mark of same class
+    assert_eq!(to_nfc("\u{0301}a"), "\u{0301}a"); // no starter
+    assert_eq!(to_nfc("\u{fb01}"), "\u{fb01}"); // compatibility decompositions are not applied
+    assert_eq!(to_nfc(""), "");
+
+    /* excluded compositions */
+    assert_eq!(to_nfc("\u{fb2c}"), "\u{05e9}\u{05bc}\u{05c1}");
+    assert_eq!(to_nfc("\u{2126}"), "\u{03a9}"); // singleton
+
+    /* Hangul Jamo */
+    assert_eq!(to_nfc("\u{1100}\u{1161}\u{11a8}"), "\u{ac01}"); // L,V,T
+    assert_eq!(to_nfc("\u{ac00}\u{11a8}"), "\u{ac01}"); // LV,T
+    assert_eq!(to_nfc("\u{1100}\u{0301}\u{1161}"), "\u{1100}\u{0301}\u{1161}"); // blocked
+
+    /* outside BMP */
+    assert_eq!(to_nfc("\u{11099}\u{110ba}"), "\u{1109a}");
+
+    /* iterator adaptor */
+    let v: Vec<char> = to_nfc_chars("e\u{0301}x".chars()).collect();
+    assert_eq!(v, ['\u{00e9}', 'x']);
+}
+
+#[test]
+fn test_nfkd_nfkc() {
+    assert_eq!(to_nfkd("abc"), "abc"); // nothing to do
+    assert_eq!(to_nfkd("\u{fb01}"), "fi"); // normal case
+    assert_eq!(to_nfkd("\u{01c4}"), "DZ\u{030c}"); // compatibility decomposition with canonical part
+    assert_eq!(to_nfkd("\u{1e9b}\u{0323}"), "s\u{0323}\u{0307}"); // recursive decomposition and reordering
+    assert_eq!(to_nfkd("\u{ac01}"), "\u{1100}\u{1161}\u{11a8}"); // Hangul Jamo
+    assert_eq!(to_nfkd("\u{2fa1d}"), "\u{2a600}"); // outside BMP
+    assert_eq!(to_nfkd("\u{fdfa}").chars().count(), 18); // very long decomposition
+
+    assert_eq!(to_nfkc("\u{fb01}"), "fi"); // normal case
+    assert_eq!(to_nfkc("\u{01c4}"), "D\u{017d}"); // recomposition
+    assert_eq!(to_nfkc("\u{1e9b}\u{0323}"), "\u{1e69}"); // recursive decomposition and recomposition
+    assert_eq!(to_nfkc("\u{ff76}\u{ff9e}"), "\u{30ac}"); // halfwidth forms
+    assert_eq!(to_nfkc("\u{3200}"), "(\u{1100})"); // Hangul compatibility forms
+    assert_eq!(to_nfkc(""), "");
+
+    /* iterator adaptors */
+    let v: Vec<char> = to_nfkd_chars("\u{00bd}".chars()).collect();
+    assert_eq!(v, ['1', '\u{2044}', '2']);
+    let v: Vec<char> = to_nfkc_chars("\u{2460}".chars()).collect();
+    assert_eq!(v, ['1']);
+}
+
+#[test]
+fn test_quick_check() {
+    assert_eq!(get_quick_check(0x0041, NormalizationForm::NFC), Ok(QuickCheck::Yes)); // normal case
+    assert_eq!(get_quick_check(0x00c4, NormalizationForm::NFD), Ok(QuickCheck::No)); // decomposable
+    assert_eq!(get_quick_check(0x00c4, NormalizationForm::NFC), Ok(QuickCheck::Yes)); // decomposable
+    assert_eq!(get_quick_check(0x0308, NormalizationForm::NFC), Ok(QuickCheck::Maybe)); // may compose with previous character
+    assert_eq!(get_quick_check(0x0958, NormalizationForm::NFC), Ok(QuickCheck::No)); // composition exclusion
+    assert_eq!(get_quick_check(0xfb01, NormalizationForm::NFC), Ok(QuickCheck::Yes)); // compatibility decomposition
+    assert_eq!(get_quick_check(0xfb01, NormalizationForm::NFKC), Ok(QuickCheck::No)); // compatibility decomposition
+    assert_eq!(get_quick_check(0xfb01, NormalizationForm::NFKD), Ok(QuickCheck::No)); // compatibility decomposition
+    assert_eq!(get_quick_check(0xac00, NormalizationForm::NFD), Ok(QuickCheck::No)); // Hangul syllable
+    assert_eq!(get_quick_check(0x11a8, NormalizationForm::NFC), Ok(QuickCheck::Maybe)); // Hangul T jamo
+    assert_eq!(get_quick_check(0x2fa1d, NormalizationForm::NFC), Ok(QuickCheck::No)); // outside BMP
+    assert_eq!(get_quick_check(0x200000, NormalizationForm::NFC), Err("invalid char")); // outside Unicode
+
+    assert_eq!(quick_check(NormalizationForm::NFC, "abc"), QuickCheck::Yes);
+    assert_eq!(quick_check(NormalizationForm::NFC, "a\u{0308}"), QuickCheck::Maybe);
+    assert_eq!(quick_check(NormalizationForm::NFC, "a\u{0301}\u{0316}"), QuickCheck::No); // wrong canonical order
+    assert_eq!(quick_check(NormalizationForm::NFD, "\u{00e4}"), QuickCheck::No);
+
+    assert!(is_normalized(NormalizationForm::NFC, "\u{00e4}"));
+    assert!(!is_normalized(NormalizationForm::NFC, "a\u{0308}"));
+    assert!(is_normalized(NormalizationForm::NFC, "\u{0308}")); // Maybe, but normalized
+    assert!(is_normalized(NormalizationForm::NFD, "a\u{0308}"));
+    assert!(!is_normalized(NormalizationForm::NFKC, "\u{fb01}"));
+
+    assert!(matches!(normalize(NormalizationForm::NFC, "abc"), Cow::Borrowed("abc"))); // already normalized
+    assert!(matches!(normalize(NormalizationForm::NFC, "\u{00e9}"), Cow::Borrowed(_))); // already normalized
+    assert_eq!(normalize(NormalizationForm::NFC, "e\u{0301}"), "\u{00e9}");
+    assert_eq!(normalize(NormalizationForm::NFKD, "\u{fb01}"), "fi");
+}
+
+#[test]
+fn test_compat_normalizer() {
+    let n = CompatNormalizer::new(&[DecompositionType::Wide, DecompositionType::Narrow]);
+    assert_eq!(n.to_composed("\u{ff21}\u{ff22}"), "AB"); // wide forms are folded
+    assert_eq!(n.to_composed("\u{ff76}\u{ff9e}"), "\u{30ac}"); // narrow forms are folded and composed
+    assert_eq!(n.to_composed("x\u{00b2}"), "x\u{00b2}"); // superscripts are kept
+    assert_eq!(n.to_composed("\u{00bd}"), "\u{00bd}"); // fractions are kept
+    assert_eq!(n.to_composed("\u{fb01}"), "\u{fb01}"); // ligatures are kept
+    assert_eq!(n.to_composed("e\u{0301}"), "\u{00e9}"); // canonical composition is always applied
+    assert_eq!(n.to_decomposed("\u{00e9}\u{ff21}"), "e\u{0301}A"); // canonical decomposition is always applied
+
+    /* recursive decomposition with mixed types */
+    let n = CompatNormalizer::new(&[DecompositionType::Compat]);
+    assert_eq!(n.to_decomposed("\u{01c4}"), "DZ\u{030c}");
+    assert_eq!(n.to_composed("\u{01c4}"), "D\u{017d}");
+    assert_eq!(n.to_composed("\u{2474}"), "(1)"); // compat: "(" "1" ")"
+    assert_eq!(n.to_composed("\u{2460}"), "\u{2460}"); // circle
+
+    /* no types is NFD/NFC, all types is NFKD/NFKC */
+    let n = CompatNormalizer::new(&[]);
+    assert_eq!(n.to_decomposed("\u{1e9b}\u{0323}"), to_nfd("\u{1e9b}\u{0323}"));
+    assert_eq!(n.to_composed("\u{1e9b}\u{0323}"), to_nfc("\u{1e9b}\u{0323}"));
+    let v: Vec<char> = n.to_composed_chars("A\u{0308}".chars()).collect();
+    assert_eq!(v, ['\u{00c4}']);
+}
+
+#[test]
+fn test_fcd_fcc() {
+    assert_eq!(get_lead_combining_class(0x0041), Ok(0)); // starter
+    assert_eq!(get_lead_combining_class(0x0301), Ok(230)); // combining mark
+    assert_eq!(get_lead_combining_class(0x0344), Ok(230)); // non-starter decomposition
+    assert_eq!(get_trail_combining_class(0x1e09), Ok(230)); // C + cedilla + acute
+    assert_eq!(get_lead_combining_class(0x1e09), Ok(0)); // C + cedilla + acute
+    assert_eq!(get_trail_combining_class(0x1f87), Ok(240)); // longest decomposition
+    assert_eq!(get_trail_combining_class(0xac01), Ok(0)); // Hangul syllable
+    assert_eq!(get_lead_combining_class(0x200000), Err("invalid char")); // outside Unicode
+
+    assert!(is_fcd("abc"));
+    assert!(is_fcd("\u{1e0d}\u{0307}")); // not NFD, but FCD
+    assert!(is_fcd("a\u{0316}\u{0301}")); // canonically ordered
+    assert!(!is_fcd("a\u{0301}\u{0316}")); // not canonically ordered
+    assert!(!is_fcd("\u{00e1}\u{0316}")); // NFC, but trail class of precomposed character is too high
+    assert!(!is_fcd("\u{1e09}\u{0316}")); // trail class of precomposed character is too high
+    assert!(is_fcd("\u{0301}\u{0301}")); // same classes
+    assert!(is_fcd(""));
+
+    assert_eq!(to_fcc("e\u{0301}"), "\u{00e9}"); // normal case
+    assert_eq!(to_fcc("a\u{0316}\u{0301}"), "a\u{0316}\u{0301}"); // not contiguous, but composed in NFC
+    assert_eq!(to_nfc("a\u{0316}\u{0301}"), "\u{00e1}\u{0316}");
+    assert_eq!(to_fcc("a\u{0301}\u{0316}"), "a\u{0316}\u{0301}"); // reordering prevents composition
+    assert_eq!(to_fcc("a\u{0308}\u{0304}"), "\u{01df}"); // contiguous compositions
+    assert_eq!(to_fcc("\u{1100}\u{1161}\u{11a8}"), "\u{ac01}"); // Hangul Jamo
+    assert!(is_fcd(&to_fcc("\u{1e09}\u{0316}")));
+    let v: Vec<char> = to_fcc_chars("e\u{0301}".chars()).collect();
+    assert_eq!(v, ['\u{00e9}']);
+}
+
+#[test]
+fn test_composition_exclusion() {
+    assert_eq!(is_composition_exclusion(0x0958), Ok(true)); // script-specific
+    assert_eq!(is_composition_exclusion(0x2adc), Ok(true)); // post composition version
+    assert_eq!(is_composition_exclusion(0x1d15e), Ok(true)); // outside BMP
+    assert_eq!(is_composition_exclusion(0x2126), Ok(false)); // singleton
+    assert_eq!(is_composition_exclusion(0x0344), Ok(false)); // non-starter decomposition
+    assert_eq!(is_composition_exclusion(0x00c4), Ok(false)); // primary composite
+    assert_eq!(is_composition_exclusion(0x0041), Ok(false)); // no decomposition
+    assert_eq!(is_composition_exclusion(0x200000), Err("invalid char")); // outside Unicode
+
+    assert_eq!(is_full_composition_exclusion(0x0958), Ok(true));
+    assert_eq!(is_full_composition_exclusion(0x2adc), Ok(true));
+    assert_eq!(is_full_composition_exclusion(0x2126), Ok(true));
+    assert_eq!(is_full_composition_exclusion(0x0344), Ok(true));
+    assert_eq!(is_full_composition_exclusion(0x0f73), Ok(true)); // non-starter decomposition
+    assert_eq!(is_full_composition_exclusion(0x00c4), Ok(false));
+    assert_eq!(is_full_composition_exclusion(0xfb01), Ok(false)); // compatibility decomposition
+    assert_eq!(is_full_composition_exclusion(0xac00), Ok(false)); // Hangul syllable
+    assert_eq!(is_full_composition_exclusion(0x200000), Err("invalid char")); // outside Unicode
+
+    /* pairs that are produced by decomposition, but not recomposed */
+    assert_eq!(decompose(0x2adc), Ok((0x2add, 0x0338)));
+    assert_eq!(compose(0x2add, 0x0338), Err("no composition found"));
+}
+
+#[test]
+fn test_nfkc_casefold() {
+    let mut cmp: [u32; 18] = [0; 18];
+    cmp[0] = 0x0061;
+    assert_eq!(nfkc_casefold(0x0041), Ok((1, cmp))); // normal case
+    cmp[0..2].clone_from_slice(&[0x0073, 0x0073]);
+    assert_eq!(nfkc_casefold(0x00df), Ok((2, cmp))); // full case folding
+    assert_eq!(nfkc_casefold(0x00ad), Ok((0, [0; 18]))); // default ignorable
+    assert_eq!(nfkc_casefold(0x0061), Err("no mapping found")); // no mapping
+    assert_eq!(nfkc_casefold(0x200000), Err("no mapping found")); // outside Unicode
+
+    assert_eq!(changes_when_nfkc_casefolded(0x0041), Ok(true));
+    assert_eq!(changes_when_nfkc_casefolded(0x00c4), Ok(true));
+    assert_eq!(changes_when_nfkc_casefolded(0xfb01), Ok(true)); // compatibility decomposition
+    assert_eq!(changes_when_nfkc_casefolded(0x200b), Ok(true)); // default ignorable
+    assert_eq!(changes_when_nfkc_casefolded(0xe0fff), Ok(true)); // unassigned default ignorable
+    assert_eq!(changes_when_nfkc_casefolded(0x10400), Ok(true)); // outside BMP
+    assert_eq!(changes_when_nfkc_casefolded(0x0061), Ok(false));
+    assert_eq!(changes_when_nfkc_casefolded(0x00e4), Ok(false));
+    assert_eq!(changes_when_nfkc_casefolded(0x200000), Err("invalid char")); // outside Unicode
+
+    assert_eq!(to_nfkc_casefold("Config_Key"), "config_key");
+    assert_eq!(to_nfkc_casefold("Stra\u{00df}e"), "strasse"); // full case folding
+    assert_eq!(to_nfkc_casefold("\u{ff21}\u{ff22}"), "ab"); // compatibility decomposition
+    assert_eq!(to_nfkc_casefold("a\u{00ad}b\u{200b}"), "ab"); // default ignorables are removed
+    assert_eq!(to_nfkc_casefold("A\u{0308}"), "\u{00e4}"); // result is composed
+    assert_eq!(to_nfkc_casefold("\u{2163}"), "iv"); // roman numeral
+    assert_eq!(to_nfkc_casefold("\u{10400}"), "\u{10428}"); // outside BMP
+    assert_eq!(to_nfkc_casefold(""), "");
+}
+
+#[test]
+fn test_concat_nfc() {
+    assert_eq!(concat_nfc("abc", "def"), "abcdef"); // nothing to do
+    assert_eq!(concat_nfc("abe", "\u{0301}"), "ab\u{00e9}"); // composition across the boundary
+    assert_eq!(concat_nfc("\u{1e0b}", "\u{0323}"), "\u{1e0d}\u{0307}"); // reordering across the boundary
+    assert_eq!(concat_nfc("a\u{0316}", "\u{0301}x"), "\u{00e1}\u{0316}x"); // composition across a mark
+    assert_eq!(concat_nfc("\u{1100}", "\u{1161}\u{11a8}"), "\u{ac01}"); // Hangul Jamo
+    assert_eq!(concat_nfc("\u{ac00}", "\u{11a8}"), "\u{ac01}"); // Hangul Jamo
+    assert_eq!(concat_nfc("\u{0301}", "\u{0316}"), "\u{0316}\u{0301}"); // no starter
+    assert_eq!(concat_nfc("", "\u{0301}"), "\u{0301}");
+    assert_eq!(concat_nfc("a", ""), "a");
+
+    let mut s = NfcString::new();
+    s.push_str("Ame");
+    s.push('\u{0301}');
+    s.push_str("lie\u{0300}");
+    assert_eq!(s.as_str(), "Am\u{00e9}li\u{00e8}");
+    s.push_str("\u{1e0b}\u{0323}"); // input that is not NFC
+    assert_eq!(s.as_ref(), "Am\u{00e9}li\u{00e8}\u{1e0d}\u{0307}");
+    assert_eq!(NfcString::from("e\u{0301}").into_string(), "\u{00e9}");
+}
+
+#[test]
+fn test_equivalence() {
+    assert!(canonically_equivalent("abc", "abc")); // identical
+    assert!(canonically_equivalent("\u{00c5}", "A\u{030a}")); // precomposed and decomposed
+    assert!(canonically_equivalent("\u{00c5}", "\u{212b}")); // singleton
+    assert!(canonically_equivalent("a\u{0301}\u{0316}", "a\u{0316}\u{0301}")); // reordering
+    assert!(canonically_equivalent("\u{1e0b}\u{0323}", "\u{1e0d}\u{0307}")); // reordering across characters
+    assert!(canonically_equivalent("\u{0301}\u{0316}x", "\u{0316}\u{0301}x")); // leading combining marks
+    assert!(canonically_equivalent("\u{ac01}", "\u{1100}\u{1161}\u{11a8}")); // Hangul Jamo
+    assert!(canonically_equivalent("", ""));
+    assert!(!canonically_equivalent("a\u{0301}\u{0300}", "a\u{0300}\u{0301}")); // same class, different order
+    assert!(!canonically_equivalent("\u{00c5}", "\u{00e5}")); // case differs
+    assert!(!canonically_equivalent("a\u{0301}", "a")); // different length
+    assert!(!canonically_equivalent("a\u{0301}", "a\u{0301}\u{0316}")); // different length
+    assert!(!canonically_equivalent("\u{fb01}", "fi")); // compatibility equivalent only
+    assert!(!canonically_equivalent("", "a"));
+
+    assert!(compatibility_equivalent("\u{fb01}", "fi")); // ligature
+    assert!(compatibility_equivalent("\u{01c4}", "D\u{017d}")); // recursive decomposition
+    assert!(compatibility_equivalent("\u{1e9b}\u{0323}", "\u{1e69}")); // reordering
+    assert!(compatibility_equivalent("\u{00c5}", "A\u{030a}")); // canonical equivalence
+    assert!(!compatibility_equivalent("\u{00bd}", "1/2")); // different slash
+}
+
+#[test]
+fn test_normalize_buffer() {
+    let mut buf32 = [0u32; 8];
+    assert_eq!(normalize_utf32(NormalizationForm::NFD, "\u{1e0b}\u{0323}".chars(), &mut buf32), Ok(3)); // reordering across characters
+    assert_eq!(&buf32[..3], &[0x64, 0x323, 0x307]);
+    assert_eq!(normalize_utf32(NormalizationForm::NFC, "\u{1e0b}\u{0323}".chars(), &mut buf32), Ok(2));
+    assert_eq!(&buf32[..2], &[0x1e0d, 0x307]);
+    assert_eq!(normalize_utf32(NormalizationForm::NFC, "\u{1100}\u{1161}\u{11a8}".chars(), &mut buf32), Ok(1)); // Hangul Jamo
+    assert_eq!(buf32[0], 0xac01);
+    assert_eq!(normalize_utf32(NormalizationForm::NFC, "a\u{0301}\u{0301}".chars(), &mut buf32), Ok(2)); // blocked mark
+    assert_eq!(&buf32[..2], &[0xe1, 0x301]);
+    assert_eq!(normalize_utf32(NormalizationForm::NFC, "\u{0301}\u{0316}a".chars(), &mut buf32), Ok(3)); // no starter
+    assert_eq!(&buf32[..3], &[0x316, 0x301, 0x61]);
+    assert_eq!(normalize_utf32(NormalizationForm::NFKC, "\u{fb01}".chars(), &mut buf32), Ok(2));
+    assert_eq!(normalize_utf32(NormalizationForm::NFD, "".chars(), &mut buf32), Ok(0));
+
+    let mut buf16 = [0u16; 4];
+    assert_eq!(normalize_utf16(NormalizationForm::NFD, "\u{1d15f}".chars(), &mut buf16), Ok(4)); // surrogate pairs
+    assert_eq!(&buf16, &[0xd834, 0xdd58, 0xd834, 0xdd65]);
+    assert_eq!(normalize_utf16(NormalizationForm::NFD, "\u{1d15f}".chars(), &mut buf16[..3]), Err(4)); // overflow
+    assert_eq!(buf16[2], 0xd834); // pair not split
+
+    let mut buf8 = [0u8; 4];
+    assert_eq!(normalize_utf8(NormalizationForm::NFC, "e\u{0301}x".chars(), &mut buf8), Ok(3));
+    assert_eq!(&buf8[..3], "\u{00e9}x".as_bytes());
+    assert_eq!(normalize_utf8(NormalizationForm::NFD, "\u{00e9}\u{00e9}".chars(), &mut buf8), Err(6)); // overflow
+    assert_eq!(&buf8[..3], "e\u{0301}".as_bytes());
+    assert_eq!(normalize_utf8(NormalizationForm::NFD, "abc".chars(), &mut []), Err(3)); // length query
+}
+
+#[test]
+fn test_hangul() {
+    assert_eq!(get_hangul_syllable_type(0x1100), Ok(HangulSyllableType::L)); // leading consonant
+    assert_eq!(get_hangul_syllable_type(0xa97c), Ok(HangulSyllableType::L)); // extended block
+    assert_eq!(get_hangul_syllable_type(0x11a7), Ok(HangulSyllableType::V)); // vowel
+    assert_eq!(get_hangul_syllable_type(0xd7b0), Ok(HangulSyllableType::V)); // extended block
+    assert_eq!(get_hangul_syllable_type(0x11a8), Ok(HangulSyllableType::T)); // trailing consonant
+    assert_eq!(get_hangul_syllable_type(0xd7fb), Ok(HangulSyllableType::T)); // extended block
+    assert_eq!(get_hangul_syllable_type(0xac00), Ok(HangulSyllableType::LV));
+    assert_eq!(get_hangul_syllable_type(0xac01), Ok(HangulSyllableType::LVT));
+    assert_eq!(get_hangul_syllable_type(0xd7a3), Ok(HangulSyllableType::LVT));
+    assert_eq!(get_hangul_syllable_type(0xd7a4), Ok(HangulSyllableType::NA)); // unassigned
+    assert_eq!(get_hangul_syllable_type(0x3131), Ok(HangulSyllableType::NA)); // compatibility Jamo
+    assert_eq!(get_hangul_syllable_type(0x110000), Err("invalid char"));
+
+    assert_eq!(decompose_hangul_full(0xac00), Ok((0x1100, 0x1161, None))); // LV
+    assert_eq!(decompose_hangul_full(0xac01), Ok((0x1100, 0x1161, Some(0x11a8)))); // LVT
+    assert_eq!(decompose_hangul_full(0xd7a3), Ok((0x1112, 0x1175, Some(0x11c2)))); // LVT
+    assert_eq!(decompose_hangul_full(0x1100), Err("not a Hangul syllable"));
+    assert_eq!(decompose_hangul_full(0xd7a4), Err("not a Hangul syllable"));
+}
+
+#[test]
+fn test_name() {
+    assert_eq!(get_name(0x0041).unwrap(), "LATIN CAPITAL LETTER A");
+    assert_eq!(get_name(0x00e1).unwrap(), "LATIN SMALL LETTER A WITH ACUTE");
+    assert_eq!(get_name(0x1f600).unwrap(), "GRINNING FACE"); // supplementary plane
+    assert_eq!(get_name(0xfe18).unwrap(), "PRESENTATION FORM FOR VERTICAL RIGHT WHITE LENTICULAR BRAKCET"); // misspelled
+    assert_eq!(get_name(0xac00).unwrap(), "HANGUL SYLLABLE GA"); // Hangul syllable (LV)
+    assert_eq!(get_name(0xd7a3).unwrap(), "HANGUL SYLLABLE HIH"); // Hangul syllable (LVT)
+    assert_eq!(get_name(0xc544).unwrap(), "HANGUL SYLLABLE A"); // Hangul syllable with empty L
+    assert_eq!(get_name(0x4e00).unwrap(), "CJK UNIFIED IDEOGRAPH-4E00"); // unified ideograph
+    assert_eq!(get_name(0x2a6d6).unwrap(), "CJK UNIFIED IDEOGRAPH-2A6D6"); // unified ideograph
+    assert_eq!(get_name(0xf900).unwrap(), "CJK COMPATIBILITY IDEOGRAPH-F900"); // listed explicitly
+    assert_eq!(get_name(0x17000).unwrap(), "TANGUT IDEOGRAPH-17000"); // Tangut ideograph
+    assert_eq!(get_name(0x0009).unwrap(), "<control-0009>"); // control
+    assert_eq!(get_name(0xe000).unwrap(), "<private-use-E000>"); // private use
+    assert_eq!(get_name(0xd800).unwrap(), "<surrogate-D800>"); // surrogate
+    assert_eq!(get_name(0xfdd0).unwrap(), "<noncharacter-FDD0>"); // noncharacter
+    assert_eq!(get_name(0x10ffff).unwrap(), "<noncharacter-10FFFF>"); // noncharacter
+    assert_eq!(get_name(0x9fd6).unwrap(), "<reserved-9FD6>"); // unassigned
+    assert_eq!(get_name(0x110000), None); // invalid
+}
+
+#[test]
+fn test_lookup_by_name() {
+    assert_eq!(lookup_by_name("LATIN SMALL LETTER A WITH ACUTE"), Some(0x00e1));
+    assert_eq!(lookup_by_name("latin small letter a with acute"), Some(0x00e1)); // case
+    assert_eq!(lookup_by_name("Latin_Small_Letter_A_With_Acute"), Some(0x00e1)); // underscores
+    assert_eq!(lookup_by_name("LATINSMALLLETTERAWITHACUTE"), Some(0x00e1)); // whitespace
+    assert_eq!(lookup_by_name("GRINNING FACE"), Some(0x1f600)); // supplementary plane
+    assert_eq!(lookup_by_name("ZERO WIDTH NO BREAK SPACE"), Some(0xfeff)); // medial hyphen
+    assert_eq!(lookup_by_name("TIBETAN LETTER -A"), Some(0x0f60)); // non-medial hyphen
+    assert_eq!(lookup_by_name("TIBETAN LETTER A"), Some(0x0f68));
+    assert_eq!(lookup_by_name("HANGUL JUNGSEONG O-E"), Some(0x1180)); // significant medial hyphen
+    assert_eq!(lookup_by_name("HANGUL JUNGSEONG OE"), Some(0x116c));
+    assert_eq!(lookup_by_name("HANGUL SYLLABLE GA"), Some(0xac00)); // Hangul syllable
+    assert_eq!(lookup_by_name("hangul syllable hih"), Some(0xd7a3)); // Hangul syllable
+    assert_eq!(lookup_by_name("HANGUL SYLLABLE A"), Some(0xc544)); // Hangul syllable with empty L
+    assert_eq!(lookup_by_name("CJK UNIFIED IDEOGRAPH-4E00"), Some(0x4e00)); // unified ideograph
+    assert_eq!(lookup_by_name("cjk unified ideograph 2a6d6"), Some(0x2a6d6)); // unified ideograph
+    assert_eq!(lookup_by_name("TANGUT IDEOGRAPH-17000"), Some(0x17000)); // Tangut ideograph
+    assert_eq!(lookup_by_name("CJK UNIFIED IDEOGRAPH-9FD6"), None); // unassigned
+    assert_eq!(lookup_by_name("CJK UNIFIED IDEOGRAPH-04E00"), None); // leading zero
+    assert_eq!(lookup_by_name("HANGUL SYLLABLE GAX"), None);
+    assert_eq!(lookup_by_name("<control-0009>"), None); // label, not a name
+    assert_eq!(lookup_by_name("LATIN SMALL LETTER"), None);
+    assert_eq!(lookup_by_name(""), None);
+}
+
+#[test]
+fn test_name_aliases() {
+    assert_eq!(get_name_aliases(0x01a2), vec![("LATIN CAPITAL LETTER GHA".to_string(), NameAliasType::Correction)]);
+    assert_eq!(get_name_aliases(0x000a), vec![
+        ("LINE FEED".to_string(), NameAliasType::Control),
+        ("NEW LINE".to_string(), NameAliasType::Control),
+        ("END OF LINE".to_string(), NameAliasType::Control),
+        ("LF".to_string(), NameAliasType::Abbreviation),
+        ("NL".to_string(), NameAliasType::Abbreviation),
+        ("EOL".to_string(), NameAliasType::Abbreviation)
+    ]);
+    assert_eq!(get_name_aliases(0xfeff), vec![
+        ("BYTE ORDER MARK".to_string(), NameAliasType::Alternate),
+        ("BOM".to_string(), NameAliasType::Abbreviation),
+        ("ZWNBSP".to_string(), NameAliasType::Abbreviation)
+    ]);
+    assert_eq!(get_name_aliases(0x0081)[0], ("HIGH OCTET PRESET".to_string(), NameAliasType::Figment));
+    assert_eq!(get_name_aliases(0x0041), vec![]); // no aliases
+    assert_eq!(get_name_aliases(0x110000), vec![]); // invalid
+    assert_eq!(get_name(0xf0000).unwrap(), "<private-use-F0000>"); // storage of aliases is hidden
+
+    assert_eq!(lookup_by_name("LATIN CAPITAL LETTER GHA"), Some(0x01a2)); // correction
+    assert_eq!(lookup_by_name("LATIN CAPITAL LETTER OI"), Some(0x01a2)); // original name
+    assert_eq!(lookup_by_name("line feed"), Some(0x000a)); // control
+    assert_eq!(lookup_by_name("BOM"), Some(0xfeff)); // abbreviation
+
+    assert_eq!(lookup_named_sequence("LATIN CAPITAL LETTER A WITH MACRON AND GRAVE"), Some(&[0x0100, 0x0300][..]));
+    assert_eq!(lookup_named_sequence("Latin Small Letter A with Macron and Grave"), Some(&[0x0101, 0x0300][..])); // loose matching
+    assert_eq!(lookup_named_sequence("TAMIL SYLLABLE KSSAU"), Some(&[0x0b95, 0x0bcd, 0x0bb7, 0x0bcc][..])); // longest sequence
+    assert_eq!(lookup_named_sequence("LATIN CAPITAL LETTER A"), None); // single codepoint
+    assert_eq!(lookup_by_name("LATIN CAPITAL LETTER A WITH MACRON AND GRAVE"), None); // not a codepoint
+}
+
+#[test]
+fn test_name_search() {
+    let results = name_search("arrow double left");
+    assert_eq!(results[0], 0x21d4); // LEFT RIGHT DOUBLE ARROW, exact word match
+    assert!(results.contains(&0x21d0)); // LEFTWARDS DOUBLE ARROW, prefix match
+    assert!(!results.contains(&0x2190)); // LEFTWARDS ARROW, not double
+    assert_eq!(name_search("latin small a acute")[0], 0x00e1); // LATIN SMALL LETTER A WITH ACUTE
+    assert_eq!(name_search("Grinning_Face")[0], 0x1f600); // underscores, case
+    assert_eq!(name_search("bom"), vec![0xfeff, 0x1f4a3]); // name alias BOM, then BOMB
+    assert_eq!(name_search("A")[0], 0x07ca); // exact matches first
+    assert_eq!(name_search("nonexistentword"), vec![]);
+    assert_eq!(name_search(""), vec![]);
+
+    let results = name_search_filtered("letter a", &[GeneralCategory::LL], &[Script::GREEK]);
+    assert_eq!(results[0], 0x03b1); // GREEK SMALL LETTER ALPHA
+    assert!(results.iter().all(|&c| get_script(c) == Ok(Script::GREEK) && get_general_category(c) == Ok(GeneralCategory::LL)));
+    assert_eq!(name_search_filtered("digit seven", &[GeneralCategory::ND], &[]).len(), 58);
+}
+
+#[test]
+fn test_numeric() {
+    assert_eq!(get_numeric_type(0x0037), Ok(NumericType::Decimal)); // DIGIT SEVEN
+    assert_eq!(get_numeric_type(0x0669), Ok(NumericType::Decimal)); // ARABIC-INDIC DIGIT NINE
+    assert_eq!(get_numeric_type(0x00b2), Ok(NumericType::Digit)); // SUPERSCRIPT TWO
+    assert_eq!(get_numeric_type(0x2460), Ok(NumericType::Digit)); // CIRCLED DIGIT ONE
+    assert_eq!(get_numeric_type(0x00bd), Ok(NumericType::Numeric)); // VULGAR FRACTION ONE HALF
+    assert_eq!(get_numeric_type(0x216b), Ok(NumericType::Numeric)); // ROMAN NUMERAL TWELVE
+    assert_eq!(get_numeric_type(0x4e09), Ok(NumericType::Numeric)); // CJK ideograph three
+    assert_eq!(get_numeric_type(0x0041), Ok(NumericType::None)); // LATIN CAPITAL LETTER A
+    assert_eq!(get_numeric_type(0x110000), Err("invalid char"));
+
+    assert_eq!(get_numeric_value(0x0037), Ok((7, 1)));
+    assert_eq!(get_numeric_value(0x0669), Ok((9, 1)));
+    assert_eq!(get_numeric_value(0x00b2), Ok((2, 1)));
+    assert_eq!(get_numeric_value(0x00bd), Ok((1, 2)));
+    assert_eq!(get_numeric_value(0x2153), Ok((1, 3))); // VULGAR FRACTION ONE THIRD
+    assert_eq!(get_numeric_value(0x0f33), Ok((-1, 2))); // TIBETAN DIGIT HALF ZERO
+    assert_eq!(get_numeric_value(0x216b), Ok((12, 1)));
+    assert_eq!(get_numeric_value(0x4e09), Ok((3, 1)));
+    assert_eq!(get_numeric_value(0x5146), Ok((1000000000000, 1))); // CJK ideograph trillion
+    assert_eq!(get_numeric_value(0x12432), Ok((216000, 1))); // CUNEIFORM NUMERIC SIGN SHAR2 TIMES GAL PLUS DISH
+    assert_eq!(get_numeric_value(0x0041), Err("no numeric value"));
+    assert_eq!(get_numeric_value(0x110000), Err("invalid char"));
+}
+
+#[test]
+fn test_simple_case() {
+    assert_eq!(to_upper(0x0061), Ok(0x0041)); // a -> A
+    assert_eq!(to_upper(0x0041), Ok(0x0041)); // A unchanged
+    assert_eq!(to_upper(0x00df), Ok(0x00df)); // sharp s has no simple uppercase
+    assert_eq!(to_upper(0x01c6), Ok(0x01c4)); // dz with caron -> DZ with caron
+    assert_eq!(to_upper(0x1f80), Ok(0x1f88)); // expands only in full mapping
+    assert_eq!(to_upper(0x13f8), Ok(0x13f0)); // Cherokee
+    assert_eq!(to_upper(0x10d0), Ok(0x10d0)); // Georgian Mkhedruli has no uppercase in 9.0
+    assert_eq!(to_upper(0x1e922), Ok(0x1e900)); // Adlam
+    assert_eq!(to_upper(0x0031), Ok(0x0031)); // digit one
+    assert_eq!(to_upper(0x110000), Err("invalid char"));
+
+    assert_eq!(to_lower(0x0041), Ok(0x0061));
+    assert_eq!(to_lower(0x0130), Ok(0x0069)); // I with dot above -> i
+    assert_eq!(to_lower(0x01c5), Ok(0x01c6)); // Dz with caron -> dz with caron
+    assert_eq!(to_lower(0x2126), Ok(0x03c9)); // OHM SIGN -> omega
+    assert_eq!(to_lower(0x104b0), Ok(0x104d8)); // Osage
+    assert_eq!(to_lower(0x110000), Err("invalid char"));
+
+    assert_eq!(to_title(0x01c6), Ok(0x01c5)); // dz with caron -> Dz with caron
+    assert_eq!(to_title(0x01c4), Ok(0x01c5)); // DZ with caron -> Dz with caron
+    assert_eq!(to_title(0x0061), Ok(0x0041));
+    assert_eq!(to_title(0x1f80), Ok(0x1f88));
+    assert_eq!(to_title(0x110000), Err("invalid char"));
+}
+
+#[test]
+fn test_full_case() {
+    assert_eq!(to_uppercase("straße"), "STRASSE");
+    assert_eq!(to_uppercase("\u{149}"), "\u{2bc}N"); // apostrophe n
+    assert_eq!(to_uppercase("\u{fb03}"), "FFI"); // ligature ffi
+    assert_eq!(to_uppercase("\u{1f80}"), "\u{1f08}\u{399}"); // alpha with psili and ypogegrammeni
+    assert_eq!(to_uppercase("abc123"), "ABC123");
+    assert_eq!(to_lowercase("ABC123"), "abc123");
+    assert_eq!(to_lowercase("\u{130}"), "i\u{307}"); // I with dot above
+    assert_eq!(to_lowercase(""), "");
+
+    // Final_Sigma
+    assert_eq!(to_lowercase("ΟΔΟΣ"), "οδος");
+    assert_eq!(to_lowercase("ΟΔΟΣ ΣΑ"), "οδος σα");
+    assert_eq!(to_lowercase("ΑΣ."), "ας.");
+    assert_eq!(to_lowercase("ΑΣ'Α"), "ασ'α"); // apostrophe is case-ignorable
+    assert_eq!(to_lowercase("Α'Σ"), "α'ς");
+    assert_eq!(to_lowercase("Σ"), "σ");
+    assert_eq!(to_lowercase("1Σ"), "1σ");
+
+    // Turkish and Azeri
+    assert_eq!(to_lowercase_tailored("\u{130}I", CaseTailoring::Turkic), "iı");
+    assert_eq!(to_lowercase_tailored("I\u{307}", CaseTailoring::Turkic), "i"); // After_I, Before_Dot
+    assert_eq!(to_lowercase_tailored("I\u{316}\u{307}", CaseTailoring::Turkic), "i\u{316}");
+    assert_eq!(to_lowercase_tailored("I\u{301}\u{307}", CaseTailoring::Turkic), "ı\u{301}\u{307}"); // blocked by ccc 230
+    assert_eq!(to_uppercase_tailored("iı", CaseTailoring::Turkic), "\u{130}I");
+    assert_eq!(to_uppercase("iı"), "II");
+
+    // Lithuanian
+    assert_eq!(to_lowercase_tailored("I\u{300}", CaseTailoring::Lithuanian), "i\u{307}\u{300}"); // More_Above
+    assert_eq!(to_lowercase_tailored("I\u{328}", CaseTailoring::Lithuanian), "i\u{328}"); // ogonek is not above
+    assert_eq!(to_lowercase_tailored("\u{cc}", CaseTailoring::Lithuanian), "i\u{307}\u{300}");
+    assert_eq!(to_lowercase("\u{cc}"), "\u{ec}");
+    assert_eq!(to_uppercase_tailored("i\u{307}", CaseTailoring::Lithuanian), "I"); // After_Soft_Dotted
+    assert_eq!(to_uppercase_tailored("i\u{316}\u{307}", CaseTailoring::Lithuanian), "I\u{316}");
+    assert_eq!(to_uppercase_tailored("a\u{307}", CaseTailoring::Lithuanian), "A\u{307}");
+
+    // the string is a single word
+    assert_eq!(to_titlecase("\u{1c6}EMAL"), "\u{1c5}emal"); // dz with caron
+    assert_eq!(to_titlecase("ßa"), "Ssa");
+    assert_eq!(to_titlecase("\u{fb02}OUR"), "Flour"); // ligature fl
+    assert_eq!(to_titlecase("1ABC"), "1Abc"); // first cased character
+    assert_eq!(to_titlecase_tailored("istanbul", CaseTailoring::Turkic), "\u{130}stanbul");
+}
+
+#[test]
+fn test_case_folding() {
+    assert_eq!(fold_case_simple(0x0041), Ok(0x0061)); // A -> a
+    assert_eq!(fold_case_simple(0x0061), Ok(0x0061));
+    assert_eq!(fold_case_simple(0x00df), Ok(0x00df)); // sharp s has no simple folding
+    assert_eq!(fold_case_simple(0x1e9e), Ok(0x00df)); // capital sharp s
+    assert_eq!(fold_case_simple(0x03c2), Ok(0x03c3)); // final sigma
+    assert_eq!(fold_case_simple(0x13f8), Ok(0x13f0)); // Cherokee folds to uppercase
+    assert_eq!(fold_case_simple(0x0130), Ok(0x0130)); // I with dot above only has a full folding
+    assert_eq!(fold_case_simple(0x0049), Ok(0x0069));
+    assert_eq!(fold_case_simple(0x110000), Err("invalid char"));
+    assert_eq!(fold_case_simple_tailored(0x0049, CaseTailoring::Turkic), Ok(0x0131));
+    assert_eq!(fold_case_simple_tailored(0x0130, CaseTailoring::Turkic), Ok(0x0069));
+    assert_eq!(fold_case_simple_tailored(0x0041, CaseTailoring::Turkic), Ok(0x0061));
+    assert_eq!(fold_case_simple_tailored(0x0049, CaseTailoring::Lithuanian), Ok(0x0069));
+
+    assert_eq!(fold_case_full("Straße"), "strasse");
+    assert_eq!(fold_case_full("STRASSE"), "strasse");
+    assert_eq!(fold_case_full("ΌΣΟΣ όσος"), "όσοσ όσοσ");
+    assert_eq!(fold_case_full("\u{130}"), "i\u{307}");
+    assert_eq!(fold_case_full("\u{fb03}"), "ffi"); // ligature ffi
+    assert_eq!(fold_case_full("\u{1f88}"), "\u{1f00}\u{3b9}"); // alpha with psili and prosgegrammeni
+    assert_eq!(fold_case_full("\u{13f8}\u{ab70}"), "\u{13f0}\u{13a0}"); // Cherokee
+    assert_eq!(fold_case_full(""), "");
+    assert_eq!(fold_case_full_tailored("\u{130}I", CaseTailoring::Turkic), "iı");
+    assert_eq!(fold_case_full_tailored("\u{130}I", CaseTailoring::None), "i\u{307}i");
+}
+
+#[test]
+fn test_caseless_eq() {
+    assert!(caseless_eq("Straße", "STRASSE"));
+    assert!(caseless_eq("\u{c5}", "\u{e5}")); // A with ring above
+    assert!(!caseless_eq("\u{c5}", "A\u{30a}")); // not normalized
+    assert!(!caseless_eq("a", "b"));
+    assert!(caseless_eq("", ""));
+
+    assert!(canonical_caseless_eq("\u{c5}", "\u{e5}"));
+    assert!(canonical_caseless_eq("\u{c5}", "A\u{30a}"));
+    assert!(canonical_caseless_eq("\u{e5}", "A\u{30a}"));
+    assert!(canonical_caseless_eq("\u{212b}", "a\u{30a}")); // ANGSTROM SIGN
+    assert!(canonical_caseless_eq("\u{3b1}\u{345}\u{313}", "\u{1f00}\u{3b9}")); // needs the inner NFD
+    assert!(!canonical_caseless_eq("\u{c5}", "A"));
+    assert!(!canonical_caseless_eq("\u{3392}", "MHz")); // SQUARE MHZ
+
+    assert!(compatibility_caseless_eq("\u{3392}", "MHz"));
+    assert!(compatibility_caseless_eq("\u{c5}", "a\u{30a}"));
+    assert!(compatibility_caseless_eq("\u{2167}", "viii")); // ROMAN NUMERAL EIGHT
+    assert!(compatibility_caseless_eq("\u{37a}", " \u{3b9}")); // GREEK YPOGEGRAMMENI needs the second folding
+    assert!(!compatibility_caseless_eq("\u{3392}", "MH"));
+}
+
+#[test]
+fn test_titlecase_words() {
+    assert_eq!(to_titlecase("hello world"), "Hello World");
+    assert_eq!(to_titlecase("HELLO WORLD"), "Hello World");
+    assert_eq!(to_titlecase("don't stop"), "Don't Stop"); // apostrophe inside a word
+    assert_eq!(to_titlecase("l\u{2019}amour"), "L\u{2019}amour"); // RIGHT SINGLE QUOTATION MARK
+    assert_eq!(to_titlecase("'quoted' text"), "'Quoted' Text");
+    assert_eq!(to_titlecase("jean-luc o'neil"), "Jean-Luc O'neil");
+    assert_eq!(to_titlecase("snake_case word"), "Snake_case Word");
+    assert_eq!(to_titlecase("3rd place"), "3Rd Place"); // first cased character of the word
+    assert_eq!(to_titlecase("e\u{301}COLE \u{1f00}\u{345}ΘΗΝΑ"), "E\u{301}cole \u{1f08}\u{345}θηνα"); // combining marks
+    assert_eq!(to_titlecase("ΟΔΟΣ ΟΔΟΣ"), "Οδος Οδος"); // final sigma
+    assert_eq!(to_titlecase("\u{1c6}emal \u{1c4}EMAL"), "\u{1c5}emal \u{1c5}emal"); // dz with caron
+    assert_eq!(to_titlecase("\u{fb01}sh \u{df}"), "Fish Ss"); // ligature fi, sharp s
+    assert_eq!(to_titlecase("  "), "  ");
+    assert_eq!(to_titlecase(""), "");
+
+    assert_eq!(to_titlecase("ijsland"), "Ijsland");
+    assert_eq!(to_titlecase_tailored("ijsland IJMUIDEN", CaseTailoring::Dutch), "IJsland IJmuiden");
+    assert_eq!(to_titlecase_tailored("in ij", CaseTailoring::Dutch), "In IJ");
+    assert_eq!(to_titlecase_tailored("istanbul ılık", CaseTailoring::Turkic), "\u{130}stanbul Ilık");
+    assert_eq!(to_titlecase_tailored("X\u{cc}", CaseTailoring::Lithuanian), "Xi\u{307}\u{300}");
+}
+
+#[test]
+fn test_case_properties() {
+    assert_eq!(is_cased(0x0041), Ok(true));
+    assert_eq!(is_cased(0x01c5), Ok(true)); // Dz with caron, titlecase letter
+    assert_eq!(is_cased(0x02b0), Ok(true)); // MODIFIER LETTER SMALL H
+    assert_eq!(is_cased(0x24b6), Ok(true)); // CIRCLED LATIN CAPITAL LETTER A
+    assert_eq!(is_cased(0x0031), Ok(false));
+    assert_eq!(is_cased(0x05d0), Ok(false)); // HEBREW LETTER ALEF
+    assert_eq!(is_cased(0x110000), Err("invalid char"));
+
+    assert_eq!(is_case_ignorable(0x0027), Ok(true)); // APOSTROPHE
+    assert_eq!(is_case_ignorable(0x2019), Ok(true)); // RIGHT SINGLE QUOTATION MARK
+    assert_eq!(is_case_ignorable(0x0301), Ok(true)); // COMBINING ACUTE ACCENT
+    assert_eq!(is_case_ignorable(0x00ad), Ok(true)); // SOFT HYPHEN
+    assert_eq!(is_case_ignorable(0x02b0), Ok(true)); // cased as well
+    assert_eq!(is_case_ignorable(0x0041), Ok(false));
+    assert_eq!(is_case_ignorable(0x0020), Ok(false));
+    assert_eq!(is_case_ignorable(0x110000), Err("invalid char"));
+
+    assert_eq!(changes_when_lowercased(0x0041), Ok(true));
+    assert_eq!(changes_when_lowercased(0x0061), Ok(false));
+    assert_eq!(changes_when_lowercased(0x01c5), Ok(true));
+    assert_eq!(changes_when_lowercased(0x212b), Ok(true)); // ANGSTROM SIGN
+
+    assert_eq!(changes_when_uppercased(0x0061), Ok(true));
+    assert_eq!(changes_when_uppercased(0x00df), Ok(true)); // sharp s
+    assert_eq!(changes_when_uppercased(0x0041), Ok(false));
+    assert_eq!(changes_when_uppercased(0x01c5), Ok(true));
+    assert_eq!(changes_when_uppercased(0x0149), Ok(true)); // apostrophe n
+
+    assert_eq!(changes_when_titlecased(0x01c5), Ok(false));
+    assert_eq!(changes_when_titlecased(0x01c6), Ok(true));
+    assert_eq!(changes_when_titlecased(0x0041), Ok(false));
+    assert_eq!(changes_when_titlecased(0x1f88), Ok(false)); // titlecase letter
+    assert_eq!(changes_when_titlecased(0x1f80), Ok(true));
+
+    assert_eq!(changes_when_casefolded(0x0041), Ok(true));
+    assert_eq!(changes_when_casefolded(0x0061), Ok(false));
+    assert_eq!(changes_when_casefolded(0x00df), Ok(true));
+    assert_eq!(changes_when_casefolded(0x03c2), Ok(true)); // final sigma
+    assert_eq!(changes_when_casefolded(0x1e9e), Ok(true)); // capital sharp s
+
+    assert_eq!(changes_when_casemapped(0x0061), Ok(true));
+    assert_eq!(changes_when_casemapped(0x0041), Ok(true));
+    assert_eq!(changes_when_casemapped(0x02b0), Ok(false));
+    assert_eq!(changes_when_casemapped(0x0031), Ok(false));
+    assert_eq!(changes_when_casemapped(0xac00), Ok(false)); // Hangul syllable
+    assert_eq!(changes_when_casemapped(0x110000), Err("invalid char"));
+}
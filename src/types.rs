@@ -36,6 +36,78 @@ pub enum BracketType {
     None = 2
 }
 
+#[derive(Copy, Clone, PartialEq, Debug)] #[repr(u8)]
+pub enum QuickCheck {
+    Yes = 0,
+    Maybe = 1,
+    No = 2
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)] #[repr(u8)]
+pub enum NormalizationForm {
+    NFD = 0,
+    NFKD = 1,
+    NFC = 2,
+    NFKC = 3
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)] #[repr(u8)]
+pub enum DecompositionType {
+    Canonical = 0,
+    NoBreak = 1,
+    Compat = 2,
+    Super = 3,
+    Fraction = 4,
+    Sub = 5,
+    Font = 6,
+    Circle = 7,
+    Wide = 8,
+    Vertical = 9,
+    Square = 10,
+    Isolated = 11,
+    Final = 12,
+    Initial = 13,
+    Medial = 14,
+    Small = 15,
+    Narrow = 16,
+    None = 17
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)] #[repr(u8)]
+pub enum HangulSyllableType {
+    L = 0,
+    V = 1,
+    T = 2,
+    LV = 3,
+    LVT = 4,
+    NA = 5
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)] #[repr(u8)]
+pub enum NameAliasType {
+    Correction = 0,
+    Control = 1,
+    Alternate = 2,
+    Figment = 3,
+    Abbreviation = 4
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)] #[repr(u8)]
+pub enum NumericType {
+    Decimal = 0,
+    Digit = 1,
+    Numeric = 2,
+    None = 3
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)] #[repr(u8)]
+pub enum CaseTailoring {
+    None = 0,
+    Turkic = 1,
+    Lithuanian = 2,
+    Dutch = 3
+}
+
 #[derive(Copy, Clone, PartialEq, Debug)] #[repr(u8)]
 pub enum LinebreakClass {
     OP = 0,
@@ -147,4 +219,37 @@ impl TryFrom<u8> for BracketType {
             Err("invalid variant")
         }
     }
+}
+
+impl TryFrom<u8> for QuickCheck {
+    type Error = &'static str;
+    fn try_from(t: u8) -> Result<QuickCheck, &'static str> {
+        if 0 as u8 <= t && t <= QuickCheck::No as u8 {
+            unsafe { Ok(transmute(t)) }
+        } else {
+            Err("invalid variant")
+        }
+    }
+}
+
+impl TryFrom<u8> for DecompositionType {
+    type Error = &'static str;
+    fn try_from(t: u8) -> Result<DecompositionType, &'static str> {
+        if 0 as u8 <= t && t <= DecompositionType::None as u8 {
+            unsafe { Ok(transmute(t)) }
+        } else {
+            Err("invalid variant")
+        }
+    }
+}
+
+impl TryFrom<u8> for NameAliasType {
+    type Error = &'static str;
+    fn try_from(t: u8) -> Result<NameAliasType, &'static str> {
+        if 0 as u8 <= t && t <= NameAliasType::Abbreviation as u8 {
+            unsafe { Ok(transmute(t)) }
+        } else {
+            Err("invalid variant")
+        }
+    }
 }
\ No newline at end of file